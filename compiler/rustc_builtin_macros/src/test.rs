@@ -5,13 +5,20 @@
 use rustc_ast as ast;
 use rustc_ast::attr;
 use rustc_ast::ptr::P;
+use rustc_ast::token;
 use rustc_ast_pretty::pprust;
+use rustc_errors::PResult;
 use rustc_expand::base::*;
+use rustc_feature::AttributeTemplate;
+use rustc_parse::validate_attr;
+use rustc_session::parse::feature_err;
 use rustc_session::Session;
 use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::Span;
 
+use std::convert::TryFrom;
 use std::iter;
+use std::mem;
 
 // #[test_case] is used by custom test authors to mark tests
 // When building for test, it needs to make the item public and gensym the name
@@ -55,7 +62,7 @@ pub fn expand_test(
     item: Annotatable,
 ) -> Vec<Annotatable> {
     check_builtin_macro_attribute(cx, meta_item, sym::test);
-    expand_test_or_bench(cx, attr_sp, item, false)
+    expand_test_or_bench(cx, attr_sp, item, TestKind::Test)
 }
 
 pub fn expand_bench(
@@ -65,20 +72,83 @@ pub fn expand_bench(
     item: Annotatable,
 ) -> Vec<Annotatable> {
     check_builtin_macro_attribute(cx, meta_item, sym::bench);
-    expand_test_or_bench(cx, attr_sp, item, true)
+    expand_test_or_bench(cx, attr_sp, item, TestKind::Bench)
 }
 
-pub fn expand_test_or_bench(
+pub fn expand_test_setup(
+    cx: &mut ExtCtxt<'_>,
+    attr_sp: Span,
+    meta_item: &ast::MetaItem,
+    item: Annotatable,
+) -> Vec<Annotatable> {
+    let kind = if runs_around_each_test(cx, meta_item, sym::test_setup) {
+        "SetupEach"
+    } else {
+        "SetupOnce"
+    };
+    expand_test_or_bench(cx, attr_sp, item, TestKind::Fixture(kind))
+}
+
+pub fn expand_test_teardown(
+    cx: &mut ExtCtxt<'_>,
+    attr_sp: Span,
+    meta_item: &ast::MetaItem,
+    item: Annotatable,
+) -> Vec<Annotatable> {
+    let kind = if runs_around_each_test(cx, meta_item, sym::test_teardown) {
+        "TeardownEach"
+    } else {
+        "TeardownOnce"
+    };
+    expand_test_or_bench(cx, attr_sp, item, TestKind::Fixture(kind))
+}
+
+/// Checks the input of `#[test_setup]` and `#[test_teardown]`, and returns whether
+/// the function runs around each test (`(each)`) rather than once.
+fn runs_around_each_test(cx: &ExtCtxt<'_>, meta_item: &ast::MetaItem, name: Symbol) -> bool {
+    let template = AttributeTemplate { word: true, list: Some("each"), ..Default::default() };
+    let attr = cx.attribute(meta_item.clone());
+    validate_attr::check_builtin_attribute(&cx.sess.parse_sess, &attr, name, template);
+
+    match meta_item.meta_item_list() {
+        None => false,
+        Some([nmi]) if nmi.is_word() && nmi.has_name(sym::each) => true,
+        Some(_) => {
+            cx.struct_span_err(meta_item.span, &format!("malformed `{}` attribute input", name))
+                .help(&format!(
+                    "use `#[{0}]` to run the function once, \
+                     or `#[{0}(each)]` to run it around each test",
+                    name
+                ))
+                .emit();
+            false
+        }
+    }
+}
+
+/// What a function marked with one of the test attributes is turned into.
+#[derive(Clone, Copy, PartialEq)]
+enum TestKind {
+    Test,
+    Bench,
+    /// A `#[test_setup]` or `#[test_teardown]` function, with the name of its
+    /// `test::FixtureKind`.
+    Fixture(&'static str),
+}
+
+fn expand_test_or_bench(
     cx: &mut ExtCtxt<'_>,
     attr_sp: Span,
     item: Annotatable,
-    is_bench: bool,
+    kind: TestKind,
 ) -> Vec<Annotatable> {
     // If we're not in test configuration, remove the annotated item
     if !cx.ecfg.should_test {
         return vec![];
     }
 
+    let is_bench = kind == TestKind::Bench;
+
     let (item, is_stmt) = match item {
         Annotatable::Item(i) => (i, false),
         Annotatable::Stmt(stmt) if matches!(stmt.kind, ast::StmtKind::Item(_)) => {
@@ -107,15 +177,59 @@ pub fn expand_test_or_bench(
         return vec![Annotatable::Item(item)];
     }
 
+    // `#[test_params]` attributes are taken off the item here, as their arguments are
+    // expressions, which the builtin attribute checks would reject as malformed.
+    let mut item = item;
+    let params_attrs = take_attrs(&mut item, sym::test_params);
+    // `#[timeout]` and `#[flaky]` are not builtin attributes, which would clash with
+    // attribute macros of the same names. They are only taken off the item when their
+    // feature is enabled, and otherwise left to whatever attribute macro is in scope.
+    let features = cx.ecfg.features;
+    let timeout_attrs = if features.map_or(false, |features| features.test_timeout) {
+        take_attrs(&mut item, sym::timeout)
+    } else {
+        Vec::new()
+    };
+    let flaky_attrs = if features.map_or(false, |features| features.test_flaky) {
+        take_attrs(&mut item, sym::flaky)
+    } else {
+        Vec::new()
+    };
+
+    if let Some(attr) = params_attrs.first() {
+        if !features.map_or(false, |features| features.test_params) {
+            feature_err(
+                &cx.sess.parse_sess,
+                sym::test_params,
+                attr.span,
+                "the `#[test_params]` attribute is an experimental feature",
+            )
+            .emit();
+        }
+        if kind != TestKind::Test {
+            cx.struct_span_err(
+                attr.span,
+                "`#[test_params]` can only be used on `#[test]` functions",
+            )
+            .emit();
+            return vec![Annotatable::Item(item)];
+        }
+    }
+
     // has_*_signature will report any errors in the type so compilation
     // will fail. We shouldn't try to expand in this case because the errors
     // would be spurious.
-    if (!is_bench && !has_test_signature(cx, &item))
+    if (!is_bench && !has_test_signature(cx, &item, !params_attrs.is_empty()))
         || (is_bench && !has_bench_signature(cx, &item))
     {
         return vec![Annotatable::Item(item)];
     }
 
+    let cases = match test_cases(cx, &item, &params_attrs) {
+        Some(cases) => cases,
+        None => return vec![Annotatable::Item(item)],
+    };
+
     let (sp, attr_sp) = (cx.with_def_site_ctxt(item.span), cx.with_def_site_ctxt(attr_sp));
 
     let test_id = Ident::new(sym::test, attr_sp);
@@ -147,190 +261,279 @@ pub fn expand_test_or_bench(
         )
     };
 
+    // creates test::FixtureKind::$name
+    let fixture_kind_path = |name| {
+        cx.path(
+            sp,
+            vec![
+                test_id,
+                Ident::from_str_and_span("FixtureKind", sp),
+                Ident::from_str_and_span(name, sp),
+            ],
+        )
+    };
+
     // creates $name: $expr
     let field = |name, expr| cx.field_imm(sp, Ident::from_str_and_span(name, sp), expr);
 
-    let test_fn = if is_bench {
-        // A simple ident for a lambda
-        let b = Ident::from_str_and_span("b", attr_sp);
-
-        cx.expr_call(
+    // creates || test::assert_test_result($test_fn($args...))
+    let run_test = |args| {
+        cx.lambda0(
             sp,
-            cx.expr_path(test_path("StaticBenchFn")),
-            vec![
-                // |b| self::test::assert_test_result(
-                cx.lambda1(
-                    sp,
-                    cx.expr_call(
-                        sp,
-                        cx.expr_path(test_path("assert_test_result")),
-                        vec![
-                            // super::$test_fn(b)
-                            cx.expr_call(
-                                sp,
-                                cx.expr_path(cx.path(sp, vec![item.ident])),
-                                vec![cx.expr_ident(sp, b)],
-                            ),
-                        ],
-                    ),
-                    b,
-                ), // )
-            ],
+            cx.expr_call(
+                sp,
+                cx.expr_path(test_path("assert_test_result")),
+                vec![cx.expr_call(sp, cx.expr_path(cx.path(sp, vec![item.ident])), args)],
+            ),
         )
-    } else {
-        cx.expr_call(
+    };
+
+    // creates test::StaticTestFn(...) | test::StaticBenchFn(...) | test::StaticFixtureFn(...)
+    // calling the test with `args`
+    let test_fn = |args: Vec<P<ast::Expr>>| match kind {
+        TestKind::Bench => {
+            // A simple ident for a lambda
+            let b = Ident::from_str_and_span("b", attr_sp);
+
+            cx.expr_call(
+                sp,
+                cx.expr_path(test_path("StaticBenchFn")),
+                vec![
+                    // |b| self::test::assert_test_result(
+                    cx.lambda1(
+                        sp,
+                        cx.expr_call(
+                            sp,
+                            cx.expr_path(test_path("assert_test_result")),
+                            vec![
+                                // super::$test_fn(b)
+                                cx.expr_call(
+                                    sp,
+                                    cx.expr_path(cx.path(sp, vec![item.ident])),
+                                    vec![cx.expr_ident(sp, b)],
+                                ),
+                            ],
+                        ),
+                        b,
+                    ), // )
+                ],
+            )
+        }
+        TestKind::Test => {
+            cx.expr_call(sp, cx.expr_path(test_path("StaticTestFn")), vec![run_test(args)])
+        }
+        TestKind::Fixture(fixture_kind) => cx.expr_call(
             sp,
-            cx.expr_path(test_path("StaticTestFn")),
+            cx.expr_path(test_path("StaticFixtureFn")),
+            vec![cx.expr_path(fixture_kind_path(fixture_kind)), run_test(args)],
+        ),
+    };
+
+    let timeout = timeout_attrs.first().and_then(|attr| test_timeout(cx, attr));
+    let retries = flaky_attrs.first().and_then(|attr| test_retries(cx, attr, kind));
+    let should_panic = should_panic(cx, &item);
+    let test_type = test_type(cx);
+    let allow_fail = should_fail(&cx.sess, &item);
+    let ignore = should_ignore(&cx.sess, &item);
+    // skip the name of the root module
+    let path = item_path(&cx.current_expansion.module.mod_path[1..], &item.ident);
+
+    let test_const = |ident: Ident, name: &str, ignore: bool, test_fn: P<ast::Expr>| {
+        cx.item(
+            sp,
+            ident,
             vec![
-                // || {
-                cx.lambda0(
-                    sp,
-                    // test::assert_test_result(
-                    cx.expr_call(
+                // #[cfg(test)]
+                cx.attribute(attr::mk_list_item(
+                    Ident::new(sym::cfg, attr_sp),
+                    vec![attr::mk_nested_word_item(Ident::new(sym::test, attr_sp))],
+                )),
+                // #[rustc_test_marker]
+                cx.attribute(cx.meta_word(attr_sp, sym::rustc_test_marker)),
+            ],
+            // const $ident: test::TestDescAndFn =
+            ast::ItemKind::Const(
+                ast::Defaultness::Final,
+                cx.ty(sp, ast::TyKind::Path(None, test_path("TestDescAndFn"))),
+                // test::TestDescAndFn {
+                Some(
+                    cx.expr_struct(
                         sp,
-                        cx.expr_path(test_path("assert_test_result")),
+                        test_path("TestDescAndFn"),
                         vec![
-                            // $test_fn()
-                            cx.expr_call(sp, cx.expr_path(cx.path(sp, vec![item.ident])), vec![]), // )
-                        ],
-                    ), // }
-                ), // )
-            ],
-        )
-    };
-
-    let mut test_const = cx.item(
-        sp,
-        Ident::new(item.ident.name, sp),
-        vec![
-            // #[cfg(test)]
-            cx.attribute(attr::mk_list_item(
-                Ident::new(sym::cfg, attr_sp),
-                vec![attr::mk_nested_word_item(Ident::new(sym::test, attr_sp))],
-            )),
-            // #[rustc_test_marker]
-            cx.attribute(cx.meta_word(attr_sp, sym::rustc_test_marker)),
-        ],
-        // const $ident: test::TestDescAndFn =
-        ast::ItemKind::Const(
-            ast::Defaultness::Final,
-            cx.ty(sp, ast::TyKind::Path(None, test_path("TestDescAndFn"))),
-            // test::TestDescAndFn {
-            Some(
-                cx.expr_struct(
-                    sp,
-                    test_path("TestDescAndFn"),
-                    vec![
-                        // desc: test::TestDesc {
-                        field(
-                            "desc",
-                            cx.expr_struct(
-                                sp,
-                                test_path("TestDesc"),
-                                vec![
-                                    // name: "path::to::test"
-                                    field(
-                                        "name",
-                                        cx.expr_call(
-                                            sp,
-                                            cx.expr_path(test_path("StaticTestName")),
-                                            vec![cx.expr_str(
+                            // desc: test::TestDesc {
+                            field(
+                                "desc",
+                                cx.expr_struct(
+                                    sp,
+                                    test_path("TestDesc"),
+                                    vec![
+                                        // name: "path::to::test"
+                                        field(
+                                            "name",
+                                            cx.expr_call(
                                                 sp,
-                                                Symbol::intern(&item_path(
-                                                    // skip the name of the root module
-                                                    &cx.current_expansion.module.mod_path[1..],
-                                                    &item.ident,
+                                                cx.expr_path(test_path("StaticTestName")),
+                                                vec![cx.expr_str(sp, Symbol::intern(name))],
+                                            ),
+                                        ),
+                                        // ignore: true | false
+                                        field("ignore", cx.expr_bool(sp, ignore)),
+                                        // allow_fail: true | false
+                                        field("allow_fail", cx.expr_bool(sp, allow_fail)),
+                                        // timeout: ...
+                                        field(
+                                            "timeout",
+                                            match timeout {
+                                                // Some(Duration::from_millis(...))
+                                                Some(millis) => cx.expr_some(
+                                                    sp,
+                                                    cx.expr_call_global(
+                                                        sp,
+                                                        cx.std_path(&[
+                                                            sym::time,
+                                                            sym::Duration,
+                                                            sym::from_millis,
+                                                        ]),
+                                                        vec![cx.expr_lit(
+                                                            sp,
+                                                            ast::LitKind::Int(
+                                                                millis.into(),
+                                                                ast::LitIntType::Unsigned(
+                                                                    ast::UintTy::U64,
+                                                                ),
+                                                            ),
+                                                        )],
+                                                    ),
+                                                ),
+                                                // None
+                                                None => cx.expr_path(cx.path_global(
+                                                    sp,
+                                                    cx.std_path(&[
+                                                        sym::option,
+                                                        sym::Option,
+                                                        sym::None,
+                                                    ]),
                                                 )),
-                                            )],
+                                            },
                                         ),
-                                    ),
-                                    // ignore: true | false
-                                    field(
-                                        "ignore",
-                                        cx.expr_bool(sp, should_ignore(&cx.sess, &item)),
-                                    ),
-                                    // allow_fail: true | false
-                                    field(
-                                        "allow_fail",
-                                        cx.expr_bool(sp, should_fail(&cx.sess, &item)),
-                                    ),
-                                    // should_panic: ...
-                                    field(
-                                        "should_panic",
-                                        match should_panic(cx, &item) {
-                                            // test::ShouldPanic::No
-                                            ShouldPanic::No => {
-                                                cx.expr_path(should_panic_path("No"))
-                                            }
-                                            // test::ShouldPanic::Yes
-                                            ShouldPanic::Yes(None) => {
-                                                cx.expr_path(should_panic_path("Yes"))
-                                            }
-                                            // test::ShouldPanic::YesWithMessage("...")
-                                            ShouldPanic::Yes(Some(sym)) => cx.expr_call(
-                                                sp,
-                                                cx.expr_path(should_panic_path("YesWithMessage")),
-                                                vec![cx.expr_str(sp, sym)],
-                                            ),
-                                        },
-                                    ),
-                                    // test_type: ...
-                                    field(
-                                        "test_type",
-                                        match test_type(cx) {
-                                            // test::TestType::UnitTest
-                                            TestType::UnitTest => {
-                                                cx.expr_path(test_type_path("UnitTest"))
-                                            }
-                                            // test::TestType::IntegrationTest
-                                            TestType::IntegrationTest => {
-                                                cx.expr_path(test_type_path("IntegrationTest"))
-                                            }
-                                            // test::TestPath::Unknown
-                                            TestType::Unknown => {
-                                                cx.expr_path(test_type_path("Unknown"))
-                                            }
-                                        },
-                                    ),
-                                    // },
-                                ],
+                                        // retries: ...
+                                        field(
+                                            "retries",
+                                            match retries {
+                                                // Some(...)
+                                                Some(retries) => {
+                                                    cx.expr_some(sp, cx.expr_usize(sp, retries))
+                                                }
+                                                // None
+                                                None => cx.expr_path(cx.path_global(
+                                                    sp,
+                                                    cx.std_path(&[
+                                                        sym::option,
+                                                        sym::Option,
+                                                        sym::None,
+                                                    ]),
+                                                )),
+                                            },
+                                        ),
+                                        // should_panic: ...
+                                        field(
+                                            "should_panic",
+                                            match should_panic {
+                                                // test::ShouldPanic::No
+                                                ShouldPanic::No => {
+                                                    cx.expr_path(should_panic_path("No"))
+                                                }
+                                                // test::ShouldPanic::Yes
+                                                ShouldPanic::Yes(None) => {
+                                                    cx.expr_path(should_panic_path("Yes"))
+                                                }
+                                                // test::ShouldPanic::YesWithMessage("...")
+                                                ShouldPanic::Yes(Some(sym)) => cx.expr_call(
+                                                    sp,
+                                                    cx.expr_path(should_panic_path(
+                                                        "YesWithMessage",
+                                                    )),
+                                                    vec![cx.expr_str(sp, sym)],
+                                                ),
+                                            },
+                                        ),
+                                        // test_type: ...
+                                        field(
+                                            "test_type",
+                                            match test_type {
+                                                // test::TestType::UnitTest
+                                                TestType::UnitTest => {
+                                                    cx.expr_path(test_type_path("UnitTest"))
+                                                }
+                                                // test::TestType::IntegrationTest
+                                                TestType::IntegrationTest => {
+                                                    cx.expr_path(test_type_path("IntegrationTest"))
+                                                }
+                                                // test::TestPath::Unknown
+                                                TestType::Unknown => {
+                                                    cx.expr_path(test_type_path("Unknown"))
+                                                }
+                                            },
+                                        ),
+                                        // },
+                                    ],
+                                ),
                             ),
-                        ),
-                        // testfn: test::StaticTestFn(...) | test::StaticBenchFn(...)
-                        field("testfn", test_fn), // }
-                    ],
-                ), // }
+                            // testfn: test::StaticTestFn(...) | test::StaticBenchFn(...)
+                            field("testfn", test_fn), // }
+                        ],
+                    ), // }
+                ),
             ),
-        ),
-    );
-    test_const = test_const.map(|mut tc| {
-        tc.vis.kind = ast::VisibilityKind::Public;
-        tc
-    });
+        )
+        .map(|mut tc| {
+            tc.vis.kind = ast::VisibilityKind::Public;
+            tc
+        })
+    };
+
+    let test_consts = if cases.is_empty() {
+        vec![test_const(Ident::new(item.ident.name, sp), &path, ignore, test_fn(vec![]))]
+    } else {
+        // One test per case, named `path::to::test::case_N_args`
+        cases
+            .into_iter()
+            .enumerate()
+            .map(|(i, case)| {
+                let ident =
+                    Ident::from_str_and_span(&format!("{}_case_{}", item.ident.name, i + 1), sp);
+                let name = format!("{}::{}", path, case_name(i, &case.args));
+                test_const(ident, &name, ignore || case.ignore, test_fn(case.args))
+            })
+            .collect()
+    };
 
     // extern crate test
     let test_extern = cx.item(sp, test_id, vec![], ast::ItemKind::ExternCrate(None));
 
-    tracing::debug!("synthetic test item:\n{}\n", pprust::item_to_string(&test_const));
+    for test_const in &test_consts {
+        tracing::debug!("synthetic test item:\n{}\n", pprust::item_to_string(test_const));
+    }
 
     if is_stmt {
-        vec![
-            // Access to libtest under a hygienic name
-            Annotatable::Stmt(P(cx.stmt_item(sp, test_extern))),
-            // The generated test case
-            Annotatable::Stmt(P(cx.stmt_item(sp, test_const))),
+        // Access to libtest under a hygienic name
+        iter::once(test_extern)
+            // The generated test cases
+            .chain(test_consts)
             // The original item
-            Annotatable::Stmt(P(cx.stmt_item(sp, item))),
-        ]
+            .chain(iter::once(item))
+            .map(|item| Annotatable::Stmt(P(cx.stmt_item(sp, item))))
+            .collect()
     } else {
-        vec![
-            // Access to libtest under a hygienic name
-            Annotatable::Item(test_extern),
-            // The generated test case
-            Annotatable::Item(test_const),
+        // Access to libtest under a hygienic name
+        iter::once(test_extern)
+            // The generated test cases
+            .chain(test_consts)
             // The original item
-            Annotatable::Item(item),
-        ]
+            .chain(iter::once(item))
+            .map(Annotatable::Item)
+            .collect()
     }
 }
 
@@ -343,11 +546,108 @@ fn item_path(mod_path: &[Ident], item_ident: &Ident) -> String {
         .join("::")
 }
 
+/// Names the `index`th case of a parameterized test after its position and its
+/// arguments, keeping only the words and numbers of the latter, e.g.
+/// `case_3_i32_MAX_Sign_Positive` for `#[test_params(i32::MAX, Sign::Positive)]`.
+fn case_name(index: usize, args: &[P<ast::Expr>]) -> String {
+    let mut name = format!("case_{}", index + 1);
+    for arg in args {
+        let arg = pprust::expr_to_string(arg);
+        for word in arg.split(|c: char| !c.is_ascii_alphanumeric() && c != '_') {
+            if !word.is_empty() {
+                name.push('_');
+                name.push_str(word);
+            }
+        }
+    }
+    name
+}
+
 enum ShouldPanic {
     No,
     Yes(Option<Symbol>),
 }
 
+/// One set of arguments of a parameterized test, as given by `#[test_params(...)]`.
+struct TestCase {
+    args: Vec<P<ast::Expr>>,
+    ignore: bool,
+}
+
+/// Returns the cases of a parameterized test, or `None` if any of them is invalid.
+fn test_cases(cx: &ExtCtxt<'_>, i: &ast::Item, attrs: &[ast::Attribute]) -> Option<Vec<TestCase>> {
+    let arity = match i.kind {
+        ast::ItemKind::Fn(_, ref sig, _, _) => sig.decl.inputs.len(),
+        _ => 0,
+    };
+
+    let mut cases = Vec::new();
+    let mut valid = true;
+    for attr in attrs {
+        match parse_test_case(cx, attr) {
+            Ok(case) if case.args.len() == arity => cases.push(case),
+            Ok(case) => {
+                let s = |n| if n == 1 { "" } else { "s" };
+                cx.struct_span_err(
+                    attr.span,
+                    &format!(
+                        "this test case has {} argument{} but `{}` takes {} argument{}",
+                        case.args.len(),
+                        s(case.args.len()),
+                        i.ident,
+                        arity,
+                        s(arity),
+                    ),
+                )
+                .emit();
+                valid = false;
+            }
+            Err(mut err) => {
+                err.emit();
+                valid = false;
+            }
+        }
+    }
+    if valid { Some(cases) } else { None }
+}
+
+/// Parses `#[test_params(arg1, arg2, ...)]`, optionally followed by `; ignore` to ignore
+/// only this case.
+fn parse_test_case<'a>(cx: &ExtCtxt<'a>, attr: &ast::Attribute) -> PResult<'a, TestCase> {
+    let tokens = match attr.get_normal_item().args {
+        ast::MacArgs::Delimited(_, ast::MacDelimiter::Parenthesis, ref tokens) => tokens.clone(),
+        _ => {
+            let mut err = cx.struct_span_err(attr.span, "malformed `test_params` attribute input");
+            err.help(
+                "the arguments of the test must be given as `#[test_params(arg1, arg2, ...)]`",
+            );
+            return Err(err);
+        }
+    };
+
+    let mut parser = cx.new_parser_from_tts(tokens);
+    let mut args = Vec::new();
+    while parser.token != token::Eof && parser.token != token::Semi {
+        args.push(parser.parse_expr()?);
+        if !parser.eat(&token::Comma) {
+            break;
+        }
+    }
+
+    let ignore = parser.eat(&token::Semi);
+    if ignore {
+        if !parser.token.is_ident_named(sym::ignore) {
+            return Err(parser.struct_span_err(parser.token.span, "expected `ignore`"));
+        }
+        parser.bump();
+    }
+
+    if parser.token != token::Eof {
+        return parser.unexpected();
+    }
+    Ok(TestCase { args, ignore })
+}
+
 fn should_ignore(sess: &Session, i: &ast::Item) -> bool {
     sess.contains_name(&i.attrs, sym::ignore)
 }
@@ -356,6 +656,79 @@ fn should_fail(sess: &Session, i: &ast::Item) -> bool {
     sess.contains_name(&i.attrs, sym::allow_fail)
 }
 
+/// Takes the attributes called `name` off the item and returns them.
+fn take_attrs(item: &mut ast::Item, name: Symbol) -> Vec<ast::Attribute> {
+    let (taken, attrs) =
+        mem::take(&mut item.attrs).into_iter().partition(|attr| attr.has_name(name));
+    item.attrs = attrs;
+    taken
+}
+
+/// Returns the time limit of a test in milliseconds, as set by `#[timeout = "30s"]`.
+fn test_timeout(cx: &ExtCtxt<'_>, attr: &ast::Attribute) -> Option<u64> {
+    let value = match attr.value_str() {
+        Some(value) => value,
+        None => {
+            cx.struct_span_err(attr.span, "malformed `timeout` attribute input")
+                .help("the timeout must be given as a string, e.g. `#[timeout = \"30s\"]`")
+                .emit();
+            return None;
+        }
+    };
+
+    let timeout = parse_timeout(&value.as_str());
+    if timeout.is_none() {
+        cx.sess
+            .parse_sess
+            .span_diagnostic
+            .struct_span_err(attr.span, &format!("invalid test timeout `{}`", value))
+            .help("the timeout must be a number followed by `ms`, `s` or `m`, e.g. `30s`")
+            .emit();
+    }
+    timeout
+}
+
+/// Parses a timeout such as `500ms`, `30s` or `2m` into milliseconds. This accepts
+/// the same syntax as the `--test-timeout` option of libtest.
+fn parse_timeout(timeout: &str) -> Option<u64> {
+    let unit_start = timeout.find(|c: char| !c.is_ascii_digit()).unwrap_or(timeout.len());
+    let (value, unit) = timeout.split_at(unit_start);
+    let value: u64 = value.parse().ok()?;
+
+    match unit {
+        "ms" => Some(value),
+        "s" => value.checked_mul(1000),
+        "m" => value.checked_mul(60 * 1000),
+        _ => None,
+    }
+}
+
+/// Returns how many times a failing test is rerun, as set by `#[flaky(retries = 3)]`.
+fn test_retries(cx: &ExtCtxt<'_>, attr: &ast::Attribute, kind: TestKind) -> Option<usize> {
+    if kind != TestKind::Test {
+        cx.struct_span_err(attr.span, "`#[flaky]` can only be used on `#[test]` functions").emit();
+        return None;
+    }
+
+    let retries = match attr.meta_item_list().as_deref() {
+        Some([nmi]) if nmi.has_name(sym::retries) => {
+            match nmi.meta_item().and_then(|mi| mi.name_value_literal()).map(|lit| &lit.kind) {
+                Some(ast::LitKind::Int(retries, ast::LitIntType::Unsuffixed)) => {
+                    usize::try_from(*retries).ok()
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    if retries.is_none() {
+        cx.struct_span_err(attr.span, "malformed `flaky` attribute input")
+            .help("the number of retries must be given as an integer, e.g. `#[flaky(retries = 3)]`")
+            .emit();
+    }
+    retries
+}
+
 fn should_panic(cx: &ExtCtxt<'_>, i: &ast::Item) -> ShouldPanic {
     match cx.sess.find_by_name(&i.attrs, sym::should_panic) {
         Some(attr) => {
@@ -422,7 +795,7 @@ fn test_type(cx: &ExtCtxt<'_>) -> TestType {
     }
 }
 
-fn has_test_signature(cx: &ExtCtxt<'_>, i: &ast::Item) -> bool {
+fn has_test_signature(cx: &ExtCtxt<'_>, i: &ast::Item, has_params: bool) -> bool {
     let has_should_panic_attr = cx.sess.contains_name(&i.attrs, sym::should_panic);
     let sd = &cx.sess.parse_sess.span_diagnostic;
     if let ast::ItemKind::Fn(_, ref sig, ref generics, _) = i.kind {
@@ -447,7 +820,7 @@ fn has_test_signature(cx: &ExtCtxt<'_>, i: &ast::Item) -> bool {
             _ => true,
         };
 
-        if !sig.decl.inputs.is_empty() {
+        if !sig.decl.inputs.is_empty() && !has_params {
             sd.span_err(i.span, "functions used as tests can not have any arguments");
             return false;
         }
@@ -634,6 +634,15 @@ pub fn set(&self, features: &mut Features, span: Span) {
 
     /// Allows using garbage collection.
     (active, bronze_gc, "1.51.0", None, None),
+
+    /// Allows setting a time limit for a test with `#[timeout = "30s"]`.
+    (active, test_timeout, "1.51.0", None, None),
+
+    /// Allows running a test once per set of arguments given with `#[test_params(...)]`.
+    (active, test_params, "1.51.0", None, None),
+
+    /// Allows rerunning a failing test with `#[flaky(retries = N)]`.
+    (active, test_flaky, "1.51.0", None, None),
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
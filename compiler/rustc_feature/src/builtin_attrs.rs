@@ -318,6 +318,7 @@ macro_rules! experimental {
 
     // Testing:
     gated!(allow_fail, Normal, template!(Word), experimental!(allow_fail)),
+    gated!(test_params, Normal, template!(List: "arg1, arg2, ..."), experimental!(test_params)),
     gated!(
         test_runner, CrateLevel, template!(List: "path"), custom_test_frameworks,
         "custom test frameworks are an unstable feature",
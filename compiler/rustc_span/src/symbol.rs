@@ -136,6 +136,7 @@
         Decodable,
         Decoder,
         Default,
+        Duration,
         Encodable,
         Encoder,
         Eq,
@@ -473,6 +474,7 @@
         dropck_parametricity,
         dylib,
         dyn_trait,
+        each,
         edition_macro_pats,
         eh_catch_typeinfo,
         eh_personality,
@@ -532,6 +534,7 @@
         fill,
         finish,
         flags,
+        flaky,
         float_to_int_unchecked,
         floorf32,
         floorf64,
@@ -558,6 +561,7 @@
         from_error,
         from_generator,
         from_method,
+        from_millis,
         from_ok,
         from_size_align_unchecked,
         from_trait,
@@ -914,6 +918,7 @@
         repr_transparent,
         result,
         result_type,
+        retries,
         rhs,
         rintf32,
         rintf64,
@@ -1144,11 +1149,18 @@
         test_2018_feature,
         test_accepted_feature,
         test_case,
+        test_flaky,
+        test_params,
         test_removed_feature,
         test_runner,
+        test_setup,
+        test_teardown,
+        test_timeout,
         then_with,
         thread,
         thread_local,
+        time,
+        timeout,
         tool_attributes,
         tool_lints,
         trace_macros,
@@ -50,6 +50,15 @@
 //! The [`alloc`](alloc/index.html) module defines the low-level interface to the
 //! default global allocator. It is not compatible with the libc allocator API.
 //!
+//! ## Building without infallible allocation
+//!
+//! When compiled with `--cfg no_global_oom_handling`, every API that may call
+//! [`handle_alloc_error`](alloc::handle_alloc_error) on allocation failure is
+//! removed, leaving only the fallible `try_*` family (such as
+//! [`Vec::try_push`](vec::Vec::try_push) and [`Box::try_new`](boxed::Box::try_new)).
+//! This is intended for environments like kernels where aborting on OOM is not
+//! an option.
+//!
 //! [`Arc`]: sync
 //! [`Box`]: boxed
 //! [`Cell`]: core::cell
@@ -84,6 +93,7 @@
 #![feature(arbitrary_self_types)]
 #![feature(box_patterns)]
 #![feature(box_syntax)]
+#![feature(byte_pattern)]
 #![feature(cfg_sanitize)]
 #![feature(cfg_target_has_atomic)]
 #![feature(coerce_unsized)]
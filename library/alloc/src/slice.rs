@@ -83,11 +83,19 @@
 #![cfg_attr(test, allow(unused_imports, dead_code))]
 
 use core::borrow::{Borrow, BorrowMut};
+#[cfg(not(no_global_oom_handling))]
 use core::cmp::Ordering::{self, Less};
+#[cfg(not(no_global_oom_handling))]
 use core::mem::{self, size_of};
+#[cfg(not(no_global_oom_handling))]
 use core::ptr;
+#[cfg(all(not(no_global_oom_handling), not(test)))]
+use core::str::pattern::Pattern;
 
-use crate::alloc::{Allocator, Global};
+use crate::alloc::Allocator;
+#[cfg(not(no_global_oom_handling))]
+use crate::alloc::Global;
+#[cfg(not(no_global_oom_handling))]
 use crate::borrow::ToOwned;
 use crate::boxed::Box;
 use crate::vec::Vec;
@@ -120,6 +128,10 @@
 pub use core::slice::{RSplit, RSplitMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{RSplitN, RSplitNMut, SplitN, SplitNMut};
+#[unstable(feature = "byte_pattern", issue = "none")]
+pub use core::slice::{MatchIndices, Matches, RMatchIndices, RMatches};
+#[unstable(feature = "byte_pattern", issue = "none")]
+pub use core::slice::{RSplitNPattern, RSplitPattern, SplitNPattern, SplitPattern};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Basic slice extension methods
@@ -132,7 +144,7 @@
 
 // HACK(japaric) needed for the implementation of `Vec::clone` during testing
 // N.B., see the `hack` module in this file for more details.
-#[cfg(test)]
+#[cfg(all(not(no_global_oom_handling), test))]
 pub use hack::to_vec;
 
 // HACK(japaric): With cfg(test) `impl [T]` is not available, these three
@@ -156,17 +168,20 @@ pub fn into_vec<T, A: Allocator>(b: Box<[T], A>) -> Vec<T, A> {
         }
     }
 
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     pub fn to_vec<T: ConvertVec, A: Allocator>(s: &[T], alloc: A) -> Vec<T, A> {
         T::to_vec(s, alloc)
     }
 
+    #[cfg(not(no_global_oom_handling))]
     pub trait ConvertVec {
         fn to_vec<A: Allocator>(s: &[Self], alloc: A) -> Vec<Self, A>
         where
             Self: Sized;
     }
 
+    #[cfg(not(no_global_oom_handling))]
     impl<T: Clone> ConvertVec for T {
         #[inline]
         default fn to_vec<A: Allocator>(s: &[Self], alloc: A) -> Vec<Self, A> {
@@ -203,6 +218,7 @@ fn drop(&mut self) {
         }
     }
 
+    #[cfg(not(no_global_oom_handling))]
     impl<T: Copy> ConvertVec for T {
         #[inline]
         fn to_vec<A: Allocator>(s: &[Self], alloc: A) -> Vec<Self, A> {
@@ -248,6 +264,7 @@ impl<T> [T] {
     /// v.sort();
     /// assert!(v == [-5, -3, 1, 2, 4]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[inline]
     pub fn sort(&mut self)
@@ -302,6 +319,7 @@ pub fn sort(&mut self)
     /// v.sort_by(|a, b| b.cmp(a));
     /// assert!(v == [5, 4, 3, 2, 1]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[inline]
     pub fn sort_by<F>(&mut self, mut compare: F)
@@ -342,6 +360,7 @@ pub fn sort_by<F>(&mut self, mut compare: F)
     /// v.sort_by_key(|k| k.abs());
     /// assert!(v == [1, 2, -3, 4, -5]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "slice_sort_by_key", since = "1.7.0")]
     #[inline]
     pub fn sort_by_key<K, F>(&mut self, mut f: F)
@@ -384,6 +403,7 @@ pub fn sort_by_key<K, F>(&mut self, mut f: F)
     /// ```
     ///
     /// [pdqsort]: https://github.com/orlp/pdqsort
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "slice_sort_by_cached_key", since = "1.34.0")]
     #[inline]
     pub fn sort_by_cached_key<K, F>(&mut self, f: F)
@@ -441,6 +461,7 @@ macro_rules! sort_by_key {
     /// let x = s.to_vec();
     /// // Here, `s` and `x` can be modified independently.
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[rustc_conversion_suggestion]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[inline]
@@ -464,6 +485,7 @@ pub fn to_vec(&self) -> Vec<T>
     /// let x = s.to_vec_in(System);
     /// // Here, `s` and `x` can be modified independently.
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[unstable(feature = "allocator_api", issue = "32838")]
     pub fn to_vec_in<A: Allocator>(&self, alloc: A) -> Vec<T, A>
@@ -515,6 +537,7 @@ pub fn into_vec<A: Allocator>(self: Box<Self, A>) -> Vec<T, A> {
     /// // this will panic at runtime
     /// b"0123456789abcdef".repeat(usize::MAX);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "repeat_generic_slice", since = "1.40.0")]
     pub fn repeat(&self, n: usize) -> Vec<T>
     where
@@ -582,6 +605,7 @@ pub fn repeat(&self, n: usize) -> Vec<T>
     /// assert_eq!(["hello", "world"].concat(), "helloworld");
     /// assert_eq!([[1, 2], [3, 4]].concat(), [1, 2, 3, 4]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn concat<Item: ?Sized>(&self) -> <Self as Concat<Item>>::Output
     where
@@ -600,6 +624,7 @@ pub fn concat<Item: ?Sized>(&self) -> <Self as Concat<Item>>::Output
     /// assert_eq!([[1, 2], [3, 4]].join(&0), [1, 2, 0, 3, 4]);
     /// assert_eq!([[1, 2], [3, 4]].join(&[0, 0][..]), [1, 2, 0, 0, 3, 4]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rename_connect_to_join", since = "1.3.0")]
     pub fn join<Separator>(&self, sep: Separator) -> <Self as Join<Separator>>::Output
     where
@@ -618,6 +643,7 @@ pub fn join<Separator>(&self, sep: Separator) -> <Self as Join<Separator>>::Outp
     /// assert_eq!(["hello", "world"].connect(" "), "hello world");
     /// assert_eq!([[1, 2], [3, 4]].connect(&0), [1, 2, 0, 3, 4]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_deprecated(since = "1.3.0", reason = "renamed to join")]
     pub fn connect<Separator>(&self, sep: Separator) -> <Self as Join<Separator>>::Output
@@ -640,6 +666,7 @@ impl [u8] {
     /// To uppercase the value in-place, use [`make_ascii_uppercase`].
     ///
     /// [`make_ascii_uppercase`]: u8::make_ascii_uppercase
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "ascii_methods_on_intrinsics", since = "1.23.0")]
     #[inline]
     pub fn to_ascii_uppercase(&self) -> Vec<u8> {
@@ -657,6 +684,7 @@ pub fn to_ascii_uppercase(&self) -> Vec<u8> {
     /// To lowercase the value in-place, use [`make_ascii_lowercase`].
     ///
     /// [`make_ascii_lowercase`]: u8::make_ascii_lowercase
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "ascii_methods_on_intrinsics", since = "1.23.0")]
     #[inline]
     pub fn to_ascii_lowercase(&self) -> Vec<u8> {
@@ -664,6 +692,74 @@ pub fn to_ascii_lowercase(&self) -> Vec<u8> {
         me.make_ascii_lowercase();
         me
     }
+
+    /// Replaces all matches of a pattern with another byte slice.
+    ///
+    /// `replace` creates a new [`Vec`], and copies the data from this slice into it.
+    /// While doing so, it attempts to find matches of a pattern. If it finds any, it
+    /// replaces them with the replacement slice.
+    ///
+    /// The [pattern] can be a [`u8`], a `&[u8]`, a `&[u8; N]`, or a function or
+    /// closure that determines if a byte matches.
+    ///
+    /// [pattern]: core::str::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let s = b"this is old";
+    ///
+    /// assert_eq!(b"this is new", &s.replace(b"old", b"new")[..]);
+    /// assert_eq!(b"this_is_old", &s.replace(b' ', b"_")[..]);
+    /// assert_eq!(s, &s.replace(b"cookie monster", b"little lamb")[..]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[must_use = "this returns the replaced slice as a new allocation, \
+                  without modifying the original"]
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn replace<'a, P: Pattern<'a, [u8]>>(&'a self, from: P, to: &[u8]) -> Vec<u8> {
+        self.replacen(from, to, usize::MAX)
+    }
+
+    /// Replaces the first `count` matches of a pattern with another byte slice.
+    ///
+    /// `replacen` creates a new [`Vec`], and copies the data from this slice into it.
+    /// While doing so, it attempts to find matches of a pattern. If it finds any, it
+    /// replaces them with the replacement slice at most `count` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let s = b"foo foo 123 foo";
+    ///
+    /// assert_eq!(b"new new 123 foo", &s.replacen(b"foo", b"new", 2)[..]);
+    /// assert_eq!(b"faa fao 123 foo", &s.replacen(b'o', b"a", 3)[..]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[must_use = "this returns the replaced slice as a new allocation, \
+                  without modifying the original"]
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    pub fn replacen<'a, P: Pattern<'a, [u8]>>(
+        &'a self,
+        pat: P,
+        to: &[u8],
+        count: usize,
+    ) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.len());
+        let mut last_end = 0;
+        for (start, part) in self.match_indices(pat).take(count) {
+            result.extend_from_slice(&self[last_end..start]);
+            result.extend_from_slice(to);
+            last_end = start + part.len();
+        }
+        result.extend_from_slice(&self[last_end..]);
+        result
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -722,6 +818,7 @@ pub trait Join<Separator> {
     fn join(slice: &Self, sep: Separator) -> Self::Output;
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[unstable(feature = "slice_concat_ext", issue = "27747")]
 impl<T: Clone, V: Borrow<[T]>> Concat<T> for [V] {
     type Output = Vec<T>;
@@ -736,6 +833,7 @@ fn concat(slice: &Self) -> Vec<T> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[unstable(feature = "slice_concat_ext", issue = "27747")]
 impl<T: Clone, V: Borrow<[T]>> Join<&T> for [V] {
     type Output = Vec<T>;
@@ -758,6 +856,7 @@ fn join(slice: &Self, sep: &T) -> Vec<T> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[unstable(feature = "slice_concat_ext", issue = "27747")]
 impl<T: Clone, V: Borrow<[T]>> Join<&[T]> for [V] {
     type Output = Vec<T>;
@@ -799,6 +898,7 @@ fn borrow_mut(&mut self) -> &mut [T] {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: Clone> ToOwned for [T] {
     type Owned = Vec<T>;
@@ -833,6 +933,7 @@ fn clone_into(&self, target: &mut Vec<T>) {
 /// Inserts `v[0]` into pre-sorted sequence `v[1..]` so that whole `v[..]` becomes sorted.
 ///
 /// This is the integral subroutine of insertion sort.
+#[cfg(not(no_global_oom_handling))]
 fn insert_head<T, F>(v: &mut [T], is_less: &mut F)
 where
     F: FnMut(&T, &T) -> bool,
@@ -904,6 +1005,7 @@ fn drop(&mut self) {
 ///
 /// The two slices must be non-empty and `mid` must be in bounds. Buffer `buf` must be long enough
 /// to hold a copy of the shorter slice. Also, `T` must not be a zero-sized type.
+#[cfg(not(no_global_oom_handling))]
 unsafe fn merge<T, F>(v: &mut [T], mid: usize, buf: *mut T, is_less: &mut F)
 where
     F: FnMut(&T, &T) -> bool,
@@ -1024,6 +1126,7 @@ fn drop(&mut self) {
 /// 2. for every `i` in `2..runs.len()`: `runs[i - 2].len > runs[i - 1].len + runs[i].len`
 ///
 /// The invariants ensure that the total running time is *O*(*n* \* log(*n*)) worst-case.
+#[cfg(not(no_global_oom_handling))]
 fn merge_sort<T, F>(v: &mut [T], mut is_less: F)
 where
     F: FnMut(&T, &T) -> bool,
@@ -48,6 +48,12 @@ fn trait_object() {
     assert!(b.upgrade().is_none());
 }
 
+#[test]
+fn try_pin() {
+    let x = Arc::try_pin(5).unwrap();
+    assert_eq!(*x, 5);
+}
+
 #[test]
 fn float_nan_ne() {
     let x = Arc::new(f32::NAN);
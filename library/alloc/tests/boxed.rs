@@ -18,6 +18,21 @@ fn unitialized_zero_size_box() {
     );
 }
 
+#[test]
+fn try_new_uninit_slice() {
+    let mut values = Box::<[u32]>::try_new_uninit_slice(3).unwrap();
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = MaybeUninit::new(i as u32);
+    }
+    let values = unsafe { values.assume_init() };
+    assert_eq!(*values, [0, 1, 2]);
+
+    let zeroes = Box::<[u32]>::try_new_zeroed_slice(3).unwrap();
+    assert_eq!(*unsafe { zeroes.assume_init() }, [0, 0, 0]);
+
+    assert!(Box::<[u64]>::try_new_uninit_slice(usize::MAX).is_err());
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 struct Dummy {
     _data: u8,
@@ -1956,3 +1956,18 @@ fn test_group_by_mut() {
     assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
     assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_byte_pattern_replace() {
+    let s = b"a\xffb\xff\xffc";
+    assert_eq!(s.replace(b'\xff', b"--"), b"a--b----c");
+    assert_eq!(s.replace(b"\xff\xff", b""), b"a\xffbc");
+    assert_eq!(s.replace(|b: u8| b.is_ascii(), b"."), b".\xff.\xff\xff.");
+    assert_eq!(s.replace(b"d", b"e"), s);
+    assert_eq!(b"ab".replace(b"", b"-"), b"-a-b-");
+    assert_eq!(b"".replace(b"a", b"b"), b"");
+
+    assert_eq!(s.replacen(b'\xff', b"-", 2), b"a-b-\xffc");
+    assert_eq!(s.replacen(b'\xff', b"-", 0), s);
+    assert_eq!(b"aaa".replacen(&b"a"[..], b"bb", 5), b"bbbbbb");
+}
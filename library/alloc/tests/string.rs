@@ -812,6 +812,35 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_with_capacity() {
+    let s = String::try_with_capacity(10).unwrap();
+    assert_eq!(s.len(), 0);
+    assert!(s.capacity() >= 10);
+
+    assert!(String::try_with_capacity(usize::MAX).is_err());
+}
+
+#[test]
+fn test_try_push() {
+    let mut s = String::new();
+    s.try_push('a').unwrap();
+    s.try_push('é').unwrap();
+    s.try_push('ꙮ').unwrap();
+    assert_eq!(s, "aéꙮ");
+}
+
+#[test]
+fn test_try_push_str() {
+    let mut s = String::new();
+    s.try_push_str("").unwrap();
+    assert_eq!(&s[0..], "");
+    s.try_push_str("abc").unwrap();
+    assert_eq!(&s[0..], "abc");
+    s.try_push_str("ประเทศไทย中华Việt Nam").unwrap();
+    assert_eq!(&s[0..], "abcประเทศไทย中华Việt Nam");
+}
+
 #[test]
 fn test_from_char() {
     assert_eq!(String::from('a'), 'a'.to_string());
@@ -1539,6 +1539,50 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_with_capacity() {
+    let v: Vec<u32> = Vec::try_with_capacity(10).unwrap();
+    assert_eq!(v.len(), 0);
+    assert_eq!(v.capacity(), 10);
+
+    let zst: Vec<()> = Vec::try_with_capacity(usize::MAX).unwrap();
+    assert_eq!(zst.capacity(), usize::MAX);
+
+    if let Err(CapacityOverflow) = Vec::<u16>::try_with_capacity(usize::MAX) {
+    } else {
+        panic!("usize::MAX should trigger an overflow!")
+    }
+}
+
+#[test]
+fn test_try_push() {
+    let mut v = Vec::new();
+    for i in 0..100 {
+        v.try_push(i).unwrap();
+    }
+    assert_eq!(v, (0..100).collect::<Vec<_>>());
+
+    // Pushing into a full vector of ZSTs overflows the length.
+    let mut zst: Vec<()> = Vec::new();
+    unsafe { zst.set_len(usize::MAX) };
+    if let Err(CapacityOverflow) = zst.try_push(()) {
+    } else {
+        panic!("pushing past usize::MAX elements should trigger an overflow!")
+    }
+    assert_eq!(zst.len(), usize::MAX);
+}
+
+#[test]
+fn test_try_extend_from_slice() {
+    let mut v = vec![1, 2, 3];
+    v.try_extend_from_slice(&[4, 5, 6]).unwrap();
+    assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+
+    let mut strings = vec![String::from("a")];
+    strings.try_extend_from_slice(&[String::from("b"), String::from("c")]).unwrap();
+    assert_eq!(strings, ["a", "b", "c"]);
+}
+
 #[test]
 fn test_stable_pointers() {
     /// Pull an element from the iterator, then drop it.
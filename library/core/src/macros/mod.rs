@@ -1341,6 +1341,32 @@ macro_rules! trace_macros {
         /* compiler built-in */
     }
 
+    /// Attribute macro applied to a function to run it before the tests: once with
+    /// `#[test_setup]`, or before each test with `#[test_setup(each)]`.
+    #[unstable(
+        feature = "test_fixtures",
+        issue = "none",
+        reason = "test fixtures are an experimental feature"
+    )]
+    #[allow_internal_unstable(test, rustc_attrs)]
+    #[rustc_builtin_macro]
+    pub macro test_setup($item:item) {
+        /* compiler built-in */
+    }
+
+    /// Attribute macro applied to a function to run it after the tests: once with
+    /// `#[test_teardown]`, or after each test with `#[test_teardown(each)]`.
+    #[unstable(
+        feature = "test_fixtures",
+        issue = "none",
+        reason = "test fixtures are an experimental feature"
+    )]
+    #[allow_internal_unstable(test, rustc_attrs)]
+    #[rustc_builtin_macro]
+    pub macro test_teardown($item:item) {
+        /* compiler built-in */
+    }
+
     /// Attribute macro applied to a static to register it as a global allocator.
     ///
     /// See also [`std::alloc::GlobalAlloc`](../std/alloc/trait.GlobalAlloc.html).
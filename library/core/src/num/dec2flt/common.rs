@@ -0,0 +1,80 @@
+//! Common utilities, for internal use only.
+
+/// Helper methods to process immutable bytes.
+pub(crate) trait ByteSlice {
+    /// Reads the first 8 bytes as a 64-bit integer in little-endian order.
+    ///
+    /// Panics if there are fewer than 8 bytes.
+    fn read_u64(&self) -> u64;
+
+    /// Writes a 64-bit integer as 8 bytes in little-endian order.
+    ///
+    /// Panics if there are fewer than 8 bytes.
+    fn write_u64(&mut self, value: u64);
+
+    /// Returns the bytes after all leading bytes equal to `c`.
+    fn skip_chars(&self, c: u8) -> &Self;
+
+    /// Iteratively parses and consumes leading decimal digits, calling `func`
+    /// with the value of each. Returns the bytes after the last digit.
+    fn parse_digits(&self, func: impl FnMut(u8)) -> &Self;
+}
+
+impl ByteSlice for [u8] {
+    #[inline(always)]
+    fn read_u64(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self[..8]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, value: u64) {
+        self[..8].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[inline]
+    fn skip_chars(&self, c: u8) -> &Self {
+        let n = self.iter().take_while(|&&x| x == c).count();
+        &self[n..]
+    }
+
+    #[inline]
+    fn parse_digits(&self, mut func: impl FnMut(u8)) -> &Self {
+        let mut s = self;
+        while let Some((&c, rest)) = s.split_first() {
+            let digit = c.wrapping_sub(b'0');
+            if digit >= 10 {
+                break;
+            }
+            func(digit);
+            s = rest;
+        }
+        s
+    }
+}
+
+/// Determines if 8 bytes are all decimal digits.
+/// This does not care about the order in which the bytes were loaded.
+#[inline]
+pub(crate) fn is_8digits(v: u64) -> bool {
+    let a = v.wrapping_add(0x4646_4646_4646_4646);
+    let b = v.wrapping_sub(0x3030_3030_3030_3030);
+    (a | b) & 0x8080_8080_8080_8080 == 0
+}
+
+/// A custom 64-bit floating point type, representing `f * 2^e`.
+/// `e` is biased, so it can be directly shifted into the exponent bits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct BiasedFp {
+    /// The significant digits.
+    pub f: u64,
+    /// The biased, binary exponent.
+    pub e: i32,
+}
+
+impl BiasedFp {
+    pub const fn zero_pow2(e: i32) -> Self {
+        Self { f: 0, e }
+    }
+}
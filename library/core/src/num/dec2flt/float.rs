@@ -0,0 +1,177 @@
+//! Helper trait for generic float types.
+
+use crate::fmt::{Debug, LowerExp};
+use crate::num::FpCategory;
+use crate::ops::{Div, Mul, Neg};
+
+/// A helper trait to avoid duplicating basically all the conversion code for `f32` and `f64`.
+///
+/// See the parent module's doc comment for why this is necessary.
+///
+/// Should **never ever** be implemented for other types or be used outside the dec2flt module.
+pub trait RawFloat:
+    Copy + Debug + LowerExp + PartialEq + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    const INFINITY: Self;
+    const NAN: Self;
+    const ZERO: Self;
+
+    /// The number of bits in the significand, *excluding* the hidden bit.
+    const MANTISSA_EXPLICIT_BITS: usize;
+
+    /// The range of decimal exponents `q` in which a value `w * 10^q` can lie exactly
+    /// half-way between two floats, so that ties have to be rounded to even.
+    ///
+    /// For `q >= 0`, this requires `5^q <= 2^(MANTISSA_EXPLICIT_BITS + 2)`. For `q < 0`, it
+    /// requires `w >= (2m + 1) * 5^-q` to fit into 64 bits, where `2m + 1` has
+    /// `MANTISSA_EXPLICIT_BITS + 2` bits. In both cases, `5^|q|` fits into a 64-bit word.
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32;
+
+    /// Minimum decimal exponent for the fast path,
+    /// `-floor((MANTISSA_EXPLICIT_BITS + 1) / log2(5))`.
+    const MIN_EXPONENT_FAST_PATH: i64;
+
+    /// Maximum decimal exponent for the fast path,
+    /// `floor((MANTISSA_EXPLICIT_BITS + 1) / log2(5))`.
+    const MAX_EXPONENT_FAST_PATH: i64;
+
+    /// Maximum decimal exponent for the disguised fast path, where the excess is moved into the
+    /// significant digits,
+    /// `MAX_EXPONENT_FAST_PATH + floor((MANTISSA_EXPLICIT_BITS + 1) / log2(10))`.
+    const MAX_EXPONENT_DISGUISED_FAST_PATH: i64;
+
+    /// Minimum binary exponent, `-(1 << (EXP_BITS - 1)) + 1`.
+    const MINIMUM_EXPONENT: i32;
+
+    /// Biased exponent of infinity, `(1 << EXP_BITS) - 1`.
+    const INFINITE_POWER: i32;
+
+    /// Smallest decimal exponent for a non-zero value.
+    const SMALLEST_POWER_OF_TEN: i32;
+
+    /// Largest decimal exponent for a non-infinite value.
+    const LARGEST_POWER_OF_TEN: i32;
+
+    /// Maximum significant digits for the fast path, `2^(MANTISSA_EXPLICIT_BITS + 1)`.
+    const MAX_MANTISSA_FAST_PATH: u64 = 2_u64 << Self::MANTISSA_EXPLICIT_BITS;
+
+    /// Converts an integer into a float through an `as` cast.
+    ///
+    /// This is only called in the fast path, with values no larger than
+    /// `MAX_MANTISSA_FAST_PATH`, so the conversion is exact.
+    fn from_u64(v: u64) -> Self;
+
+    /// Performs a raw transmutation from an integer.
+    fn from_u64_bits(v: u64) -> Self;
+
+    /// Gets a small power-of-ten for fast-path multiplication.
+    fn pow10_fast_path(exponent: usize) -> Self;
+
+    /// Returns the category that this number falls into.
+    fn classify(self) -> FpCategory;
+
+    /// Returns the mantissa, exponent and sign as integers.
+    fn integer_decode(self) -> (u64, i16, i8);
+}
+
+impl RawFloat for f32 {
+    const INFINITY: Self = f32::INFINITY;
+    const NAN: Self = f32::NAN;
+    const ZERO: Self = 0.0;
+
+    const MANTISSA_EXPLICIT_BITS: usize = 23;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -17;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 10;
+    const MIN_EXPONENT_FAST_PATH: i64 = -10; // assuming FLT_EVAL_METHOD = 0
+    const MAX_EXPONENT_FAST_PATH: i64 = 10;
+    const MAX_EXPONENT_DISGUISED_FAST_PATH: i64 = 17;
+    const MINIMUM_EXPONENT: i32 = -127;
+    const INFINITE_POWER: i32 = 0xFF;
+    const SMALLEST_POWER_OF_TEN: i32 = -65;
+    const LARGEST_POWER_OF_TEN: i32 = 38;
+
+    fn from_u64(v: u64) -> Self {
+        debug_assert!(v <= Self::MAX_MANTISSA_FAST_PATH);
+        v as _
+    }
+
+    fn from_u64_bits(v: u64) -> Self {
+        f32::from_bits((v & 0xFFFFFFFF) as u32)
+    }
+
+    fn pow10_fast_path(exponent: usize) -> Self {
+        const TABLE: [f32; 16] =
+            [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 0., 0., 0., 0., 0.];
+        TABLE[exponent & 15]
+    }
+
+    /// Returns the mantissa, exponent and sign as integers.
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let bits = self.to_bits();
+        let sign: i8 = if bits >> 31 == 0 { 1 } else { -1 };
+        let mut exponent: i16 = ((bits >> 23) & 0xff) as i16;
+        let mantissa =
+            if exponent == 0 { (bits & 0x7fffff) << 1 } else { (bits & 0x7fffff) | 0x800000 };
+        // Exponent bias + mantissa shift
+        exponent -= 127 + 23;
+        (mantissa as u64, exponent, sign)
+    }
+
+    fn classify(self) -> FpCategory {
+        self.classify()
+    }
+}
+
+impl RawFloat for f64 {
+    const INFINITY: Self = f64::INFINITY;
+    const NAN: Self = f64::NAN;
+    const ZERO: Self = 0.0;
+
+    const MANTISSA_EXPLICIT_BITS: usize = 52;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -4;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 23;
+    const MIN_EXPONENT_FAST_PATH: i64 = -22; // assuming FLT_EVAL_METHOD = 0
+    const MAX_EXPONENT_FAST_PATH: i64 = 22;
+    const MAX_EXPONENT_DISGUISED_FAST_PATH: i64 = 37;
+    const MINIMUM_EXPONENT: i32 = -1023;
+    const INFINITE_POWER: i32 = 0x7FF;
+    const SMALLEST_POWER_OF_TEN: i32 = -342;
+    const LARGEST_POWER_OF_TEN: i32 = 308;
+
+    fn from_u64(v: u64) -> Self {
+        debug_assert!(v <= Self::MAX_MANTISSA_FAST_PATH);
+        v as _
+    }
+
+    fn from_u64_bits(v: u64) -> Self {
+        f64::from_bits(v)
+    }
+
+    fn pow10_fast_path(exponent: usize) -> Self {
+        const TABLE: [f64; 32] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+            1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22, 0., 0., 0., 0., 0., 0., 0., 0., 0.,
+        ];
+        TABLE[exponent & 31]
+    }
+
+    /// Returns the mantissa, exponent and sign as integers.
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let bits = self.to_bits();
+        let sign: i8 = if bits >> 63 == 0 { 1 } else { -1 };
+        let mut exponent: i16 = ((bits >> 52) & 0x7ff) as i16;
+        let mantissa = if exponent == 0 {
+            (bits & 0xfffffffffffff) << 1
+        } else {
+            (bits & 0xfffffffffffff) | 0x10000000000000
+        };
+        // Exponent bias + mantissa shift
+        exponent -= 1023 + 52;
+        (mantissa, exponent, sign)
+    }
+
+    fn classify(self) -> FpCategory {
+        self.classify()
+    }
+}
@@ -1,124 +1,174 @@
-//! Validating and decomposing a decimal string of the form:
-//!
-//! `(digits | digits? '.'? digits?) (('e' | 'E') ('+' | '-')? digits)?`
-//!
-//! In other words, standard floating-point syntax, with two exceptions: No sign, and no
-//! handling of "inf" and "NaN". These are handled by the driver function (super::dec2flt).
-//!
-//! Although recognizing valid inputs is relatively easy, this module also has to reject the
-//! countless invalid variations, never panic, and perform numerous checks that the other
-//! modules rely on to not panic (or overflow) in turn.
-//! To make matters worse, all that happens in a single pass over the input.
-//! So, be careful when modifying anything, and double-check with the other modules.
-use self::ParseResult::{Invalid, ShortcutToInf, ShortcutToZero, Valid};
-use super::num;
+//! Functions to parse floating-point numbers.
 
-#[derive(Debug)]
-pub enum Sign {
-    Positive,
-    Negative,
-}
+use crate::num::dec2flt::common::{is_8digits, ByteSlice};
+use crate::num::dec2flt::float::RawFloat;
+use crate::num::dec2flt::number::Number;
+
+/// The smallest integer with 19 decimal digits. Any number of significant digits up to and
+/// including this many is guaranteed to fit into a `u64`.
+const MIN_19DIGIT_INT: u64 = 100_0000_0000_0000_0000;
 
-#[derive(Debug, PartialEq, Eq)]
-/// The interesting parts of a decimal string.
-pub struct Decimal<'a> {
-    pub integral: &'a [u8],
-    pub fractional: &'a [u8],
-    /// The decimal exponent, guaranteed to have fewer than 18 decimal digits.
-    pub exp: i64,
+/// Parses 8 digits, loaded as bytes in little-endian order.
+///
+/// This uses the trick where every digit is in [0x030, 0x39],
+/// and therefore can be parsed in 3 multiplications, much
+/// faster than the normal 8.
+///
+/// This is based off the algorithm described in "Fast numeric string to
+/// int", available here: <https://johnnylee-sde.github.io/Fast-numeric-string-to-int/>.
+fn parse_8digits(mut v: u64) -> u64 {
+    const MASK: u64 = 0x0000_00FF_0000_00FF;
+    const MUL1: u64 = 0x000F_4240_0000_0064;
+    const MUL2: u64 = 0x0000_2710_0000_0001;
+    v -= 0x3030_3030_3030_3030;
+    v = (v * 10) + (v >> 8); // will not overflow, fits in 63 bits
+    let v1 = (v & MASK).wrapping_mul(MUL1);
+    let v2 = ((v >> 16) & MASK).wrapping_mul(MUL2);
+    ((v1.wrapping_add(v2) >> 32) as u32) as u64
 }
 
-impl<'a> Decimal<'a> {
-    pub fn new(integral: &'a [u8], fractional: &'a [u8], exp: i64) -> Decimal<'a> {
-        Decimal { integral, fractional, exp }
+/// Parses digits using the fast path of 8 digits at a time, then one digit at a time.
+/// The value may overflow, which is detected and handled by the caller.
+fn parse_digits<'a>(mut s: &'a [u8], x: &mut u64) -> &'a [u8] {
+    while s.len() >= 8 {
+        let v = s.read_u64();
+        if !is_8digits(v) {
+            break;
+        }
+        *x = x.wrapping_mul(1_0000_0000).wrapping_add(parse_8digits(v));
+        s = &s[8..];
     }
+    s.parse_digits(|digit| *x = x.wrapping_mul(10).wrapping_add(digit as u64))
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum ParseResult<'a> {
-    Valid(Decimal<'a>),
-    ShortcutToInf,
-    ShortcutToZero,
-    Invalid,
+/// Parses up to 19 digits, as long as the value fits into a `u64`.
+fn parse_19digits<'a>(mut s: &'a [u8], x: &mut u64) -> &'a [u8] {
+    while *x < MIN_19DIGIT_INT {
+        match s.split_first() {
+            Some((&c, rest)) if c.is_ascii_digit() => {
+                *x = (*x * 10) + (c - b'0') as u64;
+                s = rest;
+            }
+            _ => break,
+        }
+    }
+    s
 }
 
-/// Checks if the input string is a valid floating point number and if so, locate the integral
-/// part, the fractional part, and the exponent in it. Does not handle signs.
-pub fn parse_decimal(s: &str) -> ParseResult<'_> {
-    if s.is_empty() {
-        return Invalid;
+/// Parses the exponent after the `e` or `E` of scientific notation.
+///
+/// Returns `None` if there are no digits. Exponents too large to be represented saturate, which
+/// makes no difference to the result: they are far beyond the largest and smallest exponents of
+/// any float, no matter how many digits the mantissa has.
+fn parse_scientific(s: &[u8]) -> Option<(i64, &[u8])> {
+    let (negative, s) = match s.split_first() {
+        Some((&b'-', rest)) => (true, rest),
+        Some((&b'+', rest)) => (false, rest),
+        _ => (false, s),
+    };
+    if !s.first().map_or(false, u8::is_ascii_digit) {
+        return None;
     }
+    let mut exponent = 0_i64;
+    let s = s.parse_digits(|digit| {
+        exponent = exponent.saturating_mul(10).saturating_add(digit as i64);
+    });
+    Some((if negative { -exponent } else { exponent }, s))
+}
 
-    let s = s.as_bytes();
-    let (integral, s) = eat_digits(s);
+/// Parses a partial, non-special floating point number.
+///
+/// This creates a representation of the float as the
+/// significant digits and the decimal exponent.
+fn parse_partial_number(s: &[u8], negative: bool) -> Option<(Number, usize)> {
+    let start = s;
 
-    match s.first() {
-        None => Valid(Decimal::new(integral, b"", 0)),
-        Some(&b'e' | &b'E') => {
-            if integral.is_empty() {
-                return Invalid; // No digits before 'e'
-            }
+    // parse initial digits before dot
+    let mut mantissa = 0_u64;
+    let s = parse_digits(s, &mut mantissa);
+    let int_end = s;
+    let mut n_digits = start.len() - s.len();
 
-            parse_exp(integral, b"", &s[1..])
+    // handle dot with the following digits
+    let mut exponent = 0_i64;
+    let s = match s.split_first() {
+        Some((&b'.', rest)) => {
+            let s = parse_digits(rest, &mut mantissa);
+            let n_after_dot = rest.len() - s.len();
+            n_digits += n_after_dot;
+            exponent = -(n_after_dot as i64);
+            s
         }
-        Some(&b'.') => {
-            let (fractional, s) = eat_digits(&s[1..]);
-            if integral.is_empty() && fractional.is_empty() {
-                // We require at least a single digit before or after the point.
-                return Invalid;
-            }
+        _ => s,
+    };
+    if n_digits == 0 {
+        return None;
+    }
 
-            match s.first() {
-                None => Valid(Decimal::new(integral, fractional, 0)),
-                Some(&b'e' | &b'E') => parse_exp(integral, fractional, &s[1..]),
-                _ => Invalid, // Trailing junk after fractional part
-            }
+    // handle scientific format
+    let mut exp_number = 0_i64;
+    let s = match s.split_first() {
+        Some((&c, rest)) if c == b'e' || c == b'E' => {
+            // If None, we have no trailing digits after exponent, or an invalid float.
+            let (exp, s) = parse_scientific(rest)?;
+            exp_number = exp;
+            exponent = exponent.saturating_add(exp_number);
+            s
         }
-        _ => Invalid, // Trailing junk after first digit string
-    }
-}
+        _ => s,
+    };
+    let len = start.len() - s.len();
 
-/// Carves off decimal digits up to the first non-digit character.
-fn eat_digits(s: &[u8]) -> (&[u8], &[u8]) {
-    let mut i = 0;
-    while i < s.len() && b'0' <= s[i] && s[i] <= b'9' {
-        i += 1;
+    // handle uncommon case with many digits
+    if n_digits <= 19 {
+        return Some((Number { exponent, mantissa, negative, many_digits: false }, len));
     }
-    (&s[..i], &s[i..])
-}
 
-/// Exponent extraction and error checking.
-fn parse_exp<'a>(integral: &'a [u8], fractional: &'a [u8], rest: &'a [u8]) -> ParseResult<'a> {
-    let (sign, rest) = match rest.first() {
-        Some(&b'-') => (Sign::Negative, &rest[1..]),
-        Some(&b'+') => (Sign::Positive, &rest[1..]),
-        _ => (Sign::Positive, rest),
-    };
-    let (mut number, trailing) = eat_digits(rest);
-    if !trailing.is_empty() {
-        return Invalid; // Trailing junk after exponent
-    }
-    if number.is_empty() {
-        return Invalid; // Empty exponent
-    }
-    // At this point, we certainly have a valid string of digits. It may be too long to put into
-    // an `i64`, but if it's that huge, the input is certainly zero or infinity. Since each zero
-    // in the decimal digits only adjusts the exponent by +/- 1, at exp = 10^18 the input would
-    // have to be 17 exabyte (!) of zeros to get even remotely close to being finite.
-    // This is not exactly a use case we need to cater to.
-    while number.first() == Some(&b'0') {
-        number = &number[1..];
+    // Leading zeros (and the dot) are not significant.
+    let mut n_significant = n_digits;
+    for &c in start.iter().take_while(|&&c| c == b'0' || c == b'.') {
+        if c == b'0' {
+            n_significant -= 1;
+        }
     }
-    if number.len() >= 18 {
-        return match sign {
-            Sign::Positive => ShortcutToInf,
-            Sign::Negative => ShortcutToZero,
+    let mut many_digits = false;
+    if n_significant > 19 {
+        // at this point we have more than 19 significant digits, let's try again
+        many_digits = true;
+        mantissa = 0;
+        let s = parse_19digits(start, &mut mantissa);
+        exponent = if mantissa >= MIN_19DIGIT_INT {
+            // big int
+            (s.len() - int_end.len()) as i64
+        } else {
+            // the integral part is exhausted, continue after the dot
+            let fraction = &s[1..];
+            let s = parse_19digits(fraction, &mut mantissa);
+            -((fraction.len() - s.len()) as i64)
         };
+        // add back the explicit part
+        exponent = exponent.saturating_add(exp_number);
     }
-    let abs_exp = num::from_str_unchecked(number);
-    let e = match sign {
-        Sign::Positive => abs_exp as i64,
-        Sign::Negative => -(abs_exp as i64),
+
+    Some((Number { exponent, mantissa, negative, many_digits }, len))
+}
+
+/// Tries to parse a non-special floating point number, without sign.
+///
+/// The whole string has to be consumed.
+pub fn parse_number(s: &[u8], negative: bool) -> Option<Number> {
+    match parse_partial_number(s, negative) {
+        Some((number, len)) if len == s.len() => Some(number),
+        _ => None,
+    }
+}
+
+/// Parses the special values `inf` and `NaN`, without sign.
+pub fn parse_inf_nan<F: RawFloat>(s: &[u8], negative: bool) -> Option<F> {
+    let value = match s {
+        b"inf" => F::INFINITY,
+        b"NaN" => F::NAN,
+        _ => return None,
     };
-    Valid(Decimal::new(integral, fractional, e))
+    Some(if negative { -value } else { value })
 }
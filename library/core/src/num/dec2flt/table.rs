@@ -1,1277 +1,743 @@
-//! Tables of approximations of powers of ten.
+//! Pre-computed tables powers-of-5 for extended-precision representations.
+//!
+//! These tables enable fast scaling of the significant digits
+//! of a float to the decimal exponent, with minimal rounding
+//! errors, in a 128-bit representation.
+//!
 //! DO NOT MODIFY: Generated by `src/etc/dec2flt_table.py`
 
-pub const MIN_E: i16 = -305;
-pub const MAX_E: i16 = 305;
+pub const SMALLEST_POWER_OF_FIVE: i32 = -342;
+pub const LARGEST_POWER_OF_FIVE: i32 = 308;
+pub const N_POWERS_OF_FIVE: usize = (LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1) as usize;
 
-#[rustfmt::skip]
-pub const POWERS: ([u64; 611], [i16; 611]) = (
-    [
-        0xe0b62e2929aba83c,
-        0x8c71dcd9ba0b4926,
-        0xaf8e5410288e1b6f,
-        0xdb71e91432b1a24b,
-        0x892731ac9faf056f,
-        0xab70fe17c79ac6ca,
-        0xd64d3d9db981787d,
-        0x85f0468293f0eb4e,
-        0xa76c582338ed2622,
-        0xd1476e2c07286faa,
-        0x82cca4db847945ca,
-        0xa37fce126597973d,
-        0xcc5fc196fefd7d0c,
-        0xff77b1fcbebcdc4f,
-        0x9faacf3df73609b1,
-        0xc795830d75038c1e,
-        0xf97ae3d0d2446f25,
-        0x9becce62836ac577,
-        0xc2e801fb244576d5,
-        0xf3a20279ed56d48a,
-        0x9845418c345644d7,
-        0xbe5691ef416bd60c,
-        0xedec366b11c6cb8f,
-        0x94b3a202eb1c3f39,
-        0xb9e08a83a5e34f08,
-        0xe858ad248f5c22ca,
-        0x91376c36d99995be,
-        0xb58547448ffffb2e,
-        0xe2e69915b3fff9f9,
-        0x8dd01fad907ffc3c,
-        0xb1442798f49ffb4b,
-        0xdd95317f31c7fa1d,
-        0x8a7d3eef7f1cfc52,
-        0xad1c8eab5ee43b67,
-        0xd863b256369d4a41,
-        0x873e4f75e2224e68,
-        0xa90de3535aaae202,
-        0xd3515c2831559a83,
-        0x8412d9991ed58092,
-        0xa5178fff668ae0b6,
-        0xce5d73ff402d98e4,
-        0x80fa687f881c7f8e,
-        0xa139029f6a239f72,
-        0xc987434744ac874f,
-        0xfbe9141915d7a922,
-        0x9d71ac8fada6c9b5,
-        0xc4ce17b399107c23,
-        0xf6019da07f549b2b,
-        0x99c102844f94e0fb,
-        0xc0314325637a193a,
-        0xf03d93eebc589f88,
-        0x96267c7535b763b5,
-        0xbbb01b9283253ca3,
-        0xea9c227723ee8bcb,
-        0x92a1958a7675175f,
-        0xb749faed14125d37,
-        0xe51c79a85916f485,
-        0x8f31cc0937ae58d3,
-        0xb2fe3f0b8599ef08,
-        0xdfbdcece67006ac9,
-        0x8bd6a141006042be,
-        0xaecc49914078536d,
-        0xda7f5bf590966849,
-        0x888f99797a5e012d,
-        0xaab37fd7d8f58179,
-        0xd5605fcdcf32e1d7,
-        0x855c3be0a17fcd26,
-        0xa6b34ad8c9dfc070,
-        0xd0601d8efc57b08c,
-        0x823c12795db6ce57,
-        0xa2cb1717b52481ed,
-        0xcb7ddcdda26da269,
-        0xfe5d54150b090b03,
-        0x9efa548d26e5a6e2,
-        0xc6b8e9b0709f109a,
-        0xf867241c8cc6d4c1,
-        0x9b407691d7fc44f8,
-        0xc21094364dfb5637,
-        0xf294b943e17a2bc4,
-        0x979cf3ca6cec5b5b,
-        0xbd8430bd08277231,
-        0xece53cec4a314ebe,
-        0x940f4613ae5ed137,
-        0xb913179899f68584,
-        0xe757dd7ec07426e5,
-        0x9096ea6f3848984f,
-        0xb4bca50b065abe63,
-        0xe1ebce4dc7f16dfc,
-        0x8d3360f09cf6e4bd,
-        0xb080392cc4349ded,
-        0xdca04777f541c568,
-        0x89e42caaf9491b61,
-        0xac5d37d5b79b6239,
-        0xd77485cb25823ac7,
-        0x86a8d39ef77164bd,
-        0xa8530886b54dbdec,
-        0xd267caa862a12d67,
-        0x8380dea93da4bc60,
-        0xa46116538d0deb78,
-        0xcd795be870516656,
-        0x806bd9714632dff6,
-        0xa086cfcd97bf97f4,
-        0xc8a883c0fdaf7df0,
-        0xfad2a4b13d1b5d6c,
-        0x9cc3a6eec6311a64,
-        0xc3f490aa77bd60fd,
-        0xf4f1b4d515acb93c,
-        0x991711052d8bf3c5,
-        0xbf5cd54678eef0b7,
-        0xef340a98172aace5,
-        0x9580869f0e7aac0f,
-        0xbae0a846d2195713,
-        0xe998d258869facd7,
-        0x91ff83775423cc06,
-        0xb67f6455292cbf08,
-        0xe41f3d6a7377eeca,
-        0x8e938662882af53e,
-        0xb23867fb2a35b28e,
-        0xdec681f9f4c31f31,
-        0x8b3c113c38f9f37f,
-        0xae0b158b4738705f,
-        0xd98ddaee19068c76,
-        0x87f8a8d4cfa417ca,
-        0xa9f6d30a038d1dbc,
-        0xd47487cc8470652b,
-        0x84c8d4dfd2c63f3b,
-        0xa5fb0a17c777cf0a,
-        0xcf79cc9db955c2cc,
-        0x81ac1fe293d599c0,
-        0xa21727db38cb0030,
-        0xca9cf1d206fdc03c,
-        0xfd442e4688bd304b,
-        0x9e4a9cec15763e2f,
-        0xc5dd44271ad3cdba,
-        0xf7549530e188c129,
-        0x9a94dd3e8cf578ba,
-        0xc13a148e3032d6e8,
-        0xf18899b1bc3f8ca2,
-        0x96f5600f15a7b7e5,
-        0xbcb2b812db11a5de,
-        0xebdf661791d60f56,
-        0x936b9fcebb25c996,
-        0xb84687c269ef3bfb,
-        0xe65829b3046b0afa,
-        0x8ff71a0fe2c2e6dc,
-        0xb3f4e093db73a093,
-        0xe0f218b8d25088b8,
-        0x8c974f7383725573,
-        0xafbd2350644eead0,
-        0xdbac6c247d62a584,
-        0x894bc396ce5da772,
-        0xab9eb47c81f5114f,
-        0xd686619ba27255a3,
-        0x8613fd0145877586,
-        0xa798fc4196e952e7,
-        0xd17f3b51fca3a7a1,
-        0x82ef85133de648c5,
-        0xa3ab66580d5fdaf6,
-        0xcc963fee10b7d1b3,
-        0xffbbcfe994e5c620,
-        0x9fd561f1fd0f9bd4,
-        0xc7caba6e7c5382c9,
-        0xf9bd690a1b68637b,
-        0x9c1661a651213e2d,
-        0xc31bfa0fe5698db8,
-        0xf3e2f893dec3f126,
-        0x986ddb5c6b3a76b8,
-        0xbe89523386091466,
-        0xee2ba6c0678b597f,
-        0x94db483840b717f0,
-        0xba121a4650e4ddec,
-        0xe896a0d7e51e1566,
-        0x915e2486ef32cd60,
-        0xb5b5ada8aaff80b8,
-        0xe3231912d5bf60e6,
-        0x8df5efabc5979c90,
-        0xb1736b96b6fd83b4,
-        0xddd0467c64bce4a1,
-        0x8aa22c0dbef60ee4,
-        0xad4ab7112eb3929e,
-        0xd89d64d57a607745,
-        0x87625f056c7c4a8b,
-        0xa93af6c6c79b5d2e,
-        0xd389b47879823479,
-        0x843610cb4bf160cc,
-        0xa54394fe1eedb8ff,
-        0xce947a3da6a9273e,
-        0x811ccc668829b887,
-        0xa163ff802a3426a9,
-        0xc9bcff6034c13053,
-        0xfc2c3f3841f17c68,
-        0x9d9ba7832936edc1,
-        0xc5029163f384a931,
-        0xf64335bcf065d37d,
-        0x99ea0196163fa42e,
-        0xc06481fb9bcf8d3a,
-        0xf07da27a82c37088,
-        0x964e858c91ba2655,
-        0xbbe226efb628afeb,
-        0xeadab0aba3b2dbe5,
-        0x92c8ae6b464fc96f,
-        0xb77ada0617e3bbcb,
-        0xe55990879ddcaabe,
-        0x8f57fa54c2a9eab7,
-        0xb32df8e9f3546564,
-        0xdff9772470297ebd,
-        0x8bfbea76c619ef36,
-        0xaefae51477a06b04,
-        0xdab99e59958885c5,
-        0x88b402f7fd75539b,
-        0xaae103b5fcd2a882,
-        0xd59944a37c0752a2,
-        0x857fcae62d8493a5,
-        0xa6dfbd9fb8e5b88f,
-        0xd097ad07a71f26b2,
-        0x825ecc24c8737830,
-        0xa2f67f2dfa90563b,
-        0xcbb41ef979346bca,
-        0xfea126b7d78186bd,
-        0x9f24b832e6b0f436,
-        0xc6ede63fa05d3144,
-        0xf8a95fcf88747d94,
-        0x9b69dbe1b548ce7d,
-        0xc24452da229b021c,
-        0xf2d56790ab41c2a3,
-        0x97c560ba6b0919a6,
-        0xbdb6b8e905cb600f,
-        0xed246723473e3813,
-        0x9436c0760c86e30c,
-        0xb94470938fa89bcf,
-        0xe7958cb87392c2c3,
-        0x90bd77f3483bb9ba,
-        0xb4ecd5f01a4aa828,
-        0xe2280b6c20dd5232,
-        0x8d590723948a535f,
-        0xb0af48ec79ace837,
-        0xdcdb1b2798182245,
-        0x8a08f0f8bf0f156b,
-        0xac8b2d36eed2dac6,
-        0xd7adf884aa879177,
-        0x86ccbb52ea94baeb,
-        0xa87fea27a539e9a5,
-        0xd29fe4b18e88640f,
-        0x83a3eeeef9153e89,
-        0xa48ceaaab75a8e2b,
-        0xcdb02555653131b6,
-        0x808e17555f3ebf12,
-        0xa0b19d2ab70e6ed6,
-        0xc8de047564d20a8c,
-        0xfb158592be068d2f,
-        0x9ced737bb6c4183d,
-        0xc428d05aa4751e4d,
-        0xf53304714d9265e0,
-        0x993fe2c6d07b7fac,
-        0xbf8fdb78849a5f97,
-        0xef73d256a5c0f77d,
-        0x95a8637627989aae,
-        0xbb127c53b17ec159,
-        0xe9d71b689dde71b0,
-        0x9226712162ab070e,
-        0xb6b00d69bb55c8d1,
-        0xe45c10c42a2b3b06,
-        0x8eb98a7a9a5b04e3,
-        0xb267ed1940f1c61c,
-        0xdf01e85f912e37a3,
-        0x8b61313bbabce2c6,
-        0xae397d8aa96c1b78,
-        0xd9c7dced53c72256,
-        0x881cea14545c7575,
-        0xaa242499697392d3,
-        0xd4ad2dbfc3d07788,
-        0x84ec3c97da624ab5,
-        0xa6274bbdd0fadd62,
-        0xcfb11ead453994ba,
-        0x81ceb32c4b43fcf5,
-        0xa2425ff75e14fc32,
-        0xcad2f7f5359a3b3e,
-        0xfd87b5f28300ca0e,
-        0x9e74d1b791e07e48,
-        0xc612062576589ddb,
-        0xf79687aed3eec551,
-        0x9abe14cd44753b53,
-        0xc16d9a0095928a27,
-        0xf1c90080baf72cb1,
-        0x971da05074da7bef,
-        0xbce5086492111aeb,
-        0xec1e4a7db69561a5,
-        0x9392ee8e921d5d07,
-        0xb877aa3236a4b449,
-        0xe69594bec44de15b,
-        0x901d7cf73ab0acd9,
-        0xb424dc35095cd80f,
-        0xe12e13424bb40e13,
-        0x8cbccc096f5088cc,
-        0xafebff0bcb24aaff,
-        0xdbe6fecebdedd5bf,
-        0x89705f4136b4a597,
-        0xabcc77118461cefd,
-        0xd6bf94d5e57a42bc,
-        0x8637bd05af6c69b6,
-        0xa7c5ac471b478423,
-        0xd1b71758e219652c,
-        0x83126e978d4fdf3b,
-        0xa3d70a3d70a3d70a,
-        0xcccccccccccccccd,
-        0x8000000000000000,
-        0xa000000000000000,
-        0xc800000000000000,
-        0xfa00000000000000,
-        0x9c40000000000000,
-        0xc350000000000000,
-        0xf424000000000000,
-        0x9896800000000000,
-        0xbebc200000000000,
-        0xee6b280000000000,
-        0x9502f90000000000,
-        0xba43b74000000000,
-        0xe8d4a51000000000,
-        0x9184e72a00000000,
-        0xb5e620f480000000,
-        0xe35fa931a0000000,
-        0x8e1bc9bf04000000,
-        0xb1a2bc2ec5000000,
-        0xde0b6b3a76400000,
-        0x8ac7230489e80000,
-        0xad78ebc5ac620000,
-        0xd8d726b7177a8000,
-        0x878678326eac9000,
-        0xa968163f0a57b400,
-        0xd3c21bcecceda100,
-        0x84595161401484a0,
-        0xa56fa5b99019a5c8,
-        0xcecb8f27f4200f3a,
-        0x813f3978f8940984,
-        0xa18f07d736b90be5,
-        0xc9f2c9cd04674edf,
-        0xfc6f7c4045812296,
-        0x9dc5ada82b70b59e,
-        0xc5371912364ce305,
-        0xf684df56c3e01bc7,
-        0x9a130b963a6c115c,
-        0xc097ce7bc90715b3,
-        0xf0bdc21abb48db20,
-        0x96769950b50d88f4,
-        0xbc143fa4e250eb31,
-        0xeb194f8e1ae525fd,
-        0x92efd1b8d0cf37be,
-        0xb7abc627050305ae,
-        0xe596b7b0c643c719,
-        0x8f7e32ce7bea5c70,
-        0xb35dbf821ae4f38c,
-        0xe0352f62a19e306f,
-        0x8c213d9da502de45,
-        0xaf298d050e4395d7,
-        0xdaf3f04651d47b4c,
-        0x88d8762bf324cd10,
-        0xab0e93b6efee0054,
-        0xd5d238a4abe98068,
-        0x85a36366eb71f041,
-        0xa70c3c40a64e6c52,
-        0xd0cf4b50cfe20766,
-        0x82818f1281ed44a0,
-        0xa321f2d7226895c8,
-        0xcbea6f8ceb02bb3a,
-        0xfee50b7025c36a08,
-        0x9f4f2726179a2245,
-        0xc722f0ef9d80aad6,
-        0xf8ebad2b84e0d58c,
-        0x9b934c3b330c8577,
-        0xc2781f49ffcfa6d5,
-        0xf316271c7fc3908b,
-        0x97edd871cfda3a57,
-        0xbde94e8e43d0c8ec,
-        0xed63a231d4c4fb27,
-        0x945e455f24fb1cf9,
-        0xb975d6b6ee39e437,
-        0xe7d34c64a9c85d44,
-        0x90e40fbeea1d3a4b,
-        0xb51d13aea4a488dd,
-        0xe264589a4dcdab15,
-        0x8d7eb76070a08aed,
-        0xb0de65388cc8ada8,
-        0xdd15fe86affad912,
-        0x8a2dbf142dfcc7ab,
-        0xacb92ed9397bf996,
-        0xd7e77a8f87daf7fc,
-        0x86f0ac99b4e8dafd,
-        0xa8acd7c0222311bd,
-        0xd2d80db02aabd62c,
-        0x83c7088e1aab65db,
-        0xa4b8cab1a1563f52,
-        0xcde6fd5e09abcf27,
-        0x80b05e5ac60b6178,
-        0xa0dc75f1778e39d6,
-        0xc913936dd571c84c,
-        0xfb5878494ace3a5f,
-        0x9d174b2dcec0e47b,
-        0xc45d1df942711d9a,
-        0xf5746577930d6501,
-        0x9968bf6abbe85f20,
-        0xbfc2ef456ae276e9,
-        0xefb3ab16c59b14a3,
-        0x95d04aee3b80ece6,
-        0xbb445da9ca61281f,
-        0xea1575143cf97227,
-        0x924d692ca61be758,
-        0xb6e0c377cfa2e12e,
-        0xe498f455c38b997a,
-        0x8edf98b59a373fec,
-        0xb2977ee300c50fe7,
-        0xdf3d5e9bc0f653e1,
-        0x8b865b215899f46d,
-        0xae67f1e9aec07188,
-        0xda01ee641a708dea,
-        0x884134fe908658b2,
-        0xaa51823e34a7eedf,
-        0xd4e5e2cdc1d1ea96,
-        0x850fadc09923329e,
-        0xa6539930bf6bff46,
-        0xcfe87f7cef46ff17,
-        0x81f14fae158c5f6e,
-        0xa26da3999aef774a,
-        0xcb090c8001ab551c,
-        0xfdcb4fa002162a63,
-        0x9e9f11c4014dda7e,
-        0xc646d63501a1511e,
-        0xf7d88bc24209a565,
-        0x9ae757596946075f,
-        0xc1a12d2fc3978937,
-        0xf209787bb47d6b85,
-        0x9745eb4d50ce6333,
-        0xbd176620a501fc00,
-        0xec5d3fa8ce427b00,
-        0x93ba47c980e98ce0,
-        0xb8a8d9bbe123f018,
-        0xe6d3102ad96cec1e,
-        0x9043ea1ac7e41393,
-        0xb454e4a179dd1877,
-        0xe16a1dc9d8545e95,
-        0x8ce2529e2734bb1d,
-        0xb01ae745b101e9e4,
-        0xdc21a1171d42645d,
-        0x899504ae72497eba,
-        0xabfa45da0edbde69,
-        0xd6f8d7509292d603,
-        0x865b86925b9bc5c2,
-        0xa7f26836f282b733,
-        0xd1ef0244af2364ff,
-        0x8335616aed761f1f,
-        0xa402b9c5a8d3a6e7,
-        0xcd036837130890a1,
-        0x802221226be55a65,
-        0xa02aa96b06deb0fe,
-        0xc83553c5c8965d3d,
-        0xfa42a8b73abbf48d,
-        0x9c69a97284b578d8,
-        0xc38413cf25e2d70e,
-        0xf46518c2ef5b8cd1,
-        0x98bf2f79d5993803,
-        0xbeeefb584aff8604,
-        0xeeaaba2e5dbf6785,
-        0x952ab45cfa97a0b3,
-        0xba756174393d88e0,
-        0xe912b9d1478ceb17,
-        0x91abb422ccb812ef,
-        0xb616a12b7fe617aa,
-        0xe39c49765fdf9d95,
-        0x8e41ade9fbebc27d,
-        0xb1d219647ae6b31c,
-        0xde469fbd99a05fe3,
-        0x8aec23d680043bee,
-        0xada72ccc20054aea,
-        0xd910f7ff28069da4,
-        0x87aa9aff79042287,
-        0xa99541bf57452b28,
-        0xd3fa922f2d1675f2,
-        0x847c9b5d7c2e09b7,
-        0xa59bc234db398c25,
-        0xcf02b2c21207ef2f,
-        0x8161afb94b44f57d,
-        0xa1ba1ba79e1632dc,
-        0xca28a291859bbf93,
-        0xfcb2cb35e702af78,
-        0x9defbf01b061adab,
-        0xc56baec21c7a1916,
-        0xf6c69a72a3989f5c,
-        0x9a3c2087a63f6399,
-        0xc0cb28a98fcf3c80,
-        0xf0fdf2d3f3c30b9f,
-        0x969eb7c47859e744,
-        0xbc4665b596706115,
-        0xeb57ff22fc0c795a,
-        0x9316ff75dd87cbd8,
-        0xb7dcbf5354e9bece,
-        0xe5d3ef282a242e82,
-        0x8fa475791a569d11,
-        0xb38d92d760ec4455,
-        0xe070f78d3927556b,
-        0x8c469ab843b89563,
-        0xaf58416654a6babb,
-        0xdb2e51bfe9d0696a,
-        0x88fcf317f22241e2,
-        0xab3c2fddeeaad25b,
-        0xd60b3bd56a5586f2,
-        0x85c7056562757457,
-        0xa738c6bebb12d16d,
-        0xd106f86e69d785c8,
-        0x82a45b450226b39d,
-        0xa34d721642b06084,
-        0xcc20ce9bd35c78a5,
-        0xff290242c83396ce,
-        0x9f79a169bd203e41,
-        0xc75809c42c684dd1,
-        0xf92e0c3537826146,
-        0x9bbcc7a142b17ccc,
-        0xc2abf989935ddbfe,
-        0xf356f7ebf83552fe,
-        0x98165af37b2153df,
-        0xbe1bf1b059e9a8d6,
-        0xeda2ee1c7064130c,
-        0x9485d4d1c63e8be8,
-        0xb9a74a0637ce2ee1,
-        0xe8111c87c5c1ba9a,
-        0x910ab1d4db9914a0,
-        0xb54d5e4a127f59c8,
-        0xe2a0b5dc971f303a,
-        0x8da471a9de737e24,
-        0xb10d8e1456105dad,
-        0xdd50f1996b947519,
-        0x8a5296ffe33cc930,
-        0xace73cbfdc0bfb7b,
-        0xd8210befd30efa5a,
-        0x8714a775e3e95c78,
-        0xa8d9d1535ce3b396,
-        0xd31045a8341ca07c,
-        0x83ea2b892091e44e,
-        0xa4e4b66b68b65d61,
-        0xce1de40642e3f4b9,
-        0x80d2ae83e9ce78f4,
-        0xa1075a24e4421731,
-        0xc94930ae1d529cfd,
-        0xfb9b7cd9a4a7443c,
-        0x9d412e0806e88aa6,
-        0xc491798a08a2ad4f,
-        0xf5b5d7ec8acb58a3,
-        0x9991a6f3d6bf1766,
-        0xbff610b0cc6edd3f,
-        0xeff394dcff8a948f,
-        0x95f83d0a1fb69cd9,
-        0xbb764c4ca7a44410,
-        0xea53df5fd18d5514,
-        0x92746b9be2f8552c,
-        0xb7118682dbb66a77,
-        0xe4d5e82392a40515,
-        0x8f05b1163ba6832d,
-        0xb2c71d5bca9023f8,
-        0xdf78e4b2bd342cf7,
-        0x8bab8eefb6409c1a,
-        0xae9672aba3d0c321,
-        0xda3c0f568cc4f3e9,
-        0x8865899617fb1871,
-        0xaa7eebfb9df9de8e,
-        0xd51ea6fa85785631,
-        0x8533285c936b35df,
-        0xa67ff273b8460357,
-        0xd01fef10a657842c,
-        0x8213f56a67f6b29c,
-        0xa298f2c501f45f43,
-        0xcb3f2f7642717713,
-        0xfe0efb53d30dd4d8,
-        0x9ec95d1463e8a507,
-        0xc67bb4597ce2ce49,
-        0xf81aa16fdc1b81db,
-        0x9b10a4e5e9913129,
-        0xc1d4ce1f63f57d73,
-        0xf24a01a73cf2dcd0,
-        0x976e41088617ca02,
-        0xbd49d14aa79dbc82,
-        0xec9c459d51852ba3,
-        0x93e1ab8252f33b46,
-        0xb8da1662e7b00a17,
-        0xe7109bfba19c0c9d,
-        0x906a617d450187e2,
-        0xb484f9dc9641e9db,
-        0xe1a63853bbd26451,
-        0x8d07e33455637eb3,
-        0xb049dc016abc5e60,
-        0xdc5c5301c56b75f7,
-        0x89b9b3e11b6329bb,
-        0xac2820d9623bf429,
-        0xd732290fbacaf134,
-        0x867f59a9d4bed6c0,
-        0xa81f301449ee8c70,
-        0xd226fc195c6a2f8c,
-        0x83585d8fd9c25db8,
-        0xa42e74f3d032f526,
-        0xcd3a1230c43fb26f,
-        0x80444b5e7aa7cf85,
-        0xa0555e361951c367,
-        0xc86ab5c39fa63441,
-        0xfa856334878fc151,
-        0x9c935e00d4b9d8d2,
-        0xc3b8358109e84f07,
-        0xf4a642e14c6262c9,
-        0x98e7e9cccfbd7dbe,
-        0xbf21e44003acdd2d,
-        0xeeea5d5004981478,
-        0x95527a5202df0ccb,
-        0xbaa718e68396cffe,
-        0xe950df20247c83fd,
-        0x91d28b7416cdd27e,
-    ],
-    [
-        -1077,
-        -1073,
-        -1070,
-        -1067,
-        -1063,
-        -1060,
-        -1057,
-        -1053,
-        -1050,
-        -1047,
-        -1043,
-        -1040,
-        -1037,
-        -1034,
-        -1030,
-        -1027,
-        -1024,
-        -1020,
-        -1017,
-        -1014,
-        -1010,
-        -1007,
-        -1004,
-        -1000,
-        -997,
-        -994,
-        -990,
-        -987,
-        -984,
-        -980,
-        -977,
-        -974,
-        -970,
-        -967,
-        -964,
-        -960,
-        -957,
-        -954,
-        -950,
-        -947,
-        -944,
-        -940,
-        -937,
-        -934,
-        -931,
-        -927,
-        -924,
-        -921,
-        -917,
-        -914,
-        -911,
-        -907,
-        -904,
-        -901,
-        -897,
-        -894,
-        -891,
-        -887,
-        -884,
-        -881,
-        -877,
-        -874,
-        -871,
-        -867,
-        -864,
-        -861,
-        -857,
-        -854,
-        -851,
-        -847,
-        -844,
-        -841,
-        -838,
-        -834,
-        -831,
-        -828,
-        -824,
-        -821,
-        -818,
-        -814,
-        -811,
-        -808,
-        -804,
-        -801,
-        -798,
-        -794,
-        -791,
-        -788,
-        -784,
-        -781,
-        -778,
-        -774,
-        -771,
-        -768,
-        -764,
-        -761,
-        -758,
-        -754,
-        -751,
-        -748,
-        -744,
-        -741,
-        -738,
-        -735,
-        -731,
-        -728,
-        -725,
-        -721,
-        -718,
-        -715,
-        -711,
-        -708,
-        -705,
-        -701,
-        -698,
-        -695,
-        -691,
-        -688,
-        -685,
-        -681,
-        -678,
-        -675,
-        -671,
-        -668,
-        -665,
-        -661,
-        -658,
-        -655,
-        -651,
-        -648,
-        -645,
-        -642,
-        -638,
-        -635,
-        -632,
-        -628,
-        -625,
-        -622,
-        -618,
-        -615,
-        -612,
-        -608,
-        -605,
-        -602,
-        -598,
-        -595,
-        -592,
-        -588,
-        -585,
-        -582,
-        -578,
-        -575,
-        -572,
-        -568,
-        -565,
-        -562,
-        -558,
-        -555,
-        -552,
-        -549,
-        -545,
-        -542,
-        -539,
-        -535,
-        -532,
-        -529,
-        -525,
-        -522,
-        -519,
-        -515,
-        -512,
-        -509,
-        -505,
-        -502,
-        -499,
-        -495,
-        -492,
-        -489,
-        -485,
-        -482,
-        -479,
-        -475,
-        -472,
-        -469,
-        -465,
-        -462,
-        -459,
-        -455,
-        -452,
-        -449,
-        -446,
-        -442,
-        -439,
-        -436,
-        -432,
-        -429,
-        -426,
-        -422,
-        -419,
-        -416,
-        -412,
-        -409,
-        -406,
-        -402,
-        -399,
-        -396,
-        -392,
-        -389,
-        -386,
-        -382,
-        -379,
-        -376,
-        -372,
-        -369,
-        -366,
-        -362,
-        -359,
-        -356,
-        -353,
-        -349,
-        -346,
-        -343,
-        -339,
-        -336,
-        -333,
-        -329,
-        -326,
-        -323,
-        -319,
-        -316,
-        -313,
-        -309,
-        -306,
-        -303,
-        -299,
-        -296,
-        -293,
-        -289,
-        -286,
-        -283,
-        -279,
-        -276,
-        -273,
-        -269,
-        -266,
-        -263,
-        -259,
-        -256,
-        -253,
-        -250,
-        -246,
-        -243,
-        -240,
-        -236,
-        -233,
-        -230,
-        -226,
-        -223,
-        -220,
-        -216,
-        -213,
-        -210,
-        -206,
-        -203,
-        -200,
-        -196,
-        -193,
-        -190,
-        -186,
-        -183,
-        -180,
-        -176,
-        -173,
-        -170,
-        -166,
-        -163,
-        -160,
-        -157,
-        -153,
-        -150,
-        -147,
-        -143,
-        -140,
-        -137,
-        -133,
-        -130,
-        -127,
-        -123,
-        -120,
-        -117,
-        -113,
-        -110,
-        -107,
-        -103,
-        -100,
-        -97,
-        -93,
-        -90,
-        -87,
-        -83,
-        -80,
-        -77,
-        -73,
-        -70,
-        -67,
-        -63,
-        -60,
-        -57,
-        -54,
-        -50,
-        -47,
-        -44,
-        -40,
-        -37,
-        -34,
-        -30,
-        -27,
-        -24,
-        -20,
-        -17,
-        -14,
-        -10,
-        -7,
-        -4,
-        0,
-        3,
-        6,
-        10,
-        13,
-        16,
-        20,
-        23,
-        26,
-        30,
-        33,
-        36,
-        39,
-        43,
-        46,
-        49,
-        53,
-        56,
-        59,
-        63,
-        66,
-        69,
-        73,
-        76,
-        79,
-        83,
-        86,
-        89,
-        93,
-        96,
-        99,
-        103,
-        106,
-        109,
-        113,
-        116,
-        119,
-        123,
-        126,
-        129,
-        132,
-        136,
-        139,
-        142,
-        146,
-        149,
-        152,
-        156,
-        159,
-        162,
-        166,
-        169,
-        172,
-        176,
-        179,
-        182,
-        186,
-        189,
-        192,
-        196,
-        199,
-        202,
-        206,
-        209,
-        212,
-        216,
-        219,
-        222,
-        226,
-        229,
-        232,
-        235,
-        239,
-        242,
-        245,
-        249,
-        252,
-        255,
-        259,
-        262,
-        265,
-        269,
-        272,
-        275,
-        279,
-        282,
-        285,
-        289,
-        292,
-        295,
-        299,
-        302,
-        305,
-        309,
-        312,
-        315,
-        319,
-        322,
-        325,
-        328,
-        332,
-        335,
-        338,
-        342,
-        345,
-        348,
-        352,
-        355,
-        358,
-        362,
-        365,
-        368,
-        372,
-        375,
-        378,
-        382,
-        385,
-        388,
-        392,
-        395,
-        398,
-        402,
-        405,
-        408,
-        412,
-        415,
-        418,
-        422,
-        425,
-        428,
-        431,
-        435,
-        438,
-        441,
-        445,
-        448,
-        451,
-        455,
-        458,
-        461,
-        465,
-        468,
-        471,
-        475,
-        478,
-        481,
-        485,
-        488,
-        491,
-        495,
-        498,
-        501,
-        505,
-        508,
-        511,
-        515,
-        518,
-        521,
-        524,
-        528,
-        531,
-        534,
-        538,
-        541,
-        544,
-        548,
-        551,
-        554,
-        558,
-        561,
-        564,
-        568,
-        571,
-        574,
-        578,
-        581,
-        584,
-        588,
-        591,
-        594,
-        598,
-        601,
-        604,
-        608,
-        611,
-        614,
-        617,
-        621,
-        624,
-        627,
-        631,
-        634,
-        637,
-        641,
-        644,
-        647,
-        651,
-        654,
-        657,
-        661,
-        664,
-        667,
-        671,
-        674,
-        677,
-        681,
-        684,
-        687,
-        691,
-        694,
-        697,
-        701,
-        704,
-        707,
-        711,
-        714,
-        717,
-        720,
-        724,
-        727,
-        730,
-        734,
-        737,
-        740,
-        744,
-        747,
-        750,
-        754,
-        757,
-        760,
-        764,
-        767,
-        770,
-        774,
-        777,
-        780,
-        784,
-        787,
-        790,
-        794,
-        797,
-        800,
-        804,
-        807,
-        810,
-        813,
-        817,
-        820,
-        823,
-        827,
-        830,
-        833,
-        837,
-        840,
-        843,
-        847,
-        850,
-        853,
-        857,
-        860,
-        863,
-        867,
-        870,
-        873,
-        877,
-        880,
-        883,
-        887,
-        890,
-        893,
-        897,
-        900,
-        903,
-        907,
-        910,
-        913,
-        916,
-        920,
-        923,
-        926,
-        930,
-        933,
-        936,
-        940,
-        943,
-        946,
-        950,
-    ],
-);
+// Use static to avoid long compile times: Rust compiler errors
+// can have the entire table compiled multiple times, and then
+// emit code multiple times, even if it's stripped out in
+// the final binary.
+pub static POWER_OF_FIVE_128: [(u64, u64); N_POWERS_OF_FIVE] = [
+    (0xEEF4_53D6_923B_D65A, 0x113F_AA29_06A1_3B3F), // 5^-342
+    (0x9558_B466_1B65_65F8, 0x4AC7_CA59_A424_C507), // 5^-341
+    (0xBAAE_E17F_A23E_BF76, 0x5D79_BCF0_0D2D_F649), // 5^-340
+    (0xE95A_99DF_8ACE_6F53, 0xF4D8_2C2C_1079_73DC), // 5^-339
+    (0x91D8_A02B_B6C1_0594, 0x7907_1B9B_8A4B_E869), // 5^-338
+    (0xB64E_C836_A471_46F9, 0x9748_E282_6CDE_E284), // 5^-337
+    (0xE3E2_7A44_4D8D_98B7, 0xFD1B_1B23_0816_9B25), // 5^-336
+    (0x8E6D_8C6A_B078_7F72, 0xFE30_F0F5_E50E_20F7), // 5^-335
+    (0xB208_EF85_5C96_9F4F, 0xBDBD_2D33_5E51_A935), // 5^-334
+    (0xDE8B_2B66_B3BC_4723, 0xAD2C_7880_35E6_1382), // 5^-333
+    (0x8B16_FB20_3055_AC76, 0x4C3B_CB50_21AF_CC31), // 5^-332
+    (0xADDC_B9E8_3C6B_1793, 0xDF4A_BE24_2A1B_BF3D), // 5^-331
+    (0xD953_E862_4B85_DD78, 0xD71D_6DAD_34A2_AF0D), // 5^-330
+    (0x87D4_713D_6F33_AA6B, 0x8672_648C_40E5_AD68), // 5^-329
+    (0xA9C9_8D8C_CB00_9506, 0x680E_FDAF_511F_18C2), // 5^-328
+    (0xD43B_F0EF_FDC0_BA48, 0x0212_BD1B_2566_DEF2), // 5^-327
+    (0x84A5_7695_FE98_746D, 0x014B_B630_F760_4B57), // 5^-326
+    (0xA5CE_D43B_7E3E_9188, 0x419E_A3BD_3538_5E2D), // 5^-325
+    (0xCF42_894A_5DCE_35EA, 0x5206_4CAC_8286_75B9), // 5^-324
+    (0x8189_95CE_7AA0_E1B2, 0x7343_EFEB_D194_0993), // 5^-323
+    (0xA1EB_FB42_1949_1A1F, 0x1014_EBE6_C5F9_0BF8), // 5^-322
+    (0xCA66_FA12_9F9B_60A6, 0xD41A_26E0_7777_4EF6), // 5^-321
+    (0xFD00_B897_4782_38D0, 0x8920_B098_9555_22B4), // 5^-320
+    (0x9E20_735E_8CB1_6382, 0x55B4_6E5F_5D55_35B0), // 5^-319
+    (0xC5A8_9036_2FDD_BC62, 0xEB21_89F7_34AA_831D), // 5^-318
+    (0xF712_B443_BBD5_2B7B, 0xA5E9_EC75_01D5_23E4), // 5^-317
+    (0x9A6B_B0AA_5565_3B2D, 0x47B2_33C9_2125_366E), // 5^-316
+    (0xC106_9CD4_EABE_89F8, 0x999E_C0BB_696E_840A), // 5^-315
+    (0xF148_440A_256E_2C76, 0xC006_70EA_43CA_250D), // 5^-314
+    (0x96CD_2A86_5764_DBCA, 0x3804_0692_6A5E_5728), // 5^-313
+    (0xBC80_7527_ED3E_12BC, 0xC605_0837_04F5_ECF2), // 5^-312
+    (0xEBA0_9271_E88D_976B, 0xF786_4A44_C633_682E), // 5^-311
+    (0x9344_5B87_3158_7EA3, 0x7AB3_EE6A_FBE0_211D), // 5^-310
+    (0xB815_7268_FDAE_9E4C, 0x5960_EA05_BAD8_2964), // 5^-309
+    (0xE61A_CF03_3D1A_45DF, 0x6FB9_2487_298E_33BD), // 5^-308
+    (0x8FD0_C162_0630_6BAB, 0xA5D3_B6D4_79F8_E056), // 5^-307
+    (0xB3C4_F1BA_87BC_8696, 0x8F48_A489_9877_186C), // 5^-306
+    (0xE0B6_2E29_29AB_A83C, 0x331A_CDAB_FE94_DE87), // 5^-305
+    (0x8C71_DCD9_BA0B_4925, 0x9FF0_C08B_7F1D_0B14), // 5^-304
+    (0xAF8E_5410_288E_1B6F, 0x07EC_F0AE_5EE4_4DD9), // 5^-303
+    (0xDB71_E914_32B1_A24A, 0xC9E8_2CD9_F69D_6150), // 5^-302
+    (0x8927_31AC_9FAF_056E, 0xBE31_1C08_3A22_5CD2), // 5^-301
+    (0xAB70_FE17_C79A_C6CA, 0x6DBD_630A_48AA_F406), // 5^-300
+    (0xD64D_3D9D_B981_787D, 0x092C_BBCC_DAD5_B108), // 5^-299
+    (0x85F0_4682_93F0_EB4E, 0x25BB_F560_08C5_8EA5), // 5^-298
+    (0xA76C_5823_38ED_2621, 0xAF2A_F2B8_0AF6_F24E), // 5^-297
+    (0xD147_6E2C_0728_6FAA, 0x1AF5_AF66_0DB4_AEE1), // 5^-296
+    (0x82CC_A4DB_8479_45CA, 0x50D9_8D9F_C890_ED4D), // 5^-295
+    (0xA37F_CE12_6597_973C, 0xE50F_F107_BAB5_28A0), // 5^-294
+    (0xCC5F_C196_FEFD_7D0C, 0x1E53_ED49_A962_72C8), // 5^-293
+    (0xFF77_B1FC_BEBC_DC4F, 0x25E8_E89C_13BB_0F7A), // 5^-292
+    (0x9FAA_CF3D_F736_09B1, 0x77B1_9161_8C54_E9AC), // 5^-291
+    (0xC795_830D_7503_8C1D, 0xD59D_F5B9_EF6A_2417), // 5^-290
+    (0xF97A_E3D0_D244_6F25, 0x4B05_7328_6B44_AD1D), // 5^-289
+    (0x9BEC_CE62_836A_C577, 0x4EE3_67F9_430A_EC32), // 5^-288
+    (0xC2E8_01FB_2445_76D5, 0x229C_41F7_93CD_A73F), // 5^-287
+    (0xF3A2_0279_ED56_D48A, 0x6B43_5275_78C1_110F), // 5^-286
+    (0x9845_418C_3456_44D6, 0x830A_1389_6B78_AAA9), // 5^-285
+    (0xBE56_91EF_416B_D60C, 0x23CC_986B_C656_D553), // 5^-284
+    (0xEDEC_366B_11C6_CB8F, 0x2CBF_BE86_B7EC_8AA8), // 5^-283
+    (0x94B3_A202_EB1C_3F39, 0x7BF7_D714_32F3_D6A9), // 5^-282
+    (0xB9E0_8A83_A5E3_4F07, 0xDAF5_CCD9_3FB0_CC53), // 5^-281
+    (0xE858_AD24_8F5C_22C9, 0xD1B3_400F_8F9C_FF68), // 5^-280
+    (0x9137_6C36_D999_95BE, 0x2310_0809_B9C2_1FA1), // 5^-279
+    (0xB585_4744_8FFF_FB2D, 0xABD4_0A0C_2832_A78A), // 5^-278
+    (0xE2E6_9915_B3FF_F9F9, 0x16C9_0C8F_323F_516C), // 5^-277
+    (0x8DD0_1FAD_907F_FC3B, 0xAE3D_A7D9_7F67_92E3), // 5^-276
+    (0xB144_2798_F49F_FB4A, 0x99CD_11CF_DF41_779C), // 5^-275
+    (0xDD95_317F_31C7_FA1D, 0x4040_5643_D711_D583), // 5^-274
+    (0x8A7D_3EEF_7F1C_FC52, 0x4828_35EA_666B_2572), // 5^-273
+    (0xAD1C_8EAB_5EE4_3B66, 0xDA32_4365_0005_EECF), // 5^-272
+    (0xD863_B256_369D_4A40, 0x90BE_D43E_4007_6A82), // 5^-271
+    (0x873E_4F75_E222_4E68, 0x5A77_44A6_E804_A291), // 5^-270
+    (0xA90D_E353_5AAA_E202, 0x7115_15D0_A205_CB36), // 5^-269
+    (0xD351_5C28_3155_9A83, 0x0D5A_5B44_CA87_3E03), // 5^-268
+    (0x8412_D999_1ED5_8091, 0xE858_790A_FE94_86C2), // 5^-267
+    (0xA517_8FFF_668A_E0B6, 0x626E_974D_BE39_A872), // 5^-266
+    (0xCE5D_73FF_402D_98E3, 0xFB0A_3D21_2DC8_128F), // 5^-265
+    (0x80FA_687F_881C_7F8E, 0x7CE6_6634_BC9D_0B99), // 5^-264
+    (0xA139_029F_6A23_9F72, 0x1C1F_FFC1_EBC4_4E80), // 5^-263
+    (0xC987_4347_44AC_874E, 0xA327_FFB2_66B5_6220), // 5^-262
+    (0xFBE9_1419_15D7_A922, 0x4BF1_FF9F_0062_BAA8), // 5^-261
+    (0x9D71_AC8F_ADA6_C9B5, 0x6F77_3FC3_603D_B4A9), // 5^-260
+    (0xC4CE_17B3_9910_7C22, 0xCB55_0FB4_384D_21D3), // 5^-259
+    (0xF601_9DA0_7F54_9B2B, 0x7E2A_53A1_4660_6A48), // 5^-258
+    (0x99C1_0284_4F94_E0FB, 0x2EDA_7444_CBFC_426D), // 5^-257
+    (0xC031_4325_637A_1939, 0xFA91_1155_FEFB_5308), // 5^-256
+    (0xF03D_93EE_BC58_9F88, 0x7935_55AB_7EBA_27CA), // 5^-255
+    (0x9626_7C75_35B7_63B5, 0x4BC1_558B_2F34_58DE), // 5^-254
+    (0xBBB0_1B92_8325_3CA2, 0x9EB1_AAED_FB01_6F16), // 5^-253
+    (0xEA9C_2277_23EE_8BCB, 0x465E_15A9_79C1_CADC), // 5^-252
+    (0x92A1_958A_7675_175F, 0x0BFA_CD89_EC19_1EC9), // 5^-251
+    (0xB749_FAED_1412_5D36, 0xCEF9_80EC_671F_667B), // 5^-250
+    (0xE51C_79A8_5916_F484, 0x82B7_E127_80E7_401A), // 5^-249
+    (0x8F31_CC09_37AE_58D2, 0xD1B2_ECB8_B090_8810), // 5^-248
+    (0xB2FE_3F0B_8599_EF07, 0x861F_A7E6_DCB4_AA15), // 5^-247
+    (0xDFBD_CECE_6700_6AC9, 0x67A7_91E0_93E1_D49A), // 5^-246
+    (0x8BD6_A141_0060_42BD, 0xE0C8_BB2C_5C6D_24E0), // 5^-245
+    (0xAECC_4991_4078_536D, 0x58FA_E9F7_7388_6E18), // 5^-244
+    (0xDA7F_5BF5_9096_6848, 0xAF39_A475_506A_899E), // 5^-243
+    (0x888F_9979_7A5E_012D, 0x6D84_06C9_5242_9603), // 5^-242
+    (0xAAB3_7FD7_D8F5_8178, 0xC8E5_087B_A6D3_3B83), // 5^-241
+    (0xD560_5FCD_CF32_E1D6, 0xFB1E_4A9A_9088_0A64), // 5^-240
+    (0x855C_3BE0_A17F_CD26, 0x5CF2_EEA0_9A55_067F), // 5^-239
+    (0xA6B3_4AD8_C9DF_C06F, 0xF42F_AA48_C0EA_481E), // 5^-238
+    (0xD060_1D8E_FC57_B08B, 0xF13B_94DA_F124_DA26), // 5^-237
+    (0x823C_1279_5DB6_CE57, 0x76C5_3D08_D6B7_0858), // 5^-236
+    (0xA2CB_1717_B524_81ED, 0x5476_8C4B_0C64_CA6E), // 5^-235
+    (0xCB7D_DCDD_A26D_A268, 0xA994_2F5D_CF7D_FD09), // 5^-234
+    (0xFE5D_5415_0B09_0B02, 0xD3F9_3B35_435D_7C4C), // 5^-233
+    (0x9EFA_548D_26E5_A6E1, 0xC47B_C501_4A1A_6DAF), // 5^-232
+    (0xC6B8_E9B0_709F_109A, 0x359A_B641_9CA1_091B), // 5^-231
+    (0xF867_241C_8CC6_D4C0, 0xC301_63D2_03C9_4B62), // 5^-230
+    (0x9B40_7691_D7FC_44F8, 0x79E0_DE63_425D_CF1D), // 5^-229
+    (0xC210_9436_4DFB_5636, 0x9859_15FC_12F5_42E4), // 5^-228
+    (0xF294_B943_E17A_2BC4, 0x3E6F_5B7B_17B2_939D), // 5^-227
+    (0x979C_F3CA_6CEC_5B5A, 0xA705_992C_EECF_9C42), // 5^-226
+    (0xBD84_30BD_0827_7231, 0x50C6_FF78_2A83_8353), // 5^-225
+    (0xECE5_3CEC_4A31_4EBD, 0xA4F8_BF56_3524_6428), // 5^-224
+    (0x940F_4613_AE5E_D136, 0x871B_7795_E136_BE99), // 5^-223
+    (0xB913_1798_99F6_8584, 0x28E2_557B_5984_6E3F), // 5^-222
+    (0xE757_DD7E_C074_26E5, 0x331A_EADA_2FE5_89CF), // 5^-221
+    (0x9096_EA6F_3848_984F, 0x3FF0_D2C8_5DEF_7621), // 5^-220
+    (0xB4BC_A50B_065A_BE63, 0x0FED_077A_756B_53A9), // 5^-219
+    (0xE1EB_CE4D_C7F1_6DFB, 0xD3E8_4959_12C6_2894), // 5^-218
+    (0x8D33_60F0_9CF6_E4BD, 0x6471_2DD7_ABBB_D95C), // 5^-217
+    (0xB080_392C_C434_9DEC, 0xBD8D_794D_96AA_CFB3), // 5^-216
+    (0xDCA0_4777_F541_C567, 0xECF0_D7A0_FC55_83A0), // 5^-215
+    (0x89E4_2CAA_F949_1B60, 0xF416_86C4_9DB5_7244), // 5^-214
+    (0xAC5D_37D5_B79B_6239, 0x311C_2875_C522_CED5), // 5^-213
+    (0xD774_85CB_2582_3AC7, 0x7D63_3293_366B_828B), // 5^-212
+    (0x86A8_D39E_F771_64BC, 0xAE5D_FF9C_0203_3197), // 5^-211
+    (0xA853_0886_B54D_BDEB, 0xD9F5_7F83_0283_FDFC), // 5^-210
+    (0xD267_CAA8_62A1_2D66, 0xD072_DF63_C324_FD7B), // 5^-209
+    (0x8380_DEA9_3DA4_BC60, 0x4247_CB9E_59F7_1E6D), // 5^-208
+    (0xA461_1653_8D0D_EB78, 0x52D9_BE85_F074_E608), // 5^-207
+    (0xCD79_5BE8_7051_6656, 0x6790_2E27_6C92_1F8B), // 5^-206
+    (0x806B_D971_4632_DFF6, 0x00BA_1CD8_A3DB_53B6), // 5^-205
+    (0xA086_CFCD_97BF_97F3, 0x80E8_A40E_CCD2_28A4), // 5^-204
+    (0xC8A8_83C0_FDAF_7DF0, 0x6122_CD12_8006_B2CD), // 5^-203
+    (0xFAD2_A4B1_3D1B_5D6C, 0x796B_8057_2008_5F81), // 5^-202
+    (0x9CC3_A6EE_C631_1A63, 0xCBE3_3036_7405_3BB0), // 5^-201
+    (0xC3F4_90AA_77BD_60FC, 0xBEDB_FC44_1106_8A9C), // 5^-200
+    (0xF4F1_B4D5_15AC_B93B, 0xEE92_FB55_1548_2D44), // 5^-199
+    (0x9917_1105_2D8B_F3C5, 0x751B_DD15_2D4D_1C4A), // 5^-198
+    (0xBF5C_D546_78EE_F0B6, 0xD262_D45A_78A0_635D), // 5^-197
+    (0xEF34_0A98_172A_ACE4, 0x86FB_8971_16C8_7C34), // 5^-196
+    (0x9580_869F_0E7A_AC0E, 0xD45D_35E6_AE3D_4DA0), // 5^-195
+    (0xBAE0_A846_D219_5712, 0x8974_8360_59CC_A109), // 5^-194
+    (0xE998_D258_869F_ACD7, 0x2BD1_A438_703F_C94B), // 5^-193
+    (0x91FF_8377_5423_CC06, 0x7B63_06A3_4627_DDCF), // 5^-192
+    (0xB67F_6455_292C_BF08, 0x1A3B_C84C_17B1_D542), // 5^-191
+    (0xE41F_3D6A_7377_EECA, 0x20CA_BA5F_1D9E_4A93), // 5^-190
+    (0x8E93_8662_882A_F53E, 0x547E_B47B_7282_EE9C), // 5^-189
+    (0xB238_67FB_2A35_B28D, 0xE99E_619A_4F23_AA43), // 5^-188
+    (0xDEC6_81F9_F4C3_1F31, 0x6405_FA00_E2EC_94D4), // 5^-187
+    (0x8B3C_113C_38F9_F37E, 0xDE83_BC40_8DD3_DD04), // 5^-186
+    (0xAE0B_158B_4738_705E, 0x9624_AB50_B148_D445), // 5^-185
+    (0xD98D_DAEE_1906_8C76, 0x3BAD_D624_DD9B_0957), // 5^-184
+    (0x87F8_A8D4_CFA4_17C9, 0xE54C_A5D7_0A80_E5D6), // 5^-183
+    (0xA9F6_D30A_038D_1DBC, 0x5E9F_CF4C_CD21_1F4C), // 5^-182
+    (0xD474_87CC_8470_652B, 0x7647_C320_0069_671F), // 5^-181
+    (0x84C8_D4DF_D2C6_3F3B, 0x29EC_D9F4_0041_E073), // 5^-180
+    (0xA5FB_0A17_C777_CF09, 0xF468_1071_0052_5890), // 5^-179
+    (0xCF79_CC9D_B955_C2CC, 0x7182_148D_4066_EEB4), // 5^-178
+    (0x81AC_1FE2_93D5_99BF, 0xC6F1_4CD8_4840_5530), // 5^-177
+    (0xA217_27DB_38CB_002F, 0xB8AD_A00E_5A50_6A7C), // 5^-176
+    (0xCA9C_F1D2_06FD_C03B, 0xA6D9_0811_F0E4_851C), // 5^-175
+    (0xFD44_2E46_88BD_304A, 0x908F_4A16_6D1D_A663), // 5^-174
+    (0x9E4A_9CEC_1576_3E2E, 0x9A59_8E4E_0432_87FE), // 5^-173
+    (0xC5DD_4427_1AD3_CDBA, 0x40EF_F1E1_853F_29FD), // 5^-172
+    (0xF754_9530_E188_C128, 0xD12B_EE59_E68E_F47C), // 5^-171
+    (0x9A94_DD3E_8CF5_78B9, 0x82BB_74F8_3019_58CE), // 5^-170
+    (0xC13A_148E_3032_D6E7, 0xE36A_5236_3C1F_AF01), // 5^-169
+    (0xF188_99B1_BC3F_8CA1, 0xDC44_E6C3_CB27_9AC1), // 5^-168
+    (0x96F5_600F_15A7_B7E5, 0x29AB_103A_5EF8_C0B9), // 5^-167
+    (0xBCB2_B812_DB11_A5DE, 0x7415_D448_F6B6_F0E7), // 5^-166
+    (0xEBDF_6617_91D6_0F56, 0x111B_495B_3464_AD21), // 5^-165
+    (0x936B_9FCE_BB25_C995, 0xCAB1_0DD9_00BE_EC34), // 5^-164
+    (0xB846_87C2_69EF_3BFB, 0x3D5D_514F_40EE_A742), // 5^-163
+    (0xE658_29B3_046B_0AFA, 0x0CB4_A5A3_112A_5112), // 5^-162
+    (0x8FF7_1A0F_E2C2_E6DC, 0x47F0_E785_EABA_72AB), // 5^-161
+    (0xB3F4_E093_DB73_A093, 0x59ED_2167_6569_0F56), // 5^-160
+    (0xE0F2_18B8_D250_88B8, 0x3068_69C1_3EC3_532C), // 5^-159
+    (0x8C97_4F73_8372_5573, 0x1E41_4218_C73A_13FB), // 5^-158
+    (0xAFBD_2350_644E_EACF, 0xE5D1_929E_F908_98FA), // 5^-157
+    (0xDBAC_6C24_7D62_A583, 0xDF45_F746_B74A_BF39), // 5^-156
+    (0x894B_C396_CE5D_A772, 0x6B8B_BA8C_328E_B783), // 5^-155
+    (0xAB9E_B47C_81F5_114F, 0x066E_A92F_3F32_6564), // 5^-154
+    (0xD686_619B_A272_55A2, 0xC80A_537B_0EFE_FEBD), // 5^-153
+    (0x8613_FD01_4587_7585, 0xBD06_742C_E95F_5F36), // 5^-152
+    (0xA798_FC41_96E9_52E7, 0x2C48_1138_23B7_3704), // 5^-151
+    (0xD17F_3B51_FCA3_A7A0, 0xF75A_1586_2CA5_04C5), // 5^-150
+    (0x82EF_8513_3DE6_48C4, 0x9A98_4D73_DBE7_22FB), // 5^-149
+    (0xA3AB_6658_0D5F_DAF5, 0xC13E_60D0_D2E0_EBBA), // 5^-148
+    (0xCC96_3FEE_10B7_D1B3, 0x318D_F905_0799_26A8), // 5^-147
+    (0xFFBB_CFE9_94E5_C61F, 0xFDF1_7746_497F_7052), // 5^-146
+    (0x9FD5_61F1_FD0F_9BD3, 0xFEB6_EA8B_EDEF_A633), // 5^-145
+    (0xC7CA_BA6E_7C53_82C8, 0xFE64_A52E_E96B_8FC0), // 5^-144
+    (0xF9BD_690A_1B68_637B, 0x3DFD_CE7A_A3C6_73B0), // 5^-143
+    (0x9C16_61A6_5121_3E2D, 0x06BE_A10C_A65C_084E), // 5^-142
+    (0xC31B_FA0F_E569_8DB8, 0x486E_494F_CFF3_0A62), // 5^-141
+    (0xF3E2_F893_DEC3_F126, 0x5A89_DBA3_C3EF_CCFA), // 5^-140
+    (0x986D_DB5C_6B3A_76B7, 0xF896_2946_5A75_E01C), // 5^-139
+    (0xBE89_5233_8609_1465, 0xF6BB_B397_F113_5823), // 5^-138
+    (0xEE2B_A6C0_678B_597F, 0x746A_A07D_ED58_2E2C), // 5^-137
+    (0x94DB_4838_40B7_17EF, 0xA8C2_A44E_B457_1CDC), // 5^-136
+    (0xBA12_1A46_50E4_DDEB, 0x92F3_4D62_616C_E413), // 5^-135
+    (0xE896_A0D7_E51E_1566, 0x77B0_20BA_F9C8_1D17), // 5^-134
+    (0x915E_2486_EF32_CD60, 0x0ACE_1474_DC1D_122E), // 5^-133
+    (0xB5B5_ADA8_AAFF_80B8, 0x0D81_9992_1324_56BA), // 5^-132
+    (0xE323_1912_D5BF_60E6, 0x10E1_FFF6_97ED_6C69), // 5^-131
+    (0x8DF5_EFAB_C597_9C8F, 0xCA8D_3FFA_1EF4_63C1), // 5^-130
+    (0xB173_6B96_B6FD_83B3, 0xBD30_8FF8_A6B1_7CB2), // 5^-129
+    (0xDDD0_467C_64BC_E4A0, 0xAC7C_B3F6_D05D_DBDE), // 5^-128
+    (0x8AA2_2C0D_BEF6_0EE4, 0x6BCD_F07A_423A_A96B), // 5^-127
+    (0xAD4A_B711_2EB3_929D, 0x86C1_6C98_D2C9_53C6), // 5^-126
+    (0xD89D_64D5_7A60_7744, 0xE871_C7BF_077B_A8B7), // 5^-125
+    (0x8762_5F05_6C7C_4A8B, 0x1147_1CD7_64AD_4972), // 5^-124
+    (0xA93A_F6C6_C79B_5D2D, 0xD598_E40D_3DD8_9BCF), // 5^-123
+    (0xD389_B478_7982_3479, 0x4AFF_1D10_8D4E_C2C3), // 5^-122
+    (0x8436_10CB_4BF1_60CB, 0xCEDF_722A_5851_39BA), // 5^-121
+    (0xA543_94FE_1EED_B8FE, 0xC297_4EB4_EE65_8828), // 5^-120
+    (0xCE94_7A3D_A6A9_273E, 0x733D_2262_29FE_EA32), // 5^-119
+    (0x811C_CC66_8829_B887, 0x0806_357D_5A3F_525F), // 5^-118
+    (0xA163_FF80_2A34_26A8, 0xCA07_C2DC_B0CF_26F7), // 5^-117
+    (0xC9BC_FF60_34C1_3052, 0xFC89_B393_DD02_F0B5), // 5^-116
+    (0xFC2C_3F38_41F1_7C67, 0xBBAC_2078_D443_ACE2), // 5^-115
+    (0x9D9B_A783_2936_EDC0, 0xD54B_944B_84AA_4C0D), // 5^-114
+    (0xC502_9163_F384_A931, 0x0A9E_795E_65D4_DF11), // 5^-113
+    (0xF643_35BC_F065_D37D, 0x4D46_17B5_FF4A_16D5), // 5^-112
+    (0x99EA_0196_163F_A42E, 0x504B_CED1_BF8E_4E45), // 5^-111
+    (0xC064_81FB_9BCF_8D39, 0xE45E_C286_2F71_E1D6), // 5^-110
+    (0xF07D_A27A_82C3_7088, 0x5D76_7327_BB4E_5A4C), // 5^-109
+    (0x964E_858C_91BA_2655, 0x3A6A_07F8_D510_F86F), // 5^-108
+    (0xBBE2_26EF_B628_AFEA, 0x8904_89F7_0A55_368B), // 5^-107
+    (0xEADA_B0AB_A3B2_DBE5, 0x2B45_AC74_CCEA_842E), // 5^-106
+    (0x92C8_AE6B_464F_C96F, 0x3B0B_8BC9_0012_929D), // 5^-105
+    (0xB77A_DA06_17E3_BBCB, 0x09CE_6EBB_4017_3744), // 5^-104
+    (0xE559_9087_9DDC_AABD, 0xCC42_0A6A_101D_0515), // 5^-103
+    (0x8F57_FA54_C2A9_EAB6, 0x9FA9_4682_4A12_232D), // 5^-102
+    (0xB32D_F8E9_F354_6564, 0x4793_9822_DC96_ABF9), // 5^-101
+    (0xDFF9_7724_7029_7EBD, 0x5978_7E2B_93BC_56F7), // 5^-100
+    (0x8BFB_EA76_C619_EF36, 0x57EB_4EDB_3C55_B65A), // 5^-99
+    (0xAEFA_E514_77A0_6B03, 0xEDE6_2292_0B6B_23F1), // 5^-98
+    (0xDAB9_9E59_9588_85C4, 0xE95F_AB36_8E45_ECED), // 5^-97
+    (0x88B4_02F7_FD75_539B, 0x11DB_CB02_18EB_B414), // 5^-96
+    (0xAAE1_03B5_FCD2_A881, 0xD652_BDC2_9F26_A119), // 5^-95
+    (0xD599_44A3_7C07_52A2, 0x4BE7_6D33_46F0_495F), // 5^-94
+    (0x857F_CAE6_2D84_93A5, 0x6F70_A440_0C56_2DDB), // 5^-93
+    (0xA6DF_BD9F_B8E5_B88E, 0xCB4C_CD50_0F6B_B952), // 5^-92
+    (0xD097_AD07_A71F_26B2, 0x7E20_00A4_1346_A7A7), // 5^-91
+    (0x825E_CC24_C873_782F, 0x8ED4_0066_8C0C_28C8), // 5^-90
+    (0xA2F6_7F2D_FA90_563B, 0x7289_0080_2F0F_32FA), // 5^-89
+    (0xCBB4_1EF9_7934_6BCA, 0x4F2B_40A0_3AD2_FFB9), // 5^-88
+    (0xFEA1_26B7_D781_86BC, 0xE2F6_10C8_4987_BFA8), // 5^-87
+    (0x9F24_B832_E6B0_F436, 0x0DD9_CA7D_2DF4_D7C9), // 5^-86
+    (0xC6ED_E63F_A05D_3143, 0x9150_3D1C_7972_0DBB), // 5^-85
+    (0xF8A9_5FCF_8874_7D94, 0x75A4_4C63_97CE_912A), // 5^-84
+    (0x9B69_DBE1_B548_CE7C, 0xC986_AFBE_3EE1_1ABA), // 5^-83
+    (0xC244_52DA_229B_021B, 0xFBE8_5BAD_CE99_6168), // 5^-82
+    (0xF2D5_6790_AB41_C2A2, 0xFAE2_7299_423F_B9C3), // 5^-81
+    (0x97C5_60BA_6B09_19A5, 0xDCCD_879F_C967_D41A), // 5^-80
+    (0xBDB6_B8E9_05CB_600F, 0x5400_E987_BBC1_C920), // 5^-79
+    (0xED24_6723_473E_3813, 0x2901_23E9_AAB2_3B68), // 5^-78
+    (0x9436_C076_0C86_E30B, 0xF9A0_B672_0AAF_6521), // 5^-77
+    (0xB944_7093_8FA8_9BCE, 0xF808_E40E_8D5B_3E69), // 5^-76
+    (0xE795_8CB8_7392_C2C2, 0xB60B_1D12_30B2_0E04), // 5^-75
+    (0x90BD_77F3_483B_B9B9, 0xB1C6_F22B_5E6F_48C2), // 5^-74
+    (0xB4EC_D5F0_1A4A_A828, 0x1E38_AEB6_360B_1AF3), // 5^-73
+    (0xE228_0B6C_20DD_5232, 0x25C6_DA63_C38D_E1B0), // 5^-72
+    (0x8D59_0723_948A_535F, 0x579C_487E_5A38_AD0E), // 5^-71
+    (0xB0AF_48EC_79AC_E837, 0x2D83_5A9D_F0C6_D851), // 5^-70
+    (0xDCDB_1B27_9818_2244, 0xF8E4_3145_6CF8_8E65), // 5^-69
+    (0x8A08_F0F8_BF0F_156B, 0x1B8E_9ECB_641B_58FF), // 5^-68
+    (0xAC8B_2D36_EED2_DAC5, 0xE272_467E_3D22_2F3F), // 5^-67
+    (0xD7AD_F884_AA87_9177, 0x5B0E_D81D_CC6A_BB0F), // 5^-66
+    (0x86CC_BB52_EA94_BAEA, 0x98E9_4712_9FC2_B4E9), // 5^-65
+    (0xA87F_EA27_A539_E9A5, 0x3F23_98D7_47B3_6224), // 5^-64
+    (0xD29F_E4B1_8E88_640E, 0x8EEC_7F0D_19A0_3AAD), // 5^-63
+    (0x83A3_EEEE_F915_3E89, 0x1953_CF68_3004_24AC), // 5^-62
+    (0xA48C_EAAA_B75A_8E2B, 0x5FA8_C342_3C05_2DD7), // 5^-61
+    (0xCDB0_2555_6531_31B6, 0x3792_F412_CB06_794D), // 5^-60
+    (0x808E_1755_5F3E_BF11, 0xE2BB_D88B_BEE4_0BD0), // 5^-59
+    (0xA0B1_9D2A_B70E_6ED6, 0x5B6A_CEAE_AE9D_0EC4), // 5^-58
+    (0xC8DE_0475_64D2_0A8B, 0xF245_825A_5A44_5275), // 5^-57
+    (0xFB15_8592_BE06_8D2E, 0xEED6_E2F0_F0D5_6712), // 5^-56
+    (0x9CED_737B_B6C4_183D, 0x5546_4DD6_9685_606B), // 5^-55
+    (0xC428_D05A_A475_1E4C, 0xAA97_E14C_3C26_B886), // 5^-54
+    (0xF533_0471_4D92_65DF, 0xD53D_D99F_4B30_66A8), // 5^-53
+    (0x993F_E2C6_D07B_7FAB, 0xE546_A803_8EFE_4029), // 5^-52
+    (0xBF8F_DB78_849A_5F96, 0xDE98_5204_72BD_D033), // 5^-51
+    (0xEF73_D256_A5C0_F77C, 0x963E_6685_8F6D_4440), // 5^-50
+    (0x95A8_6376_2798_9AAD, 0xDDE7_0013_79A4_4AA8), // 5^-49
+    (0xBB12_7C53_B17E_C159, 0x5560_C018_580D_5D52), // 5^-48
+    (0xE9D7_1B68_9DDE_71AF, 0xAAB8_F01E_6E10_B4A6), // 5^-47
+    (0x9226_7121_62AB_070D, 0xCAB3_9613_04CA_70E8), // 5^-46
+    (0xB6B0_0D69_BB55_C8D1, 0x3D60_7B97_C5FD_0D22), // 5^-45
+    (0xE45C_10C4_2A2B_3B05, 0x8CB8_9A7D_B77C_506A), // 5^-44
+    (0x8EB9_8A7A_9A5B_04E3, 0x77F3_608E_92AD_B242), // 5^-43
+    (0xB267_ED19_40F1_C61C, 0x55F0_38B2_3759_1ED3), // 5^-42
+    (0xDF01_E85F_912E_37A3, 0x6B6C_46DE_C52F_6688), // 5^-41
+    (0x8B61_313B_BABC_E2C6, 0x2323_AC4B_3B3D_A015), // 5^-40
+    (0xAE39_7D8A_A96C_1B77, 0xABEC_975E_0A0D_081A), // 5^-39
+    (0xD9C7_DCED_53C7_2255, 0x96E7_BD35_8C90_4A21), // 5^-38
+    (0x881C_EA14_545C_7575, 0x7E50_D641_77DA_2E54), // 5^-37
+    (0xAA24_2499_6973_92D2, 0xDDE5_0BD1_D5D0_B9E9), // 5^-36
+    (0xD4AD_2DBF_C3D0_7787, 0x955E_4EC6_4B44_E864), // 5^-35
+    (0x84EC_3C97_DA62_4AB4, 0xBD5A_F13B_EF0B_113E), // 5^-34
+    (0xA627_4BBD_D0FA_DD61, 0xECB1_AD8A_EACD_D58E), // 5^-33
+    (0xCFB1_1EAD_4539_94BA, 0x67DE_18ED_A581_4AF2), // 5^-32
+    (0x81CE_B32C_4B43_FCF4, 0x80EA_CF94_8770_CED7), // 5^-31
+    (0xA242_5FF7_5E14_FC31, 0xA125_8379_A94D_028D), // 5^-30
+    (0xCAD2_F7F5_359A_3B3E, 0x096E_E458_13A0_4330), // 5^-29
+    (0xFD87_B5F2_8300_CA0D, 0x8BCA_9D6E_1888_53FC), // 5^-28
+    (0x9E74_D1B7_91E0_7E48, 0x775E_A264_CF55_347E), // 5^-27
+    (0xC612_0625_7658_9DDA, 0x9536_4AFE_032A_819E), // 5^-26
+    (0xF796_87AE_D3EE_C551, 0x3A83_DDBD_83F5_2205), // 5^-25
+    (0x9ABE_14CD_4475_3B52, 0xC492_6A96_7279_3543), // 5^-24
+    (0xC16D_9A00_9592_8A27, 0x75B7_053C_0F17_8294), // 5^-23
+    (0xF1C9_0080_BAF7_2CB1, 0x5324_C68B_12DD_6339), // 5^-22
+    (0x971D_A050_74DA_7BEE, 0xD3F6_FC16_EBCA_5E04), // 5^-21
+    (0xBCE5_0864_9211_1AEA, 0x88F4_BB1C_A6BC_F585), // 5^-20
+    (0xEC1E_4A7D_B695_61A5, 0x2B31_E9E3_D06C_32E6), // 5^-19
+    (0x9392_EE8E_921D_5D07, 0x3AFF_322E_6243_9FD0), // 5^-18
+    (0xB877_AA32_36A4_B449, 0x09BE_FEB9_FAD4_87C3), // 5^-17
+    (0xE695_94BE_C44D_E15B, 0x4C2E_BE68_7989_A9B4), // 5^-16
+    (0x901D_7CF7_3AB0_ACD9, 0x0F9D_3701_4BF6_0A11), // 5^-15
+    (0xB424_DC35_095C_D80F, 0x5384_84C1_9EF3_8C95), // 5^-14
+    (0xE12E_1342_4BB4_0E13, 0x2865_A5F2_06B0_6FBA), // 5^-13
+    (0x8CBC_CC09_6F50_88CB, 0xF93F_87B7_442E_45D4), // 5^-12
+    (0xAFEB_FF0B_CB24_AAFE, 0xF78F_69A5_1539_D749), // 5^-11
+    (0xDBE6_FECE_BDED_D5BE, 0xB573_440E_5A88_4D1C), // 5^-10
+    (0x8970_5F41_36B4_A597, 0x3168_0A88_F895_3031), // 5^-9
+    (0xABCC_7711_8461_CEFC, 0xFDC2_0D2B_36BA_7C3E), // 5^-8
+    (0xD6BF_94D5_E57A_42BC, 0x3D32_9076_0469_1B4D), // 5^-7
+    (0x8637_BD05_AF6C_69B5, 0xA63F_9A49_C2C1_B110), // 5^-6
+    (0xA7C5_AC47_1B47_8423, 0x0FCF_80DC_3372_1D54), // 5^-5
+    (0xD1B7_1758_E219_652B, 0xD3C3_6113_404E_A4A9), // 5^-4
+    (0x8312_6E97_8D4F_DF3B, 0x645A_1CAC_0831_26EA), // 5^-3
+    (0xA3D7_0A3D_70A3_D70A, 0x3D70_A3D7_0A3D_70A4), // 5^-2
+    (0xCCCC_CCCC_CCCC_CCCC, 0xCCCC_CCCC_CCCC_CCCD), // 5^-1
+    (0x8000_0000_0000_0000, 0x0000_0000_0000_0000), // 5^0
+    (0xA000_0000_0000_0000, 0x0000_0000_0000_0000), // 5^1
+    (0xC800_0000_0000_0000, 0x0000_0000_0000_0000), // 5^2
+    (0xFA00_0000_0000_0000, 0x0000_0000_0000_0000), // 5^3
+    (0x9C40_0000_0000_0000, 0x0000_0000_0000_0000), // 5^4
+    (0xC350_0000_0000_0000, 0x0000_0000_0000_0000), // 5^5
+    (0xF424_0000_0000_0000, 0x0000_0000_0000_0000), // 5^6
+    (0x9896_8000_0000_0000, 0x0000_0000_0000_0000), // 5^7
+    (0xBEBC_2000_0000_0000, 0x0000_0000_0000_0000), // 5^8
+    (0xEE6B_2800_0000_0000, 0x0000_0000_0000_0000), // 5^9
+    (0x9502_F900_0000_0000, 0x0000_0000_0000_0000), // 5^10
+    (0xBA43_B740_0000_0000, 0x0000_0000_0000_0000), // 5^11
+    (0xE8D4_A510_0000_0000, 0x0000_0000_0000_0000), // 5^12
+    (0x9184_E72A_0000_0000, 0x0000_0000_0000_0000), // 5^13
+    (0xB5E6_20F4_8000_0000, 0x0000_0000_0000_0000), // 5^14
+    (0xE35F_A931_A000_0000, 0x0000_0000_0000_0000), // 5^15
+    (0x8E1B_C9BF_0400_0000, 0x0000_0000_0000_0000), // 5^16
+    (0xB1A2_BC2E_C500_0000, 0x0000_0000_0000_0000), // 5^17
+    (0xDE0B_6B3A_7640_0000, 0x0000_0000_0000_0000), // 5^18
+    (0x8AC7_2304_89E8_0000, 0x0000_0000_0000_0000), // 5^19
+    (0xAD78_EBC5_AC62_0000, 0x0000_0000_0000_0000), // 5^20
+    (0xD8D7_26B7_177A_8000, 0x0000_0000_0000_0000), // 5^21
+    (0x8786_7832_6EAC_9000, 0x0000_0000_0000_0000), // 5^22
+    (0xA968_163F_0A57_B400, 0x0000_0000_0000_0000), // 5^23
+    (0xD3C2_1BCE_CCED_A100, 0x0000_0000_0000_0000), // 5^24
+    (0x8459_5161_4014_84A0, 0x0000_0000_0000_0000), // 5^25
+    (0xA56F_A5B9_9019_A5C8, 0x0000_0000_0000_0000), // 5^26
+    (0xCECB_8F27_F420_0F3A, 0x0000_0000_0000_0000), // 5^27
+    (0x813F_3978_F894_0984, 0x4000_0000_0000_0000), // 5^28
+    (0xA18F_07D7_36B9_0BE5, 0x5000_0000_0000_0000), // 5^29
+    (0xC9F2_C9CD_0467_4EDE, 0xA400_0000_0000_0000), // 5^30
+    (0xFC6F_7C40_4581_2296, 0x4D00_0000_0000_0000), // 5^31
+    (0x9DC5_ADA8_2B70_B59D, 0xF020_0000_0000_0000), // 5^32
+    (0xC537_1912_364C_E305, 0x6C28_0000_0000_0000), // 5^33
+    (0xF684_DF56_C3E0_1BC6, 0xC732_0000_0000_0000), // 5^34
+    (0x9A13_0B96_3A6C_115C, 0x3C7F_4000_0000_0000), // 5^35
+    (0xC097_CE7B_C907_15B3, 0x4B9F_1000_0000_0000), // 5^36
+    (0xF0BD_C21A_BB48_DB20, 0x1E86_D400_0000_0000), // 5^37
+    (0x9676_9950_B50D_88F4, 0x1314_4480_0000_0000), // 5^38
+    (0xBC14_3FA4_E250_EB31, 0x17D9_55A0_0000_0000), // 5^39
+    (0xEB19_4F8E_1AE5_25FD, 0x5DCF_AB08_0000_0000), // 5^40
+    (0x92EF_D1B8_D0CF_37BE, 0x5AA1_CAE5_0000_0000), // 5^41
+    (0xB7AB_C627_0503_05AD, 0xF14A_3D9E_4000_0000), // 5^42
+    (0xE596_B7B0_C643_C719, 0x6D9C_CD05_D000_0000), // 5^43
+    (0x8F7E_32CE_7BEA_5C6F, 0xE482_0023_A200_0000), // 5^44
+    (0xB35D_BF82_1AE4_F38B, 0xDDA2_802C_8A80_0000), // 5^45
+    (0xE035_2F62_A19E_306E, 0xD50B_2037_AD20_0000), // 5^46
+    (0x8C21_3D9D_A502_DE45, 0x4526_F422_CC34_0000), // 5^47
+    (0xAF29_8D05_0E43_95D6, 0x9670_B12B_7F41_0000), // 5^48
+    (0xDAF3_F046_51D4_7B4C, 0x3C0C_DD76_5F11_4000), // 5^49
+    (0x88D8_762B_F324_CD0F, 0xA588_0A69_FB6A_C800), // 5^50
+    (0xAB0E_93B6_EFEE_0053, 0x8EEA_0D04_7A45_7A00), // 5^51
+    (0xD5D2_38A4_ABE9_8068, 0x72A4_9045_98D6_D880), // 5^52
+    (0x85A3_6366_EB71_F041, 0x47A6_DA2B_7F86_4750), // 5^53
+    (0xA70C_3C40_A64E_6C51, 0x9990_90B6_5F67_D924), // 5^54
+    (0xD0CF_4B50_CFE2_0765, 0xFFF4_B4E3_F741_CF6D), // 5^55
+    (0x8281_8F12_81ED_449F, 0xBFF8_F10E_7A89_21A4), // 5^56
+    (0xA321_F2D7_2268_95C7, 0xAFF7_2D52_192B_6A0D), // 5^57
+    (0xCBEA_6F8C_EB02_BB39, 0x9BF4_F8A6_9F76_4490), // 5^58
+    (0xFEE5_0B70_25C3_6A08, 0x02F2_36D0_4753_D5B4), // 5^59
+    (0x9F4F_2726_179A_2245, 0x01D7_6242_2C94_6590), // 5^60
+    (0xC722_F0EF_9D80_AAD6, 0x424D_3AD2_B7B9_7EF5), // 5^61
+    (0xF8EB_AD2B_84E0_D58B, 0xD2E0_8987_65A7_DEB2), // 5^62
+    (0x9B93_4C3B_330C_8577, 0x63CC_55F4_9F88_EB2F), // 5^63
+    (0xC278_1F49_FFCF_A6D5, 0x3CBF_6B71_C76B_25FB), // 5^64
+    (0xF316_271C_7FC3_908A, 0x8BEF_464E_3945_EF7A), // 5^65
+    (0x97ED_D871_CFDA_3A56, 0x9775_8BF0_E3CB_B5AC), // 5^66
+    (0xBDE9_4E8E_43D0_C8EC, 0x3D52_EEED_1CBE_A317), // 5^67
+    (0xED63_A231_D4C4_FB27, 0x4CA7_AAA8_63EE_4BDD), // 5^68
+    (0x945E_455F_24FB_1CF8, 0x8FE8_CAA9_3E74_EF6A), // 5^69
+    (0xB975_D6B6_EE39_E436, 0xB3E2_FD53_8E12_2B44), // 5^70
+    (0xE7D3_4C64_A9C8_5D44, 0x60DB_BCA8_7196_B616), // 5^71
+    (0x90E4_0FBE_EA1D_3A4A, 0xBC89_55E9_46FE_31CD), // 5^72
+    (0xB51D_13AE_A4A4_88DD, 0x6BAB_AB63_98BD_BE41), // 5^73
+    (0xE264_589A_4DCD_AB14, 0xC696_963C_7EED_2DD1), // 5^74
+    (0x8D7E_B760_70A0_8AEC, 0xFC1E_1DE5_CF54_3CA2), // 5^75
+    (0xB0DE_6538_8CC8_ADA8, 0x3B25_A55F_4329_4BCB), // 5^76
+    (0xDD15_FE86_AFFA_D912, 0x49EF_0EB7_13F3_9EBE), // 5^77
+    (0x8A2D_BF14_2DFC_C7AB, 0x6E35_6932_6C78_4337), // 5^78
+    (0xACB9_2ED9_397B_F996, 0x49C2_C37F_0796_5404), // 5^79
+    (0xD7E7_7A8F_87DA_F7FB, 0xDC33_745E_C97B_E906), // 5^80
+    (0x86F0_AC99_B4E8_DAFD, 0x69A0_28BB_3DED_71A3), // 5^81
+    (0xA8AC_D7C0_2223_11BC, 0xC408_32EA_0D68_CE0C), // 5^82
+    (0xD2D8_0DB0_2AAB_D62B, 0xF50A_3FA4_90C3_0190), // 5^83
+    (0x83C7_088E_1AAB_65DB, 0x7926_67C6_DA79_E0FA), // 5^84
+    (0xA4B8_CAB1_A156_3F52, 0x5770_01B8_9118_5938), // 5^85
+    (0xCDE6_FD5E_09AB_CF26, 0xED4C_0226_B55E_6F86), // 5^86
+    (0x80B0_5E5A_C60B_6178, 0x544F_8158_315B_05B4), // 5^87
+    (0xA0DC_75F1_778E_39D6, 0x6963_61AE_3DB1_C721), // 5^88
+    (0xC913_936D_D571_C84C, 0x03BC_3A19_CD1E_38E9), // 5^89
+    (0xFB58_7849_4ACE_3A5F, 0x04AB_48A0_4065_C723), // 5^90
+    (0x9D17_4B2D_CEC0_E47B, 0x62EB_0D64_283F_9C76), // 5^91
+    (0xC45D_1DF9_4271_1D9A, 0x3BA5_D0BD_324F_8394), // 5^92
+    (0xF574_6577_930D_6500, 0xCA8F_44EC_7EE3_6479), // 5^93
+    (0x9968_BF6A_BBE8_5F20, 0x7E99_8B13_CF4E_1ECB), // 5^94
+    (0xBFC2_EF45_6AE2_76E8, 0x9E3F_EDD8_C321_A67E), // 5^95
+    (0xEFB3_AB16_C59B_14A2, 0xC5CF_E94E_F3EA_101E), // 5^96
+    (0x95D0_4AEE_3B80_ECE5, 0xBBA1_F1D1_5872_4A12), // 5^97
+    (0xBB44_5DA9_CA61_281F, 0x2A8A_6E45_AE8E_DC97), // 5^98
+    (0xEA15_7514_3CF9_7226, 0xF52D_09D7_1A32_93BD), // 5^99
+    (0x924D_692C_A61B_E758, 0x593C_2626_705F_9C56), // 5^100
+    (0xB6E0_C377_CFA2_E12E, 0x6F8B_2FB0_0C77_836C), // 5^101
+    (0xE498_F455_C38B_997A, 0x0B6D_FB9C_0F95_6447), // 5^102
+    (0x8EDF_98B5_9A37_3FEC, 0x4724_BD41_89BD_5EAC), // 5^103
+    (0xB297_7EE3_00C5_0FE7, 0x58ED_EC91_EC2C_B657), // 5^104
+    (0xDF3D_5E9B_C0F6_53E1, 0x2F29_67B6_6737_E3ED), // 5^105
+    (0x8B86_5B21_5899_F46C, 0xBD79_E0D2_0082_EE74), // 5^106
+    (0xAE67_F1E9_AEC0_7187, 0xECD8_5906_80A3_AA11), // 5^107
+    (0xDA01_EE64_1A70_8DE9, 0xE80E_6F48_20CC_9495), // 5^108
+    (0x8841_34FE_9086_58B2, 0x3109_058D_147F_DCDD), // 5^109
+    (0xAA51_823E_34A7_EEDE, 0xBD4B_46F0_599F_D415), // 5^110
+    (0xD4E5_E2CD_C1D1_EA96, 0x6C9E_18AC_7007_C91A), // 5^111
+    (0x850F_ADC0_9923_329E, 0x03E2_CF6B_C604_DDB0), // 5^112
+    (0xA653_9930_BF6B_FF45, 0x84DB_8346_B786_151C), // 5^113
+    (0xCFE8_7F7C_EF46_FF16, 0xE612_6418_6567_9A63), // 5^114
+    (0x81F1_4FAE_158C_5F6E, 0x4FCB_7E8F_3F60_C07E), // 5^115
+    (0xA26D_A399_9AEF_7749, 0xE3BE_5E33_0F38_F09D), // 5^116
+    (0xCB09_0C80_01AB_551C, 0x5CAD_F5BF_D307_2CC5), // 5^117
+    (0xFDCB_4FA0_0216_2A63, 0x73D9_732F_C7C8_F7F6), // 5^118
+    (0x9E9F_11C4_014D_DA7E, 0x2867_E7FD_DCDD_9AFA), // 5^119
+    (0xC646_D635_01A1_511D, 0xB281_E1FD_5415_01B8), // 5^120
+    (0xF7D8_8BC2_4209_A565, 0x1F22_5A7C_A91A_4226), // 5^121
+    (0x9AE7_5759_6946_075F, 0x3375_788D_E9B0_6958), // 5^122
+    (0xC1A1_2D2F_C397_8937, 0x0052_D6B1_641C_83AE), // 5^123
+    (0xF209_787B_B47D_6B84, 0xC067_8C5D_BD23_A49A), // 5^124
+    (0x9745_EB4D_50CE_6332, 0xF840_B7BA_9636_46E0), // 5^125
+    (0xBD17_6620_A501_FBFF, 0xB650_E5A9_3BC3_D898), // 5^126
+    (0xEC5D_3FA8_CE42_7AFF, 0xA3E5_1F13_8AB4_CEBE), // 5^127
+    (0x93BA_47C9_80E9_8CDF, 0xC66F_336C_36B1_0137), // 5^128
+    (0xB8A8_D9BB_E123_F017, 0xB80B_0047_445D_4184), // 5^129
+    (0xE6D3_102A_D96C_EC1D, 0xA60D_C059_1574_91E5), // 5^130
+    (0x9043_EA1A_C7E4_1392, 0x87C8_9837_AD68_DB2F), // 5^131
+    (0xB454_E4A1_79DD_1877, 0x29BA_BE45_98C3_11FB), // 5^132
+    (0xE16A_1DC9_D854_5E94, 0xF429_6DD6_FEF3_D67A), // 5^133
+    (0x8CE2_529E_2734_BB1D, 0x1899_E4A6_5F58_660C), // 5^134
+    (0xB01A_E745_B101_E9E4, 0x5EC0_5DCF_F72E_7F8F), // 5^135
+    (0xDC21_A117_1D42_645D, 0x7670_7543_F4FA_1F73), // 5^136
+    (0x8995_04AE_7249_7EBA, 0x6A06_494A_791C_53A8), // 5^137
+    (0xABFA_45DA_0EDB_DE69, 0x0487_DB9D_1763_6892), // 5^138
+    (0xD6F8_D750_9292_D603, 0x45A9_D284_5D3C_42B6), // 5^139
+    (0x865B_8692_5B9B_C5C2, 0x0B8A_2392_BA45_A9B2), // 5^140
+    (0xA7F2_6836_F282_B732, 0x8E6C_AC77_68D7_141E), // 5^141
+    (0xD1EF_0244_AF23_64FF, 0x3207_D795_430C_D926), // 5^142
+    (0x8335_616A_ED76_1F1F, 0x7F44_E6BD_49E8_07B8), // 5^143
+    (0xA402_B9C5_A8D3_A6E7, 0x5F16_206C_9C62_09A6), // 5^144
+    (0xCD03_6837_1308_90A1, 0x36DB_A887_C37A_8C0F), // 5^145
+    (0x8022_2122_6BE5_5A64, 0xC249_4954_DA2C_9789), // 5^146
+    (0xA02A_A96B_06DE_B0FD, 0xF2DB_9BAA_10B7_BD6C), // 5^147
+    (0xC835_53C5_C896_5D3D, 0x6F92_8294_94E5_ACC7), // 5^148
+    (0xFA42_A8B7_3ABB_F48C, 0xCB77_2339_BA1F_17F9), // 5^149
+    (0x9C69_A972_84B5_78D7, 0xFF2A_7604_1453_6EFB), // 5^150
+    (0xC384_13CF_25E2_D70D, 0xFEF5_1385_1968_4ABA), // 5^151
+    (0xF465_18C2_EF5B_8CD1, 0x7EB2_5866_5FC2_5D69), // 5^152
+    (0x98BF_2F79_D599_3802, 0xEF2F_773F_FBD9_7A61), // 5^153
+    (0xBEEE_FB58_4AFF_8603, 0xAAFB_550F_FACF_D8FA), // 5^154
+    (0xEEAA_BA2E_5DBF_6784, 0x95BA_2A53_F983_CF38), // 5^155
+    (0x952A_B45C_FA97_A0B2, 0xDD94_5A74_7BF2_6183), // 5^156
+    (0xBA75_6174_393D_88DF, 0x94F9_7111_9AEE_F9E4), // 5^157
+    (0xE912_B9D1_478C_EB17, 0x7A37_CD56_01AA_B85D), // 5^158
+    (0x91AB_B422_CCB8_12EE, 0xAC62_E055_C10A_B33A), // 5^159
+    (0xB616_A12B_7FE6_17AA, 0x577B_986B_314D_6009), // 5^160
+    (0xE39C_4976_5FDF_9D94, 0xED5A_7E85_FDA0_B80B), // 5^161
+    (0x8E41_ADE9_FBEB_C27D, 0x1458_8F13_BE84_7307), // 5^162
+    (0xB1D2_1964_7AE6_B31C, 0x596E_B2D8_AE25_8FC8), // 5^163
+    (0xDE46_9FBD_99A0_5FE3, 0x6FCA_5F8E_D9AE_F3BB), // 5^164
+    (0x8AEC_23D6_8004_3BEE, 0x25DE_7BB9_480D_5854), // 5^165
+    (0xADA7_2CCC_2005_4AE9, 0xAF56_1AA7_9A10_AE6A), // 5^166
+    (0xD910_F7FF_2806_9DA4, 0x1B2B_A151_8094_DA04), // 5^167
+    (0x87AA_9AFF_7904_2286, 0x90FB_44D2_F05D_0842), // 5^168
+    (0xA995_41BF_5745_2B28, 0x353A_1607_AC74_4A53), // 5^169
+    (0xD3FA_922F_2D16_75F2, 0x4288_9B89_9791_5CE8), // 5^170
+    (0x847C_9B5D_7C2E_09B7, 0x6995_6135_FEBA_DA11), // 5^171
+    (0xA59B_C234_DB39_8C25, 0x43FA_B983_7E69_9095), // 5^172
+    (0xCF02_B2C2_1207_EF2E, 0x94F9_67E4_5E03_F4BB), // 5^173
+    (0x8161_AFB9_4B44_F57D, 0x1D1B_E0EE_BAC2_78F5), // 5^174
+    (0xA1BA_1BA7_9E16_32DC, 0x6462_D92A_6973_1732), // 5^175
+    (0xCA28_A291_859B_BF93, 0x7D7B_8F75_03CF_DCFE), // 5^176
+    (0xFCB2_CB35_E702_AF78, 0x5CDA_7352_44C3_D43E), // 5^177
+    (0x9DEF_BF01_B061_ADAB, 0x3A08_8813_6AFA_64A7), // 5^178
+    (0xC56B_AEC2_1C7A_1916, 0x088A_AA18_45B8_FDD0), // 5^179
+    (0xF6C6_9A72_A398_9F5B, 0x8AAD_549E_5727_3D45), // 5^180
+    (0x9A3C_2087_A63F_6399, 0x36AC_54E2_F678_864B), // 5^181
+    (0xC0CB_28A9_8FCF_3C7F, 0x8457_6A1B_B416_A7DD), // 5^182
+    (0xF0FD_F2D3_F3C3_0B9F, 0x656D_44A2_A11C_51D5), // 5^183
+    (0x969E_B7C4_7859_E743, 0x9F64_4AE5_A4B1_B325), // 5^184
+    (0xBC46_65B5_9670_6114, 0x873D_5D9F_0DDE_1FEE), // 5^185
+    (0xEB57_FF22_FC0C_7959, 0xA90C_B506_D155_A7EA), // 5^186
+    (0x9316_FF75_DD87_CBD8, 0x09A7_F124_42D5_88F2), // 5^187
+    (0xB7DC_BF53_54E9_BECE, 0x0C11_ED6D_538A_EB2F), // 5^188
+    (0xE5D3_EF28_2A24_2E81, 0x8F16_68C8_A86D_A5FA), // 5^189
+    (0x8FA4_7579_1A56_9D10, 0xF96E_017D_6944_87BC), // 5^190
+    (0xB38D_92D7_60EC_4455, 0x37C9_81DC_C395_A9AC), // 5^191
+    (0xE070_F78D_3927_556A, 0x85BB_E253_F47B_1417), // 5^192
+    (0x8C46_9AB8_43B8_9562, 0x9395_6D74_78CC_EC8E), // 5^193
+    (0xAF58_4166_54A6_BABB, 0x387A_C8D1_9700_27B2), // 5^194
+    (0xDB2E_51BF_E9D0_696A, 0x0699_7B05_FCC0_319E), // 5^195
+    (0x88FC_F317_F222_41E2, 0x441F_ECE3_BDF8_1F03), // 5^196
+    (0xAB3C_2FDD_EEAA_D25A, 0xD527_E81C_AD76_26C3), // 5^197
+    (0xD60B_3BD5_6A55_86F1, 0x8A71_E223_D8D3_B074), // 5^198
+    (0x85C7_0565_6275_7456, 0xF687_2D56_6784_4E49), // 5^199
+    (0xA738_C6BE_BB12_D16C, 0xB428_F8AC_0165_61DB), // 5^200
+    (0xD106_F86E_69D7_85C7, 0xE133_36D7_01BE_BA52), // 5^201
+    (0x82A4_5B45_0226_B39C, 0xECC0_0246_6117_3473), // 5^202
+    (0xA34D_7216_42B0_6084, 0x27F0_02D7_F95D_0190), // 5^203
+    (0xCC20_CE9B_D35C_78A5, 0x31EC_038D_F7B4_41F4), // 5^204
+    (0xFF29_0242_C833_96CE, 0x7E67_0471_75A1_5271), // 5^205
+    (0x9F79_A169_BD20_3E41, 0x0F00_62C6_E984_D386), // 5^206
+    (0xC758_09C4_2C68_4DD1, 0x52C0_7B78_A3E6_0868), // 5^207
+    (0xF92E_0C35_3782_6145, 0xA770_9A56_CCDF_8A82), // 5^208
+    (0x9BBC_C7A1_42B1_7CCB, 0x88A6_6076_400B_B691), // 5^209
+    (0xC2AB_F989_935D_DBFE, 0x6ACF_F893_D00E_A435), // 5^210
+    (0xF356_F7EB_F835_52FE, 0x0583_F6B8_C412_4D43), // 5^211
+    (0x9816_5AF3_7B21_53DE, 0xC372_7A33_7A8B_704A), // 5^212
+    (0xBE1B_F1B0_59E9_A8D6, 0x744F_18C0_592E_4C5C), // 5^213
+    (0xEDA2_EE1C_7064_130C, 0x1162_DEF0_6F79_DF73), // 5^214
+    (0x9485_D4D1_C63E_8BE7, 0x8ADD_CB56_45AC_2BA8), // 5^215
+    (0xB9A7_4A06_37CE_2EE1, 0x6D95_3E2B_D717_3692), // 5^216
+    (0xE811_1C87_C5C1_BA99, 0xC8FA_8DB6_CCDD_0437), // 5^217
+    (0x910A_B1D4_DB99_14A0, 0x1D9C_9892_400A_22A2), // 5^218
+    (0xB54D_5E4A_127F_59C8, 0x2503_BEB6_D00C_AB4B), // 5^219
+    (0xE2A0_B5DC_971F_303A, 0x2E44_AE64_840F_D61D), // 5^220
+    (0x8DA4_71A9_DE73_7E24, 0x5CEA_ECFE_D289_E5D2), // 5^221
+    (0xB10D_8E14_5610_5DAD, 0x7425_A83E_872C_5F47), // 5^222
+    (0xDD50_F199_6B94_7518, 0xD12F_124E_28F7_7719), // 5^223
+    (0x8A52_96FF_E33C_C92F, 0x82BD_6B70_D99A_AA6F), // 5^224
+    (0xACE7_3CBF_DC0B_FB7B, 0x636C_C64D_1001_550B), // 5^225
+    (0xD821_0BEF_D30E_FA5A, 0x3C47_F7E0_5401_AA4E), // 5^226
+    (0x8714_A775_E3E9_5C78, 0x65AC_FAEC_3481_0A71), // 5^227
+    (0xA8D9_D153_5CE3_B396, 0x7F18_39A7_41A1_4D0D), // 5^228
+    (0xD310_45A8_341C_A07C, 0x1EDE_4811_1209_A050), // 5^229
+    (0x83EA_2B89_2091_E44D, 0x934A_ED0A_AB46_0432), // 5^230
+    (0xA4E4_B66B_68B6_5D60, 0xF81D_A84D_5617_853F), // 5^231
+    (0xCE1D_E406_42E3_F4B9, 0x3625_1260_AB9D_668E), // 5^232
+    (0x80D2_AE83_E9CE_78F3, 0xC1D7_2B7C_6B42_6019), // 5^233
+    (0xA107_5A24_E442_1730, 0xB24C_F65B_8612_F81F), // 5^234
+    (0xC949_30AE_1D52_9CFC, 0xDEE0_33F2_6797_B627), // 5^235
+    (0xFB9B_7CD9_A4A7_443C, 0x1698_40EF_017D_A3B1), // 5^236
+    (0x9D41_2E08_06E8_8AA5, 0x8E1F_2895_60EE_864E), // 5^237
+    (0xC491_798A_08A2_AD4E, 0xF1A6_F2BA_B92A_27E2), // 5^238
+    (0xF5B5_D7EC_8ACB_58A2, 0xAE10_AF69_6774_B1DB), // 5^239
+    (0x9991_A6F3_D6BF_1765, 0xACCA_6DA1_E0A8_EF29), // 5^240
+    (0xBFF6_10B0_CC6E_DD3F, 0x17FD_090A_58D3_2AF3), // 5^241
+    (0xEFF3_94DC_FF8A_948E, 0xDDFC_4B4C_EF07_F5B0), // 5^242
+    (0x95F8_3D0A_1FB6_9CD9, 0x4ABD_AF10_1564_F98E), // 5^243
+    (0xBB76_4C4C_A7A4_440F, 0x9D6D_1AD4_1ABE_37F1), // 5^244
+    (0xEA53_DF5F_D18D_5513, 0x84C8_6189_216D_C5ED), // 5^245
+    (0x9274_6B9B_E2F8_552C, 0x32FD_3CF5_B4E4_9BB4), // 5^246
+    (0xB711_8682_DBB6_6A77, 0x3FBC_8C33_221D_C2A1), // 5^247
+    (0xE4D5_E823_92A4_0515, 0x0FAB_AF3F_EAA5_334A), // 5^248
+    (0x8F05_B116_3BA6_832D, 0x29CB_4D87_F2A7_400E), // 5^249
+    (0xB2C7_1D5B_CA90_23F8, 0x743E_20E9_EF51_1012), // 5^250
+    (0xDF78_E4B2_BD34_2CF6, 0x914D_A924_6B25_5416), // 5^251
+    (0x8BAB_8EEF_B640_9C1A, 0x1AD0_89B6_C2F7_548E), // 5^252
+    (0xAE96_72AB_A3D0_C320, 0xA184_AC24_73B5_29B1), // 5^253
+    (0xDA3C_0F56_8CC4_F3E8, 0xC9E5_D72D_90A2_741E), // 5^254
+    (0x8865_8996_17FB_1871, 0x7E2F_A67C_7A65_8892), // 5^255
+    (0xAA7E_EBFB_9DF9_DE8D, 0xDDBB_901B_98FE_EAB7), // 5^256
+    (0xD51E_A6FA_8578_5631, 0x552A_7422_7F3E_A565), // 5^257
+    (0x8533_285C_936B_35DE, 0xD53A_8895_8F87_275F), // 5^258
+    (0xA67F_F273_B846_0356, 0x8A89_2ABA_F368_F137), // 5^259
+    (0xD01F_EF10_A657_842C, 0x2D2B_7569_B043_2D85), // 5^260
+    (0x8213_F56A_67F6_B29B, 0x9C3B_2962_0E29_FC73), // 5^261
+    (0xA298_F2C5_01F4_5F42, 0x8349_F3BA_91B4_7B8F), // 5^262
+    (0xCB3F_2F76_4271_7713, 0x241C_70A9_3621_9A73), // 5^263
+    (0xFE0E_FB53_D30D_D4D7, 0xED23_8CD3_83AA_0110), // 5^264
+    (0x9EC9_5D14_63E8_A506, 0xF436_3804_324A_40AA), // 5^265
+    (0xC67B_B459_7CE2_CE48, 0xB143_C605_3EDC_D0D5), // 5^266
+    (0xF81A_A16F_DC1B_81DA, 0xDD94_B786_8E94_050A), // 5^267
+    (0x9B10_A4E5_E991_3128, 0xCA7C_F2B4_191C_8326), // 5^268
+    (0xC1D4_CE1F_63F5_7D72, 0xFD1C_2F61_1F63_A3F0), // 5^269
+    (0xF24A_01A7_3CF2_DCCF, 0xBC63_3B39_673C_8CEC), // 5^270
+    (0x976E_4108_8617_CA01, 0xD5BE_0503_E085_D813), // 5^271
+    (0xBD49_D14A_A79D_BC82, 0x4B2D_8644_D8A7_4E18), // 5^272
+    (0xEC9C_459D_5185_2BA2, 0xDDF8_E7D6_0ED1_219E), // 5^273
+    (0x93E1_AB82_52F3_3B45, 0xCABB_90E5_C942_B503), // 5^274
+    (0xB8DA_1662_E7B0_0A17, 0x3D6A_751F_3B93_6243), // 5^275
+    (0xE710_9BFB_A19C_0C9D, 0x0CC5_1267_0A78_3AD4), // 5^276
+    (0x906A_617D_4501_87E2, 0x27FB_2B80_668B_24C5), // 5^277
+    (0xB484_F9DC_9641_E9DA, 0xB1F9_F660_802D_EDF6), // 5^278
+    (0xE1A6_3853_BBD2_6451, 0x5E78_73F8_A039_6973), // 5^279
+    (0x8D07_E334_5563_7EB2, 0xDB0B_487B_6423_E1E8), // 5^280
+    (0xB049_DC01_6ABC_5E5F, 0x91CE_1A9A_3D2C_DA62), // 5^281
+    (0xDC5C_5301_C56B_75F7, 0x7641_A140_CC78_10FB), // 5^282
+    (0x89B9_B3E1_1B63_29BA, 0xA9E9_04C8_7FCB_0A9D), // 5^283
+    (0xAC28_20D9_623B_F429, 0x5463_45FA_9FBD_CD44), // 5^284
+    (0xD732_290F_BACA_F133, 0xA97C_1779_47AD_4095), // 5^285
+    (0x867F_59A9_D4BE_D6C0, 0x49ED_8EAB_CCCC_485D), // 5^286
+    (0xA81F_3014_49EE_8C70, 0x5C68_F256_BFFF_5A74), // 5^287
+    (0xD226_FC19_5C6A_2F8C, 0x7383_2EEC_6FFF_3111), // 5^288
+    (0x8358_5D8F_D9C2_5DB7, 0xC831_FD53_C5FF_7EAB), // 5^289
+    (0xA42E_74F3_D032_F525, 0xBA3E_7CA8_B77F_5E55), // 5^290
+    (0xCD3A_1230_C43F_B26F, 0x28CE_1BD2_E55F_35EB), // 5^291
+    (0x8044_4B5E_7AA7_CF85, 0x7980_D163_CF5B_81B3), // 5^292
+    (0xA055_5E36_1951_C366, 0xD7E1_05BC_C332_621F), // 5^293
+    (0xC86A_B5C3_9FA6_3440, 0x8DD9_472B_F3FE_FAA7), // 5^294
+    (0xFA85_6334_878F_C150, 0xB14F_98F6_F0FE_B951), // 5^295
+    (0x9C93_5E00_D4B9_D8D2, 0x6ED1_BF9A_569F_33D3), // 5^296
+    (0xC3B8_3581_09E8_4F07, 0x0A86_2F80_EC47_00C8), // 5^297
+    (0xF4A6_42E1_4C62_62C8, 0xCD27_BB61_2758_C0FA), // 5^298
+    (0x98E7_E9CC_CFBD_7DBD, 0x8038_D51C_B897_789C), // 5^299
+    (0xBF21_E440_03AC_DD2C, 0xE047_0A63_E6BD_56C3), // 5^300
+    (0xEEEA_5D50_0498_1478, 0x1858_CCFC_E06C_AC74), // 5^301
+    (0x9552_7A52_02DF_0CCB, 0x0F37_801E_0C43_EBC8), // 5^302
+    (0xBAA7_18E6_8396_CFFD, 0xD305_6025_8F54_E6BA), // 5^303
+    (0xE950_DF20_247C_83FD, 0x47C6_B82E_F32A_2069), // 5^304
+    (0x91D2_8B74_16CD_D27E, 0x4CDC_331D_57FA_5441), // 5^305
+    (0xB647_2E51_1C81_471D, 0xE013_3FE4_ADF8_E952), // 5^306
+    (0xE3D8_F9E5_63A1_98E5, 0x5818_0FDD_D977_23A6), // 5^307
+    (0x8E67_9C2F_5E44_FF8F, 0x570F_09EA_A7EA_7648), // 5^308
+];
 
+/// The number of new digits and the offset of the digits of `5^shift` in
+/// `LEFT_SHIFT_POW5`, for every left shift of a decimal in `0..=60`.
 #[rustfmt::skip]
-pub const F32_SHORT_POWERS: [f32; 11] = [
-    1e0,
-    1e1,
-    1e2,
-    1e3,
-    1e4,
-    1e5,
-    1e6,
-    1e7,
-    1e8,
-    1e9,
-    1e10,
+pub const LEFT_SHIFT_TABLE: [u16; 62] = [
+    0x0000, 0x0800, 0x0801, 0x0803, 0x1006, 0x1009, 0x100D, 0x1812,
+    0x1817, 0x181D, 0x2024, 0x202B, 0x2033, 0x203C, 0x2846, 0x2850,
+    0x285B, 0x3067, 0x3073, 0x3080, 0x388E, 0x389C, 0x38AB, 0x38BB,
+    0x40CC, 0x40DD, 0x40EF, 0x4902, 0x4915, 0x4929, 0x513E, 0x5153,
+    0x5169, 0x5180, 0x5998, 0x59B0, 0x59C9, 0x61E3, 0x61FD, 0x6218,
+    0x6A34, 0x6A50, 0x6A6D, 0x6A8B, 0x72AA, 0x72C9, 0x72E9, 0x7B0A,
+    0x7B2B, 0x7B4D, 0x8370, 0x8393, 0x83B7, 0x83DC, 0x8C02, 0x8C28,
+    0x8C4F, 0x9477, 0x949F, 0x94C8, 0x9CF2, 0x9D1C,
 ];
 
+/// The decimal digits of `5^1`, `5^2`, ..., concatenated.
 #[rustfmt::skip]
-pub const F64_SHORT_POWERS: [f64; 23] = [
-    1e0,
-    1e1,
-    1e2,
-    1e3,
-    1e4,
-    1e5,
-    1e6,
-    1e7,
-    1e8,
-    1e9,
-    1e10,
-    1e11,
-    1e12,
-    1e13,
-    1e14,
-    1e15,
-    1e16,
-    1e17,
-    1e18,
-    1e19,
-    1e20,
-    1e21,
-    1e22,
+pub const LEFT_SHIFT_POW5: [u8; 1308] = [
+    5, 2, 5, 1, 2, 5, 6, 2, 5, 3, 1, 2, 5, 1, 5, 6, 2, 5, 7, 8, 1, 2, 5, 3,
+    9, 0, 6, 2, 5, 1, 9, 5, 3, 1, 2, 5, 9, 7, 6, 5, 6, 2, 5, 4, 8, 8, 2, 8,
+    1, 2, 5, 2, 4, 4, 1, 4, 0, 6, 2, 5, 1, 2, 2, 0, 7, 0, 3, 1, 2, 5, 6, 1,
+    0, 3, 5, 1, 5, 6, 2, 5, 3, 0, 5, 1, 7, 5, 7, 8, 1, 2, 5, 1, 5, 2, 5, 8,
+    7, 8, 9, 0, 6, 2, 5, 7, 6, 2, 9, 3, 9, 4, 5, 3, 1, 2, 5, 3, 8, 1, 4, 6,
+    9, 7, 2, 6, 5, 6, 2, 5, 1, 9, 0, 7, 3, 4, 8, 6, 3, 2, 8, 1, 2, 5, 9, 5,
+    3, 6, 7, 4, 3, 1, 6, 4, 0, 6, 2, 5, 4, 7, 6, 8, 3, 7, 1, 5, 8, 2, 0, 3,
+    1, 2, 5, 2, 3, 8, 4, 1, 8, 5, 7, 9, 1, 0, 1, 5, 6, 2, 5, 1, 1, 9, 2, 0,
+    9, 2, 8, 9, 5, 5, 0, 7, 8, 1, 2, 5, 5, 9, 6, 0, 4, 6, 4, 4, 7, 7, 5, 3,
+    9, 0, 6, 2, 5, 2, 9, 8, 0, 2, 3, 2, 2, 3, 8, 7, 6, 9, 5, 3, 1, 2, 5, 1,
+    4, 9, 0, 1, 1, 6, 1, 1, 9, 3, 8, 4, 7, 6, 5, 6, 2, 5, 7, 4, 5, 0, 5, 8,
+    0, 5, 9, 6, 9, 2, 3, 8, 2, 8, 1, 2, 5, 3, 7, 2, 5, 2, 9, 0, 2, 9, 8, 4,
+    6, 1, 9, 1, 4, 0, 6, 2, 5, 1, 8, 6, 2, 6, 4, 5, 1, 4, 9, 2, 3, 0, 9, 5,
+    7, 0, 3, 1, 2, 5, 9, 3, 1, 3, 2, 2, 5, 7, 4, 6, 1, 5, 4, 7, 8, 5, 1, 5,
+    6, 2, 5, 4, 6, 5, 6, 6, 1, 2, 8, 7, 3, 0, 7, 7, 3, 9, 2, 5, 7, 8, 1, 2,
+    5, 2, 3, 2, 8, 3, 0, 6, 4, 3, 6, 5, 3, 8, 6, 9, 6, 2, 8, 9, 0, 6, 2, 5,
+    1, 1, 6, 4, 1, 5, 3, 2, 1, 8, 2, 6, 9, 3, 4, 8, 1, 4, 4, 5, 3, 1, 2, 5,
+    5, 8, 2, 0, 7, 6, 6, 0, 9, 1, 3, 4, 6, 7, 4, 0, 7, 2, 2, 6, 5, 6, 2, 5,
+    2, 9, 1, 0, 3, 8, 3, 0, 4, 5, 6, 7, 3, 3, 7, 0, 3, 6, 1, 3, 2, 8, 1, 2,
+    5, 1, 4, 5, 5, 1, 9, 1, 5, 2, 2, 8, 3, 6, 6, 8, 5, 1, 8, 0, 6, 6, 4, 0,
+    6, 2, 5, 7, 2, 7, 5, 9, 5, 7, 6, 1, 4, 1, 8, 3, 4, 2, 5, 9, 0, 3, 3, 2,
+    0, 3, 1, 2, 5, 3, 6, 3, 7, 9, 7, 8, 8, 0, 7, 0, 9, 1, 7, 1, 2, 9, 5, 1,
+    6, 6, 0, 1, 5, 6, 2, 5, 1, 8, 1, 8, 9, 8, 9, 4, 0, 3, 5, 4, 5, 8, 5, 6,
+    4, 7, 5, 8, 3, 0, 0, 7, 8, 1, 2, 5, 9, 0, 9, 4, 9, 4, 7, 0, 1, 7, 7, 2,
+    9, 2, 8, 2, 3, 7, 9, 1, 5, 0, 3, 9, 0, 6, 2, 5, 4, 5, 4, 7, 4, 7, 3, 5,
+    0, 8, 8, 6, 4, 6, 4, 1, 1, 8, 9, 5, 7, 5, 1, 9, 5, 3, 1, 2, 5, 2, 2, 7,
+    3, 7, 3, 6, 7, 5, 4, 4, 3, 2, 3, 2, 0, 5, 9, 4, 7, 8, 7, 5, 9, 7, 6, 5,
+    6, 2, 5, 1, 1, 3, 6, 8, 6, 8, 3, 7, 7, 2, 1, 6, 1, 6, 0, 2, 9, 7, 3, 9,
+    3, 7, 9, 8, 8, 2, 8, 1, 2, 5, 5, 6, 8, 4, 3, 4, 1, 8, 8, 6, 0, 8, 0, 8,
+    0, 1, 4, 8, 6, 9, 6, 8, 9, 9, 4, 1, 4, 0, 6, 2, 5, 2, 8, 4, 2, 1, 7, 0,
+    9, 4, 3, 0, 4, 0, 4, 0, 0, 7, 4, 3, 4, 8, 4, 4, 9, 7, 0, 7, 0, 3, 1, 2,
+    5, 1, 4, 2, 1, 0, 8, 5, 4, 7, 1, 5, 2, 0, 2, 0, 0, 3, 7, 1, 7, 4, 2, 2,
+    4, 8, 5, 3, 5, 1, 5, 6, 2, 5, 7, 1, 0, 5, 4, 2, 7, 3, 5, 7, 6, 0, 1, 0,
+    0, 1, 8, 5, 8, 7, 1, 1, 2, 4, 2, 6, 7, 5, 7, 8, 1, 2, 5, 3, 5, 5, 2, 7,
+    1, 3, 6, 7, 8, 8, 0, 0, 5, 0, 0, 9, 2, 9, 3, 5, 5, 6, 2, 1, 3, 3, 7, 8,
+    9, 0, 6, 2, 5, 1, 7, 7, 6, 3, 5, 6, 8, 3, 9, 4, 0, 0, 2, 5, 0, 4, 6, 4,
+    6, 7, 7, 8, 1, 0, 6, 6, 8, 9, 4, 5, 3, 1, 2, 5, 8, 8, 8, 1, 7, 8, 4, 1,
+    9, 7, 0, 0, 1, 2, 5, 2, 3, 2, 3, 3, 8, 9, 0, 5, 3, 3, 4, 4, 7, 2, 6, 5,
+    6, 2, 5, 4, 4, 4, 0, 8, 9, 2, 0, 9, 8, 5, 0, 0, 6, 2, 6, 1, 6, 1, 6, 9,
+    4, 5, 2, 6, 6, 7, 2, 3, 6, 3, 2, 8, 1, 2, 5, 2, 2, 2, 0, 4, 4, 6, 0, 4,
+    9, 2, 5, 0, 3, 1, 3, 0, 8, 0, 8, 4, 7, 2, 6, 3, 3, 3, 6, 1, 8, 1, 6, 4,
+    0, 6, 2, 5, 1, 1, 1, 0, 2, 2, 3, 0, 2, 4, 6, 2, 5, 1, 5, 6, 5, 4, 0, 4,
+    2, 3, 6, 3, 1, 6, 6, 8, 0, 9, 0, 8, 2, 0, 3, 1, 2, 5, 5, 5, 5, 1, 1, 1,
+    5, 1, 2, 3, 1, 2, 5, 7, 8, 2, 7, 0, 2, 1, 1, 8, 1, 5, 8, 3, 4, 0, 4, 5,
+    4, 1, 0, 1, 5, 6, 2, 5, 2, 7, 7, 5, 5, 5, 7, 5, 6, 1, 5, 6, 2, 8, 9, 1,
+    3, 5, 1, 0, 5, 9, 0, 7, 9, 1, 7, 0, 2, 2, 7, 0, 5, 0, 7, 8, 1, 2, 5, 1,
+    3, 8, 7, 7, 7, 8, 7, 8, 0, 7, 8, 1, 4, 4, 5, 6, 7, 5, 5, 2, 9, 5, 3, 9,
+    5, 8, 5, 1, 1, 3, 5, 2, 5, 3, 9, 0, 6, 2, 5, 6, 9, 3, 8, 8, 9, 3, 9, 0,
+    3, 9, 0, 7, 2, 2, 8, 3, 7, 7, 6, 4, 7, 6, 9, 7, 9, 2, 5, 5, 6, 7, 6, 2,
+    6, 9, 5, 3, 1, 2, 5, 3, 4, 6, 9, 4, 4, 6, 9, 5, 1, 9, 5, 3, 6, 1, 4, 1,
+    8, 8, 8, 2, 3, 8, 4, 8, 9, 6, 2, 7, 8, 3, 8, 1, 3, 4, 7, 6, 5, 6, 2, 5,
+    1, 7, 3, 4, 7, 2, 3, 4, 7, 5, 9, 7, 6, 8, 0, 7, 0, 9, 4, 4, 1, 1, 9, 2,
+    4, 4, 8, 1, 3, 9, 1, 9, 0, 6, 7, 3, 8, 2, 8, 1, 2, 5, 8, 6, 7, 3, 6, 1,
+    7, 3, 7, 9, 8, 8, 4, 0, 3, 5, 4, 7, 2, 0, 5, 9, 6, 2, 2, 4, 0, 6, 9, 5,
+    9, 5, 3, 3, 6, 9, 1, 4, 0, 6, 2, 5,
 ];
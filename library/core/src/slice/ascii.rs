@@ -1,6 +1,11 @@
-//! Operations on ASCII `[u8]`.
+//! Operations on ASCII `[u8]`, and searching `[u8]` with a pattern.
 
 use crate::mem;
+use crate::str::iter::{MatchIndicesInternal, MatchesInternal, SplitInternal, SplitNInternal};
+use crate::str::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher};
+
+use super::{MatchIndices, Matches, RMatchIndices, RMatches};
+use super::{RSplitNPattern, RSplitPattern, SplitNPattern, SplitPattern};
 
 #[lang = "slice_u8"]
 #[cfg(not(test))]
@@ -55,6 +60,379 @@ pub fn make_ascii_lowercase(&mut self) {
             byte.make_ascii_lowercase();
         }
     }
+
+    /// Returns the index of the first match of the pattern in this slice.
+    ///
+    /// Returns [`None`] if the pattern doesn't match.
+    ///
+    /// The [pattern] can be a [`u8`], a `&[u8]`, a `&[u8; N]`, or a function or
+    /// closure that determines if a byte matches.
+    ///
+    /// [pattern]: crate::str::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let s = b"GET /index.html HTTP/1.1";
+    ///
+    /// assert_eq!(s.find(b' '), Some(3));
+    /// assert_eq!(s.find(b"HTTP"), Some(16));
+    /// assert_eq!(s.find(|b: u8| b.is_ascii_digit()), Some(21));
+    /// assert_eq!(s.find(b"POST"), None);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn find<'a, P: Pattern<'a, [u8]>>(&'a self, pat: P) -> Option<usize> {
+        pat.into_searcher(self).next_match().map(|(i, _)| i)
+    }
+
+    /// Returns the index of the last match of the pattern in this slice.
+    ///
+    /// Returns [`None`] if the pattern doesn't match.
+    ///
+    /// The [pattern] can be a [`u8`], a `&[u8]`, a `&[u8; N]`, or a function or
+    /// closure that determines if a byte matches.
+    ///
+    /// [pattern]: crate::str::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let s = b"GET /index.html HTTP/1.1";
+    ///
+    /// assert_eq!(s.rfind(b' '), Some(15));
+    /// assert_eq!(s.rfind(b"/"), Some(20));
+    /// assert_eq!(s.rfind(|b: u8| b.is_ascii_uppercase()), Some(19));
+    /// assert_eq!(s.rfind(b"POST"), None);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn rfind<'a, P>(&'a self, pat: P) -> Option<usize>
+    where
+        P: Pattern<'a, [u8], Searcher: ReverseSearcher<'a, [u8]>>,
+    {
+        pat.into_searcher(self).next_match_back().map(|(i, _)| i)
+    }
+
+    /// An iterator over sub-slices of this slice, separated by matches of a
+    /// pattern.
+    ///
+    /// Unlike [`split`], which splits on single elements matching a predicate,
+    /// this splits on any [pattern], so that the separator can be longer than
+    /// one byte.
+    ///
+    /// [`split`]: #method.split
+    /// [pattern]: crate::str::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<&[u8]> = b"lion::tiger::leopard".split_pattern(b"::").collect();
+    /// assert_eq!(v, [&b"lion"[..], b"tiger", b"leopard"]);
+    ///
+    /// let v: Vec<&[u8]> = b"a,b,,c".split_pattern(b',').collect();
+    /// assert_eq!(v, [&b"a"[..], b"b", b"", b"c"]);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn split_pattern<'a, P: Pattern<'a, [u8]>>(&'a self, pat: P) -> SplitPattern<'a, P> {
+        SplitPattern(SplitInternal {
+            start: 0,
+            end: self.len(),
+            matcher: pat.into_searcher(self),
+            allow_trailing_empty: true,
+            finished: false,
+        })
+    }
+
+    /// An iterator over sub-slices of this slice, separated by matches of a
+    /// pattern and yielded in reverse order.
+    ///
+    /// See [`split_pattern`] for more details.
+    ///
+    /// [`split_pattern`]: #method.split_pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<&[u8]> = b"lion::tiger::leopard".rsplit_pattern(b"::").collect();
+    /// assert_eq!(v, [&b"leopard"[..], b"tiger", b"lion"]);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn rsplit_pattern<'a, P>(&'a self, pat: P) -> RSplitPattern<'a, P>
+    where
+        P: Pattern<'a, [u8], Searcher: ReverseSearcher<'a, [u8]>>,
+    {
+        RSplitPattern(self.split_pattern(pat).0)
+    }
+
+    /// An iterator over sub-slices of this slice, separated by matches of a
+    /// pattern, restricted to returning at most `n` items.
+    ///
+    /// If `n` sub-slices are returned, the last one will contain the
+    /// remainder of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<&[u8]> = b"key=value=more".splitn_pattern(2, b'=').collect();
+    /// assert_eq!(v, [&b"key"[..], b"value=more"]);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn splitn_pattern<'a, P: Pattern<'a, [u8]>>(
+        &'a self,
+        n: usize,
+        pat: P,
+    ) -> SplitNPattern<'a, P> {
+        SplitNPattern(SplitNInternal { iter: self.split_pattern(pat).0, count: n })
+    }
+
+    /// An iterator over sub-slices of this slice, separated by matches of a
+    /// pattern, starting from the end of the slice and restricted to
+    /// returning at most `n` items.
+    ///
+    /// If `n` sub-slices are returned, the last one will contain the
+    /// remainder of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<&[u8]> = b"key=value=more".rsplitn_pattern(2, b'=').collect();
+    /// assert_eq!(v, [&b"more"[..], b"key=value"]);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn rsplitn_pattern<'a, P>(&'a self, n: usize, pat: P) -> RSplitNPattern<'a, P>
+    where
+        P: Pattern<'a, [u8], Searcher: ReverseSearcher<'a, [u8]>>,
+    {
+        RSplitNPattern(self.splitn_pattern(n, pat).0)
+    }
+
+    /// Splits the slice on the first occurrence of the specified delimiter and
+    /// returns the prefix before the delimiter and the suffix after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// assert_eq!(b"cfg".split_once(b'='), None);
+    /// assert_eq!(b"cfg=foo".split_once(b'='), Some((&b"cfg"[..], &b"foo"[..])));
+    /// assert_eq!(b"cfg=foo=bar".split_once(b"="), Some((&b"cfg"[..], &b"foo=bar"[..])));
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn split_once<'a, P: Pattern<'a, [u8]>>(
+        &'a self,
+        delimiter: P,
+    ) -> Option<(&'a [u8], &'a [u8])> {
+        let (start, end) = delimiter.into_searcher(self).next_match()?;
+        Some((&self[..start], &self[end..]))
+    }
+
+    /// Splits the slice on the last occurrence of the specified delimiter and
+    /// returns the prefix before the delimiter and the suffix after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// assert_eq!(b"cfg".rsplit_once(b'='), None);
+    /// assert_eq!(b"cfg=foo".rsplit_once(b'='), Some((&b"cfg"[..], &b"foo"[..])));
+    /// assert_eq!(b"cfg=foo=bar".rsplit_once(b"="), Some((&b"cfg=foo"[..], &b"bar"[..])));
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn rsplit_once<'a, P>(&'a self, delimiter: P) -> Option<(&'a [u8], &'a [u8])>
+    where
+        P: Pattern<'a, [u8], Searcher: ReverseSearcher<'a, [u8]>>,
+    {
+        let (start, end) = delimiter.into_searcher(self).next_match_back()?;
+        Some((&self[..start], &self[end..]))
+    }
+
+    /// An iterator over the disjoint matches of a pattern within this slice.
+    ///
+    /// The returned iterator will be a [`DoubleEndedIterator`] if the pattern
+    /// allows a reverse search and forward/reverse search yields the same
+    /// elements. This is true for, e.g., [`u8`], but not for `&[u8]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<&[u8]> = b"abcXXXabcYYYabc".matches(b"abc").collect();
+    /// assert_eq!(v, [b"abc", b"abc", b"abc"]);
+    ///
+    /// let v: Vec<&[u8]> = b"1abc2abc3".matches(|b: u8| b.is_ascii_digit()).collect();
+    /// assert_eq!(v, [b"1", b"2", b"3"]);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn matches<'a, P: Pattern<'a, [u8]>>(&'a self, pat: P) -> Matches<'a, P> {
+        Matches(MatchesInternal(pat.into_searcher(self)))
+    }
+
+    /// An iterator over the disjoint matches of a pattern within this slice,
+    /// yielded in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<&[u8]> = b"1abc2abc3".rmatches(|b: u8| b.is_ascii_digit()).collect();
+    /// assert_eq!(v, [b"3", b"2", b"1"]);
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn rmatches<'a, P>(&'a self, pat: P) -> RMatches<'a, P>
+    where
+        P: Pattern<'a, [u8], Searcher: ReverseSearcher<'a, [u8]>>,
+    {
+        RMatches(self.matches(pat).0)
+    }
+
+    /// An iterator over the disjoint matches of a pattern within this slice,
+    /// as well as the index that the match starts at.
+    ///
+    /// For matches of `pat` within `self` that overlap, only the indices
+    /// corresponding to the first match are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<_> = b"ababa".match_indices(b"aba").collect();
+    /// assert_eq!(v, [(0, &b"aba"[..])]); // only the first `aba`
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn match_indices<'a, P: Pattern<'a, [u8]>>(&'a self, pat: P) -> MatchIndices<'a, P> {
+        MatchIndices(MatchIndicesInternal(pat.into_searcher(self)))
+    }
+
+    /// An iterator over the disjoint matches of a pattern within this slice,
+    /// yielded in reverse order along with the index of the match.
+    ///
+    /// For matches of `pat` within `self` that overlap, only the indices
+    /// corresponding to the last match are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// let v: Vec<_> = b"ababa".rmatch_indices(b"aba").collect();
+    /// assert_eq!(v, [(2, &b"aba"[..])]); // only the last `aba`
+    /// ```
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    #[inline]
+    pub fn rmatch_indices<'a, P>(&'a self, pat: P) -> RMatchIndices<'a, P>
+    where
+        P: Pattern<'a, [u8], Searcher: ReverseSearcher<'a, [u8]>>,
+    {
+        RMatchIndices(self.match_indices(pat).0)
+    }
+
+    /// Returns a sub-slice with all prefixes and suffixes that match a pattern
+    /// repeatedly removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// assert_eq!(b"11foo1bar11".trim_matches(b'1'), b"foo1bar");
+    /// assert_eq!(b"\t key \r\n".trim_matches(|b: u8| b.is_ascii_whitespace()), b"key");
+    /// ```
+    #[must_use = "this returns the trimmed slice as a new slice, \
+                  without modifying the original"]
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    pub fn trim_matches<'a, P>(&'a self, pat: P) -> &'a [u8]
+    where
+        P: Pattern<'a, [u8], Searcher: DoubleEndedSearcher<'a, [u8]>>,
+    {
+        let mut i = 0;
+        let mut j = 0;
+        let mut matcher = pat.into_searcher(self);
+        if let Some((a, b)) = matcher.next_reject() {
+            i = a;
+            j = b; // Remember earliest known match, correct it below if
+            // last match is different
+        }
+        if let Some((_, b)) = matcher.next_reject_back() {
+            j = b;
+        }
+        &self[i..j]
+    }
+
+    /// Returns a sub-slice with all prefixes that match a pattern repeatedly
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// assert_eq!(b"11foo1bar11".trim_start_matches(b'1'), b"foo1bar11");
+    /// assert_eq!(b"abababc".trim_start_matches(b"ab"), b"c");
+    /// ```
+    #[must_use = "this returns the trimmed slice as a new slice, \
+                  without modifying the original"]
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    pub fn trim_start_matches<'a, P: Pattern<'a, [u8]>>(&'a self, pat: P) -> &'a [u8] {
+        let mut i = self.len();
+        let mut matcher = pat.into_searcher(self);
+        if let Some((a, _)) = matcher.next_reject() {
+            i = a;
+        }
+        &self[i..]
+    }
+
+    /// Returns a sub-slice with all suffixes that match a pattern repeatedly
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_pattern)]
+    ///
+    /// assert_eq!(b"11foo1bar11".trim_end_matches(b'1'), b"11foo1bar");
+    /// assert_eq!(b"line\r\n\r\n".trim_end_matches(b"\r\n"), b"line");
+    /// ```
+    #[must_use = "this returns the trimmed slice as a new slice, \
+                  without modifying the original"]
+    #[unstable(feature = "byte_pattern", issue = "none")]
+    pub fn trim_end_matches<'a, P>(&'a self, pat: P) -> &'a [u8]
+    where
+        P: Pattern<'a, [u8], Searcher: ReverseSearcher<'a, [u8]>>,
+    {
+        let mut j = 0;
+        let mut matcher = pat.into_searcher(self);
+        if let Some((_, b)) = matcher.next_reject_back() {
+            j = b;
+        }
+        &self[..j]
+    }
 }
 
 /// Returns `true` if any byte in the word `v` is nonascii (>= 128). Snarfed
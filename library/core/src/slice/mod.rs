@@ -63,6 +63,11 @@
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::{SplitInclusive, SplitInclusiveMut};
 
+#[unstable(feature = "byte_pattern", issue = "none")]
+pub use crate::str::iter::bytes::{MatchIndices, Matches, RMatchIndices, RMatches};
+#[unstable(feature = "byte_pattern", issue = "none")]
+pub use crate::str::iter::bytes::{RSplitNPattern, RSplitPattern, SplitNPattern, SplitPattern};
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use raw::{from_raw_parts, from_raw_parts_mut};
 
@@ -10,8 +10,8 @@
 use crate::slice::{self, Split as SliceSplit};
 
 use super::from_utf8_unchecked;
-use super::pattern::Pattern;
 use super::pattern::{DoubleEndedSearcher, ReverseSearcher, Searcher};
+use super::pattern::{Haystack, Pattern};
 use super::validations::{next_code_point, next_code_point_reverse, utf8_is_cont_byte};
 use super::LinesAnyMap;
 use super::{BytesIsNotEmpty, UnsafeBytesToStr};
@@ -333,12 +333,13 @@ fn may_have_side_effect() -> bool {
 }
 
 /// This macro generates a Clone impl for string pattern API
-/// wrapper types of the form X<'a, P>
+/// wrapper types of the form X<'a, P, H>
 macro_rules! derive_pattern_clone {
     (clone $t:ident with |$s:ident| $e:expr) => {
-        impl<'a, P> Clone for $t<'a, P>
+        impl<'a, P, H> Clone for $t<'a, P, H>
         where
-            P: Pattern<'a, Searcher: Clone>,
+            H: ?Sized + Haystack,
+            P: Pattern<'a, H, Searcher: Clone>,
         {
             fn clone(&self) -> Self {
                 let $s = self;
@@ -351,7 +352,8 @@ fn clone(&self) -> Self {
 /// This macro generates two public iterator structs
 /// wrapping a private internal one that makes use of the `Pattern` API.
 ///
-/// For all patterns `P: Pattern<'a>` the following items will be
+/// For all patterns `P: Pattern<'a, H>` searching in the haystack type `H`
+/// given with the internal iterator, the following items will be
 /// generated (generics omitted):
 ///
 /// struct $forward_iterator($internal_iterator);
@@ -404,19 +406,21 @@ macro_rules! generate_pattern_iterators {
 
         // Internal almost-iterator that is being delegated to
         internal:
-            $internal_iterator:ident yielding ($iterty:ty);
+            $internal_iterator:ident<$haystack:ty> yielding ($iterty:ty);
 
         // Kind of delegation - either single ended or double ended
         delegate $($t:tt)*
     } => {
         $(#[$forward_iterator_attribute])*
         $(#[$common_stability_attribute])*
-        pub struct $forward_iterator<'a, P: Pattern<'a>>(pub(super) $internal_iterator<'a, P>);
+        pub struct $forward_iterator<'a, P: Pattern<'a, $haystack>>(
+            pub(crate) $internal_iterator<'a, P, $haystack>,
+        );
 
         $(#[$common_stability_attribute])*
         impl<'a, P> fmt::Debug for $forward_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: fmt::Debug>,
+            P: Pattern<'a, $haystack, Searcher: fmt::Debug>,
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 f.debug_tuple(stringify!($forward_iterator))
@@ -426,7 +430,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
 
         $(#[$common_stability_attribute])*
-        impl<'a, P: Pattern<'a>> Iterator for $forward_iterator<'a, P> {
+        impl<'a, P: Pattern<'a, $haystack>> Iterator for $forward_iterator<'a, P> {
             type Item = $iterty;
 
             #[inline]
@@ -438,7 +442,7 @@ fn next(&mut self) -> Option<$iterty> {
         $(#[$common_stability_attribute])*
         impl<'a, P> Clone for $forward_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: Clone>,
+            P: Pattern<'a, $haystack, Searcher: Clone>,
         {
             fn clone(&self) -> Self {
                 $forward_iterator(self.0.clone())
@@ -447,12 +451,14 @@ fn clone(&self) -> Self {
 
         $(#[$reverse_iterator_attribute])*
         $(#[$common_stability_attribute])*
-        pub struct $reverse_iterator<'a, P: Pattern<'a>>(pub(super) $internal_iterator<'a, P>);
+        pub struct $reverse_iterator<'a, P: Pattern<'a, $haystack>>(
+            pub(crate) $internal_iterator<'a, P, $haystack>,
+        );
 
         $(#[$common_stability_attribute])*
         impl<'a, P> fmt::Debug for $reverse_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: fmt::Debug>,
+            P: Pattern<'a, $haystack, Searcher: fmt::Debug>,
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 f.debug_tuple(stringify!($reverse_iterator))
@@ -464,7 +470,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         $(#[$common_stability_attribute])*
         impl<'a, P> Iterator for $reverse_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: ReverseSearcher<'a>>,
+            P: Pattern<'a, $haystack, Searcher: ReverseSearcher<'a, $haystack>>,
         {
             type Item = $iterty;
 
@@ -477,7 +483,7 @@ fn next(&mut self) -> Option<$iterty> {
         $(#[$common_stability_attribute])*
         impl<'a, P> Clone for $reverse_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: Clone>,
+            P: Pattern<'a, $haystack, Searcher: Clone>,
         {
             fn clone(&self) -> Self {
                 $reverse_iterator(self.0.clone())
@@ -485,27 +491,27 @@ fn clone(&self) -> Self {
         }
 
         #[stable(feature = "fused", since = "1.26.0")]
-        impl<'a, P: Pattern<'a>> FusedIterator for $forward_iterator<'a, P> {}
+        impl<'a, P: Pattern<'a, $haystack>> FusedIterator for $forward_iterator<'a, P> {}
 
         #[stable(feature = "fused", since = "1.26.0")]
         impl<'a, P> FusedIterator for $reverse_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: ReverseSearcher<'a>>,
+            P: Pattern<'a, $haystack, Searcher: ReverseSearcher<'a, $haystack>>,
         {}
 
         generate_pattern_iterators!($($t)* with $(#[$common_stability_attribute])*,
                                                 $forward_iterator,
-                                                $reverse_iterator, $iterty);
+                                                $reverse_iterator, $haystack, $iterty);
     };
     {
         double ended; with $(#[$common_stability_attribute:meta])*,
                            $forward_iterator:ident,
-                           $reverse_iterator:ident, $iterty:ty
+                           $reverse_iterator:ident, $haystack:ty, $iterty:ty
     } => {
         $(#[$common_stability_attribute])*
         impl<'a, P> DoubleEndedIterator for $forward_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: DoubleEndedSearcher<'a>>,
+            P: Pattern<'a, $haystack, Searcher: DoubleEndedSearcher<'a, $haystack>>,
         {
             #[inline]
             fn next_back(&mut self) -> Option<$iterty> {
@@ -516,7 +522,7 @@ fn next_back(&mut self) -> Option<$iterty> {
         $(#[$common_stability_attribute])*
         impl<'a, P> DoubleEndedIterator for $reverse_iterator<'a, P>
         where
-            P: Pattern<'a, Searcher: DoubleEndedSearcher<'a>>,
+            P: Pattern<'a, $haystack, Searcher: DoubleEndedSearcher<'a, $haystack>>,
         {
             #[inline]
             fn next_back(&mut self) -> Option<$iterty> {
@@ -527,7 +533,7 @@ fn next_back(&mut self) -> Option<$iterty> {
     {
         single ended; with $(#[$common_stability_attribute:meta])*,
                            $forward_iterator:ident,
-                           $reverse_iterator:ident, $iterty:ty
+                           $reverse_iterator:ident, $haystack:ty, $iterty:ty
     } => {}
 }
 
@@ -536,17 +542,18 @@ fn next_back(&mut self) -> Option<$iterty> {
     with |s| SplitInternal { matcher: s.matcher.clone(), ..*s }
 }
 
-pub(super) struct SplitInternal<'a, P: Pattern<'a>> {
-    pub(super) start: usize,
-    pub(super) end: usize,
-    pub(super) matcher: P::Searcher,
-    pub(super) allow_trailing_empty: bool,
-    pub(super) finished: bool,
+pub(crate) struct SplitInternal<'a, P: Pattern<'a, H>, H: ?Sized + Haystack = str> {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) matcher: P::Searcher,
+    pub(crate) allow_trailing_empty: bool,
+    pub(crate) finished: bool,
 }
 
-impl<'a, P> fmt::Debug for SplitInternal<'a, P>
+impl<'a, P, H> fmt::Debug for SplitInternal<'a, P, H>
 where
-    P: Pattern<'a, Searcher: fmt::Debug>,
+    H: ?Sized + Haystack,
+    P: Pattern<'a, H, Searcher: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("SplitInternal")
@@ -559,9 +566,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
-impl<'a, P: Pattern<'a>> SplitInternal<'a, P> {
+impl<'a, P: Pattern<'a, H>, H: ?Sized + Haystack> SplitInternal<'a, P, H> {
     #[inline]
-    fn get_end(&mut self) -> Option<&'a str> {
+    fn get_end(&mut self) -> Option<&'a H> {
         if !self.finished && (self.allow_trailing_empty || self.end - self.start > 0) {
             self.finished = true;
             // SAFETY: `self.start` and `self.end` always lie on unicode boundaries.
@@ -575,7 +582,7 @@ fn get_end(&mut self) -> Option<&'a str> {
     }
 
     #[inline]
-    fn next(&mut self) -> Option<&'a str> {
+    fn next(&mut self) -> Option<&'a H> {
         if self.finished {
             return None;
         }
@@ -593,7 +600,7 @@ fn next(&mut self) -> Option<&'a str> {
     }
 
     #[inline]
-    fn next_inclusive(&mut self) -> Option<&'a str> {
+    fn next_inclusive(&mut self) -> Option<&'a H> {
         if self.finished {
             return None;
         }
@@ -613,9 +620,9 @@ fn next_inclusive(&mut self) -> Option<&'a str> {
     }
 
     #[inline]
-    fn next_back(&mut self) -> Option<&'a str>
+    fn next_back(&mut self) -> Option<&'a H>
     where
-        P::Searcher: ReverseSearcher<'a>,
+        P::Searcher: ReverseSearcher<'a, H>,
     {
         if self.finished {
             return None;
@@ -650,9 +657,9 @@ fn next_back(&mut self) -> Option<&'a str>
     }
 
     #[inline]
-    fn next_back_inclusive(&mut self) -> Option<&'a str>
+    fn next_back_inclusive(&mut self) -> Option<&'a H>
     where
-        P::Searcher: ReverseSearcher<'a>,
+        P::Searcher: ReverseSearcher<'a, H>,
     {
         if self.finished {
             return None;
@@ -691,7 +698,9 @@ fn next_back_inclusive(&mut self) -> Option<&'a str>
             },
         }
     }
+}
 
+impl<'a, P: Pattern<'a>> SplitInternal<'a, P> {
     #[inline]
     fn as_str(&self) -> &'a str {
         // `Self::get_end` doesn't change `self.start`
@@ -718,7 +727,7 @@ fn as_str(&self) -> &'a str {
     stability:
         #[stable(feature = "rust1", since = "1.0.0")]
     internal:
-        SplitInternal yielding (&'a str);
+        SplitInternal<str> yielding (&'a str);
     delegate double ended;
 }
 
@@ -778,7 +787,7 @@ pub fn as_str(&self) -> &'a str {
     stability:
         #[stable(feature = "rust1", since = "1.0.0")]
     internal:
-        SplitInternal yielding (&'a str);
+        SplitInternal<str> yielding (&'a str);
     delegate double ended;
 }
 
@@ -829,15 +838,16 @@ pub fn as_str(&self) -> &'a str {
     with |s| SplitNInternal { iter: s.iter.clone(), ..*s }
 }
 
-pub(super) struct SplitNInternal<'a, P: Pattern<'a>> {
-    pub(super) iter: SplitInternal<'a, P>,
+pub(crate) struct SplitNInternal<'a, P: Pattern<'a, H>, H: ?Sized + Haystack = str> {
+    pub(crate) iter: SplitInternal<'a, P, H>,
     /// The number of splits remaining
-    pub(super) count: usize,
+    pub(crate) count: usize,
 }
 
-impl<'a, P> fmt::Debug for SplitNInternal<'a, P>
+impl<'a, P, H> fmt::Debug for SplitNInternal<'a, P, H>
 where
-    P: Pattern<'a, Searcher: fmt::Debug>,
+    H: ?Sized + Haystack,
+    P: Pattern<'a, H, Searcher: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("SplitNInternal")
@@ -847,9 +857,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
-impl<'a, P: Pattern<'a>> SplitNInternal<'a, P> {
+impl<'a, P: Pattern<'a, H>, H: ?Sized + Haystack> SplitNInternal<'a, P, H> {
     #[inline]
-    fn next(&mut self) -> Option<&'a str> {
+    fn next(&mut self) -> Option<&'a H> {
         match self.count {
             0 => None,
             1 => {
@@ -864,9 +874,9 @@ fn next(&mut self) -> Option<&'a str> {
     }
 
     #[inline]
-    fn next_back(&mut self) -> Option<&'a str>
+    fn next_back(&mut self) -> Option<&'a H>
     where
-        P::Searcher: ReverseSearcher<'a>,
+        P::Searcher: ReverseSearcher<'a, H>,
     {
         match self.count {
             0 => None,
@@ -880,7 +890,9 @@ fn next_back(&mut self) -> Option<&'a str>
             }
         }
     }
+}
 
+impl<'a, P: Pattern<'a>> SplitNInternal<'a, P> {
     #[inline]
     fn as_str(&self) -> &'a str {
         self.iter.as_str()
@@ -901,7 +913,7 @@ fn as_str(&self) -> &'a str {
     stability:
         #[stable(feature = "rust1", since = "1.0.0")]
     internal:
-        SplitNInternal yielding (&'a str);
+        SplitNInternal<str> yielding (&'a str);
     delegate single ended;
 }
 
@@ -952,20 +964,23 @@ pub fn as_str(&self) -> &'a str {
     with |s| MatchIndicesInternal(s.0.clone())
 }
 
-pub(super) struct MatchIndicesInternal<'a, P: Pattern<'a>>(pub(super) P::Searcher);
+pub(crate) struct MatchIndicesInternal<'a, P: Pattern<'a, H>, H: ?Sized + Haystack = str>(
+    pub(crate) P::Searcher,
+);
 
-impl<'a, P> fmt::Debug for MatchIndicesInternal<'a, P>
+impl<'a, P, H> fmt::Debug for MatchIndicesInternal<'a, P, H>
 where
-    P: Pattern<'a, Searcher: fmt::Debug>,
+    H: ?Sized + Haystack,
+    P: Pattern<'a, H, Searcher: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("MatchIndicesInternal").field(&self.0).finish()
     }
 }
 
-impl<'a, P: Pattern<'a>> MatchIndicesInternal<'a, P> {
+impl<'a, P: Pattern<'a, H>, H: ?Sized + Haystack> MatchIndicesInternal<'a, P, H> {
     #[inline]
-    fn next(&mut self) -> Option<(usize, &'a str)> {
+    fn next(&mut self) -> Option<(usize, &'a H)> {
         self.0
             .next_match()
             // SAFETY: `Searcher` guarantees that `start` and `end` lie on unicode boundaries.
@@ -973,9 +988,9 @@ fn next(&mut self) -> Option<(usize, &'a str)> {
     }
 
     #[inline]
-    fn next_back(&mut self) -> Option<(usize, &'a str)>
+    fn next_back(&mut self) -> Option<(usize, &'a H)>
     where
-        P::Searcher: ReverseSearcher<'a>,
+        P::Searcher: ReverseSearcher<'a, H>,
     {
         self.0
             .next_match_back()
@@ -998,7 +1013,7 @@ fn next_back(&mut self) -> Option<(usize, &'a str)>
     stability:
         #[stable(feature = "str_match_indices", since = "1.5.0")]
     internal:
-        MatchIndicesInternal yielding ((usize, &'a str));
+        MatchIndicesInternal<str> yielding ((usize, &'a str));
     delegate double ended;
 }
 
@@ -1007,20 +1022,23 @@ fn next_back(&mut self) -> Option<(usize, &'a str)>
     with |s| MatchesInternal(s.0.clone())
 }
 
-pub(super) struct MatchesInternal<'a, P: Pattern<'a>>(pub(super) P::Searcher);
+pub(crate) struct MatchesInternal<'a, P: Pattern<'a, H>, H: ?Sized + Haystack = str>(
+    pub(crate) P::Searcher,
+);
 
-impl<'a, P> fmt::Debug for MatchesInternal<'a, P>
+impl<'a, P, H> fmt::Debug for MatchesInternal<'a, P, H>
 where
-    P: Pattern<'a, Searcher: fmt::Debug>,
+    H: ?Sized + Haystack,
+    P: Pattern<'a, H, Searcher: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("MatchesInternal").field(&self.0).finish()
     }
 }
 
-impl<'a, P: Pattern<'a>> MatchesInternal<'a, P> {
+impl<'a, P: Pattern<'a, H>, H: ?Sized + Haystack> MatchesInternal<'a, P, H> {
     #[inline]
-    fn next(&mut self) -> Option<&'a str> {
+    fn next(&mut self) -> Option<&'a H> {
         // SAFETY: `Searcher` guarantees that `start` and `end` lie on unicode boundaries.
         self.0.next_match().map(|(a, b)| unsafe {
             // Indices are known to be on utf8 boundaries
@@ -1029,9 +1047,9 @@ fn next(&mut self) -> Option<&'a str> {
     }
 
     #[inline]
-    fn next_back(&mut self) -> Option<&'a str>
+    fn next_back(&mut self) -> Option<&'a H>
     where
-        P::Searcher: ReverseSearcher<'a>,
+        P::Searcher: ReverseSearcher<'a, H>,
     {
         // SAFETY: `Searcher` guarantees that `start` and `end` lie on unicode boundaries.
         self.0.next_match_back().map(|(a, b)| unsafe {
@@ -1055,10 +1073,14 @@ fn next_back(&mut self) -> Option<&'a str>
     stability:
         #[stable(feature = "str_matches", since = "1.2.0")]
     internal:
-        MatchesInternal yielding (&'a str);
+        MatchesInternal<str> yielding (&'a str);
     delegate double ended;
 }
 
+// The iterators of the `Pattern` based methods of `[u8]` are generated with
+// the macros above, so their module has to be declared after them.
+pub(crate) mod bytes;
+
 /// An iterator over the lines of a string, as string slices.
 ///
 /// This struct is created with the [`lines`] method on [`str`].
@@ -0,0 +1,82 @@
+//! Iterators for the `Pattern` based methods of `[u8]`.
+//!
+//! These share their implementation with the iterators of the corresponding
+//! `str` methods, and are re-exported from `core::slice`.
+
+use crate::fmt;
+use crate::iter::FusedIterator;
+
+use super::{MatchIndicesInternal, MatchesInternal, SplitInternal, SplitNInternal};
+use crate::str::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher};
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`split_pattern`].
+        ///
+        /// [`split_pattern`]: ../../std/primitive.slice.html#method.split_pattern
+        struct SplitPattern;
+    reverse:
+        /// Created with the method [`rsplit_pattern`].
+        ///
+        /// [`rsplit_pattern`]: ../../std/primitive.slice.html#method.rsplit_pattern
+        struct RSplitPattern;
+    stability:
+        #[unstable(feature = "byte_pattern", issue = "none")]
+    internal:
+        SplitInternal<[u8]> yielding (&'a [u8]);
+    delegate double ended;
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`splitn_pattern`].
+        ///
+        /// [`splitn_pattern`]: ../../std/primitive.slice.html#method.splitn_pattern
+        struct SplitNPattern;
+    reverse:
+        /// Created with the method [`rsplitn_pattern`].
+        ///
+        /// [`rsplitn_pattern`]: ../../std/primitive.slice.html#method.rsplitn_pattern
+        struct RSplitNPattern;
+    stability:
+        #[unstable(feature = "byte_pattern", issue = "none")]
+    internal:
+        SplitNInternal<[u8]> yielding (&'a [u8]);
+    delegate single ended;
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`match_indices`].
+        ///
+        /// [`match_indices`]: ../../std/primitive.slice.html#method.match_indices
+        struct MatchIndices;
+    reverse:
+        /// Created with the method [`rmatch_indices`].
+        ///
+        /// [`rmatch_indices`]: ../../std/primitive.slice.html#method.rmatch_indices
+        struct RMatchIndices;
+    stability:
+        #[unstable(feature = "byte_pattern", issue = "none")]
+    internal:
+        MatchIndicesInternal<[u8]> yielding ((usize, &'a [u8]));
+    delegate double ended;
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`matches`].
+        ///
+        /// [`matches`]: ../../std/primitive.slice.html#method.matches
+        struct Matches;
+    reverse:
+        /// Created with the method [`rmatches`].
+        ///
+        /// [`rmatches`]: ../../std/primitive.slice.html#method.rmatches
+        struct RMatches;
+    stability:
+        #[unstable(feature = "byte_pattern", issue = "none")]
+    internal:
+        MatchesInternal<[u8]> yielding (&'a [u8]);
+    delegate double ended;
+}
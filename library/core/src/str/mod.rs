@@ -8,7 +8,7 @@
 
 mod converts;
 mod error;
-mod iter;
+pub(crate) mod iter;
 mod traits;
 mod validations;
 
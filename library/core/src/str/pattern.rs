@@ -9,6 +9,11 @@
 //! Although this API is unstable, it is exposed via stable APIs on the
 //! [`str`] type.
 //!
+//! The same machinery is used to search byte slices: every trait takes the
+//! type of the searched [`Haystack`] as a parameter, which defaults to
+//! [`str`]. Patterns for `[u8]` haystacks are implemented for [`u8`],
+//! `&[u8]`, `&[u8; N]` and `FnMut(u8) -> bool`.
+//!
 //! # Examples
 //!
 //! [`Pattern`] is [implemented][pattern-impls] in the stable API for
@@ -38,14 +43,76 @@
 
 use crate::cmp;
 use crate::fmt;
+use crate::ops::Range;
 use crate::slice::memchr;
 
+// Haystack
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for str {}
+    impl Sealed for [u8] {}
+}
+
+/// A type that can be searched with a [`Pattern`].
+///
+/// This trait is sealed: it is implemented for [`str`] and for byte slices
+/// `[u8]` only. All indices handed out by searchers are byte offsets into the
+/// haystack.
+pub trait Haystack: private::Sealed {
+    /// Returns the length of the haystack, in bytes.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the haystack has a length of zero bytes.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a subslice of the haystack, without doing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `range` must lie within the haystack. For [`str`], both ends must also
+    /// lie on utf8 boundaries.
+    unsafe fn get_unchecked(&self, range: Range<usize>) -> &Self;
+}
+
+impl Haystack for str {
+    #[inline]
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, range: Range<usize>) -> &str {
+        // SAFETY: the caller must uphold the safety contract for `get_unchecked`.
+        unsafe { str::get_unchecked(self, range) }
+    }
+}
+
+impl Haystack for [u8] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, range: Range<usize>) -> &[u8] {
+        // SAFETY: the caller must uphold the safety contract for `get_unchecked`.
+        unsafe { <[u8]>::get_unchecked(self, range) }
+    }
+}
+
 // Pattern
 
 /// A string pattern.
 ///
 /// A `Pattern<'a>` expresses that the implementing type
 /// can be used as a string pattern for searching in a [`&'a str`][str].
+/// More generally, a `Pattern<'a, H>` can be used for searching in
+/// any `&'a H` where `H` is a [`Haystack`], such as a byte slice.
 ///
 /// For example, both `'a'` and `"aa"` are patterns that
 /// would match at index `1` in the string `"baaaab"`.
@@ -67,6 +134,14 @@
 /// | `&&str`                  | is substring                              |
 /// | `&String`                | is substring                              |
 ///
+/// The patterns for searching in a `[u8]` haystack behave the same:
+///
+/// | Pattern type             | Match condition                           |
+/// |--------------------------|-------------------------------------------|
+/// | `&[u8]`, `&[u8; N]`      | is sub-slice                              |
+/// | `u8`                     | is contained in slice                     |
+/// | `F: FnMut(u8) -> bool`   | `F` returns `true` for a byte in slice    |
+///
 /// # Examples
 ///
 /// ```
@@ -88,38 +163,38 @@
 /// assert_eq!("abcdef_z".find(|ch| ch > 'd' && ch < 'y'), Some(4));
 /// assert_eq!("abcddd_z".find(|ch| ch > 'd' && ch < 'y'), None);
 /// ```
-pub trait Pattern<'a>: Sized {
+pub trait Pattern<'a, H: ?Sized + Haystack = str>: Sized {
     /// Associated searcher for this pattern
-    type Searcher: Searcher<'a>;
+    type Searcher: Searcher<'a, H>;
 
     /// Constructs the associated searcher from
     /// `self` and the `haystack` to search in.
-    fn into_searcher(self, haystack: &'a str) -> Self::Searcher;
+    fn into_searcher(self, haystack: &'a H) -> Self::Searcher;
 
     /// Checks whether the pattern matches anywhere in the haystack
     #[inline]
-    fn is_contained_in(self, haystack: &'a str) -> bool {
+    fn is_contained_in(self, haystack: &'a H) -> bool {
         self.into_searcher(haystack).next_match().is_some()
     }
 
     /// Checks whether the pattern matches at the front of the haystack
     #[inline]
-    fn is_prefix_of(self, haystack: &'a str) -> bool {
+    fn is_prefix_of(self, haystack: &'a H) -> bool {
         matches!(self.into_searcher(haystack).next(), SearchStep::Match(0, _))
     }
 
     /// Checks whether the pattern matches at the back of the haystack
     #[inline]
-    fn is_suffix_of(self, haystack: &'a str) -> bool
+    fn is_suffix_of(self, haystack: &'a H) -> bool
     where
-        Self::Searcher: ReverseSearcher<'a>,
+        Self::Searcher: ReverseSearcher<'a, H>,
     {
         matches!(self.into_searcher(haystack).next_back(), SearchStep::Match(_, j) if haystack.len() == j)
     }
 
     /// Removes the pattern from the front of haystack, if it matches.
     #[inline]
-    fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
+    fn strip_prefix_of(self, haystack: &'a H) -> Option<&'a H> {
         if let SearchStep::Match(start, len) = self.into_searcher(haystack).next() {
             debug_assert_eq!(
                 start, 0,
@@ -127,7 +202,7 @@ fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
                  must include the first character"
             );
             // SAFETY: `Searcher` is known to return valid indices.
-            unsafe { Some(haystack.get_unchecked(len..)) }
+            unsafe { Some(haystack.get_unchecked(len..haystack.len())) }
         } else {
             None
         }
@@ -135,9 +210,9 @@ fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
 
     /// Removes the pattern from the back of haystack, if it matches.
     #[inline]
-    fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
+    fn strip_suffix_of(self, haystack: &'a H) -> Option<&'a H>
     where
-        Self::Searcher: ReverseSearcher<'a>,
+        Self::Searcher: ReverseSearcher<'a, H>,
     {
         if let SearchStep::Match(start, end) = self.into_searcher(haystack).next_back() {
             debug_assert_eq!(
@@ -147,7 +222,7 @@ fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
                  must include the last character"
             );
             // SAFETY: `Searcher` is known to return valid indices.
-            unsafe { Some(haystack.get_unchecked(..start)) }
+            unsafe { Some(haystack.get_unchecked(0..start)) }
         } else {
             None
         }
@@ -183,13 +258,14 @@ pub enum SearchStep {
 ///
 /// The trait is marked unsafe because the indices returned by the
 /// [`next()`][Searcher::next] methods are required to lie on valid utf8
-/// boundaries in the haystack. This enables consumers of this trait to
-/// slice the haystack without additional runtime checks.
-pub unsafe trait Searcher<'a> {
+/// boundaries in the haystack (or, for a `[u8]` haystack, within its
+/// bounds). This enables consumers of this trait to slice the haystack
+/// without additional runtime checks.
+pub unsafe trait Searcher<'a, H: ?Sized + Haystack = str> {
     /// Getter for the underlying string to be searched in
     ///
-    /// Will always return the same [`&str`][str].
-    fn haystack(&self) -> &'a str;
+    /// Will always return the same [`&str`][str] (or `&H`).
+    fn haystack(&self) -> &'a H;
 
     /// Performs the next search step starting from the front.
     ///
@@ -262,7 +338,7 @@ fn next_reject(&mut self) -> Option<(usize, usize)> {
 ///
 /// For the reason why this trait is marked unsafe, see them
 /// parent trait [`Searcher`].
-pub unsafe trait ReverseSearcher<'a>: Searcher<'a> {
+pub unsafe trait ReverseSearcher<'a, H: ?Sized + Haystack = str>: Searcher<'a, H> {
     /// Performs the next search step starting from the back.
     ///
     /// - Returns [`Match(a, b)`][SearchStep::Match] if `haystack[a..b]`
@@ -334,7 +410,7 @@ fn next_reject_back(&mut self) -> Option<(usize, usize)> {
 /// `(&str)::Searcher` is not a `DoubleEndedSearcher` because
 /// the pattern `"aa"` in the haystack `"aaa"` matches as either
 /// `"[aa]a"` or `"a[aa]"`, depending from which side it is searched.
-pub trait DoubleEndedSearcher<'a>: ReverseSearcher<'a> {}
+pub trait DoubleEndedSearcher<'a, H: ?Sized + Haystack = str>: ReverseSearcher<'a, H> {}
 
 /////////////////////////////////////////////////////////////////////////////
 // Impl for char
@@ -676,41 +752,41 @@ impl<'a, C: MultiCharEq> DoubleEndedSearcher<'a> for MultiCharEqSearcher<'a, C>
 /////////////////////////////////////////////////////////////////////////////
 
 macro_rules! pattern_methods {
-    ($t:ty, $pmap:expr, $smap:expr) => {
+    ($h:ty, $t:ty, $pmap:expr, $smap:expr) => {
         type Searcher = $t;
 
         #[inline]
-        fn into_searcher(self, haystack: &'a str) -> $t {
+        fn into_searcher(self, haystack: &'a $h) -> $t {
             ($smap)(($pmap)(self).into_searcher(haystack))
         }
 
         #[inline]
-        fn is_contained_in(self, haystack: &'a str) -> bool {
+        fn is_contained_in(self, haystack: &'a $h) -> bool {
             ($pmap)(self).is_contained_in(haystack)
         }
 
         #[inline]
-        fn is_prefix_of(self, haystack: &'a str) -> bool {
+        fn is_prefix_of(self, haystack: &'a $h) -> bool {
             ($pmap)(self).is_prefix_of(haystack)
         }
 
         #[inline]
-        fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
+        fn strip_prefix_of(self, haystack: &'a $h) -> Option<&'a $h> {
             ($pmap)(self).strip_prefix_of(haystack)
         }
 
         #[inline]
-        fn is_suffix_of(self, haystack: &'a str) -> bool
+        fn is_suffix_of(self, haystack: &'a $h) -> bool
         where
-            $t: ReverseSearcher<'a>,
+            $t: ReverseSearcher<'a, $h>,
         {
             ($pmap)(self).is_suffix_of(haystack)
         }
 
         #[inline]
-        fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
+        fn strip_suffix_of(self, haystack: &'a $h) -> Option<&'a $h>
         where
-            $t: ReverseSearcher<'a>,
+            $t: ReverseSearcher<'a, $h>,
         {
             ($pmap)(self).strip_suffix_of(haystack)
         }
@@ -781,7 +857,7 @@ impl<'a, 'b> DoubleEndedSearcher<'a> for CharSliceSearcher<'a, 'b> {}
 /// assert_eq!("Hello world".find(&['l', 'l'][..]), Some(2));
 /// ```
 impl<'a, 'b> Pattern<'a> for &'b [char] {
-    pattern_methods!(CharSliceSearcher<'a, 'b>, MultiCharEqPattern, CharSliceSearcher);
+    pattern_methods!(str, CharSliceSearcher<'a, 'b>, MultiCharEqPattern, CharSliceSearcher);
 }
 
 /////////////////////////////////////////////////////////////////////////////
@@ -833,7 +909,7 @@ impl<'a, F> Pattern<'a> for F
 where
     F: FnMut(char) -> bool,
 {
-    pattern_methods!(CharPredicateSearcher<'a, F>, MultiCharEqPattern, CharPredicateSearcher);
+    pattern_methods!(str, CharPredicateSearcher<'a, F>, MultiCharEqPattern, CharPredicateSearcher);
 }
 
 /////////////////////////////////////////////////////////////////////////////
@@ -842,7 +918,7 @@ impl<'a, F> Pattern<'a> for F
 
 /// Delegates to the `&str` impl.
 impl<'a, 'b, 'c> Pattern<'a> for &'c &'b str {
-    pattern_methods!(StrSearcher<'a, 'b>, |&s| s, |s| s);
+    pattern_methods!(str, StrSearcher<'a, 'b>, |&s| s, |s| s);
 }
 
 /////////////////////////////////////////////////////////////////////////////
@@ -903,6 +979,301 @@ fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str> {
     }
 }
 
+/////////////////////////////////////////////////////////////////////////////
+// Impl for u8
+/////////////////////////////////////////////////////////////////////////////
+
+/// Associated type for `<u8 as Pattern<'a, [u8]>>::Searcher`.
+#[derive(Clone, Debug)]
+pub struct ByteSearcher<'a> {
+    haystack: &'a [u8],
+    // invariant: `finger <= finger_back <= haystack.len()`
+    /// `finger` is the index of the first byte the forward search has not
+    /// inspected yet.
+    finger: usize,
+    /// `finger_back` is the index after the first byte the reverse search
+    /// has not inspected yet.
+    finger_back: usize,
+    /// The byte being searched for
+    needle: u8,
+}
+
+unsafe impl<'a> Searcher<'a, [u8]> for ByteSearcher<'a> {
+    #[inline]
+    fn haystack(&self) -> &'a [u8] {
+        self.haystack
+    }
+
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        let old_finger = self.finger;
+        if old_finger == self.finger_back {
+            return SearchStep::Done;
+        }
+        self.finger += 1;
+        if self.haystack[old_finger] == self.needle {
+            SearchStep::Match(old_finger, self.finger)
+        } else {
+            SearchStep::Reject(old_finger, self.finger)
+        }
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let bytes = &self.haystack[self.finger..self.finger_back];
+        if let Some(index) = memchr::memchr(self.needle, bytes) {
+            let found = self.finger + index;
+            self.finger = found + 1;
+            Some((found, self.finger))
+        } else {
+            self.finger = self.finger_back;
+            None
+        }
+    }
+
+    // let next_reject use the default implementation from the Searcher trait
+}
+
+unsafe impl<'a> ReverseSearcher<'a, [u8]> for ByteSearcher<'a> {
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        let old_finger = self.finger_back;
+        if old_finger == self.finger {
+            return SearchStep::Done;
+        }
+        self.finger_back -= 1;
+        if self.haystack[self.finger_back] == self.needle {
+            SearchStep::Match(self.finger_back, old_finger)
+        } else {
+            SearchStep::Reject(self.finger_back, old_finger)
+        }
+    }
+
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        let bytes = &self.haystack[self.finger..self.finger_back];
+        if let Some(index) = memchr::memrchr(self.needle, bytes) {
+            let found = self.finger + index;
+            self.finger_back = found;
+            Some((found, found + 1))
+        } else {
+            self.finger_back = self.finger;
+            None
+        }
+    }
+
+    // let next_reject_back use the default implementation from the Searcher trait
+}
+
+impl<'a> DoubleEndedSearcher<'a, [u8]> for ByteSearcher<'a> {}
+
+/// Searches for bytes that are equal to a given [`u8`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(byte_pattern)]
+/// assert_eq!(b"Hello world".find(b'o'), Some(4));
+/// ```
+impl<'a> Pattern<'a, [u8]> for u8 {
+    type Searcher = ByteSearcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a [u8]) -> Self::Searcher {
+        ByteSearcher { haystack, finger: 0, finger_back: haystack.len(), needle: self }
+    }
+
+    #[inline]
+    fn is_contained_in(self, haystack: &'a [u8]) -> bool {
+        memchr::memchr(self, haystack).is_some()
+    }
+
+    #[inline]
+    fn is_prefix_of(self, haystack: &'a [u8]) -> bool {
+        haystack.first() == Some(&self)
+    }
+
+    #[inline]
+    fn strip_prefix_of(self, haystack: &'a [u8]) -> Option<&'a [u8]> {
+        match haystack.split_first() {
+            Some((&first, rest)) if first == self => Some(rest),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn is_suffix_of(self, haystack: &'a [u8]) -> bool
+    where
+        Self::Searcher: ReverseSearcher<'a, [u8]>,
+    {
+        haystack.last() == Some(&self)
+    }
+
+    #[inline]
+    fn strip_suffix_of(self, haystack: &'a [u8]) -> Option<&'a [u8]>
+    where
+        Self::Searcher: ReverseSearcher<'a, [u8]>,
+    {
+        match haystack.split_last() {
+            Some((&last, rest)) if last == self => Some(rest),
+            _ => None,
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Impl for F: FnMut(u8) -> bool
+/////////////////////////////////////////////////////////////////////////////
+
+/// Associated type for `<F as Pattern<'a, [u8]>>::Searcher`.
+#[derive(Clone)]
+pub struct BytePredicateSearcher<'a, F>
+where
+    F: FnMut(u8) -> bool,
+{
+    haystack: &'a [u8],
+    // invariant: `finger <= finger_back <= haystack.len()`
+    finger: usize,
+    finger_back: usize,
+    predicate: F,
+}
+
+impl<F> fmt::Debug for BytePredicateSearcher<'_, F>
+where
+    F: FnMut(u8) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BytePredicateSearcher")
+            .field("haystack", &self.haystack)
+            .field("finger", &self.finger)
+            .field("finger_back", &self.finger_back)
+            .finish()
+    }
+}
+
+unsafe impl<'a, F> Searcher<'a, [u8]> for BytePredicateSearcher<'a, F>
+where
+    F: FnMut(u8) -> bool,
+{
+    #[inline]
+    fn haystack(&self) -> &'a [u8] {
+        self.haystack
+    }
+
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        let old_finger = self.finger;
+        if old_finger == self.finger_back {
+            return SearchStep::Done;
+        }
+        self.finger += 1;
+        if (self.predicate)(self.haystack[old_finger]) {
+            SearchStep::Match(old_finger, self.finger)
+        } else {
+            SearchStep::Reject(old_finger, self.finger)
+        }
+    }
+}
+
+unsafe impl<'a, F> ReverseSearcher<'a, [u8]> for BytePredicateSearcher<'a, F>
+where
+    F: FnMut(u8) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        let old_finger = self.finger_back;
+        if old_finger == self.finger {
+            return SearchStep::Done;
+        }
+        self.finger_back -= 1;
+        if (self.predicate)(self.haystack[self.finger_back]) {
+            SearchStep::Match(self.finger_back, old_finger)
+        } else {
+            SearchStep::Reject(self.finger_back, old_finger)
+        }
+    }
+}
+
+impl<'a, F> DoubleEndedSearcher<'a, [u8]> for BytePredicateSearcher<'a, F> where F: FnMut(u8) -> bool
+{}
+
+/// Searches for bytes that match the given predicate.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(byte_pattern)]
+/// assert_eq!(b"Hello world".find(|b: u8| b.is_ascii_whitespace()), Some(5));
+/// ```
+impl<'a, F> Pattern<'a, [u8]> for F
+where
+    F: FnMut(u8) -> bool,
+{
+    type Searcher = BytePredicateSearcher<'a, F>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a [u8]) -> Self::Searcher {
+        BytePredicateSearcher { haystack, finger: 0, finger_back: haystack.len(), predicate: self }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Impl for &[u8; N]
+/////////////////////////////////////////////////////////////////////////////
+
+/// Delegates to the `&[u8]` impl.
+impl<'a, 'b, const N: usize> Pattern<'a, [u8]> for &'b [u8; N] {
+    pattern_methods!([u8], BytesSearcher<'a, 'b>, |s: &'b [u8; N]| &s[..], |s| s);
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Impl for &[u8]
+/////////////////////////////////////////////////////////////////////////////
+
+/// Non-allocating sub-slice search.
+///
+/// Will handle the pattern `b""` as returning empty matches at each byte
+/// offset, including both ends of the haystack.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(byte_pattern)]
+/// assert_eq!(b"Hello world".find(&b"world"[..]), Some(6));
+/// ```
+impl<'a, 'b> Pattern<'a, [u8]> for &'b [u8] {
+    type Searcher = BytesSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a [u8]) -> BytesSearcher<'a, 'b> {
+        BytesSearcher::new(haystack, self)
+    }
+
+    /// Checks whether the pattern matches at the front of the haystack.
+    #[inline]
+    fn is_prefix_of(self, haystack: &'a [u8]) -> bool {
+        haystack.starts_with(self)
+    }
+
+    /// Removes the pattern from the front of haystack, if it matches.
+    #[inline]
+    fn strip_prefix_of(self, haystack: &'a [u8]) -> Option<&'a [u8]> {
+        haystack.strip_prefix(self)
+    }
+
+    /// Checks whether the pattern matches at the back of the haystack.
+    #[inline]
+    fn is_suffix_of(self, haystack: &'a [u8]) -> bool {
+        haystack.ends_with(self)
+    }
+
+    /// Removes the pattern from the back of haystack, if it matches.
+    #[inline]
+    fn strip_suffix_of(self, haystack: &'a [u8]) -> Option<&'a [u8]> {
+        haystack.strip_suffix(self)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Two Way substring searcher
 /////////////////////////////////////////////////////////////////////////////
@@ -930,28 +1301,27 @@ struct EmptyNeedle {
     is_match_bw: bool,
 }
 
-impl<'a, 'b> StrSearcher<'a, 'b> {
-    fn new(haystack: &'a str, needle: &'b str) -> StrSearcher<'a, 'b> {
+impl StrSearcherImpl {
+    fn new(needle: &[u8], end: usize) -> StrSearcherImpl {
         if needle.is_empty() {
-            StrSearcher {
-                haystack,
-                needle,
-                searcher: StrSearcherImpl::Empty(EmptyNeedle {
-                    position: 0,
-                    end: haystack.len(),
-                    is_match_fw: true,
-                    is_match_bw: true,
-                }),
-            }
+            StrSearcherImpl::Empty(EmptyNeedle {
+                position: 0,
+                end,
+                is_match_fw: true,
+                is_match_bw: true,
+            })
         } else {
-            StrSearcher {
-                haystack,
-                needle,
-                searcher: StrSearcherImpl::TwoWay(TwoWaySearcher::new(
-                    needle.as_bytes(),
-                    haystack.len(),
-                )),
-            }
+            StrSearcherImpl::TwoWay(TwoWaySearcher::new(needle, end))
+        }
+    }
+}
+
+impl<'a, 'b> StrSearcher<'a, 'b> {
+    fn new(haystack: &'a str, needle: &'b str) -> StrSearcher<'a, 'b> {
+        StrSearcher {
+            haystack,
+            needle,
+            searcher: StrSearcherImpl::new(needle.as_bytes(), haystack.len()),
         }
     }
 }
@@ -1112,6 +1482,130 @@ fn next_match_back(&mut self) -> Option<(usize, usize)> {
     }
 }
 
+/// Associated type for `<&[u8] as Pattern<'a, [u8]>>::Searcher`.
+///
+/// This uses the same Two-Way searcher as [`StrSearcher`], but as byte slices
+/// have no character boundaries, matches and rejects can fall on any index.
+#[derive(Clone, Debug)]
+pub struct BytesSearcher<'a, 'b> {
+    haystack: &'a [u8],
+    needle: &'b [u8],
+
+    searcher: StrSearcherImpl,
+}
+
+impl<'a, 'b> BytesSearcher<'a, 'b> {
+    fn new(haystack: &'a [u8], needle: &'b [u8]) -> BytesSearcher<'a, 'b> {
+        BytesSearcher { haystack, needle, searcher: StrSearcherImpl::new(needle, haystack.len()) }
+    }
+}
+
+unsafe impl<'a, 'b> Searcher<'a, [u8]> for BytesSearcher<'a, 'b> {
+    #[inline]
+    fn haystack(&self) -> &'a [u8] {
+        self.haystack
+    }
+
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        match self.searcher {
+            StrSearcherImpl::Empty(ref mut searcher) => {
+                // empty needle rejects every byte and matches every empty slice between them
+                let is_match = searcher.is_match_fw;
+                searcher.is_match_fw = !searcher.is_match_fw;
+                let pos = searcher.position;
+                match self.haystack.get(pos) {
+                    _ if is_match => SearchStep::Match(pos, pos),
+                    None => SearchStep::Done,
+                    Some(_) => {
+                        searcher.position += 1;
+                        SearchStep::Reject(pos, searcher.position)
+                    }
+                }
+            }
+            StrSearcherImpl::TwoWay(ref mut searcher) => {
+                if searcher.position == self.haystack.len() {
+                    return SearchStep::Done;
+                }
+                let is_long = searcher.memory == usize::MAX;
+                searcher.next::<RejectAndMatch>(self.haystack, self.needle, is_long)
+            }
+        }
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        match self.searcher {
+            StrSearcherImpl::Empty(..) => loop {
+                match self.next() {
+                    SearchStep::Match(a, b) => return Some((a, b)),
+                    SearchStep::Done => return None,
+                    SearchStep::Reject(..) => {}
+                }
+            },
+            StrSearcherImpl::TwoWay(ref mut searcher) => {
+                let is_long = searcher.memory == usize::MAX;
+                // write out `true` and `false`, like `StrSearcher::next_match`
+                if is_long {
+                    searcher.next::<MatchOnly>(self.haystack, self.needle, true)
+                } else {
+                    searcher.next::<MatchOnly>(self.haystack, self.needle, false)
+                }
+            }
+        }
+    }
+}
+
+unsafe impl<'a, 'b> ReverseSearcher<'a, [u8]> for BytesSearcher<'a, 'b> {
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        match self.searcher {
+            StrSearcherImpl::Empty(ref mut searcher) => {
+                let is_match = searcher.is_match_bw;
+                searcher.is_match_bw = !searcher.is_match_bw;
+                let end = searcher.end;
+                match self.haystack[..end].last() {
+                    _ if is_match => SearchStep::Match(end, end),
+                    None => SearchStep::Done,
+                    Some(_) => {
+                        searcher.end -= 1;
+                        SearchStep::Reject(searcher.end, end)
+                    }
+                }
+            }
+            StrSearcherImpl::TwoWay(ref mut searcher) => {
+                if searcher.end == 0 {
+                    return SearchStep::Done;
+                }
+                let is_long = searcher.memory == usize::MAX;
+                searcher.next_back::<RejectAndMatch>(self.haystack, self.needle, is_long)
+            }
+        }
+    }
+
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        match self.searcher {
+            StrSearcherImpl::Empty(..) => loop {
+                match self.next_back() {
+                    SearchStep::Match(a, b) => return Some((a, b)),
+                    SearchStep::Done => return None,
+                    SearchStep::Reject(..) => {}
+                }
+            },
+            StrSearcherImpl::TwoWay(ref mut searcher) => {
+                let is_long = searcher.memory == usize::MAX;
+                // write out `true` and `false`, like `StrSearcher::next_match`
+                if is_long {
+                    searcher.next_back::<MatchOnly>(self.haystack, self.needle, true)
+                } else {
+                    searcher.next_back::<MatchOnly>(self.haystack, self.needle, false)
+                }
+            }
+        }
+    }
+}
+
 /// The internal state of the two-way substring search algorithm.
 #[derive(Clone, Debug)]
 struct TwoWaySearcher {
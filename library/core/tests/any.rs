@@ -114,3 +114,42 @@ fn any_unsized() {
     fn is_any<T: Any + ?Sized>() {}
     is_any::<[i32]>();
 }
+
+struct SomeConcreteType {
+    some_string: String,
+}
+
+impl Provider for SomeConcreteType {
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        demand
+            .provide_ref::<String>(&self.some_string)
+            .provide_ref::<str>(&self.some_string)
+            .provide_value::<String>(self.some_string.clone())
+            .provide_value_with::<u8>(|| 42);
+    }
+}
+
+#[test]
+fn test_provider() {
+    let obj: &dyn Provider = &SomeConcreteType { some_string: "hello".to_owned() };
+
+    assert_eq!(&**request_ref::<String, _>(obj).unwrap(), "hello");
+    assert_eq!(request_ref::<str, _>(obj).unwrap(), "hello");
+    assert_eq!(&*request_value::<String, _>(obj).unwrap(), "hello");
+    assert_eq!(request_value::<u8, _>(obj).unwrap(), 42);
+    assert!(request_ref::<u8, _>(obj).is_none());
+    assert!(request_value::<i32, _>(obj).is_none());
+}
+
+#[test]
+fn test_provider_first_value_wins() {
+    struct Twice;
+
+    impl Provider for Twice {
+        fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+            demand.provide_value::<u32>(1).provide_value::<u32>(2);
+        }
+    }
+
+    assert_eq!(request_value::<u32, _>(&Twice), Some(1));
+}
@@ -7,6 +7,7 @@
 #![feature(bool_to_option)]
 #![feature(bound_cloned)]
 #![feature(box_syntax)]
+#![feature(byte_pattern)]
 #![feature(cell_update)]
 #![feature(cfg_panic)]
 #![feature(cfg_target_has_atomic)]
@@ -33,6 +34,7 @@
 #![feature(try_find)]
 #![feature(is_sorted)]
 #![feature(pattern)]
+#![feature(provide_any)]
 #![feature(raw)]
 #![feature(sort_internals)]
 #![feature(slice_partition_at_index)]
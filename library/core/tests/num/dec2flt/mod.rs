@@ -1,7 +1,8 @@
 #![allow(overflowing_literals)]
 
+mod float;
+mod lemire;
 mod parse;
-mod rawfp;
 
 // Take a float literal, turn it into a string in various ways (that are all trusted
 // to be correct) and see if those strings are parsed back to the value of the literal.
@@ -147,7 +148,37 @@ fn borderline_overflow() {
     for _ in 0..375 {
         s.push('3');
     }
-    // At the time of this writing, this returns Err(..), but this is a bug that should be fixed.
-    // It makes no sense to enshrine that in a test, the important part is that it doesn't panic.
-    let _ = s.parse::<f64>();
+    assert_eq!(s.parse(), Ok(1.0f64 / 3.0));
+    assert_eq!(s.parse(), Ok(1.0f32 / 3.0));
+}
+
+#[test]
+fn many_digits() {
+    // Too many digits for the mantissa, but the truncated digits don't affect rounding.
+    test_literal!(123456789012345678901234567890.0);
+    test_literal!(0.000000000012345678901234567890123456789);
+    // Exactly half-way between two floats, followed by many zeros, and then by a digit which
+    // decides the rounding.
+    let halfway = format!("9007199254740993.{}", "0".repeat(1000));
+    assert_eq!(halfway.parse(), Ok(9007199254740992.0f64));
+    assert_eq!(format!("{}1", halfway).parse(), Ok(9007199254740994.0f64));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Miri is too slow
+fn long_input() {
+    let s = format!("0.{}1e5001", "0".repeat(5000));
+    assert_eq!(s.parse(), Ok(1.0f64));
+    let s = format!("1{}e-5000", "0".repeat(5000));
+    assert_eq!(s.parse(), Ok(1.0f64));
+    assert_eq!("9".repeat(400).parse(), Ok(f64::INFINITY));
+}
+
+#[test]
+fn issue_31407() {
+    // The compiler parses float literals with the std it was built with, which may still
+    // reject this one, see src/test/ui/issues/issue-31109.rs.
+    let s = "1234567890123456789012345678901234567890e-340";
+    assert_eq!(s.parse(), Ok(1.2345678901234567e-301f64));
+    assert_eq!(format!("-{}", s).parse(), Ok(-1.2345678901234567e-301f64));
 }
@@ -1,11 +1,15 @@
-use core::num::dec2flt::parse::ParseResult::{Invalid, Valid};
-use core::num::dec2flt::parse::{parse_decimal, Decimal};
+use core::num::dec2flt::number::Number;
+use core::num::dec2flt::parse::parse_number;
+
+fn new_number(exponent: i64, mantissa: u64) -> Number {
+    Number { exponent, mantissa, negative: false, many_digits: false }
+}
 
 #[test]
 fn missing_pieces() {
     let permutations = &[".e", "1e", "e4", "e", ".12e", "321.e", "32.12e+", "12.32e-"];
     for &s in permutations {
-        assert_eq!(parse_decimal(s), Invalid);
+        assert_eq!(parse_number(s.as_bytes(), false), None);
     }
 }
 
@@ -19,7 +23,11 @@ fn invalid_chars() {
                 let mut input = String::new();
                 input.push_str(s);
                 input.insert(i, c);
-                assert!(parse_decimal(&input) == Invalid, "did not reject invalid {:?}", input);
+                assert!(
+                    parse_number(input.as_bytes(), false).is_none(),
+                    "did not reject invalid {:?}",
+                    input
+                );
             }
         }
     }
@@ -27,15 +35,44 @@ fn invalid_chars() {
 
 #[test]
 fn valid() {
-    assert_eq!(parse_decimal("123.456e789"), Valid(Decimal::new(b"123", b"456", 789)));
-    assert_eq!(parse_decimal("123.456e+789"), Valid(Decimal::new(b"123", b"456", 789)));
-    assert_eq!(parse_decimal("123.456e-789"), Valid(Decimal::new(b"123", b"456", -789)));
-    assert_eq!(parse_decimal(".050"), Valid(Decimal::new(b"", b"050", 0)));
-    assert_eq!(parse_decimal("999"), Valid(Decimal::new(b"999", b"", 0)));
-    assert_eq!(parse_decimal("1.e300"), Valid(Decimal::new(b"1", b"", 300)));
-    assert_eq!(parse_decimal(".1e300"), Valid(Decimal::new(b"", b"1", 300)));
-    assert_eq!(parse_decimal("101e-33"), Valid(Decimal::new(b"101", b"", -33)));
+    assert_eq!(parse_number(b"123.456e789", false), Some(new_number(786, 123456)));
+    assert_eq!(parse_number(b"123.456e+789", false), Some(new_number(786, 123456)));
+    assert_eq!(parse_number(b"123.456e-789", false), Some(new_number(-792, 123456)));
+    assert_eq!(parse_number(b".050", false), Some(new_number(-3, 50)));
+    assert_eq!(parse_number(b"999", false), Some(new_number(0, 999)));
+    assert_eq!(parse_number(b"1.e300", false), Some(new_number(300, 1)));
+    assert_eq!(parse_number(b".1e300", false), Some(new_number(299, 1)));
+    assert_eq!(parse_number(b"101e-33", false), Some(new_number(-33, 101)));
     let zeros = "0".repeat(25);
     let s = format!("1.5e{}", zeros);
-    assert_eq!(parse_decimal(&s), Valid(Decimal::new(b"1", b"5", 0)));
+    assert_eq!(parse_number(s.as_bytes(), false), Some(new_number(-1, 15)));
+}
+
+#[test]
+fn negative() {
+    let number = Number { exponent: -1, mantissa: 15, negative: true, many_digits: false };
+    assert_eq!(parse_number(b"1.5", true), Some(number));
+}
+
+#[test]
+fn many_digits() {
+    // Only the first 19 significant digits are kept, leading zeros don't count.
+    let number =
+        Number { exponent: -21, mantissa: 1234567890123456789, negative: false, many_digits: true };
+    assert_eq!(parse_number(b"0.00123456789012345678901", false), Some(number));
+    let number =
+        Number { exponent: 2, mantissa: 1234567890123456789, negative: false, many_digits: true };
+    assert_eq!(parse_number(b"123456789012345678901", false), Some(number));
+    // Leading zeros alone don't make for many digits.
+    let s = format!("0.{}1", "0".repeat(30));
+    assert_eq!(parse_number(s.as_bytes(), false), Some(new_number(-31, 1)));
+}
+
+#[test]
+fn huge_exponent() {
+    // Exponents which don't fit into 64 bits saturate.
+    let s = format!("1e{}", "9".repeat(30));
+    assert_eq!(parse_number(s.as_bytes(), false), Some(new_number(i64::MAX, 1)));
+    let s = format!("1e-{}", "9".repeat(30));
+    assert_eq!(parse_number(s.as_bytes(), false), Some(new_number(-i64::MAX, 1)));
 }
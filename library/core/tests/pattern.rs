@@ -501,3 +501,111 @@ fn double_ended_regression_test() {
         [InRange(10, 13), Rejects(13, 14), InRange(37, 40), Rejects(34, 37), Done]
     );
 }
+
+#[test]
+fn test_byte_searcher() {
+    search_asserts!(
+        &b"abcab"[..],
+        b'a',
+        "forward iteration for a byte",
+        [next, next, next, next, next, next],
+        [Matches(0, 1), Rejects(1, 2), Rejects(2, 3), Matches(3, 4), Rejects(4, 5), Done]
+    );
+    search_asserts!(
+        &b"abcab"[..],
+        b'a',
+        "reverse iteration for a byte",
+        [next_back, next_back, next_back, next_back, next_back, next_back],
+        [Rejects(4, 5), Matches(3, 4), Rejects(2, 3), Rejects(1, 2), Matches(0, 1), Done]
+    );
+    search_asserts!(
+        &b"\xffa\xff\xff"[..],
+        b'\xff',
+        "next_match for a non-ASCII byte",
+        [next_match, next_match, next_match, next_match],
+        [InRange(0, 1), InRange(2, 3), InRange(3, 4), Done]
+    );
+    search_asserts!(
+        &b"\xffa\xff\xff"[..],
+        b'\xff',
+        "next_match_back for a non-ASCII byte",
+        [next_match_back, next_match_back, next_match_back, next_match_back],
+        [InRange(3, 4), InRange(2, 3), InRange(0, 1), Done]
+    );
+    search_asserts!(
+        &b"abab"[..],
+        b'b',
+        "next_match and next_match_back meet in the middle",
+        [next_match, next_match_back, next_match, next_match_back],
+        [InRange(1, 2), InRange(3, 4), Done, Done]
+    );
+}
+
+#[test]
+fn test_byte_predicate_searcher() {
+    search_asserts!(
+        &b"a1b2"[..],
+        |b: u8| b.is_ascii_digit(),
+        "forward iteration for a predicate",
+        [next, next, next, next, next],
+        [Rejects(0, 1), Matches(1, 2), Rejects(2, 3), Matches(3, 4), Done]
+    );
+    search_asserts!(
+        &b"a1b2"[..],
+        |b: u8| b.is_ascii_digit(),
+        "next_match_back for a predicate",
+        [next_match_back, next_match_back, next_match_back],
+        [InRange(3, 4), InRange(1, 2), Done]
+    );
+}
+
+#[test]
+fn test_bytes_searcher() {
+    // Matches and rejects may fall on any index, as there are no char boundaries.
+    search_asserts!(
+        &b"\xff\xfe\xff\xfe\xfe"[..],
+        &b"\xfe\xff"[..],
+        "forward iteration for a non-UTF-8 needle",
+        [next, next, next, next],
+        [Rejects(0, 1), Matches(1, 3), Rejects(3, 5), Done]
+    );
+    search_asserts!(
+        &b"\xff\xfe\xff\xfe\xfe"[..],
+        &b"\xfe\xff"[..],
+        "reverse iteration for a non-UTF-8 needle",
+        [next_back, next_back, next_back, next_back],
+        [Rejects(3, 5), Matches(1, 3), Rejects(0, 1), Done]
+    );
+    search_asserts!(
+        &b"aaaaa"[..],
+        b"aa",
+        "next_match for an array needle",
+        [next_match, next_match, next_match],
+        [InRange(0, 2), InRange(2, 4), Done]
+    );
+    search_asserts!(
+        &b"aaaaa"[..],
+        b"aa",
+        "next_match_back for an array needle",
+        [next_match_back, next_match_back, next_match_back],
+        [InRange(3, 5), InRange(1, 3), Done]
+    );
+}
+
+#[test]
+fn test_empty_bytes_needle() {
+    search_asserts!(
+        &b"a\xff"[..],
+        &b""[..],
+        "forward iteration for an empty needle",
+        [next, next, next, next, next, next],
+        [Matches(0, 0), Rejects(0, 1), Matches(1, 1), Rejects(1, 2), Matches(2, 2), Done]
+    );
+    search_asserts!(
+        &b"a\xff"[..],
+        &b""[..],
+        "reverse iteration for an empty needle",
+        [next_back, next_back, next_back, next_back, next_back, next_back],
+        [Matches(2, 2), Rejects(1, 2), Matches(1, 1), Rejects(0, 1), Matches(0, 0), Done]
+    );
+}
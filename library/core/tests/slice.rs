@@ -2038,3 +2038,71 @@ fn foo(x: &Cell<isize>) -> Foo<'_> {
 
     assert_eq!(x.get(), 1);
 }
+
+#[test]
+fn test_byte_pattern_find() {
+    let s = b"GET /a%20b HTTP/1.1\r\n";
+    assert_eq!(s.find(b' '), Some(3));
+    assert_eq!(s.find(b"\r\n"), Some(19));
+    assert_eq!(s.find(&b"HTTP"[..]), Some(11));
+    assert_eq!(s.find(|b: u8| b.is_ascii_digit()), Some(7));
+    assert_eq!(s.find(b"http"), None);
+    assert_eq!(s.find(b""), Some(0));
+    assert_eq!(s.rfind(b' '), Some(10));
+    assert_eq!(s.rfind(b"/"), Some(15));
+    assert_eq!(s.rfind(|b: u8| b.is_ascii_digit()), Some(18));
+    assert_eq!(s.rfind(b""), Some(s.len()));
+
+    let empty: &[u8] = &[];
+    assert_eq!(empty.find(b'a'), None);
+    assert_eq!(empty.find(b""), Some(0));
+    assert_eq!(empty.rfind(b"a"), None);
+}
+
+#[test]
+fn test_byte_pattern_split() {
+    let s = b"a::b::::c";
+    let v: Vec<&[u8]> = s.split_pattern(b"::").collect();
+    assert_eq!(v, [&b"a"[..], b"b", b"", b"c"]);
+    let v: Vec<&[u8]> = s.rsplit_pattern(b"::").collect();
+    assert_eq!(v, [&b"c"[..], b"", b"b", b"a"]);
+    let v: Vec<&[u8]> = s.split_pattern(b':').rev().collect();
+    assert_eq!(v, [&b"c"[..], b"", b"", b"", b"b", b"", b"a"]);
+    let v: Vec<&[u8]> = s.splitn_pattern(2, b"::").collect();
+    assert_eq!(v, [&b"a"[..], b"b::::c"]);
+    let v: Vec<&[u8]> = s.rsplitn_pattern(2, b"::").collect();
+    assert_eq!(v, [&b"c"[..], b"a::b::"]);
+    let v: Vec<&[u8]> = b"\xff,\xfe,".split_pattern(|b: u8| b == b',').collect();
+    assert_eq!(v, [&b"\xff"[..], b"\xfe", b""]);
+
+    assert_eq!(b"key=value=x".split_once(b'='), Some((&b"key"[..], &b"value=x"[..])));
+    assert_eq!(b"key=value=x".rsplit_once(b"="), Some((&b"key=value"[..], &b"x"[..])));
+    assert_eq!(b"key".split_once(b'='), None);
+    assert_eq!(b"key".rsplit_once(b'='), None);
+}
+
+#[test]
+fn test_byte_pattern_matches() {
+    let s = b"abcXXXabcYYYabc";
+    let v: Vec<&[u8]> = s.matches(b"abc").collect();
+    assert_eq!(v, [b"abc", b"abc", b"abc"]);
+    let v: Vec<(usize, &[u8])> = s.match_indices(b"abc").collect();
+    assert_eq!(v, [(0, &b"abc"[..]), (6, b"abc"), (12, b"abc")]);
+    let v: Vec<(usize, &[u8])> = s.rmatch_indices(b'X').collect();
+    assert_eq!(v, [(5, &b"X"[..]), (4, b"X"), (3, b"X")]);
+    let v: Vec<&[u8]> = b"1a22b3".matches(|b: u8| b.is_ascii_digit()).rev().collect();
+    assert_eq!(v, [b"3", b"2", b"2", b"1"]);
+    let v: Vec<&[u8]> = b"aaaaa".rmatches(b"aa").collect();
+    assert_eq!(v, [b"aa", b"aa"]);
+}
+
+#[test]
+fn test_byte_pattern_trim() {
+    assert_eq!(b"11foo1bar11".trim_matches(b'1'), b"foo1bar");
+    assert_eq!(b"11foo1bar11".trim_start_matches(b'1'), b"foo1bar11");
+    assert_eq!(b"11foo1bar11".trim_end_matches(b'1'), b"11foo1bar");
+    assert_eq!(b"\t key \r\n".trim_matches(|b: u8| b.is_ascii_whitespace()), b"key");
+    assert_eq!(b"ababc".trim_start_matches(b"ab"), b"c");
+    assert_eq!(b"abab".trim_end_matches(&b"ab"[..]), b"");
+    assert_eq!(b"1111".trim_matches(b'1'), b"");
+}
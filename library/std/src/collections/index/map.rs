@@ -0,0 +1,1598 @@
+#[cfg(test)]
+mod tests;
+
+use self::Entry::*;
+
+use hashbrown::raw::RawTable;
+
+use crate::borrow::Borrow;
+use crate::collections::hash_map::RandomState;
+use crate::fmt::{self, Debug};
+use crate::hash::{BuildHasher, Hash, Hasher};
+use crate::iter::{FromIterator, FusedIterator};
+use crate::mem;
+use crate::ops::Index;
+use crate::slice;
+use crate::vec;
+
+/// A hash map that remembers the order in which its keys were inserted.
+///
+/// `IndexMap` stores its entries densely in a vector, in insertion order, and
+/// keeps a hash table of indices into that vector; the table is the same
+/// SwissTable implementation that backs [`HashMap`]. Iteration therefore
+/// visits the entries in the order they were inserted (or in the order
+/// established by one of the sorting methods), independently of the hash
+/// function, and every entry can also be accessed by its position with
+/// [`get_index`].
+///
+/// Inserting a key that is already present replaces its value but keeps its
+/// position. Because the entries are stored contiguously, removing an entry
+/// has to fill its slot, and there are two ways to do so:
+///
+/// * [`swap_remove`] moves the last entry into the hole. This takes constant
+///   time, but perturbs the order of the map.
+/// * [`shift_remove`] shifts all following entries down by one. This keeps
+///   the order of the remaining entries, but takes linear time.
+///
+/// Like [`HashMap`], an `IndexMap` requires its keys to implement [`Eq`] and
+/// [`Hash`], uses a randomly seeded, HashDoS-resistant hasher by default, and
+/// accepts any other [`BuildHasher`]. The same rules about keys apply: if two
+/// keys are equal, their hashes must be equal, and it is a logic error for a
+/// key to be modified in such a way that its hash or its equality changes
+/// while it is in the map. The behavior resulting from such a logic error is
+/// not specified, but will not result in undefined behavior.
+///
+/// Two maps compare equal if they contain the same key-value pairs, whatever
+/// their order.
+///
+/// [`HashMap`]: crate::collections::HashMap
+/// [`get_index`]: IndexMap::get_index
+/// [`swap_remove`]: IndexMap::swap_remove
+/// [`shift_remove`]: IndexMap::shift_remove
+///
+/// # Examples
+///
+/// ```
+/// #![feature(index_map)]
+/// use std::collections::IndexMap;
+///
+/// let mut settings = IndexMap::new();
+/// settings.insert("name", "demo");
+/// settings.insert("version", "1.0");
+/// settings.insert("edition", "2018");
+///
+/// // Replacing a value keeps the key where it was.
+/// settings.insert("version", "1.1");
+///
+/// let keys: Vec<_> = settings.keys().copied().collect();
+/// assert_eq!(keys, ["name", "version", "edition"]);
+/// assert_eq!(settings.get_index(1), Some((&"version", &"1.1")));
+///
+/// // Removing with `shift_remove` preserves the order of the others.
+/// settings.shift_remove("name");
+/// let keys: Vec<_> = settings.keys().copied().collect();
+/// assert_eq!(keys, ["version", "edition"]);
+/// ```
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IndexMap<K, V, S = RandomState> {
+    hash_builder: S,
+    core: IndexMapCore<K, V>,
+}
+
+/// The entries of an `IndexMap`, together with the table that indexes them.
+///
+/// The table stores positions in `entries`, hashed with the hash of the key
+/// found at that position, which is cached in the entry. None of the
+/// operations here need to hash a key, so they do not depend on the
+/// `BuildHasher`.
+struct IndexMapCore<K, V> {
+    indices: RawTable<usize>,
+    entries: Vec<Bucket<K, V>>,
+}
+
+#[derive(Clone)]
+struct Bucket<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+impl<K, V> Bucket<K, V> {
+    fn refs(&self) -> (&K, &V) {
+        (&self.key, &self.value)
+    }
+
+    fn muts(&mut self) -> (&K, &mut V) {
+        (&self.key, &mut self.value)
+    }
+
+    fn key_value(self) -> (K, V) {
+        (self.key, self.value)
+    }
+}
+
+impl<K, V> IndexMapCore<K, V> {
+    fn with_capacity(capacity: usize) -> IndexMapCore<K, V> {
+        IndexMapCore {
+            indices: RawTable::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn find(&self, hash: u64, mut eq: impl FnMut(&K) -> bool) -> Option<usize> {
+        let entries = &*self.entries;
+        self.indices.get(hash, |&i| eq(&entries[i].key)).copied()
+    }
+
+    fn push(&mut self, hash: u64, key: K, value: V) -> usize {
+        let index = self.entries.len();
+        let entries = &*self.entries;
+        self.indices.insert(hash, index, |&i| entries[i].hash);
+        self.entries.push(Bucket { hash, key, value });
+        index
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let entries = &*self.entries;
+        self.indices.reserve(additional, |&i| entries[i].hash);
+        self.entries.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        let entries = &*self.entries;
+        self.indices.shrink_to(0, |&i| entries[i].hash);
+        self.entries.shrink_to_fit();
+    }
+
+    fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+    }
+
+    /// Changes the position recorded in the table for the entry with the
+    /// given hash from `old` to `new`.
+    fn update_index(&mut self, hash: u64, old: usize, new: usize) {
+        let index = self.indices.get_mut(hash, |&i| i == old).expect("index not found");
+        *index = new;
+    }
+
+    fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.indices.erase_entry(hash, |&i| i == index);
+
+        // The last entry is about to be moved into the hole.
+        let last = self.entries.len() - 1;
+        if index != last {
+            self.update_index(self.entries[last].hash, last, index);
+        }
+        Some(self.entries.swap_remove(index).key_value())
+    }
+
+    fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.indices.erase_entry(hash, |&i| i == index);
+
+        for i in index + 1..self.entries.len() {
+            self.update_index(self.entries[i].hash, i, i - 1);
+        }
+        Some(self.entries.remove(index).key_value())
+    }
+
+    /// Repopulates the table after the entries have been reordered or
+    /// removed in bulk.
+    fn rebuild(&mut self) {
+        self.indices.clear();
+        let entries = &*self.entries;
+        for (index, entry) in entries.iter().enumerate() {
+            self.indices.insert(entry.hash, index, |&i| entries[i].hash);
+        }
+    }
+
+    fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        // The rejected entries are gathered right after the kept ones. If
+        // `keep` panics, the guard moves them behind the entries that were not
+        // visited yet before dropping them, and fixes up the table.
+        struct RemoveOnDrop<'a, K, V> {
+            core: &'a mut IndexMapCore<K, V>,
+            processed: usize,
+            removed: usize,
+        }
+
+        impl<K, V> Drop for RemoveOnDrop<'_, K, V> {
+            fn drop(&mut self) {
+                if self.removed > 0 {
+                    let entries = &mut self.core.entries;
+                    let len = entries.len();
+                    entries[self.processed - self.removed..].rotate_left(self.removed);
+                    entries.truncate(len - self.removed);
+                    self.core.rebuild();
+                }
+            }
+        }
+
+        let len = self.entries.len();
+        let mut g = RemoveOnDrop { core: self, processed: 0, removed: 0 };
+        for i in 0..len {
+            let entry = &mut g.core.entries[i];
+            if !keep(&entry.key, &mut entry.value) {
+                g.removed += 1;
+            } else if g.removed > 0 {
+                g.core.entries.swap(i - g.removed, i);
+            }
+            g.processed += 1;
+        }
+    }
+
+    /// Sorts the entries, rebuilding the table afterwards even if `compare`
+    /// panics.
+    fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&Bucket<K, V>, &Bucket<K, V>) -> crate::cmp::Ordering,
+    {
+        struct RebuildOnDrop<'a, K, V>(&'a mut IndexMapCore<K, V>);
+
+        impl<K, V> Drop for RebuildOnDrop<'_, K, V> {
+            fn drop(&mut self) {
+                self.0.rebuild();
+            }
+        }
+
+        let g = RebuildOnDrop(self);
+        g.0.entries.sort_by(|a, b| compare(a, b));
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for IndexMapCore<K, V> {
+    fn clone(&self) -> Self {
+        IndexMapCore { indices: self.indices.clone(), entries: self.entries.clone() }
+    }
+}
+
+impl<K, V> IndexMap<K, V, RandomState> {
+    /// Creates an empty `IndexMap`.
+    ///
+    /// The map is initially created with a capacity of 0, so it will not
+    /// allocate until it is first inserted into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// let mut map: IndexMap<&str, i32> = IndexMap::new();
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn new() -> IndexMap<K, V, RandomState> {
+        Default::default()
+    }
+
+    /// Creates an empty `IndexMap` with the specified capacity.
+    ///
+    /// The map will be able to hold at least `capacity` elements without
+    /// reallocating. If `capacity` is 0, the map will not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// let mut map: IndexMap<&str, i32> = IndexMap::with_capacity(10);
+    /// assert!(map.capacity() >= 10);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> IndexMap<K, V, RandomState> {
+        IndexMap::with_capacity_and_hasher(capacity, Default::default())
+    }
+}
+
+impl<K, V, S> IndexMap<K, V, S> {
+    /// Creates an empty `IndexMap` which will use the given hash builder to
+    /// hash keys.
+    ///
+    /// The created map has the default initial capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let s = RandomState::new();
+    /// let mut map = IndexMap::with_hasher(s);
+    /// map.insert(1, 2);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_hasher(hash_builder: S) -> IndexMap<K, V, S> {
+        IndexMap::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Creates an empty `IndexMap` with the specified capacity, using
+    /// `hash_builder` to hash the keys.
+    ///
+    /// The map will be able to hold at least `capacity` elements without
+    /// reallocating. If `capacity` is 0, the map will not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let s = RandomState::new();
+    /// let mut map = IndexMap::with_capacity_and_hasher(10, s);
+    /// map.insert(1, 2);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> IndexMap<K, V, S> {
+        IndexMap { hash_builder, core: IndexMapCore::with_capacity(capacity) }
+    }
+
+    /// Returns the number of elements the map can hold without reallocating.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.core.indices.capacity().min(self.core.entries.capacity())
+    }
+
+    /// Returns a reference to the map's [`BuildHasher`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut a = IndexMap::new();
+    /// assert_eq!(a.len(), 0);
+    /// a.insert(1, "a");
+    /// assert_eq!(a.len(), 1);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.core.entries.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted in the `IndexMap`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows [`usize`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.core.reserve(additional)
+    }
+
+    /// Shrinks the capacity of the map as much as possible.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to_fit(&mut self) {
+        self.core.shrink_to_fit()
+    }
+
+    /// An iterator visiting all keys in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert("c", 3);
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    ///
+    /// let keys: Vec<_> = map.keys().collect();
+    /// assert_eq!(keys, [&"c", &"a", &"b"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.core.entries.iter() }
+    }
+
+    /// An iterator visiting all values in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.core.entries.iter() }
+    }
+
+    /// An iterator visiting all values mutably in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { iter: self.core.entries.iter_mut() }
+    }
+
+    /// An iterator visiting all key-value pairs in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert("b", 2);
+    /// map.insert("a", 1);
+    ///
+    /// let pairs: Vec<_> = map.iter().collect();
+    /// assert_eq!(pairs, [(&"b", &2), (&"a", &1)]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { iter: self.core.entries.iter() }
+    }
+
+    /// An iterator visiting all key-value pairs in order, with mutable
+    /// references to the values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { iter: self.core.entries.iter_mut() }
+    }
+
+    /// Clears the map, returning all key-value pairs in order as an iterator.
+    /// Keeps the allocated memory for reuse.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        self.core.indices.clear();
+        Drain { iter: self.core.entries.drain(..) }
+    }
+
+    /// Clears the map, removing all key-value pairs. Keeps the allocated
+    /// memory for reuse.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn clear(&mut self) {
+        self.core.clear();
+    }
+
+    /// Returns the key-value pair at position `index`, or [`None`] if `index`
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    /// assert_eq!(map.get_index(1), Some((&"b", &2)));
+    /// assert_eq!(map.get_index(2), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.core.entries.get(index).map(Bucket::refs)
+    }
+
+    /// Returns the key and a mutable reference to the value at position
+    /// `index`, or [`None`] if `index` is out of bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.core.entries.get_mut(index).map(Bucket::muts)
+    }
+
+    /// Returns the first key-value pair, or [`None`] if the map is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.core.entries.first().map(Bucket::refs)
+    }
+
+    /// Returns the last key-value pair, or [`None`] if the map is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.core.entries.last().map(Bucket::refs)
+    }
+
+    /// Removes the key-value pair at position `index` by swapping it with the
+    /// last element of the map, and returns it. Returns [`None`] if `index`
+    /// is out of bounds.
+    ///
+    /// This perturbs the order of the map, but takes constant time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        self.core.swap_remove_index(index)
+    }
+
+    /// Removes the key-value pair at position `index` by shifting all of the
+    /// elements that follow it, and returns it. Returns [`None`] if `index`
+    /// is out of bounds.
+    ///
+    /// This preserves the order of the remaining elements, but takes linear
+    /// time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        self.core.shift_remove_index(index)
+    }
+
+    /// Removes the last key-value pair and returns it, or [`None`] if the map
+    /// is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let last = self.len().checked_sub(1)?;
+        self.core.swap_remove_index(last)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)`
+    /// returns `false`. The remaining elements keep their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map: IndexMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    /// map.retain(|&k, _| k % 2 == 0);
+    /// let keys: Vec<_> = map.keys().copied().collect();
+    /// assert_eq!(keys, [0, 2, 4, 6]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.core.retain(f)
+    }
+
+    /// Sorts the map's key-value pairs with a comparator function.
+    ///
+    /// The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert("a", 3);
+    /// map.insert("b", 1);
+    /// map.insert("c", 2);
+    ///
+    /// map.sort_by(|_, v1, _, v2| v1.cmp(v2));
+    /// let keys: Vec<_> = map.keys().copied().collect();
+    /// assert_eq!(keys, ["b", "c", "a"]);
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> crate::cmp::Ordering,
+    {
+        self.core.sort_by(|a, b| compare(&a.key, &a.value, &b.key, &b.value));
+    }
+
+    /// Sorts the map's key-value pairs by key.
+    ///
+    /// The sort is stable.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.sort_by(|k1, _, k2, _| k1.cmp(k2));
+    }
+
+    /// Reverses the order of the map's key-value pairs.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reverse(&mut self) {
+        self.core.entries.reverse();
+        self.core.rebuild();
+    }
+}
+
+impl<K, V, S> IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u64 {
+        let mut state = self.hash_builder.build_hasher();
+        key.hash(&mut state);
+        state.finish()
+    }
+
+    fn find<Q: ?Sized>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        self.core.find(self.hash(key), |k| key == k.borrow())
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut letters = IndexMap::new();
+    ///
+    /// for ch in "a short treatise on fungi".chars() {
+    ///     let counter = letters.entry(ch).or_insert(0);
+    ///     *counter += 1;
+    /// }
+    ///
+    /// assert_eq!(letters[&'s'], 2);
+    /// assert_eq!(letters[&'t'], 3);
+    /// assert_eq!(letters.get_index(0), Some((&'a', &2)));
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let hash = self.hash(&key);
+        match self.core.find(hash, |k| *k == key) {
+            Some(index) => Occupied(OccupiedEntry { core: &mut self.core, index }),
+            None => Vacant(VacantEntry { core: &mut self.core, hash, key }),
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key).map(|i| &self.core.entries[i].value)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_key_value<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key).map(|i| self.core.entries[i].refs())
+    }
+
+    /// Returns the position of the key in the map together with the stored
+    /// key and its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    /// assert_eq!(map.get_full("b"), Some((1, &"b", &2)));
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_full<Q: ?Sized>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key).map(|i| {
+            let (key, value) = self.core.entries[i].refs();
+            (i, key, value)
+        })
+    }
+
+    /// Returns the position of the key in the map, or [`None`] if it is not
+    /// present.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_of<Q: ?Sized>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert(1, "a");
+    /// if let Some(x) = map.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.find(key)?;
+        Some(&mut self.core.entries[index].value)
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, the pair is appended at the
+    /// end of the map and [`None`] is returned.
+    ///
+    /// If the map did have this key present, the value is updated, and the
+    /// old value is returned. The entry keeps its position, and the key is
+    /// not updated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// assert_eq!(map.insert(37, "a"), None);
+    /// assert_eq!(map.is_empty(), false);
+    ///
+    /// map.insert(37, "b");
+    /// assert_eq!(map.insert(37, "c"), Some("b"));
+    /// assert_eq!(map[&37], "c");
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Inserts a key-value pair into the map, and returns the position of
+    /// the entry along with the old value, if any.
+    ///
+    /// See [`insert`] for details.
+    ///
+    /// [`insert`]: IndexMap::insert
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        match self.entry(key) {
+            Occupied(mut entry) => (entry.index(), Some(entry.insert(value))),
+            Vacant(entry) => {
+                let index = entry.index();
+                entry.insert(value);
+                (index, None)
+            }
+        }
+    }
+
+    /// Removes a key from the map by swapping its entry with the last one,
+    /// returning the value at the key if the key was previously in the map.
+    ///
+    /// This perturbs the order of the map, but takes constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map: IndexMap<_, _> = (0..4).map(|x| (x, ())).collect();
+    /// assert_eq!(map.swap_remove(&1), Some(()));
+    /// let keys: Vec<_> = map.keys().copied().collect();
+    /// assert_eq!(keys, [0, 3, 2]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.swap_remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Like [`swap_remove`], but returns the stored key along with the value.
+    ///
+    /// [`swap_remove`]: IndexMap::swap_remove
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_entry<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.find(key)?;
+        self.core.swap_remove_index(index)
+    }
+
+    /// Removes a key from the map by shifting all of the entries that follow
+    /// it, returning the value at the key if the key was previously in the
+    /// map.
+    ///
+    /// This preserves the order of the remaining entries, but takes linear
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map: IndexMap<_, _> = (0..4).map(|x| (x, ())).collect();
+    /// assert_eq!(map.shift_remove(&1), Some(()));
+    /// let keys: Vec<_> = map.keys().copied().collect();
+    /// assert_eq!(keys, [0, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.shift_remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Like [`shift_remove`], but returns the stored key along with the
+    /// value.
+    ///
+    /// [`shift_remove`]: IndexMap::shift_remove
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_entry<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.find(key)?;
+        self.core.shift_remove_index(index)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Clone for IndexMap<K, V, S>
+where
+    K: Clone,
+    V: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        IndexMap { hash_builder: self.hash_builder.clone(), core: self.core.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> PartialEq for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &IndexMap<K, V, S>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        self.iter().all(|(key, value)| other.get(key).map_or(false, |v| *value == *v))
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Eq for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Debug for IndexMap<K, V, S>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Default for IndexMap<K, V, S>
+where
+    S: Default,
+{
+    /// Creates an empty `IndexMap<K, V, S>`, with the `Default` value for the hasher.
+    #[inline]
+    fn default() -> IndexMap<K, V, S> {
+        IndexMap::with_hasher(Default::default())
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, Q: ?Sized, V, S> Index<&Q> for IndexMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the `IndexMap`.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> FromIterator<(K, V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> IndexMap<K, V, S> {
+        let mut map = IndexMap::with_hasher(Default::default());
+        map.extend(iter);
+        map
+    }
+}
+
+/// Inserts all new key-values from the iterator and replaces values with
+/// existing keys with new values returned from the iterator.
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Extend<(K, V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        // Keys may be already present or show multiple times in the iterator.
+        // Reserve the entire hint lower bound if the map is empty.
+        // Otherwise reserve half the hint (rounded up), so the map
+        // will only resize twice in the worst case.
+        let iter = iter.into_iter();
+        let reserve =
+            if self.is_empty() { iter.size_hint().0 } else { (iter.size_hint().0 + 1) / 2 };
+        self.reserve(reserve);
+        iter.for_each(move |(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> Extend<(&'a K, &'a V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash + Copy,
+    V: Copy,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> IntoIterator for &'a IndexMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> IntoIterator for &'a mut IndexMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> IntoIterator for IndexMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// Creates a consuming iterator, that is, one that moves each key-value
+    /// pair out of the map in order. The map cannot be used after calling
+    /// this.
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter { iter: self.core.entries.into_iter() }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+///
+/// This `enum` is constructed from the [`entry`] method on [`IndexMap`].
+///
+/// [`entry`]: IndexMap::entry
+#[unstable(feature = "index_map", issue = "none")]
+pub enum Entry<'a, K: 'a, V: 'a> {
+    /// An occupied entry.
+    #[unstable(feature = "index_map", issue = "none")]
+    Occupied(#[unstable(feature = "index_map", issue = "none")] OccupiedEntry<'a, K, V>),
+
+    /// A vacant entry.
+    #[unstable(feature = "index_map", issue = "none")]
+    Vacant(#[unstable(feature = "index_map", issue = "none")] VacantEntry<'a, K, V>),
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Entry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Vacant(ref v) => f.debug_tuple("Entry").field(v).finish(),
+            Occupied(ref o) => f.debug_tuple("Entry").field(o).finish(),
+        }
+    }
+}
+
+/// A view into an occupied entry in an `IndexMap`.
+/// It is part of the [`Entry`] enum.
+#[unstable(feature = "index_map", issue = "none")]
+pub struct OccupiedEntry<'a, K: 'a, V: 'a> {
+    core: &'a mut IndexMapCore<K, V>,
+    index: usize,
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for OccupiedEntry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+/// A view into a vacant entry in an `IndexMap`.
+/// It is part of the [`Entry`] enum.
+#[unstable(feature = "index_map", issue = "none")]
+pub struct VacantEntry<'a, K: 'a, V: 'a> {
+    core: &'a mut IndexMapCore<K, V>,
+    hash: u64,
+    key: K,
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for VacantEntry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Ensures a value is in the entry by inserting the default if empty, and
+    /// returns a mutable reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default
+    /// function if empty, and returns a mutable reference to the value in the
+    /// entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting, if empty, the result of
+    /// the default function, which is given a reference to the key. Returns a
+    /// mutable reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        match *self {
+            Occupied(ref entry) => entry.key(),
+            Vacant(ref entry) => entry.key(),
+        }
+    }
+
+    /// Returns the position of this entry in the map. For a vacant entry,
+    /// this is the position it will be inserted at.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        match *self {
+            Occupied(ref entry) => entry.index(),
+            Vacant(ref entry) => entry.index(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map: IndexMap<&str, u32> = IndexMap::new();
+    ///
+    /// map.entry("poneyland").and_modify(|e| *e += 1).or_insert(42);
+    /// assert_eq!(map["poneyland"], 42);
+    ///
+    /// map.entry("poneyland").and_modify(|e| *e += 1).or_insert(42);
+    /// assert_eq!(map["poneyland"], 43);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Occupied(mut entry) => {
+                f(entry.get_mut());
+                Occupied(entry)
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V: Default> Entry<'a, K, V> {
+    /// Ensures a value is in the entry by inserting the default value if
+    /// empty, and returns a mutable reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_default(self) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(Default::default()),
+        }
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Gets a reference to the key in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        &self.core.entries[self.index].key
+    }
+
+    /// Returns the position of the entry in the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets a reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get(&self) -> &V {
+        &self.core.entries[self.index].value
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    ///
+    /// If you need a reference to the `OccupiedEntry` which may outlive the
+    /// destruction of the `Entry` value, see [`into_mut`].
+    ///
+    /// [`into_mut`]: Self::into_mut
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.core.entries[self.index].value
+    }
+
+    /// Converts the `OccupiedEntry` into a mutable reference to the value in
+    /// the entry with a lifetime bound to the map itself.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.core.entries[self.index].value
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map by swapping it with the last entry, and
+    /// returns the value.
+    ///
+    /// This perturbs the order of the map, but takes constant time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove(self) -> V {
+        self.swap_remove_entry().1
+    }
+
+    /// Like [`swap_remove`], but returns the stored key along with the value.
+    ///
+    /// [`swap_remove`]: Self::swap_remove
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.core.swap_remove_index(self.index).unwrap()
+    }
+
+    /// Removes the entry from the map by shifting all of the entries that
+    /// follow it, and returns the value.
+    ///
+    /// This preserves the order of the remaining entries, but takes linear
+    /// time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove(self) -> V {
+        self.shift_remove_entry().1
+    }
+
+    /// Like [`shift_remove`], but returns the stored key along with the value.
+    ///
+    /// [`shift_remove`]: Self::shift_remove
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_entry(self) -> (K, V) {
+        self.core.shift_remove_index(self.index).unwrap()
+    }
+}
+
+impl<'a, K: 'a, V: 'a> VacantEntry<'a, K, V> {
+    /// Gets a reference to the key that would be used when inserting a value
+    /// through the `VacantEntry`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take ownership of the key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Returns the position the entry will be inserted at, which is the end
+    /// of the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        self.core.entries.len()
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key, appending it
+    /// to the map, and returns a mutable reference to it.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.core.push(self.hash, self.key, value);
+        &mut self.core.entries[index].value
+    }
+}
+
+/// Implements the iterator traits for a wrapper around a slice or vector
+/// iterator over buckets, given how to turn a bucket into an item.
+macro_rules! bucket_iterator {
+    ($name:ident<$($lt:lifetime,)? $($param:ident),*>, $item:ty, $map:expr) => {
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? $($param),*> Iterator for $name<$($lt,)? $($param),*> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map($map)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.iter.nth(n).map($map)
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? $($param),*> DoubleEndedIterator for $name<$($lt,)? $($param),*> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back().map($map)
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? $($param),*> ExactSizeIterator for $name<$($lt,)? $($param),*> {
+            #[inline]
+            fn len(&self) -> usize {
+                self.iter.len()
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? $($param),*> FusedIterator for $name<$($lt,)? $($param),*> {}
+    };
+}
+
+/// An iterator over the entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`iter`] method on [`IndexMap`]. See its
+/// documentation for more.
+///
+/// [`iter`]: IndexMap::iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Iter<'a, K: 'a, V: 'a> {
+    iter: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Iter<'a, K, V>, (&'a K, &'a V), Bucket::refs);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Iter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`iter_mut`] method on [`IndexMap`]. See
+/// its documentation for more.
+///
+/// [`iter_mut`]: IndexMap::iter_mut
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IterMut<'a, K: 'a, V: 'a> {
+    iter: slice::IterMut<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(IterMut<'a, K, V>, (&'a K, &'a mut V), Bucket::muts);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Debug for IterMut<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("IterMut { .. }")
+    }
+}
+
+/// An owning iterator over the entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`into_iter`] method on [`IndexMap`]
+/// (provided by the `IntoIterator` trait). See its documentation for more.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IntoIter<K, V> {
+    iter: vec::IntoIter<Bucket<K, V>>,
+}
+
+bucket_iterator!(IntoIter<K, V>, (K, V), Bucket::key_value);
+
+impl<K, V> IntoIter<K, V> {
+    /// Returns an iterator over the keys that remain, for `IndexSet`'s
+    /// `Debug` implementation.
+    pub(super) fn remaining_keys(&self) -> impl Iterator<Item = &K> {
+        self.iter.as_slice().iter().map(|bucket| &bucket.key)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for IntoIter<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(Bucket::refs)).finish()
+    }
+}
+
+/// A draining iterator over the entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`drain`] method on [`IndexMap`]. See its
+/// documentation for more.
+///
+/// [`drain`]: IndexMap::drain
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Drain<'a, K: 'a, V: 'a> {
+    iter: vec::Drain<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Drain<'a, K, V>, (K, V), Bucket::key_value);
+
+impl<K, V> Drain<'_, K, V> {
+    /// Returns an iterator over the keys that remain, for `IndexSet`'s
+    /// `Debug` implementation.
+    pub(super) fn remaining_keys(&self) -> impl Iterator<Item = &K> {
+        self.iter.as_slice().iter().map(|bucket| &bucket.key)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Drain<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(Bucket::refs)).finish()
+    }
+}
+
+/// An iterator over the keys of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`keys`] method on [`IndexMap`]. See its
+/// documentation for more.
+///
+/// [`keys`]: IndexMap::keys
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Keys<'a, K: 'a, V: 'a> {
+    iter: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Keys<'a, K, V>, &'a K, |bucket| &bucket.key);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Keys { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for Keys<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An iterator over the values of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`values`] method on [`IndexMap`]. See its
+/// documentation for more.
+///
+/// [`values`]: IndexMap::values
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Values<'a, K: 'a, V: 'a> {
+    iter: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Values<'a, K, V>, &'a V, |bucket| &bucket.value);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Values { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V: Debug> Debug for Values<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the values of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`values_mut`] method on [`IndexMap`]. See
+/// its documentation for more.
+///
+/// [`values_mut`]: IndexMap::values_mut
+#[unstable(feature = "index_map", issue = "none")]
+pub struct ValuesMut<'a, K: 'a, V: 'a> {
+    iter: slice::IterMut<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(ValuesMut<'a, K, V>, &'a mut V, |bucket| &mut bucket.value);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Debug for ValuesMut<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("ValuesMut { .. }")
+    }
+}
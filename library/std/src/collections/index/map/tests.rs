@@ -0,0 +1,417 @@
+use super::Entry::{Occupied, Vacant};
+use super::IndexMap;
+use crate::cell::RefCell;
+use crate::collections::hash_map::RandomState;
+use crate::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Checks that every entry can be found through the table at its position.
+fn check_consistency<K, V>(map: &IndexMap<K, V>)
+where
+    K: Eq + crate::hash::Hash,
+{
+    assert_eq!(map.core.indices.len(), map.core.entries.len());
+    for (i, (key, _)) in map.iter().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(i));
+    }
+}
+
+fn keys<K: Copy, V>(map: &IndexMap<K, V>) -> Vec<K> {
+    map.keys().copied().collect()
+}
+
+#[test]
+fn test_insert_keeps_order() {
+    let mut m = IndexMap::new();
+    for &k in &[5, 3, 9, 1, 7] {
+        assert_eq!(m.insert(k, k * 10), None);
+    }
+    assert_eq!(keys(&m), [5, 3, 9, 1, 7]);
+
+    // Reinserting an existing key keeps its position.
+    assert_eq!(m.insert(9, 0), Some(90));
+    assert_eq!(m.insert_full(3, 1), (1, Some(30)));
+    assert_eq!(m.insert_full(4, 40), (5, None));
+    assert_eq!(keys(&m), [5, 3, 9, 1, 7, 4]);
+    assert_eq!(m.values().copied().collect::<Vec<_>>(), [50, 1, 0, 10, 70, 40]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_get() {
+    let mut m = IndexMap::new();
+    assert_eq!(m.get("a"), None);
+    m.insert("a".to_string(), 1);
+    m.insert("b".to_string(), 2);
+
+    assert_eq!(m.get("a"), Some(&1));
+    assert_eq!(m["b"], 2);
+    assert_eq!(m.get_key_value("b"), Some((&"b".to_string(), &2)));
+    assert_eq!(m.get_full("b"), Some((1, &"b".to_string(), &2)));
+    assert_eq!(m.get_index_of("c"), None);
+    assert!(m.contains_key("a"));
+    assert!(!m.contains_key("c"));
+
+    *m.get_mut("a").unwrap() += 10;
+    assert_eq!(m["a"], 11);
+}
+
+#[test]
+#[should_panic]
+fn test_index_nonexistent() {
+    let mut m = IndexMap::new();
+    m.insert(1, 2);
+    m[&4];
+}
+
+#[test]
+fn test_get_index() {
+    let mut m = IndexMap::new();
+    assert_eq!(m.first(), None);
+    assert_eq!(m.last(), None);
+    m.insert('a', 1);
+    m.insert('b', 2);
+    m.insert('c', 3);
+
+    assert_eq!(m.get_index(0), Some((&'a', &1)));
+    assert_eq!(m.get_index(2), Some((&'c', &3)));
+    assert_eq!(m.get_index(3), None);
+    assert_eq!(m.first(), Some((&'a', &1)));
+    assert_eq!(m.last(), Some((&'c', &3)));
+
+    *m.get_index_mut(1).unwrap().1 = 20;
+    assert_eq!(m[&'b'], 20);
+    assert!(m.get_index_mut(3).is_none());
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut m: IndexMap<_, _> = (0..6).map(|i| (i, i * 10)).collect();
+
+    assert_eq!(m.swap_remove(&1), Some(10));
+    assert_eq!(keys(&m), [0, 5, 2, 3, 4]);
+    check_consistency(&m);
+
+    // Removing the last entry moves nothing.
+    assert_eq!(m.swap_remove_entry(&4), Some((4, 40)));
+    assert_eq!(keys(&m), [0, 5, 2, 3]);
+    check_consistency(&m);
+
+    assert_eq!(m.swap_remove(&1), None);
+    assert_eq!(m.swap_remove_index(0), Some((0, 0)));
+    assert_eq!(m.swap_remove_index(3), None);
+    assert_eq!(keys(&m), [3, 5, 2]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_shift_remove() {
+    let mut m: IndexMap<_, _> = (0..6).map(|i| (i, i * 10)).collect();
+
+    assert_eq!(m.shift_remove(&1), Some(10));
+    assert_eq!(keys(&m), [0, 2, 3, 4, 5]);
+    check_consistency(&m);
+
+    assert_eq!(m.shift_remove_entry(&5), Some((5, 50)));
+    assert_eq!(m.shift_remove(&5), None);
+    assert_eq!(m.shift_remove_index(0), Some((0, 0)));
+    assert_eq!(m.shift_remove_index(3), None);
+    assert_eq!(keys(&m), [2, 3, 4]);
+    check_consistency(&m);
+
+    assert_eq!(m.pop(), Some((4, 40)));
+    assert_eq!(keys(&m), [2, 3]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_remove_all_then_reuse() {
+    let mut m = IndexMap::new();
+    for i in 0..100 {
+        m.insert(i, ());
+    }
+    for i in (0..100).step_by(2) {
+        assert_eq!(m.shift_remove(&i), Some(()));
+    }
+    for i in (1..100).step_by(2) {
+        assert_eq!(m.swap_remove(&i), Some(()));
+    }
+    assert!(m.is_empty());
+    assert_eq!(m.pop(), None);
+
+    m.insert(7, ());
+    assert_eq!(keys(&m), [7]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_entry() {
+    let mut m: IndexMap<_, _> = vec![(1, 10), (2, 20), (3, 30)].into_iter().collect();
+
+    match m.entry(2) {
+        Vacant(_) => unreachable!(),
+        Occupied(mut e) => {
+            assert_eq!(e.key(), &2);
+            assert_eq!(e.index(), 1);
+            assert_eq!(e.insert(200), 20);
+            assert_eq!(*e.get(), 200);
+            *e.get_mut() += 1;
+        }
+    }
+    assert_eq!(m[&2], 201);
+
+    match m.entry(4) {
+        Occupied(_) => unreachable!(),
+        Vacant(e) => {
+            assert_eq!(e.key(), &4);
+            assert_eq!(e.index(), 3);
+            assert_eq!(*e.insert(40), 40);
+        }
+    }
+    assert_eq!(keys(&m), [1, 2, 3, 4]);
+
+    match m.entry(1) {
+        Vacant(_) => unreachable!(),
+        Occupied(e) => assert_eq!(e.swap_remove_entry(), (1, 10)),
+    }
+    assert_eq!(keys(&m), [4, 2, 3]);
+
+    match m.entry(2) {
+        Vacant(_) => unreachable!(),
+        Occupied(e) => assert_eq!(e.shift_remove(), 201),
+    }
+    assert_eq!(keys(&m), [4, 3]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_entry_or_insert() {
+    let mut m = IndexMap::new();
+    for ch in "hello world".chars() {
+        *m.entry(ch).or_insert(0) += 1;
+    }
+    assert_eq!(keys(&m), ['h', 'e', 'l', 'o', ' ', 'w', 'r', 'd']);
+    assert_eq!(m[&'l'], 3);
+
+    let mut m: IndexMap<&str, Vec<i32>> = IndexMap::new();
+    m.entry("a").or_default().push(1);
+    m.entry("a").or_insert_with(Vec::new).push(2);
+    m.entry("bb").or_insert_with_key(|k| vec![k.len() as i32]);
+    m.entry("a").and_modify(|v| v.push(3)).or_default();
+    assert_eq!(m["a"], [1, 2, 3]);
+    assert_eq!(m["bb"], [2]);
+    assert_eq!(m.entry("c").index(), 2);
+    assert_eq!(m.entry("c").key(), &"c");
+}
+
+#[test]
+fn test_retain() {
+    let mut m: IndexMap<i32, i32> = (0..10).map(|x| (x, x * 10)).collect();
+    m.retain(|&k, v| {
+        *v += 1;
+        k % 3 != 0
+    });
+    assert_eq!(keys(&m), [1, 2, 4, 5, 7, 8]);
+    assert_eq!(m[&4], 41);
+    check_consistency(&m);
+
+    m.retain(|_, _| true);
+    assert_eq!(m.len(), 6);
+    m.retain(|_, _| false);
+    assert!(m.is_empty());
+    check_consistency(&m);
+}
+
+#[test]
+fn test_sort() {
+    let mut m = IndexMap::new();
+    m.insert("d", 1);
+    m.insert("b", 3);
+    m.insert("a", 2);
+    m.insert("c", 3);
+
+    m.sort_keys();
+    assert_eq!(keys(&m), ["a", "b", "c", "d"]);
+    check_consistency(&m);
+
+    // The sort is stable: "b" and "c" keep their relative order.
+    m.sort_by(|_, v1, _, v2| v2.cmp(v1));
+    assert_eq!(keys(&m), ["b", "c", "a", "d"]);
+    check_consistency(&m);
+
+    m.reverse();
+    assert_eq!(keys(&m), ["d", "a", "c", "b"]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_retain_panic() {
+    let mut m: IndexMap<i32, i32> = (0..10).map(|x| (x, x * 10)).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        m.retain(|&k, _| {
+            if k == 6 {
+                panic!("retain");
+            }
+            k % 2 == 0
+        })
+    }));
+    assert!(result.is_err());
+    // The entries rejected before the panic are removed, the rest are kept.
+    assert_eq!(keys(&m), [0, 2, 4, 6, 7, 8, 9]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_sort_panic() {
+    let mut m: IndexMap<i32, i32> = (0..20).rev().map(|x| (x, x)).collect();
+    let mut calls = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        m.sort_by(|k1, _, k2, _| {
+            calls += 1;
+            if calls == 10 {
+                panic!("sort_by");
+            }
+            k1.cmp(k2)
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(m.len(), 20);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_iterators() {
+    let mut m: IndexMap<_, _> = (0..5).map(|i| (i, i * 10)).collect();
+
+    let pairs: Vec<_> = m.iter().map(|(&k, &v)| (k, v)).collect();
+    assert_eq!(pairs, [(0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]);
+    assert_eq!(m.iter().rev().next(), Some((&4, &40)));
+    assert_eq!(m.iter().nth(2), Some((&2, &20)));
+    assert_eq!(m.keys().len(), 5);
+    assert_eq!(m.values().rev().copied().collect::<Vec<_>>(), [40, 30, 20, 10, 0]);
+
+    for (k, v) in m.iter_mut() {
+        *v += k;
+    }
+    for v in m.values_mut() {
+        *v += 1;
+    }
+    for (_, v) in &mut m {
+        *v *= 2;
+    }
+    assert_eq!(m.values().copied().collect::<Vec<_>>(), [2, 24, 46, 68, 90]);
+
+    let mut into_iter = m.clone().into_iter();
+    assert_eq!(into_iter.len(), 5);
+    assert_eq!(into_iter.next_back(), Some((4, 90)));
+    assert_eq!(into_iter.collect::<Vec<_>>(), [(0, 2), (1, 24), (2, 46), (3, 68)]);
+
+    let drained: Vec<_> = m.drain().collect();
+    assert_eq!(drained.len(), 5);
+    assert!(m.is_empty());
+    m.insert(1, 1);
+    assert_eq!(keys(&m), [1]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_eq() {
+    let mut m1 = IndexMap::new();
+    m1.insert(1, 2);
+    m1.insert(2, 3);
+    m1.insert(3, 4);
+
+    let mut m2 = IndexMap::new();
+    m2.insert(3, 4);
+    m2.insert(1, 2);
+    assert!(m1 != m2);
+
+    // Equality does not depend on the order.
+    m2.insert(2, 3);
+    assert_eq!(m1, m2);
+
+    m2.insert(2, 4);
+    assert!(m1 != m2);
+}
+
+#[test]
+fn test_debug() {
+    let mut map = IndexMap::new();
+    let empty: IndexMap<i32, i32> = IndexMap::new();
+
+    map.insert(3, 4);
+    map.insert(1, 2);
+
+    assert_eq!(format!("{:?}", map), "{3: 4, 1: 2}");
+    assert_eq!(format!("{:?}", empty), "{}");
+    assert_eq!(format!("{:?}", map.iter()), "[(3, 4), (1, 2)]");
+    assert_eq!(format!("{:?}", map.keys()), "[3, 1]");
+    assert_eq!(format!("{:?}", map.clone().into_iter()), "[(3, 4), (1, 2)]");
+}
+
+#[test]
+fn test_clone_and_capacity() {
+    let mut m = IndexMap::with_capacity_and_hasher(10, RandomState::new());
+    assert!(m.capacity() >= 10);
+    for i in 0..20 {
+        m.insert(i, i);
+    }
+    m.reserve(100);
+    assert!(m.capacity() >= 120);
+
+    let m2 = m.clone();
+    assert_eq!(keys(&m2), (0..20).collect::<Vec<_>>());
+    check_consistency(&m2);
+
+    m.retain(|&k, _| k < 3);
+    m.shrink_to_fit();
+    assert!(m.capacity() >= 3);
+    assert_eq!(keys(&m), [0, 1, 2]);
+    check_consistency(&m);
+}
+
+#[test]
+fn test_extend_ref() {
+    let mut a = IndexMap::new();
+    a.insert(1, "one");
+    let mut b = IndexMap::new();
+    b.insert(3, "three");
+    b.insert(2, "two");
+    b.insert(1, "uno");
+
+    a.extend(&b);
+
+    assert_eq!(a.len(), 3);
+    assert_eq!(keys(&a), [1, 3, 2]);
+    assert_eq!(a[&1], "uno");
+}
+
+#[test]
+fn test_drops() {
+    thread_local! {
+        static DROPS: RefCell<usize> = RefCell::new(0);
+    }
+
+    #[derive(Hash, PartialEq, Eq)]
+    struct Droppable(usize);
+
+    impl Drop for Droppable {
+        fn drop(&mut self) {
+            DROPS.with(|d| *d.borrow_mut() += 1);
+        }
+    }
+
+    {
+        let mut m = IndexMap::new();
+        for i in 0..10 {
+            m.insert(Droppable(i), Droppable(i + 100));
+        }
+        drop(m.swap_remove(&Droppable(0)));
+        drop(m.shift_remove(&Droppable(1)));
+        // Two removed pairs plus the two keys used for the lookups.
+        DROPS.with(|d| assert_eq!(*d.borrow(), 6));
+
+        m.retain(|k, _| k.0 % 2 == 0);
+        DROPS.with(|d| assert_eq!(*d.borrow(), 14));
+    }
+    DROPS.with(|d| assert_eq!(*d.borrow(), 22));
+}
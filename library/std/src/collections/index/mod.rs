@@ -0,0 +1,5 @@
+//! Insertion-ordered containers, implemented as a hash table of indices into
+//! a vector of entries
+
+pub mod map;
+pub mod set;
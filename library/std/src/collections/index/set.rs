@@ -0,0 +1,729 @@
+#[cfg(test)]
+mod tests;
+
+use crate::borrow::Borrow;
+use crate::collections::hash_map::RandomState;
+use crate::fmt;
+use crate::hash::{BuildHasher, Hash};
+use crate::iter::{FromIterator, FusedIterator};
+
+use super::map::{self, IndexMap};
+
+/// A hash set that remembers the order in which its values were inserted,
+/// implemented as an [`IndexMap`] where the value is `()`.
+///
+/// Iteration visits the values in the order they were inserted, and every
+/// value can also be accessed by its position with [`get_index`]. Inserting a
+/// value that is already present leaves the set unchanged. As with
+/// [`IndexMap`], values can be removed either with [`swap_remove`], which
+/// takes constant time but perturbs the order, or with [`shift_remove`],
+/// which preserves the order but takes linear time.
+///
+/// As with the [`HashSet`] type, an `IndexSet` requires that the elements
+/// implement the [`Eq`] and [`Hash`] traits, and it is a logic error for an
+/// item to be modified in such a way that the item's hash or its equality
+/// changes while it is in the set.
+///
+/// Two sets compare equal if they contain the same values, whatever their
+/// order.
+///
+/// [`HashSet`]: crate::collections::HashSet
+/// [`get_index`]: IndexSet::get_index
+/// [`swap_remove`]: IndexSet::swap_remove
+/// [`shift_remove`]: IndexSet::shift_remove
+///
+/// # Examples
+///
+/// ```
+/// #![feature(index_map)]
+/// use std::collections::IndexSet;
+///
+/// let mut seen = IndexSet::new();
+/// for word in "the quick brown fox jumps over the lazy dog".split(' ') {
+///     seen.insert(word);
+/// }
+///
+/// let words: Vec<_> = seen.iter().copied().collect();
+/// assert_eq!(words, ["the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog"]);
+/// assert_eq!(seen.get_index_of("fox"), Some(3));
+/// ```
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IndexSet<T, S = RandomState> {
+    map: IndexMap<T, (), S>,
+}
+
+impl<T> IndexSet<T, RandomState> {
+    /// Creates an empty `IndexSet`.
+    ///
+    /// The set is initially created with a capacity of 0, so it will not
+    /// allocate until it is first inserted into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexSet;
+    /// let set: IndexSet<i32> = IndexSet::new();
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn new() -> IndexSet<T, RandomState> {
+        IndexSet { map: IndexMap::new() }
+    }
+
+    /// Creates an empty `IndexSet` with the specified capacity.
+    ///
+    /// The set will be able to hold at least `capacity` elements without
+    /// reallocating. If `capacity` is 0, the set will not allocate.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> IndexSet<T, RandomState> {
+        IndexSet { map: IndexMap::with_capacity(capacity) }
+    }
+}
+
+impl<T, S> IndexSet<T, S> {
+    /// Creates a new empty set which will use the given hasher to hash keys.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_hasher(hasher: S) -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::with_hasher(hasher) }
+    }
+
+    /// Creates an empty `IndexSet` with the specified capacity, using
+    /// `hasher` to hash the keys.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::with_capacity_and_hasher(capacity, hasher) }
+    }
+
+    /// Returns the number of elements the set can hold without reallocating.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns a reference to the set's [`BuildHasher`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    /// Returns the number of elements in the set.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted in the `IndexSet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows [`usize`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+
+    /// Shrinks the capacity of the set as much as possible.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit()
+    }
+
+    /// An iterator visiting all elements in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexSet;
+    ///
+    /// let mut set = IndexSet::new();
+    /// set.insert("b");
+    /// set.insert("a");
+    /// let values: Vec<_> = set.iter().collect();
+    /// assert_eq!(values, [&"b", &"a"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { iter: self.map.keys() }
+    }
+
+    /// Clears the set, returning all elements in order as an iterator. Keeps
+    /// the allocated memory for reuse.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { iter: self.map.drain() }
+    }
+
+    /// Clears the set, removing all values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns the value at position `index`, or [`None`] if `index` is out of
+    /// bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.map.get_index(index).map(|(value, _)| value)
+    }
+
+    /// Returns the first value, or [`None`] if the set is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn first(&self) -> Option<&T> {
+        self.map.first().map(|(value, _)| value)
+    }
+
+    /// Returns the last value, or [`None`] if the set is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn last(&self) -> Option<&T> {
+        self.map.last().map(|(value, _)| value)
+    }
+
+    /// Removes the value at position `index` by swapping it with the last
+    /// element of the set, and returns it. Returns [`None`] if `index` is out
+    /// of bounds.
+    ///
+    /// This perturbs the order of the set, but takes constant time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.swap_remove_index(index).map(|(value, ())| value)
+    }
+
+    /// Removes the value at position `index` by shifting all of the elements
+    /// that follow it, and returns it. Returns [`None`] if `index` is out of
+    /// bounds.
+    ///
+    /// This preserves the order of the remaining elements, but takes linear
+    /// time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.shift_remove_index(index).map(|(value, ())| value)
+    }
+
+    /// Removes the last value and returns it, or [`None`] if the set is
+    /// empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn pop(&mut self) -> Option<T> {
+        self.map.pop().map(|(value, ())| value)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns
+    /// `false`. The remaining elements keep their order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.retain(|value, _| f(value))
+    }
+
+    /// Sorts the set's values with a comparator function.
+    ///
+    /// The sort is stable.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> crate::cmp::Ordering,
+    {
+        self.map.sort_by(|a, _, b, _| compare(a, b))
+    }
+
+    /// Sorts the set's values in their natural order.
+    ///
+    /// The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexSet;
+    ///
+    /// let mut set: IndexSet<_> = [3, 1, 2].iter().copied().collect();
+    /// set.sort();
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_keys()
+    }
+
+    /// Reverses the order of the set's values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reverse(&mut self) {
+        self.map.reverse()
+    }
+}
+
+impl<T, S> IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Adds a value to the set.
+    ///
+    /// If the set did not have this value present, it is appended at the end
+    /// of the set and `true` is returned.
+    ///
+    /// If the set did have this value present, `false` is returned, and the
+    /// set is not modified: the value keeps its position and the stored value
+    /// is not replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexSet;
+    ///
+    /// let mut set = IndexSet::new();
+    /// assert_eq!(set.insert(2), true);
+    /// assert_eq!(set.insert(2), false);
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.insert_full(value).1
+    }
+
+    /// Adds a value to the set, and returns its position along with whether
+    /// it was newly inserted.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert_full(&mut self, value: T) -> (usize, bool) {
+        match self.map.entry(value) {
+            map::Entry::Occupied(entry) => (entry.index(), false),
+            map::Entry::Vacant(entry) => {
+                let index = entry.index();
+                entry.insert(());
+                (index, true)
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains a value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to
+    /// the given value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get<Q: ?Sized>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_key_value(value).map(|(value, _)| value)
+    }
+
+    /// Returns the position of the value in the set along with the stored
+    /// value, if it is present.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_full<Q: ?Sized>(&self, value: &Q) -> Option<(usize, &T)>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_full(value).map(|(index, value, _)| (index, value))
+    }
+
+    /// Returns the position of the value in the set, or [`None`] if it is not
+    /// present.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_of<Q: ?Sized>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_index_of(value)
+    }
+
+    /// Removes a value from the set by swapping it with the last element.
+    /// Returns whether the value was present in the set.
+    ///
+    /// This perturbs the order of the set, but takes constant time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.swap_remove(value).is_some()
+    }
+
+    /// Removes a value from the set by shifting all of the elements that
+    /// follow it. Returns whether the value was present in the set.
+    ///
+    /// This preserves the order of the remaining elements, but takes linear
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexSet;
+    ///
+    /// let mut set: IndexSet<_> = (0..4).collect();
+    /// assert_eq!(set.shift_remove(&1), true);
+    /// assert_eq!(set.shift_remove(&1), false);
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [0, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.shift_remove(value).is_some()
+    }
+
+    /// Like [`swap_remove`], but returns the stored value, if any.
+    ///
+    /// [`swap_remove`]: IndexSet::swap_remove
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.swap_remove_entry(value).map(|(value, ())| value)
+    }
+
+    /// Like [`shift_remove`], but returns the stored value, if any.
+    ///
+    /// [`shift_remove`]: IndexSet::shift_remove
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.shift_remove_entry(value).map(|(value, ())| value)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Clone for IndexSet<T, S>
+where
+    T: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        IndexSet { map: self.map.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> PartialEq for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &IndexSet<T, S>) -> bool {
+        self.map == other.map
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Eq for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> fmt::Debug for IndexSet<T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Default for IndexSet<T, S>
+where
+    S: Default,
+{
+    /// Creates an empty `IndexSet<T, S>` with the `Default` value for the hasher.
+    #[inline]
+    fn default() -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::default() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> FromIterator<T> for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> IndexSet<T, S> {
+        let mut set = IndexSet::with_hasher(Default::default());
+        set.extend(iter);
+        set
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Extend<T> for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T, S> Extend<&'a T> for IndexSet<T, S>
+where
+    T: 'a + Eq + Hash + Copy,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T, S> IntoIterator for &'a IndexSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> IntoIterator for IndexSet<T, S> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out
+    /// of the set in order. The set cannot be used after calling this.
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { iter: self.map.into_iter() }
+    }
+}
+
+/// An iterator over the items of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`iter`] method on [`IndexSet`]. See its
+/// documentation for more.
+///
+/// [`iter`]: IndexSet::iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Iter<'a, T: 'a> {
+    iter: map::Keys<'a, T, ()>,
+}
+
+/// An owning iterator over the items of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`into_iter`] method on [`IndexSet`]
+/// (provided by the `IntoIterator` trait). See its documentation for more.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IntoIter<T> {
+    iter: map::IntoIter<T, ()>,
+}
+
+/// A draining iterator over the items of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`drain`] method on [`IndexSet`]. See its
+/// documentation for more.
+///
+/// [`drain`]: IndexSet::drain
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Drain<'a, T: 'a> {
+    iter: map::Drain<'a, T, ()>,
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(value, ())| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|(value, ())| value)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for IntoIter<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for IntoIter<T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.remaining_keys()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(value, ())| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|(value, ())| value)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for Drain<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for Drain<'_, T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for Drain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.remaining_keys()).finish()
+    }
+}
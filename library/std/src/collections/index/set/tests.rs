@@ -0,0 +1,108 @@
+use super::IndexSet;
+
+fn values<T: Copy>(set: &IndexSet<T>) -> Vec<T> {
+    set.iter().copied().collect()
+}
+
+#[test]
+fn test_insert() {
+    let mut s = IndexSet::new();
+    assert!(s.insert(3));
+    assert!(s.insert(1));
+    assert!(s.insert(2));
+    assert!(!s.insert(1));
+    assert_eq!(s.insert_full(2), (2, false));
+    assert_eq!(s.insert_full(0), (3, true));
+    assert_eq!(values(&s), [3, 1, 2, 0]);
+    assert_eq!(s.len(), 4);
+}
+
+#[test]
+fn test_lookup() {
+    let mut s = IndexSet::new();
+    s.insert("a".to_string());
+    s.insert("b".to_string());
+
+    assert!(s.contains("a"));
+    assert!(!s.contains("c"));
+    assert_eq!(s.get("b"), Some(&"b".to_string()));
+    assert_eq!(s.get_full("b"), Some((1, &"b".to_string())));
+    assert_eq!(s.get_index_of("a"), Some(0));
+    assert_eq!(s.get_index(1).map(|s| &**s), Some("b"));
+    assert_eq!(s.get_index(2), None);
+    assert_eq!(s.first().map(|s| &**s), Some("a"));
+    assert_eq!(s.last().map(|s| &**s), Some("b"));
+}
+
+#[test]
+fn test_remove() {
+    let mut s: IndexSet<_> = (0..6).collect();
+
+    assert!(s.swap_remove(&1));
+    assert!(!s.swap_remove(&1));
+    assert_eq!(values(&s), [0, 5, 2, 3, 4]);
+
+    assert!(s.shift_remove(&5));
+    assert_eq!(values(&s), [0, 2, 3, 4]);
+
+    assert_eq!(s.swap_take(&0), Some(0));
+    assert_eq!(s.shift_take(&0), None);
+    assert_eq!(values(&s), [4, 2, 3]);
+
+    assert_eq!(s.shift_remove_index(0), Some(4));
+    assert_eq!(s.swap_remove_index(5), None);
+    assert_eq!(s.pop(), Some(3));
+    assert_eq!(values(&s), [2]);
+    assert!(s.contains(&2));
+    assert!(!s.contains(&3));
+}
+
+#[test]
+fn test_retain_and_sort() {
+    let mut s: IndexSet<_> = [5, 2, 8, 1, 9, 4].iter().copied().collect();
+    s.retain(|&x| x != 8);
+    assert_eq!(values(&s), [5, 2, 1, 9, 4]);
+
+    s.sort();
+    assert_eq!(values(&s), [1, 2, 4, 5, 9]);
+    assert_eq!(s.get_index_of(&5), Some(3));
+
+    s.sort_by(|a, b| b.cmp(a));
+    assert_eq!(values(&s), [9, 5, 4, 2, 1]);
+    assert_eq!(s.get_index_of(&5), Some(1));
+
+    s.reverse();
+    assert_eq!(values(&s), [1, 2, 4, 5, 9]);
+}
+
+#[test]
+fn test_iterators() {
+    let mut s: IndexSet<_> = (0..4).collect();
+    assert_eq!(s.iter().len(), 4);
+    assert_eq!(s.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1, 0]);
+    assert_eq!(s.clone().into_iter().collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    let mut drain = s.drain();
+    assert_eq!(drain.next_back(), Some(3));
+    assert_eq!(drain.collect::<Vec<_>>(), [0, 1, 2]);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn test_eq_and_extend() {
+    let mut s1 = IndexSet::new();
+    s1.extend(&[1, 2, 3]);
+    let mut s2 = IndexSet::new();
+    s2.extend(vec![3, 2]);
+    assert!(s1 != s2);
+    s2.insert(1);
+    assert_eq!(s1, s2);
+}
+
+#[test]
+fn test_debug() {
+    let s: IndexSet<_> = [3, 1, 2].iter().copied().collect();
+    assert_eq!(format!("{:?}", s), "{3, 1, 2}");
+    assert_eq!(format!("{:?}", s.iter()), "[3, 1, 2]");
+    assert_eq!(format!("{:?}", s.clone().into_iter()), "[3, 1, 2]");
+}
@@ -16,8 +16,8 @@
 //! Rust's collections can be grouped into four major categories:
 //!
 //! * Sequences: [`Vec`], [`VecDeque`], [`LinkedList`]
-//! * Maps: [`HashMap`], [`BTreeMap`]
-//! * Sets: [`HashSet`], [`BTreeSet`]
+//! * Maps: [`HashMap`], [`BTreeMap`], [`IndexMap`]
+//! * Sets: [`HashSet`], [`BTreeSet`], [`IndexSet`]
 //! * Misc: [`BinaryHeap`]
 //!
 //! # When Should You Use Which Collection?
@@ -60,6 +60,11 @@
 //! * You want to find the largest or smallest key that is smaller or larger
 //!   than something.
 //!
+//! ### Use an `IndexMap` when:
+//! * You want a [`HashMap`] that iterates in insertion order, for example to
+//!   write out a configuration file in the order it was read.
+//! * You want to look entries up both by key and by position.
+//!
 //! ### Use the `Set` variant of any of these `Map`s when:
 //! * You just want to remember which keys you've seen.
 //! * There is no meaningful value to associate with your keys.
@@ -114,6 +119,10 @@
 //! |--------------|-----------|-----------|-----------|-------------|--------|
 //! | [`HashMap`]  | O(1)~     | O(1)~*    | O(1)~     | N/A         | N/A    |
 //! | [`BTreeMap`] | O(log(n)) | O(log(n)) | O(log(n)) | O(log(n))   | O(n+m) |
+//! | [`IndexMap`] | O(1)~     | O(1)~*    | O(1)~/O(n)| N/A         | N/A    |
+//!
+//! Removing from an [`IndexMap`] takes O(1)~ with `swap_remove`, which changes
+//! the order of the map, and O(n) with `shift_remove`, which preserves it.
 //!
 //! # Correct and Efficient Usage of Collections
 //!
@@ -418,10 +427,16 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::hash_set::HashSet;
 
+#[unstable(feature = "index_map", issue = "none")]
+pub use self::index_map::IndexMap;
+#[unstable(feature = "index_map", issue = "none")]
+pub use self::index_set::IndexSet;
+
 #[unstable(feature = "try_reserve", reason = "new API", issue = "48043")]
 pub use alloc_crate::collections::TryReserveError;
 
 mod hash;
+mod index;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub mod hash_map {
@@ -436,3 +451,18 @@ pub mod hash_set {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::hash::set::*;
 }
+
+#[unstable(feature = "index_map", issue = "none")]
+pub mod index_map {
+    //! A hash map that iterates in insertion order.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub use super::index::map::*;
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+pub mod index_set {
+    //! A hash set that iterates in insertion order, implemented as an
+    //! `IndexMap` where the value is `()`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub use super::index::set::*;
+}
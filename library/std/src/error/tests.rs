@@ -1,4 +1,5 @@
 use super::Error;
+use crate::any::Demand;
 use crate::fmt;
 
 #[derive(Debug, PartialEq)]
@@ -35,3 +36,50 @@ fn downcasting() {
         Err(e) => assert_eq!(*e.downcast::<A>().unwrap(), A),
     }
 }
+
+#[derive(Debug)]
+struct WithContext {
+    status: u16,
+    source: A,
+}
+
+impl fmt::Display for WithContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WithContext")
+    }
+}
+
+impl Error for WithContext {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        demand.provide_value::<u16>(self.status).provide_ref::<A>(&self.source);
+    }
+}
+
+#[test]
+fn request_member() {
+    let err = WithContext { status: 404, source: A };
+    let err = &err as &(dyn Error + 'static);
+    assert_eq!(err.request_value::<u16>(), Some(404));
+    assert_eq!(err.request_ref::<A>(), Some(&A));
+    assert_eq!(err.request_ref::<B>(), None);
+    assert_eq!(err.request_value::<u32>(), None);
+
+    // `Box<T>` forwards to the inner error.
+    let boxed: Box<dyn Error + Send + Sync> =
+        Box::new(Box::new(WithContext { status: 500, source: A }));
+    assert_eq!(boxed.request_value::<u16>(), Some(500));
+}
+
+#[test]
+fn sources() {
+    let err = WithContext { status: 404, source: A };
+    let err = &err as &(dyn Error + 'static);
+    let mut sources = err.sources();
+    assert_eq!(sources.next().unwrap().downcast_ref::<A>(), Some(&A));
+    assert!(sources.next().is_none());
+    assert_eq!(err.chain().count(), 2);
+}
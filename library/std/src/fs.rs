@@ -10,6 +10,7 @@
 
 #[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
 mod tests;
+mod walk_dir;
 
 use crate::ffi::OsString;
 use crate::fmt;
@@ -19,6 +20,9 @@
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::time::SystemTime;
 
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub use self::walk_dir::{walk_dir, WalkDir, WalkDirEntry};
+
 /// A reference to an open file on the filesystem.
 ///
 /// An instance of a `File` can be read and/or written depending on what options
@@ -699,6 +703,13 @@ fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
     }
 }
 
+#[unstable(feature = "is_terminal", issue = "none")]
+impl io::IsTerminal for File {
+    fn is_terminal(&self) -> bool {
+        self.inner.is_terminal()
+    }
+}
+
 impl OpenOptions {
     /// Creates a blank new set of options ready for configuration.
     ///
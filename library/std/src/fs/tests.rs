@@ -238,6 +238,15 @@ fn file_test_io_seek_shakedown() {
     check!(fs::remove_file(filename));
 }
 
+#[test]
+fn file_test_is_terminal() {
+    use crate::io::IsTerminal;
+
+    let tmpdir = tmpdir();
+    let file = check!(File::create(&tmpdir.join("is_terminal.txt")));
+    assert!(!file.is_terminal());
+}
+
 #[test]
 fn file_test_io_eof() {
     let tmpdir = tmpdir();
@@ -1390,3 +1399,151 @@ fn symlink_hard_link() {
     // "hard_link" should still appear as a symlink.
     assert!(check!(fs::symlink_metadata(tmpdir.join("hard_link"))).file_type().is_symlink());
 }
+
+fn walk_dir_names(walk: fs::WalkDir) -> Vec<(usize, String)> {
+    walk.map(|entry| {
+        let entry = check!(entry);
+        (entry.depth(), entry.file_name().into_string().unwrap())
+    })
+    .collect()
+}
+
+fn walk_dir_tree(tmpdir: &TempDir) {
+    check!(fs::create_dir_all(tmpdir.join("a/b/c")));
+    check!(fs::create_dir(tmpdir.join("d")));
+    check!(File::create(tmpdir.join("a/f1")));
+    check!(File::create(tmpdir.join("a/b/f2")));
+    check!(File::create(tmpdir.join("a/b/c/f3")));
+}
+
+#[test]
+fn walk_dir_sorted() {
+    let tmpdir = tmpdir();
+    walk_dir_tree(&tmpdir);
+
+    let walk = fs::walk_dir(tmpdir.path()).sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    let expected = [(1, "a"), (2, "b"), (3, "c"), (4, "f3"), (3, "f2"), (2, "f1"), (1, "d")];
+    let names = walk_dir_names(walk);
+    assert_eq!(names.iter().map(|(d, n)| (*d, &n[..])).collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn walk_dir_contents_first() {
+    let tmpdir = tmpdir();
+    walk_dir_tree(&tmpdir);
+
+    let walk = fs::walk_dir(tmpdir.path())
+        .contents_first(true)
+        .sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    let expected = [(4, "f3"), (3, "c"), (3, "f2"), (2, "b"), (2, "f1"), (1, "a"), (1, "d")];
+    let names = walk_dir_names(walk);
+    assert_eq!(names.iter().map(|(d, n)| (*d, &n[..])).collect::<Vec<_>>(), expected);
+
+    // Removing entries in this order empties the tree.
+    for entry in fs::walk_dir(tmpdir.path()).contents_first(true) {
+        let entry = check!(entry);
+        if entry.file_type().is_dir() {
+            check!(fs::remove_dir(entry.path()));
+        } else {
+            check!(fs::remove_file(entry.path()));
+        }
+    }
+    assert_eq!(check!(fs::read_dir(tmpdir.path())).count(), 0);
+}
+
+#[test]
+fn walk_dir_max_depth() {
+    let tmpdir = tmpdir();
+    walk_dir_tree(&tmpdir);
+
+    let sort = |a: &fs::DirEntry, b: &fs::DirEntry| a.file_name().cmp(&b.file_name());
+    let names = walk_dir_names(fs::walk_dir(tmpdir.path()).max_depth(2).sort_by(sort));
+    let expected = [(1, "a"), (2, "b"), (2, "f1"), (1, "d")];
+    assert_eq!(names.iter().map(|(d, n)| (*d, &n[..])).collect::<Vec<_>>(), expected);
+
+    assert_eq!(fs::walk_dir(tmpdir.path()).max_depth(0).count(), 0);
+}
+
+#[test]
+fn walk_dir_root_error() {
+    let tmpdir = tmpdir();
+    let mut walk = fs::walk_dir(tmpdir.join("missing"));
+    assert!(walk.next().unwrap().is_err());
+    assert!(walk.next().is_none());
+}
+
+#[test]
+fn walk_dir_symlinks() {
+    let tmpdir = tmpdir();
+    if !got_symlink_permission(&tmpdir) {
+        return;
+    };
+    check!(fs::create_dir_all(tmpdir.join("dir/sub")));
+    check!(File::create(tmpdir.join("dir/sub/file")));
+    check!(symlink_dir(tmpdir.join("dir/sub"), tmpdir.join("link")));
+    check!(symlink_dir(tmpdir.join("dir"), tmpdir.join("dir/sub/loop")));
+
+    // Without following links, links are yielded but not descended into.
+    let mut links = 0;
+    for entry in fs::walk_dir(tmpdir.path()) {
+        let entry = check!(entry);
+        assert_eq!(entry.path_is_symlink(), entry.file_type().is_symlink());
+        if entry.file_type().is_symlink() {
+            links += 1;
+        }
+    }
+    assert_eq!(links, 2);
+
+    // Following links finds the file twice and reports the loops instead of
+    // walking them forever. Followed links are still reported as links.
+    let mut files = 0;
+    let mut loops = 0;
+    let mut followed = Vec::new();
+    for entry in fs::walk_dir(tmpdir.path()).follow_links(true) {
+        match entry {
+            Ok(entry) => {
+                assert!(!entry.file_type().is_symlink());
+                if entry.file_type().is_file() {
+                    files += 1;
+                }
+                if entry.path_is_symlink() {
+                    followed.push(entry.file_name());
+                }
+            }
+            Err(_) => loops += 1,
+        }
+    }
+    assert_eq!(files, 2);
+    assert_eq!(loops, 2);
+    followed.sort();
+    assert_eq!(followed, ["link", "loop", "loop"]);
+}
+
+#[test]
+#[cfg(unix)]
+fn walk_dir_unreadable_dir() {
+    use crate::os::unix::fs::PermissionsExt;
+
+    let tmpdir = tmpdir();
+    walk_dir_tree(&tmpdir);
+    let locked = tmpdir.join("a/b");
+    check!(fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)));
+    if fs::read_dir(&locked).is_ok() {
+        // Running with privileges that ignore permissions.
+        check!(fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)));
+        return;
+    }
+
+    let mut names = Vec::new();
+    let mut errors = 0;
+    let walk = fs::walk_dir(tmpdir.path()).sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    for entry in walk {
+        match entry {
+            Ok(entry) => names.push(entry.file_name().into_string().unwrap()),
+            Err(_) => errors += 1,
+        }
+    }
+    check!(fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)));
+    assert_eq!(names, ["a", "b", "f1", "d"]);
+    assert_eq!(errors, 1);
+}
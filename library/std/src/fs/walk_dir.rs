@@ -0,0 +1,414 @@
+use crate::cmp::Ordering;
+use crate::ffi::OsString;
+use crate::fmt;
+use crate::fs::{self, DirEntry, FileType, Metadata, ReadDir};
+use crate::io;
+use crate::path::{Path, PathBuf};
+use crate::vec;
+
+type Sorter = dyn FnMut(&DirEntry, &DirEntry) -> Ordering + Send + Sync + 'static;
+
+/// An iterator over the entries of a directory tree.
+///
+/// This struct is created by the [`walk_dir`] function. See its documentation
+/// for more.
+///
+/// The walk is configured with builder methods before iteration starts; each
+/// of them consumes and returns the iterator, so they can be chained directly
+/// onto the call to [`walk_dir`].
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub struct WalkDir {
+    root: Option<PathBuf>,
+    max_depth: usize,
+    follow_links: bool,
+    contents_first: bool,
+    sorter: Option<Box<Sorter>>,
+    /// The directories currently being read, innermost last.
+    stack: Vec<Level>,
+    /// A result which has to be yielded before continuing the walk.
+    pending: Option<io::Result<WalkDirEntry>>,
+}
+
+/// An entry returned by the [`WalkDir`] iterator.
+///
+/// Unlike [`DirEntry`], a `WalkDirEntry` knows how deep it is below the root
+/// of the walk, and its file type reflects the target of a symbolic link when
+/// the walk follows links.
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+#[derive(Debug)]
+pub struct WalkDirEntry {
+    entry: DirEntry,
+    file_type: FileType,
+    depth: usize,
+    followed: bool,
+}
+
+/// A directory that is being walked.
+struct Level {
+    entries: Entries,
+    /// The canonical path of the directory. Only tracked when following
+    /// symbolic links, to detect loops.
+    canonical: Option<PathBuf>,
+    /// The entry of the directory itself, if it still has to be yielded after
+    /// its contents.
+    entry: Option<WalkDirEntry>,
+}
+
+enum Entries {
+    /// Entries are read from the directory as the walk proceeds.
+    Unsorted(ReadDir),
+    /// All entries were read up front so that they could be sorted.
+    Sorted(vec::IntoIter<io::Result<DirEntry>>),
+}
+
+impl Iterator for Entries {
+    type Item = io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<DirEntry>> {
+        match self {
+            Entries::Unsorted(read_dir) => read_dir.next(),
+            Entries::Sorted(entries) => entries.next(),
+        }
+    }
+}
+
+/// Returns an iterator over all entries below a directory, recursively.
+///
+/// The root directory itself is not yielded. Each subdirectory is yielded
+/// before its contents, unless [`WalkDir::contents_first`] is set. Symbolic
+/// links are not followed by default, see [`WalkDir::follow_links`].
+///
+/// The order in which entries of a single directory are returned is platform
+/// and filesystem dependent, just like for [`read_dir`](fs::read_dir). Use
+/// [`WalkDir::sort_by`] if a reproducible order is required.
+///
+/// # Errors
+///
+/// The iterator yields an [`Err`] if the root directory cannot be read, if a
+/// subdirectory cannot be opened, if reading the entries of a directory fails,
+/// or if following a symbolic link would lead into a loop. Errors never end the
+/// walk: iteration simply continues with the next entry. Subdirectories that
+/// could not be opened are still yielded themselves, just not descended into.
+///
+/// # Platform-specific behavior
+///
+/// Where the platform reports the file type of directory entries while reading
+/// the directory, such as `d_type` on most Unix systems, the walk does not need
+/// to query the metadata of every entry. Following symbolic links does require
+/// querying the metadata of each link.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     for entry in fs::walk_dir("src").max_depth(3) {
+///         let entry = entry?;
+///         if entry.file_type().is_file() {
+///             println!("{}", entry.path().display());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// Errors for individual entries can be reported without stopping the walk:
+///
+/// ```no_run
+/// #![feature(fs_walk_dir)]
+/// use std::fs;
+///
+/// for entry in fs::walk_dir("/etc").follow_links(true) {
+///     match entry {
+///         Ok(entry) => println!("{}", entry.path().display()),
+///         Err(err) => eprintln!("error: {}", err),
+///     }
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> WalkDir {
+    WalkDir {
+        root: Some(path.as_ref().to_path_buf()),
+        max_depth: usize::MAX,
+        follow_links: false,
+        contents_first: false,
+        sorter: None,
+        stack: Vec::new(),
+        pending: None,
+    }
+}
+
+impl WalkDir {
+    /// Sets the maximum depth of entries yielded by the iterator.
+    ///
+    /// The entries of the root directory have a depth of `1`, their children a
+    /// depth of `2`, and so on. Directories at the maximum depth are yielded but
+    /// not descended into. By default, the depth is unlimited.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Sets whether symbolic links are followed.
+    ///
+    /// When set, symbolic links to directories are descended into, and the
+    /// [`file_type`](WalkDirEntry::file_type) of an entry is that of the
+    /// link's target. A link which would lead back into one of the directories
+    /// currently being walked is reported as an error instead of being
+    /// followed. Broken links are yielded as links.
+    ///
+    /// This is `false` by default.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn follow_links(mut self, follow: bool) -> Self {
+        self.follow_links = follow;
+        self
+    }
+
+    /// Sets whether the contents of a directory are yielded before the
+    /// directory itself.
+    ///
+    /// This is useful when deleting a tree, since a directory can only be
+    /// removed once it is empty. This is `false` by default, which yields
+    /// directories before their contents.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn contents_first(mut self, contents_first: bool) -> Self {
+        self.contents_first = contents_first;
+        self
+    }
+
+    /// Sorts the entries of each directory with the given comparison function.
+    ///
+    /// Sorting requires reading all entries of a directory before yielding
+    /// the first one of them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// for entry in fs::walk_dir("docs").sort_by(|a, b| a.file_name().cmp(&b.file_name())) {
+    ///     println!("{}", entry.unwrap().path().display());
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn sort_by<F>(mut self, compare: F) -> Self
+    where
+        F: FnMut(&DirEntry, &DirEntry) -> Ordering + Send + Sync + 'static,
+    {
+        self.sorter = Some(Box::new(compare));
+        self
+    }
+
+    fn read_dir(&mut self, path: &Path) -> io::Result<Entries> {
+        let read_dir = fs::read_dir(path)?;
+        match &mut self.sorter {
+            None => Ok(Entries::Unsorted(read_dir)),
+            Some(sorter) => {
+                let mut entries: Vec<_> = read_dir.collect();
+                // Errors go first, so that they are not attributed to a later entry.
+                entries.sort_by(|a, b| match (a, b) {
+                    (Ok(a), Ok(b)) => sorter(a, b),
+                    (Err(_), Ok(_)) => Ordering::Less,
+                    (Ok(_), Err(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => Ordering::Equal,
+                });
+                Ok(Entries::Sorted(entries.into_iter()))
+            }
+        }
+    }
+
+    fn start(&mut self, root: PathBuf) -> io::Result<()> {
+        let canonical = if self.follow_links { Some(fs::canonicalize(&root)?) } else { None };
+        let entries = self.read_dir(&root)?;
+        self.stack.push(Level { entries, canonical, entry: None });
+        Ok(())
+    }
+
+    fn entry(&self, entry: DirEntry, depth: usize) -> io::Result<WalkDirEntry> {
+        let file_type = entry.file_type()?;
+        if self.follow_links && file_type.is_symlink() {
+            // A broken link is yielded as a link rather than as an error.
+            if let Ok(metadata) = fs::metadata(entry.path()) {
+                let file_type = metadata.file_type();
+                return Ok(WalkDirEntry { entry, file_type, depth, followed: true });
+            }
+        }
+        Ok(WalkDirEntry { entry, file_type, depth, followed: false })
+    }
+
+    /// Opens the directory of `entry` for walking.
+    fn descend(&mut self, entry: &WalkDirEntry) -> io::Result<Level> {
+        let canonical = match self.stack.last().and_then(|level| level.canonical.as_ref()) {
+            Some(parent) => {
+                let canonical = if entry.followed {
+                    fs::canonicalize(entry.path())?
+                } else {
+                    parent.join(entry.file_name())
+                };
+                if self.stack.iter().any(|level| level.canonical.as_ref() == Some(&canonical)) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        format!(
+                            "filesystem loop detected: {} points to an ancestor directory",
+                            entry.path().display()
+                        ),
+                    ));
+                }
+                Some(canonical)
+            }
+            None => None,
+        };
+        let entries = self.read_dir(&entry.path())?;
+        Ok(Level { entries, canonical, entry: None })
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl Iterator for WalkDir {
+    type Item = io::Result<WalkDirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<WalkDirEntry>> {
+        if let Some(root) = self.root.take() {
+            if self.max_depth == 0 {
+                return None;
+            }
+            if let Err(err) = self.start(root) {
+                return Some(Err(err));
+            }
+        }
+        if let Some(pending) = self.pending.take() {
+            return Some(pending);
+        }
+
+        loop {
+            let depth = self.stack.len();
+            let dent = match self.stack.last_mut()?.entries.next() {
+                Some(Ok(dent)) => dent,
+                Some(Err(err)) => {
+                    // Give up on this directory, but keep walking the rest of the tree.
+                    self.stack.last_mut().unwrap().entries =
+                        Entries::Sorted(Vec::new().into_iter());
+                    return Some(Err(err));
+                }
+                None => match self.stack.pop().unwrap().entry {
+                    Some(entry) => return Some(Ok(entry)),
+                    None => continue,
+                },
+            };
+
+            let entry = match self.entry(dent, depth) {
+                Ok(entry) => entry,
+                Err(err) => return Some(Err(err)),
+            };
+            if !entry.file_type.is_dir() || depth >= self.max_depth {
+                return Some(Ok(entry));
+            }
+
+            match self.descend(&entry) {
+                Ok(mut level) => {
+                    if self.contents_first {
+                        level.entry = Some(entry);
+                        self.stack.push(level);
+                    } else {
+                        self.stack.push(level);
+                        return Some(Ok(entry));
+                    }
+                }
+                // The directory itself is still yielded, in its usual position
+                // relative to the error about its contents.
+                Err(err) => {
+                    if self.contents_first {
+                        self.pending = Some(Ok(entry));
+                        return Some(Err(err));
+                    } else {
+                        self.pending = Some(Err(err));
+                        return Some(Ok(entry));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "none")]
+impl fmt::Debug for WalkDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalkDir")
+            .field("root", &self.root)
+            .field("max_depth", &self.max_depth)
+            .field("follow_links", &self.follow_links)
+            .field("contents_first", &self.contents_first)
+            .field("sorted", &self.sorter.is_some())
+            .field("depth", &self.stack.len())
+            .finish()
+    }
+}
+
+impl WalkDirEntry {
+    /// Returns the full path to the file that this entry represents.
+    ///
+    /// The path is the root passed to [`walk_dir`] joined with the names of
+    /// all directories leading to this entry, even when some of them were
+    /// reached through symbolic links.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path(&self) -> PathBuf {
+        self.entry.path()
+    }
+
+    /// Returns the bare file name of this entry without any leading path
+    /// component.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_name(&self) -> OsString {
+        self.entry.file_name()
+    }
+
+    /// Returns the file type of this entry.
+    ///
+    /// If the walk follows symbolic links, this is the type of the link's
+    /// target. Otherwise, symbolic links are reported as such.
+    ///
+    /// This does not require querying the metadata of the entry again.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn file_type(&self) -> FileType {
+        self.file_type
+    }
+
+    /// Returns the metadata for the file that this entry points at.
+    ///
+    /// This follows the symbolic link if the entry is a link that the walk
+    /// followed, and otherwise behaves like [`DirEntry::metadata`].
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn metadata(&self) -> io::Result<Metadata> {
+        if self.followed { fs::metadata(self.entry.path()) } else { self.entry.metadata() }
+    }
+
+    /// Returns whether the path of this entry is a symbolic link, whether or
+    /// not the walk followed it.
+    ///
+    /// Unlike [`file_type`], this is also `true` for links that the walk
+    /// followed, whose file type is the type of their target.
+    ///
+    /// [`file_type`]: WalkDirEntry::file_type
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn path_is_symlink(&self) -> bool {
+        self.followed || self.file_type.is_symlink()
+    }
+
+    /// Returns the depth of this entry below the root of the walk.
+    ///
+    /// The entries of the root directory have a depth of `1`.
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Converts this entry into the underlying [`DirEntry`].
+    #[unstable(feature = "fs_walk_dir", issue = "none")]
+    pub fn into_dir_entry(self) -> DirEntry {
+        self.entry
+    }
+}
@@ -3,7 +3,9 @@
 
 
 use crate::boxed::Box;
-use crate::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use crate::collections::{
+    BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, IndexMap, IndexSet, LinkedList, VecDeque,
+};
 use crate::vec::Vec;
 use crate::hash::{BuildHasher, Hash};
 use core::num::{
@@ -291,6 +293,25 @@ unsafe impl<T: Eq + Hash + GcTrace, S: BuildHasher> GcTrace for HashSet<T, S> {
     });
 }
 
+impl<K: Eq + Hash + GcTrace, V: GcTrace, S: BuildHasher> Finalize for IndexMap<K, V, S> {}
+unsafe impl<K: Eq + Hash + GcTrace, V: GcTrace, S: BuildHasher> GcTrace for IndexMap<K, V, S> {
+    custom_trace!(this, {
+        for (k, v) in this.iter() {
+            mark(k);
+            mark(v);
+        }
+    });
+}
+
+impl<T: Eq + Hash + GcTrace, S: BuildHasher> Finalize for IndexSet<T, S> {}
+unsafe impl<T: Eq + Hash + GcTrace, S: BuildHasher> GcTrace for IndexSet<T, S> {
+    custom_trace!(this, {
+        for v in this.iter() {
+            mark(v);
+        }
+    });
+}
+
 impl<T: Eq + Hash + GcTrace> Finalize for LinkedList<T> {}
 unsafe impl<T: Eq + Hash + GcTrace> GcTrace for LinkedList<T> {
     custom_trace!(this, {
@@ -270,6 +270,12 @@
 pub use self::cursor::Cursor;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::error::{Error, ErrorKind, Result};
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub use self::pipe::{pipe, PipeReader, PipeWriter};
+#[unstable(feature = "stdout_buffer_mode", issue = "none")]
+pub use self::stdio::BufferMode;
+#[unstable(feature = "is_terminal", issue = "none")]
+pub use self::stdio::IsTerminal;
 #[unstable(feature = "internal_output_capture", issue = "none")]
 #[doc(no_inline, hidden)]
 pub use self::stdio::set_output_capture;
@@ -287,6 +293,7 @@
 mod cursor;
 mod error;
 mod impls;
+mod pipe;
 pub mod prelude;
 mod stdio;
 mod util;
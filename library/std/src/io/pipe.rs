@@ -0,0 +1,268 @@
+#[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
+mod tests;
+
+use crate::fmt;
+use crate::io::{self, Initializer, IoSlice, IoSliceMut, Read, Write};
+use crate::sys::pipe as imp;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+
+/// Creates an anonymous pipe, returning its read and write ends.
+///
+/// Bytes written to the [`PipeWriter`] can be read back from the
+/// [`PipeReader`], in order. A read blocks until data is available, and
+/// returns `Ok(0)` once every writer has been dropped and all data has been
+/// read. A write blocks while the pipe's buffer is full, and fails with
+/// [`ErrorKind::BrokenPipe`] once every reader has been dropped.
+///
+/// Both ends can be duplicated with `try_clone` and converted into a
+/// [`Stdio`], so a single pipe can be shared between the current process and
+/// any number of child processes. For instance, the standard error of one
+/// child can be read by a thread of the parent while its standard output is
+/// fed to another child.
+///
+/// Like the pipes [`Command`] creates itself, the pipe's handles are not
+/// inherited by child processes (they are created with `CLOEXEC` on Unix and
+/// as non-inheritable handles on Windows); only ends passed through
+/// [`Command::stdin`], [`Command::stdout`] or [`Command::stderr`] are handed
+/// to a child.
+///
+/// Note that a reader only sees the end of the stream once *all* copies of
+/// the write end are closed, including ones held by the parent after
+/// spawning a child and ones held by a [`Command`] that has not been dropped.
+///
+/// [`ErrorKind::BrokenPipe`]: io::ErrorKind::BrokenPipe
+/// [`Stdio`]: crate::process::Stdio
+/// [`Command`]: crate::process::Command
+/// [`Command::stdin`]: crate::process::Command::stdin
+/// [`Command::stdout`]: crate::process::Command::stdout
+/// [`Command::stderr`]: crate::process::Command::stderr
+///
+/// # Examples
+///
+/// Collecting the standard output and standard error of a child in one
+/// stream:
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+///
+/// use std::io::{self, Read};
+/// use std::process::Command;
+///
+/// let (mut reader, writer) = io::pipe()?;
+///
+/// let mut child = Command::new("sh")
+///     .args(&["-c", "echo out; echo err >&2"])
+///     .stdout(writer.try_clone()?)
+///     .stderr(writer)
+///     .spawn()?;
+///
+/// // The `Command` above owned the last copy of the write end, so once the
+/// // child exits, the read below sees the end of the stream.
+/// let mut output = String::new();
+/// reader.read_to_string(&mut output)?;
+/// child.wait()?;
+/// # io::Result::Ok(())
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
+    let (reader, writer) = imp::pipe()?;
+    Ok((PipeReader { inner: reader }, PipeWriter { inner: writer }))
+}
+
+/// The read end of an anonymous pipe, created by [`pipe`].
+///
+/// When a `PipeReader` is dropped, its handle is closed.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeReader {
+    inner: imp::AnonPipe,
+}
+
+/// The write end of an anonymous pipe, created by [`pipe`].
+///
+/// When a `PipeWriter` is dropped, its handle is closed.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeWriter {
+    inner: imp::AnonPipe,
+}
+
+impl PipeReader {
+    /// Creates a new `PipeReader` instance that shares the same underlying
+    /// pipe as the existing one.
+    ///
+    /// Data written to the pipe is received by whichever reader reads it
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    ///
+    /// use std::io;
+    ///
+    /// let (reader, _writer) = io::pipe()?;
+    /// let reader_clone = reader.try_clone()?;
+    /// # io::Result::Ok(())
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<PipeReader> {
+        self.inner.try_clone().map(|inner| PipeReader { inner })
+    }
+}
+
+impl PipeWriter {
+    /// Creates a new `PipeWriter` instance that shares the same underlying
+    /// pipe as the existing one.
+    ///
+    /// The reader only sees the end of the stream once every copy of the
+    /// write end has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    ///
+    /// use std::io;
+    ///
+    /// let (_reader, writer) = io::pipe()?;
+    /// let writer_clone = writer.try_clone()?;
+    /// # io::Result::Ok(())
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<PipeWriter> {
+        self.inner.try_clone().map(|inner| PipeWriter { inner })
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.inner.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.inner.is_read_vectored()
+    }
+
+    #[inline]
+    unsafe fn initializer(&self) -> Initializer {
+        // SAFETY: Read is guaranteed to work on uninitialized memory
+        unsafe { Initializer::nop() }
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for &PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.inner.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.inner.is_read_vectored()
+    }
+
+    #[inline]
+    unsafe fn initializer(&self) -> Initializer {
+        // SAFETY: Read is guaranteed to work on uninitialized memory
+        unsafe { Initializer::nop() }
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self).write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        (&*self).write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        io::Write::is_write_vectored(&&*self)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self).flush()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for &PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.inner.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl AsInner<imp::AnonPipe> for PipeReader {
+    fn as_inner(&self) -> &imp::AnonPipe {
+        &self.inner
+    }
+}
+
+impl IntoInner<imp::AnonPipe> for PipeReader {
+    fn into_inner(self) -> imp::AnonPipe {
+        self.inner
+    }
+}
+
+impl FromInner<imp::AnonPipe> for PipeReader {
+    fn from_inner(inner: imp::AnonPipe) -> PipeReader {
+        PipeReader { inner }
+    }
+}
+
+impl AsInner<imp::AnonPipe> for PipeWriter {
+    fn as_inner(&self) -> &imp::AnonPipe {
+        &self.inner
+    }
+}
+
+impl IntoInner<imp::AnonPipe> for PipeWriter {
+    fn into_inner(self) -> imp::AnonPipe {
+        self.inner
+    }
+}
+
+impl FromInner<imp::AnonPipe> for PipeWriter {
+    fn from_inner(inner: imp::AnonPipe) -> PipeWriter {
+        PipeWriter { inner }
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("PipeReader { .. }")
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("PipeWriter { .. }")
+    }
+}
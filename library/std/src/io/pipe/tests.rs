@@ -0,0 +1,93 @@
+use crate::io::{self, ErrorKind, Read, Write};
+use crate::process::Command;
+use crate::thread;
+
+#[test]
+fn smoke() {
+    let (mut reader, mut writer) = io::pipe().unwrap();
+    writer.write_all(b"hello").unwrap();
+    drop(writer);
+
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"hello");
+}
+
+#[test]
+fn eof_waits_for_all_writers() {
+    let (mut reader, writer) = io::pipe().unwrap();
+    let writer2 = writer.try_clone().unwrap();
+    drop(writer);
+
+    let t = thread::spawn(move || {
+        (&writer2).write_all(b"from a clone").unwrap();
+    });
+
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).unwrap();
+    assert_eq!(buf, "from a clone");
+    t.join().unwrap();
+}
+
+#[test]
+fn cloned_reader() {
+    let (reader, mut writer) = io::pipe().unwrap();
+    let mut reader2 = reader.try_clone().unwrap();
+    drop(reader);
+
+    writer.write_all(b"abc").unwrap();
+    drop(writer);
+
+    let mut buf = Vec::new();
+    reader2.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"abc");
+}
+
+#[test]
+fn broken_pipe() {
+    let (reader, mut writer) = io::pipe().unwrap();
+    drop(reader);
+    assert_eq!(writer.write(b"x").unwrap_err().kind(), ErrorKind::BrokenPipe);
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn child_stdout_and_stderr() {
+    let (mut reader, writer) = io::pipe().unwrap();
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(&["/C", "echo out& echo err>&2"]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(&["-c", "echo out; echo err >&2"]);
+        cmd
+    };
+    cmd.stdout(writer.try_clone().unwrap()).stderr(writer);
+    let mut child = cmd.spawn().unwrap();
+    // The `Command` holds on to the last copies of the write end.
+    drop(cmd);
+
+    let mut output = String::new();
+    reader.read_to_string(&mut output).unwrap();
+    assert!(child.wait().unwrap().success());
+    assert!(output.contains("out"), "{:?}", output);
+    assert!(output.contains("err"), "{:?}", output);
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn not_inherited_by_child() {
+    // If the child inherited a copy of the write end, the read below would
+    // not see the end of the stream until the child exits.
+    let (mut reader, writer) = io::pipe().unwrap();
+    let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+    drop(writer);
+
+    let mut buf = Vec::new();
+    let res = reader.read_to_end(&mut buf);
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert_eq!(res.unwrap(), 0);
+}
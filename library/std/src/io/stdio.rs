@@ -7,7 +7,7 @@
 
 use crate::cell::{Cell, RefCell};
 use crate::fmt;
-use crate::io::{self, BufReader, Initializer, IoSlice, IoSliceMut, LineWriter};
+use crate::io::{self, BufReader, BufWriter, Initializer, IoSlice, IoSliceMut, LineWriter};
 use crate::lazy::SyncOnceCell;
 use crate::pin::Pin;
 use crate::sync::atomic::{AtomicBool, Ordering};
@@ -471,6 +471,85 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// The buffering strategy used by [`Stdout`].
+///
+/// See [`Stdout::set_buffer_mode`] for details.
+#[unstable(feature = "stdout_buffer_mode", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BufferMode {
+    /// Flush buffered data whenever a newline is written.
+    ///
+    /// This is the default mode of [`Stdout`].
+    Line,
+    /// Buffer up to the given number of bytes, only writing to the underlying
+    /// stream when the buffer fills up or when [`Write::flush`] is called.
+    Block(usize),
+    /// Pass all data straight through to the underlying stream.
+    Unbuffered,
+}
+
+/// The writer behind [`Stdout`], as selected by its [`BufferMode`].
+enum StdoutBuf {
+    Line(LineWriter<StdoutRaw>),
+    Block(BufWriter<StdoutRaw>),
+    Unbuffered(StdoutRaw),
+}
+
+impl StdoutBuf {
+    fn new(mode: BufferMode) -> StdoutBuf {
+        match mode {
+            BufferMode::Line => StdoutBuf::Line(LineWriter::new(stdout_raw())),
+            BufferMode::Block(capacity) => {
+                StdoutBuf::Block(BufWriter::with_capacity(capacity, stdout_raw()))
+            }
+            BufferMode::Unbuffered => StdoutBuf::Unbuffered(stdout_raw()),
+        }
+    }
+
+    fn mode(&self) -> BufferMode {
+        match self {
+            StdoutBuf::Line(_) => BufferMode::Line,
+            StdoutBuf::Block(w) => BufferMode::Block(w.capacity()),
+            StdoutBuf::Unbuffered(_) => BufferMode::Unbuffered,
+        }
+    }
+
+    fn get_mut(&mut self) -> &mut dyn Write {
+        match self {
+            StdoutBuf::Line(w) => w,
+            StdoutBuf::Block(w) => w,
+            StdoutBuf::Unbuffered(w) => w,
+        }
+    }
+}
+
+impl Write for StdoutBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.get_mut().write(buf)
+    }
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.get_mut().write_vectored(bufs)
+    }
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            StdoutBuf::Line(w) => w.is_write_vectored(),
+            StdoutBuf::Block(w) => w.is_write_vectored(),
+            StdoutBuf::Unbuffered(w) => w.is_write_vectored(),
+        }
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.get_mut().write_all(buf)
+    }
+    fn write_all_vectored(&mut self, bufs: &mut [IoSlice<'_>]) -> io::Result<()> {
+        self.get_mut().write_all_vectored(bufs)
+    }
+}
+
 /// A handle to the global standard output stream of the current process.
 ///
 /// Each handle shares a global buffer of data to be written to the standard
@@ -488,10 +567,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 /// [`io::stdout`]: stdout
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Stdout {
-    // FIXME: this should be LineWriter or BufWriter depending on the state of
-    //        stdout (tty or not). Note that if this is not line buffered it
-    //        should also flush-on-panic or some form of flush-on-abort.
-    inner: Pin<&'static ReentrantMutex<RefCell<LineWriter<StdoutRaw>>>>,
+    // Line buffered by default; see `Stdout::set_buffer_mode`. Note that a
+    // block buffer is only flushed at exit, not on abort.
+    inner: Pin<&'static ReentrantMutex<RefCell<StdoutBuf>>>,
 }
 
 /// A locked reference to the `Stdout` handle.
@@ -505,7 +583,7 @@ pub struct Stdout {
 /// an error.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct StdoutLock<'a> {
-    inner: ReentrantMutexGuard<'a, RefCell<LineWriter<StdoutRaw>>>,
+    inner: ReentrantMutexGuard<'a, RefCell<StdoutBuf>>,
 }
 
 /// Constructs a new handle to the standard output of the current process.
@@ -549,19 +627,17 @@ pub struct StdoutLock<'a> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn stdout() -> Stdout {
-    static INSTANCE: SyncOnceCell<ReentrantMutex<RefCell<LineWriter<StdoutRaw>>>> =
-        SyncOnceCell::new();
+    static INSTANCE: SyncOnceCell<ReentrantMutex<RefCell<StdoutBuf>>> = SyncOnceCell::new();
 
     fn cleanup() {
         if let Some(instance) = INSTANCE.get() {
             // Flush the data and disable buffering during shutdown
-            // by replacing the line writer by one with zero
-            // buffering capacity.
+            // by replacing the buffered writer by an unbuffered one.
             // We use try_lock() instead of lock(), because someone
             // might have leaked a StdoutLock, which would
             // otherwise cause a deadlock here.
             if let Some(lock) = Pin::static_ref(instance).try_lock() {
-                *lock.borrow_mut() = LineWriter::with_capacity(0, stdout_raw());
+                *lock.borrow_mut() = StdoutBuf::Unbuffered(stdout_raw());
             }
         }
     }
@@ -570,7 +646,7 @@ fn cleanup() {
         inner: Pin::static_ref(&INSTANCE).get_or_init_pin(
             || unsafe {
                 let _ = sys_common::at_exit(cleanup);
-                ReentrantMutex::new(RefCell::new(LineWriter::new(stdout_raw())))
+                ReentrantMutex::new(RefCell::new(StdoutBuf::new(BufferMode::Line)))
             },
             |mutex| unsafe { mutex.init() },
         ),
@@ -602,6 +678,62 @@ impl Stdout {
     pub fn lock(&self) -> StdoutLock<'_> {
         StdoutLock { inner: self.inner.lock() }
     }
+
+    /// Changes how output written to this handle is buffered.
+    ///
+    /// Standard output is line buffered by default, which is convenient for
+    /// interactive use but slow when large amounts of data are written to a
+    /// pipe or file. Any data buffered under the previous mode is flushed
+    /// before the new mode takes effect; if that flush fails, the error is
+    /// returned and the mode is left unchanged.
+    ///
+    /// The buffer mode is shared by all handles to standard output. Data left
+    /// in a [`BufferMode::Block`] buffer is flushed when the process exits
+    /// normally, but is lost if the process aborts.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(is_terminal, stdout_buffer_mode)]
+    /// use std::io::{self, BufferMode, IsTerminal, Write};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let stdout = io::stdout();
+    ///     if !stdout.is_terminal() {
+    ///         stdout.set_buffer_mode(BufferMode::Block(64 * 1024))?;
+    ///     }
+    ///
+    ///     let mut handle = stdout.lock();
+    ///     for i in 0..100_000 {
+    ///         writeln!(handle, "{}", i)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "stdout_buffer_mode", issue = "none")]
+    pub fn set_buffer_mode(&self, mode: BufferMode) -> io::Result<()> {
+        let lock = self.inner.lock();
+        let mut inner = lock.borrow_mut();
+        inner.flush()?;
+        *inner = StdoutBuf::new(mode);
+        Ok(())
+    }
+
+    /// Returns the current buffering strategy of standard output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(stdout_buffer_mode)]
+    /// use std::io::{self, BufferMode};
+    ///
+    /// assert_eq!(io::stdout().buffer_mode(), BufferMode::Line);
+    /// ```
+    #[unstable(feature = "stdout_buffer_mode", issue = "none")]
+    pub fn buffer_mode(&self) -> BufferMode {
+        self.inner.lock().borrow().mode()
+    }
 }
 
 #[stable(feature = "std_debug", since = "1.16.0")]
@@ -890,6 +1022,55 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Trait to determine if a descriptor/handle refers to a terminal/tty.
+///
+/// This is implemented for the standard streams and their locks, and for
+/// [`File`](crate::fs::File). Platforms without a notion of a terminal always
+/// report `false`.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(is_terminal)]
+/// use std::io::{self, IsTerminal};
+///
+/// if io::stdout().is_terminal() {
+///     println!("\x1b[1mhello\x1b[0m");
+/// } else {
+///     println!("hello");
+/// }
+/// ```
+#[unstable(feature = "is_terminal", issue = "none")]
+pub trait IsTerminal {
+    /// Returns `true` if the descriptor/handle refers to a terminal/tty.
+    ///
+    /// On Windows, only a Windows Console is detected; terminal emulators
+    /// which communicate over a pipe, such as MSYS2's mintty, are not.
+    #[unstable(feature = "is_terminal", issue = "none")]
+    fn is_terminal(&self) -> bool;
+}
+
+macro_rules! impl_is_terminal {
+    ($($t:ty => $raw:expr),* $(,)?) => {$(
+        #[unstable(feature = "is_terminal", issue = "none")]
+        impl IsTerminal for $t {
+            #[inline]
+            fn is_terminal(&self) -> bool {
+                $raw.is_terminal()
+            }
+        }
+    )*}
+}
+
+impl_is_terminal!(
+    Stdin => stdio::Stdin::new(),
+    StdinLock<'_> => stdio::Stdin::new(),
+    Stdout => stdio::Stdout::new(),
+    StdoutLock<'_> => stdio::Stdout::new(),
+    Stderr => stdio::Stderr::new(),
+    StderrLock<'_> => stdio::Stderr::new(),
+);
+
 /// Sets the thread-local output capture buffer and returns the old one.
 #[unstable(
     feature = "internal_output_capture",
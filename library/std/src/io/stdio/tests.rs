@@ -45,3 +45,20 @@ fn panic_doesnt_poison() {
     let _a = stderr();
     let _a = _a.lock();
 }
+
+#[test]
+fn stdout_buffer_modes() {
+    for &mode in &[BufferMode::Line, BufferMode::Block(8192), BufferMode::Unbuffered] {
+        assert_eq!(StdoutBuf::new(mode).mode(), mode);
+    }
+}
+
+#[test]
+fn set_stdout_buffer_mode() {
+    let stdout = stdout();
+    assert_eq!(stdout.buffer_mode(), BufferMode::Line);
+    stdout.set_buffer_mode(BufferMode::Block(1024)).unwrap();
+    assert_eq!(stdout.lock().inner.borrow().mode(), BufferMode::Block(1024));
+    stdout.set_buffer_mode(BufferMode::Line).unwrap();
+    assert_eq!(stdout.buffer_mode(), BufferMode::Line);
+}
@@ -297,6 +297,7 @@
 #![feature(panic_unwind)]
 #![feature(pin_static_ref)]
 #![feature(prelude_import)]
+#![feature(provide_any)]
 #![feature(ptr_internals)]
 #![feature(raw)]
 #![feature(raw_ref_macros)]
@@ -315,6 +316,7 @@
 #![feature(str_internals)]
 #![feature(str_split_once)]
 #![feature(test)]
+#![feature(test_fixtures)]
 #![feature(thread_local)]
 #![feature(thread_local_internals)]
 #![feature(toowned_clone_into)]
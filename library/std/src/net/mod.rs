@@ -7,6 +7,8 @@
 //!
 //! * [`TcpListener`] and [`TcpStream`] provide functionality for communication over TCP
 //! * [`UdpSocket`] provides functionality for communication over UDP
+//! * [`TcpSocket`] and [`UdpSocketBuilder`] configure socket options that have to be set
+//!   before a socket is bound or connected
 //! * [`IpAddr`] represents IP addresses of either IPv4 or IPv6; [`Ipv4Addr`] and
 //!   [`Ipv6Addr`] are respectively IPv4 and IPv6 addresses
 //! * [`SocketAddr`] represents socket addresses of either IPv4 or IPv6; [`SocketAddrV4`]
@@ -25,10 +27,14 @@
 pub use self::ip::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::parser::AddrParseError;
+#[unstable(feature = "socket_builder", issue = "none")]
+pub use self::tcp::TcpSocket;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::tcp::{Incoming, TcpListener, TcpStream};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::udp::UdpSocket;
+#[unstable(feature = "socket_builder", issue = "none")]
+pub use self::udp::UdpSocketBuilder;
 
 mod addr;
 mod ip;
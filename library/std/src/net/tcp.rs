@@ -7,7 +7,7 @@
 
 use crate::fmt;
 use crate::io::{self, Initializer, IoSlice, IoSliceMut};
-use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys_common::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 use crate::time::Duration;
@@ -940,3 +940,371 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A TCP socket that has not yet been turned into a [`TcpStream`] or a
+/// [`TcpListener`].
+///
+/// `TcpSocket` allows configuring a socket before it is bound, connected or
+/// starts listening, for options that either have to be set at that point
+/// (such as `SO_REUSEADDR` and `SO_REUSEPORT`) or that [`TcpStream`] and
+/// [`TcpListener`] do not expose. [`TcpListener::bind`] and
+/// [`TcpStream::connect`] remain the simplest way to create a socket with the
+/// default configuration.
+///
+/// A `TcpSocket` is created for one address family with [`new_v4`] or
+/// [`new_v6`], configured with the `set_*` methods, optionally bound to a
+/// local address with [`bind`], and finally consumed by [`listen`],
+/// [`connect`] or [`connect_timeout`].
+///
+/// Unlike [`TcpListener::bind`], no options are set on the socket implicitly.
+/// In particular, to quickly rebind a listening address on Unix platforms
+/// [`set_reuseaddr`] has to be called explicitly.
+///
+/// [`new_v4`]: TcpSocket::new_v4
+/// [`new_v6`]: TcpSocket::new_v6
+/// [`bind`]: TcpSocket::bind
+/// [`listen`]: TcpSocket::listen
+/// [`connect`]: TcpSocket::connect
+/// [`connect_timeout`]: TcpSocket::connect_timeout
+/// [`set_reuseaddr`]: TcpSocket::set_reuseaddr
+///
+/// # Examples
+///
+/// A listener with a larger backlog than [`TcpListener::bind`] uses:
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+///
+/// use std::net::TcpSocket;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = TcpSocket::new_v4()?;
+///     socket.set_reuseaddr(true)?;
+///     socket.bind(&"127.0.0.1:8080".parse().unwrap())?;
+///     let listener = socket.listen(1024)?;
+///
+///     for stream in listener.incoming() {
+///         // ...
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// A connection with keepalive enabled from a given local address:
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+///
+/// use std::net::TcpSocket;
+/// use std::time::Duration;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = TcpSocket::new_v4()?;
+///     socket.set_keepalive(Some(Duration::from_secs(60)))?;
+///     socket.bind(&"10.0.0.2:0".parse().unwrap())?;
+///     let stream = socket.connect(&"10.0.0.1:8080".parse().unwrap())?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "socket_builder", issue = "none")]
+pub struct TcpSocket(net_imp::TcpSocket);
+
+impl TcpSocket {
+    /// Creates a new IPv4 TCP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_builder)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create socket");
+    /// ```
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn new_v4() -> io::Result<TcpSocket> {
+        net_imp::TcpSocket::new(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0))).map(TcpSocket)
+    }
+
+    /// Creates a new IPv6 TCP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_builder)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v6().expect("couldn't create socket");
+    /// ```
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn new_v6() -> io::Result<TcpSocket> {
+        net_imp::TcpSocket::new(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0))).map(TcpSocket)
+    }
+
+    /// Returns the local address this socket is bound to.
+    ///
+    /// For a socket that has not been bound yet, this returns the unspecified
+    /// address with port 0 on most platforms, and an error on others.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.0.socket_addr()
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// On Unix platforms this allows binding to an address that still has
+    /// connections in the `TIME_WAIT` state, which is what
+    /// [`TcpListener::bind`] does by default. It has to be set before calling
+    /// [`bind`].
+    ///
+    /// On Windows this option instead allows binding to an address that is
+    /// actively in use by another socket, including sockets of other
+    /// processes. This is rarely what is wanted.
+    ///
+    /// [`bind`]: TcpSocket::bind
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_builder)]
+    ///
+    /// use std::net::TcpSocket;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create socket");
+    /// socket.set_reuseaddr(true).expect("set_reuseaddr call failed");
+    /// assert_eq!(socket.reuseaddr().unwrap_or(false), true);
+    /// ```
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_reuseaddr`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// This allows several sockets to be bound to the same address and port,
+    /// as long as all of them set this option before calling [`bind`]. On
+    /// Linux, incoming connections are then distributed between the
+    /// listening sockets.
+    ///
+    /// This option is only available on some Unix platforms, including Linux,
+    /// Android, macOS, iOS and the BSDs. Elsewhere, including on Windows, an
+    /// error is returned.
+    ///
+    /// [`bind`]: TcpSocket::bind
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.0.set_reuseport(reuseport)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_reuseport`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.0.reuseport()
+    }
+
+    /// Enables or disables TCP keepalive on this socket.
+    ///
+    /// With `Some(idle)`, keepalive probes are sent once the connection has
+    /// been idle for `idle`, rounded down to whole seconds (milliseconds on
+    /// Windows), and clamped to the longest time the platform supports.
+    /// Platforms that do not allow changing this time, such as OpenBSD, use
+    /// the system-wide default instead. `None` disables keepalive.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_builder)]
+    ///
+    /// use std::net::TcpSocket;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create socket");
+    /// socket.set_keepalive(Some(Duration::from_secs(30))).expect("set_keepalive call failed");
+    /// ```
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Returns whether TCP keepalive is enabled on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_keepalive`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// With `Some(timeout)`, dropping the connection blocks for up to
+    /// `timeout`, rounded down to whole seconds, until unsent data has been
+    /// transmitted. A timeout of zero discards unsent data and resets the
+    /// connection instead of closing it gracefully. `None` restores the
+    /// default behavior of closing the connection in the background.
+    ///
+    /// The option is kept by the [`TcpStream`] or [`TcpListener`] this socket
+    /// is turned into.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_builder)]
+    ///
+    /// use std::net::TcpSocket;
+    /// use std::time::Duration;
+    ///
+    /// let socket = TcpSocket::new_v4().expect("couldn't create socket");
+    /// socket.set_linger(Some(Duration::from_secs(0))).expect("set_linger call failed");
+    /// assert_eq!(socket.linger().unwrap_or(None), Some(Duration::from_secs(0)));
+    /// ```
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        self.0.set_linger(linger)
+    }
+
+    /// Gets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpSocket::set_linger`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.0.linger()
+    }
+
+    /// Sets the size of the buffer the operating system uses for data
+    /// received on this socket (`SO_RCVBUF`).
+    ///
+    /// The operating system may round, clamp or, as Linux does, double the
+    /// requested size, so [`recv_buffer_size`] may report a different value.
+    /// To influence the TCP window scale of a connection, this must be set
+    /// before calling [`listen`] or [`connect`].
+    ///
+    /// [`recv_buffer_size`]: TcpSocket::recv_buffer_size
+    /// [`listen`]: TcpSocket::listen
+    /// [`connect`]: TcpSocket::connect
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the receive buffer of this socket (`SO_RCVBUF`).
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the size of the buffer the operating system uses for data sent on
+    /// this socket (`SO_SNDBUF`).
+    ///
+    /// As with [`set_recv_buffer_size`], the size actually used may differ
+    /// from the requested one.
+    ///
+    /// [`set_recv_buffer_size`]: TcpSocket::set_recv_buffer_size
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the send buffer of this socket (`SO_SNDBUF`).
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_nodelay`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.0.set_nodelay(nodelay)
+    }
+
+    /// Gets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStream::set_nodelay`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.0.nodelay()
+    }
+
+    /// Binds this socket to the specified local address.
+    ///
+    /// Binding with a port number of 0 will request that the OS assigns a
+    /// port, which can be queried via [`TcpSocket::local_addr`]. Binding is
+    /// optional before [`connect`], where it selects the local address the
+    /// connection is made from.
+    ///
+    /// [`connect`]: TcpSocket::connect
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        self.0.bind(addr)
+    }
+
+    /// Starts listening for connections on this socket, turning it into a
+    /// [`TcpListener`].
+    ///
+    /// `backlog` is the maximum number of pending connections that have not
+    /// been [`accept`]ed yet. The operating system may silently cap it.
+    /// [`TcpListener::bind`] uses a backlog of 128.
+    ///
+    /// The socket should have been bound with [`bind`] first; otherwise most
+    /// platforms assign an unspecified address and a random port.
+    ///
+    /// [`accept`]: TcpListener::accept
+    /// [`bind`]: TcpSocket::bind
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        self.0.listen(backlog).map(TcpListener::from_inner)
+    }
+
+    /// Opens a connection to a remote host, turning this socket into a
+    /// [`TcpStream`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn connect(self, addr: &SocketAddr) -> io::Result<TcpStream> {
+        self.0.connect(addr).map(TcpStream::from_inner)
+    }
+
+    /// Opens a connection to a remote host with a timeout, turning this
+    /// socket into a [`TcpStream`].
+    ///
+    /// For the handling of `timeout`, see [`TcpStream::connect_timeout`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn connect_timeout(self, addr: &SocketAddr, timeout: Duration) -> io::Result<TcpStream> {
+        self.0.connect_timeout(addr, timeout).map(TcpStream::from_inner)
+    }
+}
+
+impl AsInner<net_imp::TcpSocket> for TcpSocket {
+    fn as_inner(&self) -> &net_imp::TcpSocket {
+        &self.0
+    }
+}
+
+impl FromInner<net_imp::TcpSocket> for TcpSocket {
+    fn from_inner(inner: net_imp::TcpSocket) -> TcpSocket {
+        TcpSocket(inner)
+    }
+}
+
+impl IntoInner<net_imp::TcpSocket> for TcpSocket {
+    fn into_inner(self) -> net_imp::TcpSocket {
+        self.0
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl fmt::Debug for TcpSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
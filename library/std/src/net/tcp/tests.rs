@@ -860,3 +860,111 @@ fn connect_timeout_valid() {
     let addr = listener.local_addr().unwrap();
     TcpStream::connect_timeout(&addr, Duration::from_secs(2)).unwrap();
 }
+
+fn new_socket(addr: &SocketAddr) -> TcpSocket {
+    match *addr {
+        SocketAddr::V4(..) => t!(TcpSocket::new_v4()),
+        SocketAddr::V6(..) => t!(TcpSocket::new_v6()),
+    }
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn socket_listen_connect() {
+    each_ip(&mut |addr| {
+        let socket = new_socket(&addr);
+        t!(socket.set_reuseaddr(true));
+        t!(socket.bind(&addr));
+        assert_eq!(t!(socket.local_addr()), addr);
+        let listener = t!(socket.listen(1));
+
+        let _t = thread::spawn(move || {
+            let socket = new_socket(&addr);
+            t!(socket.set_nodelay(true));
+            let mut stream = t!(socket.connect(&addr));
+            assert_eq!(t!(stream.nodelay()), true);
+            t!(stream.write(&[99]));
+        });
+
+        let mut stream = t!(listener.accept()).0;
+        let mut buf = [0];
+        t!(stream.read(&mut buf));
+        assert_eq!(buf[0], 99);
+    })
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn socket_connect_timeout() {
+    let listener = t!(TcpListener::bind("127.0.0.1:0"));
+    let addr = t!(listener.local_addr());
+    let socket = t!(TcpSocket::new_v4());
+    let stream = t!(socket.connect_timeout(&addr, Duration::from_secs(2)));
+    assert_eq!(t!(stream.peer_addr()), addr);
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn socket_bind_before_connect() {
+    let listener = t!(TcpListener::bind("127.0.0.1:0"));
+    let addr = t!(listener.local_addr());
+    let local = next_test_ip4();
+
+    let socket = t!(TcpSocket::new_v4());
+    t!(socket.bind(&local));
+    let stream = t!(socket.connect(&addr));
+    assert_eq!(t!(stream.local_addr()), local);
+    assert_eq!(t!(listener.accept()).1, local);
+}
+
+#[test]
+#[cfg_attr(target_env = "sgx", ignore)]
+fn socket_options() {
+    let socket = t!(TcpSocket::new_v4());
+
+    t!(socket.set_reuseaddr(true));
+    assert_eq!(t!(socket.reuseaddr()), true);
+    t!(socket.set_reuseaddr(false));
+    assert_eq!(t!(socket.reuseaddr()), false);
+
+    t!(socket.set_keepalive(Some(Duration::from_secs(30))));
+    assert_eq!(t!(socket.keepalive()), true);
+    t!(socket.set_keepalive(None));
+    assert_eq!(t!(socket.keepalive()), false);
+    // Idle times longer than the platform allows are clamped.
+    t!(socket.set_keepalive(Some(Duration::from_secs(u64::MAX))));
+    assert_eq!(t!(socket.keepalive()), true);
+    t!(socket.set_keepalive(None));
+
+    assert_eq!(t!(socket.linger()), None);
+    t!(socket.set_linger(Some(Duration::from_secs(5))));
+    assert_eq!(t!(socket.linger()), Some(Duration::from_secs(5)));
+    t!(socket.set_linger(None));
+    assert_eq!(t!(socket.linger()), None);
+
+    t!(socket.set_recv_buffer_size(1 << 16));
+    assert!(t!(socket.recv_buffer_size()) >= 1 << 16);
+    t!(socket.set_send_buffer_size(1 << 16));
+    assert!(t!(socket.send_buffer_size()) >= 1 << 16);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "freebsd"))]
+fn socket_reuseport() {
+    let addr = next_test_ip4();
+    let mut listeners = Vec::new();
+    for _ in 0..2 {
+        let socket = t!(TcpSocket::new_v4());
+        t!(socket.set_reuseport(true));
+        assert_eq!(t!(socket.reuseport()), true);
+        t!(socket.bind(&addr));
+        listeners.push(t!(socket.listen(128)));
+    }
+}
+
+#[test]
+#[cfg(windows)]
+fn socket_reuseport_unsupported() {
+    let socket = t!(TcpSocket::new_v4());
+    assert!(socket.set_reuseport(true).is_err());
+}
@@ -801,3 +801,194 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
+
+/// A UDP socket that has not yet been bound to a local address.
+///
+/// `UdpSocketBuilder` allows configuring a socket before it is bound, for
+/// options such as `SO_REUSEADDR` and `SO_REUSEPORT` that only have an effect
+/// at that point. [`UdpSocket::bind`] remains the simplest way to create a
+/// socket with the default configuration.
+///
+/// A builder is created for one address family with [`new_v4`] or
+/// [`new_v6`], configured with the `set_*` methods, and finally turned into a
+/// [`UdpSocket`] with [`bind`].
+///
+/// [`new_v4`]: UdpSocketBuilder::new_v4
+/// [`new_v6`]: UdpSocketBuilder::new_v6
+/// [`bind`]: UdpSocketBuilder::bind
+///
+/// # Examples
+///
+/// Two sockets receiving from the same multicast group and port:
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+///
+/// use std::net::{Ipv4Addr, UdpSocketBuilder};
+///
+/// fn main() -> std::io::Result<()> {
+///     let addr = "0.0.0.0:5353".parse().unwrap();
+///     let group = Ipv4Addr::new(224, 0, 0, 251);
+///
+///     let mut sockets = Vec::new();
+///     for _ in 0..2 {
+///         let builder = UdpSocketBuilder::new_v4()?;
+///         builder.set_reuseaddr(true)?;
+///         let socket = builder.bind(&addr)?;
+///         socket.join_multicast_v4(&group, &Ipv4Addr::UNSPECIFIED)?;
+///         sockets.push(socket);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "socket_builder", issue = "none")]
+pub struct UdpSocketBuilder(net_imp::UdpSocketBuilder);
+
+impl UdpSocketBuilder {
+    /// Creates a new IPv4 UDP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_builder)]
+    ///
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v4().expect("couldn't create socket");
+    /// ```
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn new_v4() -> io::Result<UdpSocketBuilder> {
+        let addr = SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0));
+        net_imp::UdpSocketBuilder::new(&addr).map(UdpSocketBuilder)
+    }
+
+    /// Creates a new IPv6 UDP socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(socket_builder)]
+    ///
+    /// use std::net::UdpSocketBuilder;
+    ///
+    /// let builder = UdpSocketBuilder::new_v6().expect("couldn't create socket");
+    /// ```
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn new_v6() -> io::Result<UdpSocketBuilder> {
+        let addr = SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0));
+        net_imp::UdpSocketBuilder::new(&addr).map(UdpSocketBuilder)
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For UDP sockets this allows several sockets to bind the same address
+    /// and port, which is mostly useful for receiving multicast traffic.
+    ///
+    /// See [`TcpSocket::set_reuseaddr`] for platform differences.
+    ///
+    /// [`TcpSocket::set_reuseaddr`]: crate::net::TcpSocket::set_reuseaddr
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        self.0.set_reuseaddr(reuseaddr)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocketBuilder::set_reuseaddr`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        self.0.reuseaddr()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// See [`TcpSocket::set_reuseport`] for its meaning and availability.
+    ///
+    /// [`TcpSocket::set_reuseport`]: crate::net::TcpSocket::set_reuseport
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.0.set_reuseport(reuseport)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// For more information about this option, see
+    /// [`UdpSocketBuilder::set_reuseport`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.0.reuseport()
+    }
+
+    /// Sets the size of the buffer the operating system uses for datagrams
+    /// received on this socket (`SO_RCVBUF`).
+    ///
+    /// Datagrams arriving while the buffer is full are dropped. The operating
+    /// system may round, clamp or, as Linux does, double the requested size,
+    /// so [`recv_buffer_size`] may report a different value.
+    ///
+    /// [`recv_buffer_size`]: UdpSocketBuilder::recv_buffer_size
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the receive buffer of this socket (`SO_RCVBUF`).
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the size of the buffer the operating system uses for datagrams
+    /// sent on this socket (`SO_SNDBUF`).
+    ///
+    /// As with [`set_recv_buffer_size`], the size actually used may differ
+    /// from the requested one.
+    ///
+    /// [`set_recv_buffer_size`]: UdpSocketBuilder::set_recv_buffer_size
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the send buffer of this socket (`SO_SNDBUF`).
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Binds this socket to the specified local address, turning it into a
+    /// [`UdpSocket`].
+    ///
+    /// Binding with a port number of 0 will request that the OS assigns a
+    /// port, which can be queried via [`UdpSocket::local_addr`].
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn bind(self, addr: &SocketAddr) -> io::Result<UdpSocket> {
+        self.0.bind(addr).map(UdpSocket)
+    }
+}
+
+impl AsInner<net_imp::UdpSocketBuilder> for UdpSocketBuilder {
+    fn as_inner(&self) -> &net_imp::UdpSocketBuilder {
+        &self.0
+    }
+}
+
+impl FromInner<net_imp::UdpSocketBuilder> for UdpSocketBuilder {
+    fn from_inner(inner: net_imp::UdpSocketBuilder) -> UdpSocketBuilder {
+        UdpSocketBuilder(inner)
+    }
+}
+
+impl IntoInner<net_imp::UdpSocketBuilder> for UdpSocketBuilder {
+    fn into_inner(self) -> net_imp::UdpSocketBuilder {
+        self.0
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl fmt::Debug for UdpSocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -364,3 +364,42 @@ fn set_nonblocking() {
         }
     })
 }
+
+#[test]
+fn builder_bind() {
+    each_ip(&mut |server_ip, client_ip| {
+        let builder = match server_ip {
+            SocketAddr::V4(..) => t!(UdpSocketBuilder::new_v4()),
+            SocketAddr::V6(..) => t!(UdpSocketBuilder::new_v6()),
+        };
+        t!(builder.set_recv_buffer_size(1 << 16));
+        assert!(t!(builder.recv_buffer_size()) >= 1 << 16);
+        t!(builder.set_send_buffer_size(1 << 16));
+        assert!(t!(builder.send_buffer_size()) >= 1 << 16);
+        let server = t!(builder.bind(&server_ip));
+        assert_eq!(t!(server.local_addr()), server_ip);
+
+        let client = t!(UdpSocket::bind(&client_ip));
+        t!(client.send_to(&[99], &server_ip));
+        let mut buf = [0];
+        let (nread, src) = t!(server.recv_from(&mut buf));
+        assert_eq!(nread, 1);
+        assert_eq!(buf[0], 99);
+        assert_eq!(src, client_ip);
+    })
+}
+
+// BSD-derived stacks only let unicast UDP sockets share a port with
+// `SO_REUSEPORT`.
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "windows"))]
+fn builder_reuseaddr() {
+    let addr = next_test_ip4();
+    let mut sockets = Vec::new();
+    for _ in 0..2 {
+        let builder = t!(UdpSocketBuilder::new_v4());
+        t!(builder.set_reuseaddr(true));
+        assert_eq!(t!(builder.reuseaddr()), true);
+        sockets.push(t!(builder.bind(&addr)));
+    }
+}
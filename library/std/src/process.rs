@@ -1362,6 +1362,64 @@ fn from(file: fs::File) -> Stdio {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeReader> for Stdio {
+    /// Converts the read end of an anonymous pipe into a `Stdio`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// #![feature(anonymous_pipe)]
+    ///
+    /// use std::io::{self, Write};
+    /// use std::process::Command;
+    ///
+    /// let (reader, mut writer) = io::pipe().unwrap();
+    ///
+    /// let mut rev = Command::new("rev")
+    ///     .stdin(reader)  // Converted into a Stdio here
+    ///     .spawn()
+    ///     .expect("failed reverse command");
+    ///
+    /// writer.write_all(b"Hello, world!\n").unwrap();
+    /// drop(writer);
+    /// rev.wait().unwrap();
+    /// ```
+    fn from(pipe: io::PipeReader) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeWriter> for Stdio {
+    /// Converts the write end of an anonymous pipe into a `Stdio`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// #![feature(anonymous_pipe)]
+    ///
+    /// use std::io::{self, Read};
+    /// use std::process::Command;
+    ///
+    /// let (mut reader, writer) = io::pipe().unwrap();
+    ///
+    /// let mut hello = Command::new("echo")
+    ///     .arg("Hello, world!")
+    ///     .stdout(writer)  // Converted into a Stdio here
+    ///     .spawn()
+    ///     .expect("failed echo command");
+    ///
+    /// hello.wait().unwrap();
+    /// let mut output = String::new();
+    /// reader.read_to_string(&mut output).unwrap();
+    /// assert_eq!(output, "Hello, world!\n");
+    /// ```
+    fn from(pipe: io::PipeWriter) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
 /// Describes the result of a process after it has terminated.
 ///
 /// This `struct` is used to represent the exit status of a child process.
@@ -0,0 +1,277 @@
+//! Atomic types
+//!
+//! Atomic types provide primitive shared-memory communication between
+//! threads, and are the building blocks of other concurrent
+//! types.
+//!
+//! This module defines atomic versions of a select number of primitive
+//! types, including [`AtomicBool`], [`AtomicIsize`], [`AtomicUsize`],
+//! [`AtomicI8`], [`AtomicU16`], etc.
+//! Atomic types present operations that, when used correctly, synchronize
+//! updates between threads.
+//!
+//! Each method takes an [`Ordering`] which represents the strength of
+//! the memory barrier for that operation. These orderings are the
+//! same as the [C++20 atomic orderings][1]. For more information see the [nomicon][2].
+//!
+//! [1]: https://en.cppreference.com/w/cpp/atomic/memory_order
+//! [2]: ../../../nomicon/atomics.html
+//!
+//! Atomic variables are safe to share between threads (they implement [`Sync`])
+//! but they do not themselves provide the mechanism for sharing and follow the
+//! [threading model](../../../std/thread/index.html#the-threading-model) of Rust.
+//! The most common way to share an atomic variable is to put it into an [`Arc`][arc] (an
+//! atomically-reference-counted shared pointer).
+//!
+//! [arc]: ../../../std/sync/struct.Arc.html
+//!
+//! Atomic types may be stored in static variables, initialized using
+//! the constant initializers like [`AtomicBool::new`]. Atomic statics
+//! are often used for lazy global initialization.
+//!
+//! # Portability
+//!
+//! All atomic types in this module are guaranteed to be [lock-free] if they're
+//! available. This means they don't internally acquire a global mutex. Atomic
+//! types and operations are not guaranteed to be wait-free. This means that
+//! operations like `fetch_or` may be implemented with a compare-and-swap loop.
+//!
+//! Atomic operations may be implemented at the instruction layer with
+//! larger-size atomics. For example some platforms use 4-byte atomic
+//! instructions to implement `AtomicI8`. Note that this emulation should not
+//! have an impact on correctness of code, it's just something to be aware of.
+//!
+//! The atomic types in this module may not be available on all platforms. The
+//! atomic types here are all widely available, however, and can generally be
+//! relied upon existing. Some notable exceptions are:
+//!
+//! * PowerPC and MIPS platforms with 32-bit pointers do not have `AtomicU64` or
+//!   `AtomicI64` types.
+//! * ARM platforms like `armv5te` that aren't for Linux only provide `load`
+//!   and `store` operations, and do not support Compare and Swap (CAS)
+//!   operations, such as `swap`, `fetch_add`, etc. Additionally on Linux,
+//!   these CAS operations are implemented via [operating system support], which
+//!   may come with a performance penalty.
+//! * ARM targets with `thumbv6m` only provide `load` and `store` operations,
+//!   and do not support Compare and Swap (CAS) operations, such as `swap`,
+//!   `fetch_add`, etc.
+//!
+//! [operating system support]: https://www.kernel.org/doc/Documentation/arm/kernel_user_helpers.txt
+//!
+//! Note that future platforms may be added that also do not have support for
+//! some atomic operations. Maximally portable code will want to be careful
+//! about which atomic types are used. `AtomicUsize` and `AtomicIsize` are
+//! generally the most portable, but even then they're not available everywhere.
+//! For reference, the `std` library requires pointer-sized atomics, although
+//! `core` does not.
+//!
+//! Currently you'll need to use `#[cfg(target_arch)]` primarily to
+//! conditionally compile in code with atomics. There is an unstable
+//! `#[cfg(target_has_atomic)]` as well which may be stabilized in the future.
+//!
+//! [lock-free]: https://en.wikipedia.org/wiki/Non-blocking_algorithm
+//!
+//! # Examples
+//!
+//! A simple spinlock:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::thread;
+//!
+//! fn main() {
+//!     let spinlock = Arc::new(AtomicUsize::new(1));
+//!
+//!     let spinlock_clone = Arc::clone(&spinlock);
+//!     let thread = thread::spawn(move|| {
+//!         spinlock_clone.store(0, Ordering::SeqCst);
+//!     });
+//!
+//!     // Wait for the other thread to release the lock
+//!     while spinlock.load(Ordering::SeqCst) != 0 {}
+//!
+//!     if let Err(panic) = thread.join() {
+//!         println!("Thread had an error: {:?}", panic);
+//!     }
+//! }
+//! ```
+//!
+//! Keep a global count of live threads:
+//!
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! static GLOBAL_THREAD_COUNT: AtomicUsize = AtomicUsize::new(0);
+//!
+//! let old_thread_count = GLOBAL_THREAD_COUNT.fetch_add(1, Ordering::SeqCst);
+//! println!("live threads: {}", old_thread_count + 1);
+//! ```
+//!
+//! # Waiting for a value to change
+//!
+//! The [`AtomicWait`] trait lets a thread block until the value of an
+//! [`AtomicU32`] or [`AtomicI32`] changes, and lets other threads wake it up
+//! again. This is the address-based wait/wake mechanism that the standard
+//! library uses for its own synchronization primitives, and it can be used to
+//! build new ones without platform-specific system calls or spin loops.
+
+#![stable(feature = "rust1", since = "1.0.0")]
+
+#[cfg(test)]
+mod tests;
+
+use crate::sys_common::atomic_wait;
+use crate::time::Duration;
+
+#[doc(inline)]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use core::sync::atomic::*;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Blocking wait and wake operations on 32-bit atomics.
+///
+/// A thread calls [`wait`] to go to sleep for as long as the atomic still
+/// holds an expected value. Another thread that changes the value then calls
+/// [`notify_one`] or [`notify_all`] to wake up the threads waiting on that
+/// atomic.
+///
+/// On Linux and Android these operations map directly onto futexes. On other
+/// platforms they are emulated with a global table of mutexes and condition
+/// variables keyed by the address of the atomic.
+///
+/// These are low-level primitives: [`wait`] may return spuriously, so callers
+/// must always check the value again after it returns, typically in a loop.
+/// This trait is sealed and cannot be implemented outside the standard
+/// library.
+///
+/// [`wait`]: AtomicWait::wait
+/// [`notify_one`]: AtomicWait::notify_one
+/// [`notify_all`]: AtomicWait::notify_all
+///
+/// # Examples
+///
+/// A one-shot event that a thread can block on:
+///
+/// ```
+/// #![feature(atomic_wait)]
+///
+/// use std::sync::atomic::{AtomicU32, AtomicWait, Ordering};
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let ready = Arc::new(AtomicU32::new(0));
+/// let ready2 = Arc::clone(&ready);
+///
+/// let t = thread::spawn(move || {
+///     ready2.store(1, Ordering::Release);
+///     ready2.notify_all();
+/// });
+///
+/// while ready.load(Ordering::Acquire) == 0 {
+///     ready.wait(0, None);
+/// }
+/// t.join().unwrap();
+/// ```
+#[unstable(feature = "atomic_wait", issue = "none")]
+pub trait AtomicWait: private::Sealed {
+    /// The integer type stored in the atomic.
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    type Value;
+
+    /// Blocks the current thread while the atomic holds `expected`.
+    ///
+    /// If the value is not equal to `expected` this returns immediately.
+    /// Otherwise the thread sleeps until it is woken by [`notify_one`] or
+    /// [`notify_all`], until `timeout` has elapsed (if given), or spuriously.
+    /// The comparison and going to sleep happen atomically with respect to
+    /// the notify operations, so a wakeup that follows a change of the value
+    /// is never missed.
+    ///
+    /// Nothing is reported about why the call returned; callers must load the
+    /// value again to find out whether it has changed.
+    ///
+    /// [`notify_one`]: AtomicWait::notify_one
+    /// [`notify_all`]: AtomicWait::notify_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(atomic_wait)]
+    ///
+    /// use std::sync::atomic::{AtomicU32, AtomicWait};
+    /// use std::time::Duration;
+    ///
+    /// let a = AtomicU32::new(5);
+    ///
+    /// // The value isn't 0, so this returns immediately.
+    /// a.wait(0, None);
+    ///
+    /// // Nobody will wake us up, so this returns after the timeout at the latest.
+    /// a.wait(5, Some(Duration::from_millis(10)));
+    /// ```
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    fn wait(&self, expected: Self::Value, timeout: Option<Duration>);
+
+    /// Wakes up at least one thread blocked in [`wait`] on this atomic, if
+    /// there are any.
+    ///
+    /// Depending on the platform, more than one thread may be woken.
+    ///
+    /// [`wait`]: AtomicWait::wait
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    fn notify_one(&self);
+
+    /// Wakes up all threads blocked in [`wait`] on this atomic.
+    ///
+    /// [`wait`]: AtomicWait::wait
+    #[unstable(feature = "atomic_wait", issue = "none")]
+    fn notify_all(&self);
+}
+
+impl private::Sealed for AtomicU32 {}
+
+#[unstable(feature = "atomic_wait", issue = "none")]
+impl AtomicWait for AtomicU32 {
+    type Value = u32;
+
+    fn wait(&self, expected: u32, timeout: Option<Duration>) {
+        atomic_wait::wait(self, expected, timeout)
+    }
+
+    fn notify_one(&self) {
+        atomic_wait::wake_one(self)
+    }
+
+    fn notify_all(&self) {
+        atomic_wait::wake_all(self)
+    }
+}
+
+impl private::Sealed for AtomicI32 {}
+
+#[unstable(feature = "atomic_wait", issue = "none")]
+impl AtomicWait for AtomicI32 {
+    type Value = i32;
+
+    fn wait(&self, expected: i32, timeout: Option<Duration>) {
+        atomic_wait::wait(as_u32(self), expected as u32, timeout)
+    }
+
+    fn notify_one(&self) {
+        atomic_wait::wake_one(as_u32(self))
+    }
+
+    fn notify_all(&self) {
+        atomic_wait::wake_all(as_u32(self))
+    }
+}
+
+fn as_u32(atomic: &AtomicI32) -> &AtomicU32 {
+    // SAFETY: `AtomicI32` and `AtomicU32` have the same size and alignment,
+    // and waiting only ever compares the raw bits of the value.
+    unsafe { &*(atomic as *const AtomicI32 as *const AtomicU32) }
+}
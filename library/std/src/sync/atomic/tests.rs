@@ -0,0 +1,62 @@
+use super::{AtomicI32, AtomicU32, AtomicWait, Ordering};
+use crate::sync::Arc;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+#[test]
+fn wait_returns_on_mismatch() {
+    let a = AtomicU32::new(1);
+    a.wait(0, None);
+
+    let b = AtomicI32::new(-1);
+    b.wait(0, None);
+}
+
+#[test]
+fn wait_timeout() {
+    let a = AtomicU32::new(0);
+    let start = Instant::now();
+    a.wait(0, Some(Duration::from_millis(10)));
+    // The wait may return spuriously, but never sleeps forever.
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn notify_one() {
+    let a = Arc::new(AtomicU32::new(0));
+    let a2 = a.clone();
+    let t = thread::spawn(move || {
+        while a2.load(Ordering::Acquire) == 0 {
+            a2.wait(0, None);
+        }
+    });
+    thread::sleep(Duration::from_millis(10));
+    a.store(1, Ordering::Release);
+    a.notify_one();
+    t.join().unwrap();
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn notify_all() {
+    const N: usize = 8;
+
+    let a = Arc::new(AtomicI32::new(-1));
+    let threads: Vec<_> = (0..N)
+        .map(|_| {
+            let a = a.clone();
+            thread::spawn(move || {
+                while a.load(Ordering::Acquire) == -1 {
+                    a.wait(-1, None);
+                }
+            })
+        })
+        .collect();
+    thread::sleep(Duration::from_millis(10));
+    a.store(0, Ordering::Release);
+    a.notify_all();
+    for t in threads {
+        t.join().unwrap();
+    }
+}
@@ -153,9 +153,6 @@
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use alloc_crate::sync::{Arc, Weak};
-#[stable(feature = "rust1", since = "1.0.0")]
-pub use core::sync::atomic;
-
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::barrier::{Barrier, BarrierWaitResult};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -167,9 +164,12 @@
 pub use self::once::{Once, OnceState, ONCE_INIT};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+pub use self::rwlock::RwLockUpgradableReadGuard;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use crate::sys_common::poison::{LockResult, PoisonError, TryLockError, TryLockResult};
 
+pub mod atomic;
 pub mod mpsc;
 
 mod barrier;
@@ -8,6 +8,7 @@
 use crate::ptr;
 use crate::sys_common::mutex as sys;
 use crate::sys_common::poison::{self, LockResult, TryLockError, TryLockResult};
+use crate::time::{Duration, Instant};
 
 /// A mutual exclusion primitive useful for protecting shared data
 ///
@@ -309,6 +310,92 @@ pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
         }
     }
 
+    /// Attempts to acquire this lock, waiting at most `timeout` for it to
+    /// become available.
+    ///
+    /// If the lock could not be acquired before the timeout expired, then
+    /// [`Err`] is returned with [`TryLockError::WouldBlock`]. Otherwise, an
+    /// RAII guard is returned. The lock will be unlocked when the guard is
+    /// dropped.
+    ///
+    /// The thread may wait slightly longer than `timeout`, but never shorter.
+    /// A timeout too large to be represented waits like [`lock`].
+    ///
+    /// [`lock`]: Mutex::lock
+    ///
+    /// # Errors
+    ///
+    /// If another user of this mutex panicked while holding the mutex, then
+    /// this call will return an error if the mutex would otherwise be
+    /// acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(timed_lock)]
+    ///
+    /// use std::sync::{Mutex, TryLockError};
+    /// use std::time::Duration;
+    ///
+    /// let mutex = Mutex::new(0);
+    /// let guard = mutex.lock().unwrap();
+    ///
+    /// // The lock is held, so this gives up after the timeout.
+    /// assert!(matches!(
+    ///     mutex.try_lock_for(Duration::from_millis(10)),
+    ///     Err(TryLockError::WouldBlock)
+    /// ));
+    ///
+    /// drop(guard);
+    /// *mutex.try_lock_for(Duration::from_millis(10)).unwrap() = 1;
+    /// ```
+    #[unstable(feature = "timed_lock", issue = "none")]
+    pub fn try_lock_for(&self, timeout: Duration) -> TryLockResult<MutexGuard<'_, T>> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.try_lock_until(deadline),
+            None => Ok(self.lock()?),
+        }
+    }
+
+    /// Attempts to acquire this lock, waiting until `deadline` at the latest
+    /// for it to become available.
+    ///
+    /// If the lock could not be acquired before the deadline, then [`Err`] is
+    /// returned with [`TryLockError::WouldBlock`]. A deadline in the past
+    /// behaves like [`try_lock`]. Otherwise, an RAII guard is returned. The
+    /// lock will be unlocked when the guard is dropped.
+    ///
+    /// [`try_lock`]: Mutex::try_lock
+    ///
+    /// # Errors
+    ///
+    /// If another user of this mutex panicked while holding the mutex, then
+    /// this call will return an error if the mutex would otherwise be
+    /// acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(timed_lock)]
+    ///
+    /// use std::sync::Mutex;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mutex = Mutex::new(0);
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    /// *mutex.try_lock_until(deadline).unwrap() = 1;
+    /// ```
+    #[unstable(feature = "timed_lock", issue = "none")]
+    pub fn try_lock_until(&self, deadline: Instant) -> TryLockResult<MutexGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_lock_until(deadline) {
+                Ok(MutexGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Determines whether the mutex is poisoned.
     ///
     /// If another thread is active, the mutex can still become poisoned at any
@@ -1,7 +1,8 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, Condvar, Mutex};
+use crate::sync::{Arc, Condvar, Mutex, TryLockError};
 use crate::thread;
+use crate::time::{Duration, Instant};
 
 struct Packet<T>(Arc<(Mutex<T>, Condvar)>);
 
@@ -57,6 +58,57 @@ fn try_lock() {
     *m.try_lock().unwrap() = ();
 }
 
+#[test]
+fn try_lock_for() {
+    let m = Arc::new(Mutex::new(0));
+    let guard = m.lock().unwrap();
+
+    let start = Instant::now();
+    match m.try_lock_for(Duration::from_millis(10)) {
+        Err(TryLockError::WouldBlock) => {}
+        _ => panic!("try_lock_for should time out while the lock is held"),
+    }
+    assert!(start.elapsed() >= Duration::from_millis(10));
+
+    // The lock is acquired once another thread releases it in time.
+    let m2 = m.clone();
+    let t = thread::spawn(move || *m2.try_lock_for(Duration::from_secs(60)).unwrap() += 1);
+    thread::sleep(Duration::from_millis(10));
+    drop(guard);
+    t.join().unwrap();
+    assert_eq!(*m.lock().unwrap(), 1);
+
+    // A timeout that overflows `Instant` just blocks.
+    *m.try_lock_for(Duration::MAX).unwrap() += 1;
+    assert_eq!(*m.lock().unwrap(), 2);
+}
+
+#[test]
+fn try_lock_until_past_deadline() {
+    let m = Mutex::new(());
+    let deadline = Instant::now();
+    *m.try_lock_until(deadline).unwrap() = ();
+
+    let _guard = m.lock().unwrap();
+    assert!(m.try_lock_until(deadline).is_err());
+}
+
+#[test]
+fn try_lock_for_poison() {
+    let m = Arc::new(Mutex::new(1));
+    let m2 = m.clone();
+    let _ = thread::spawn(move || {
+        let _lock = m2.lock().unwrap();
+        panic!("test panic in inner thread to poison mutex");
+    })
+    .join();
+
+    match m.try_lock_for(Duration::from_millis(10)) {
+        Err(TryLockError::Poisoned(e)) => assert_eq!(*e.into_inner(), 1),
+        _ => panic!("try_lock_for of poisoned Mutex should report the poison"),
+    }
+}
+
 #[test]
 fn test_into_inner() {
     let m = Mutex::new(NonCopy(10));
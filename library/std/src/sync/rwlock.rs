@@ -3,11 +3,14 @@
 
 use crate::cell::UnsafeCell;
 use crate::fmt;
+use crate::lazy::SyncOnceCell;
 use crate::mem;
 use crate::ops::{Deref, DerefMut};
 use crate::ptr;
+use crate::sys_common::mutex::MovableMutex;
 use crate::sys_common::poison::{self, LockResult, TryLockError, TryLockResult};
 use crate::sys_common::rwlock as sys;
+use crate::time::{Duration, Instant};
 
 /// A reader-writer lock
 ///
@@ -65,6 +68,12 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct RwLock<T: ?Sized> {
     inner: Box<sys::RWLock>,
+    // Held by an upgradable reader for as long as its guard lives, and by a
+    // writer while it acquires `inner`. This keeps writers out between an
+    // upgradable reader releasing its shared access and acquiring exclusive
+    // access in `RwLockUpgradableReadGuard::upgrade`. It is only created by the
+    // first upgradable reader, see `acquire_write`.
+    upgradable: SyncOnceCell<MovableMutex>,
     poison: poison::Flag,
     data: UnsafeCell<T>,
 }
@@ -115,6 +124,42 @@ impl<T: ?Sized> !Send for RwLockWriteGuard<'_, T> {}
 #[stable(feature = "rwlock_guard_sync", since = "1.23.0")]
 unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
 
+/// RAII structure used to release the upgradable read access of a lock when
+/// dropped.
+///
+/// An upgradable read guard gives shared access like a [`RwLockReadGuard`],
+/// and can be atomically turned into a [`RwLockWriteGuard`] with [`upgrade`]
+/// without letting any other writer in. Only one upgradable read guard can
+/// exist at a time, but it coexists with any number of plain read guards.
+///
+/// This structure is created by the [`upgradable_read`] and
+/// [`try_upgradable_read`] methods on [`RwLock`].
+///
+/// [`upgrade`]: RwLockUpgradableReadGuard::upgrade
+/// [`upgradable_read`]: RwLock::upgradable_read
+/// [`try_upgradable_read`]: RwLock::try_upgradable_read
+#[must_use = "if unused the RwLock will immediately unlock"]
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+pub struct RwLockUpgradableReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> !Send for RwLockUpgradableReadGuard<'_, T> {}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+unsafe impl<T: ?Sized + Sync> Sync for RwLockUpgradableReadGuard<'_, T> {}
+
+/// Releases the `upgradable` mutex of an `RwLock` when dropped, so that it
+/// isn't leaked if acquiring the inner lock panics.
+struct UpgradableUnlock<'a>(&'a MovableMutex);
+
+impl Drop for UpgradableUnlock<'_> {
+    fn drop(&mut self) {
+        unsafe { self.0.raw_unlock() }
+    }
+}
+
 impl<T> RwLock<T> {
     /// Creates a new instance of an `RwLock<T>` which is unlocked.
     ///
@@ -129,6 +174,7 @@ impl<T> RwLock<T> {
     pub fn new(t: T) -> RwLock<T> {
         RwLock {
             inner: box sys::RWLock::new(),
+            upgradable: SyncOnceCell::new(),
             poison: poison::Flag::new(),
             data: UnsafeCell::new(t),
         }
@@ -226,6 +272,73 @@ pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
         }
     }
 
+    /// Attempts to acquire this rwlock with shared read access, waiting at
+    /// most `timeout` for it to become available.
+    ///
+    /// If the access could not be granted before the timeout expired, then
+    /// [`Err`] is returned with [`TryLockError::WouldBlock`]. Otherwise, an
+    /// RAII guard is returned which will release the shared access when it is
+    /// dropped. A timeout too large to be represented waits like [`read`].
+    ///
+    /// [`read`]: RwLock::read
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock. An
+    /// error will only be returned if the lock would have otherwise been
+    /// acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(timed_lock)]
+    ///
+    /// use std::sync::RwLock;
+    /// use std::time::Duration;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let w = lock.write().unwrap();
+    /// assert!(lock.try_read_for(Duration::from_millis(10)).is_err());
+    /// drop(w);
+    ///
+    /// assert_eq!(*lock.try_read_for(Duration::from_millis(10)).unwrap(), 1);
+    /// ```
+    #[unstable(feature = "timed_lock", issue = "none")]
+    pub fn try_read_for(&self, timeout: Duration) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.try_read_until(deadline),
+            None => Ok(self.read()?),
+        }
+    }
+
+    /// Attempts to acquire this rwlock with shared read access, waiting until
+    /// `deadline` at the latest for it to become available.
+    ///
+    /// If the access could not be granted before the deadline, then [`Err`] is
+    /// returned with [`TryLockError::WouldBlock`]. A deadline in the past
+    /// behaves like [`try_read`].
+    ///
+    /// [`try_read`]: RwLock::try_read
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock. An
+    /// error will only be returned if the lock would have otherwise been
+    /// acquired.
+    #[unstable(feature = "timed_lock", issue = "none")]
+    pub fn try_read_until(&self, deadline: Instant) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        unsafe {
+            if self.inner.try_read_until(deadline) {
+                Ok(RwLockReadGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Locks this rwlock with exclusive write access, blocking the current
     /// thread until it can be acquired.
     ///
@@ -261,7 +374,16 @@ pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
         unsafe {
-            self.inner.write();
+            self.acquire_write(
+                |upgradable| {
+                    upgradable.raw_lock();
+                    true
+                },
+                || {
+                    self.inner.write();
+                    true
+                },
+            );
             RwLockWriteGuard::new(self)
         }
     }
@@ -300,7 +422,7 @@ pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
         unsafe {
-            if self.inner.try_write() {
+            if self.acquire_write(|upgradable| upgradable.try_lock(), || self.inner.try_write()) {
                 Ok(RwLockWriteGuard::new(self)?)
             } else {
                 Err(TryLockError::WouldBlock)
@@ -308,6 +430,161 @@ pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
         }
     }
 
+    /// Attempts to lock this rwlock with exclusive write access, waiting at
+    /// most `timeout` for it to become available.
+    ///
+    /// If the lock could not be acquired before the timeout expired, then
+    /// [`Err`] is returned with [`TryLockError::WouldBlock`]. Otherwise, an
+    /// RAII guard is returned which will release the lock when it is dropped.
+    /// A timeout too large to be represented waits like [`write`].
+    ///
+    /// [`write`]: RwLock::write
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock. An
+    /// error will only be returned if the lock would have otherwise been
+    /// acquired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(timed_lock)]
+    ///
+    /// use std::sync::RwLock;
+    /// use std::time::Duration;
+    ///
+    /// let lock = RwLock::new(1);
+    ///
+    /// let r = lock.read().unwrap();
+    /// assert!(lock.try_write_for(Duration::from_millis(10)).is_err());
+    /// drop(r);
+    ///
+    /// *lock.try_write_for(Duration::from_millis(10)).unwrap() = 2;
+    /// ```
+    #[unstable(feature = "timed_lock", issue = "none")]
+    pub fn try_write_for(&self, timeout: Duration) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.try_write_until(deadline),
+            None => Ok(self.write()?),
+        }
+    }
+
+    /// Attempts to lock this rwlock with exclusive write access, waiting until
+    /// `deadline` at the latest for it to become available.
+    ///
+    /// If the lock could not be acquired before the deadline, then [`Err`] is
+    /// returned with [`TryLockError::WouldBlock`]. A deadline in the past
+    /// behaves like [`try_write`].
+    ///
+    /// [`try_write`]: RwLock::try_write
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock. An
+    /// error will only be returned if the lock would have otherwise been
+    /// acquired.
+    #[unstable(feature = "timed_lock", issue = "none")]
+    pub fn try_write_until(&self, deadline: Instant) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        unsafe {
+            if self.acquire_write(
+                |upgradable| upgradable.try_lock_until(deadline),
+                || self.inner.try_write_until(deadline),
+            ) {
+                Ok(RwLockWriteGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
+    /// Locks this rwlock with upgradable read access, blocking the current
+    /// thread until it can be acquired.
+    ///
+    /// The returned guard gives shared access like [`read`], alongside any
+    /// number of other readers, but only one upgradable reader can hold the
+    /// lock at a time. Its access can later be turned into exclusive write
+    /// access with [`RwLockUpgradableReadGuard::upgrade`], without any other
+    /// writer getting the lock in between.
+    ///
+    /// [`read`]: RwLock::read
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock.
+    /// The failure will occur immediately after the lock has been acquired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when called if the lock is already held by the current thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(vec![1, 2]);
+    ///
+    /// let r = lock.upgradable_read().unwrap();
+    /// if !r.contains(&3) {
+    ///     let mut w = RwLockUpgradableReadGuard::upgrade(r);
+    ///     w.push(3);
+    /// }
+    ///
+    /// assert_eq!(*lock.read().unwrap(), [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn upgradable_read(&self) -> LockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            let upgradable = self.upgradable.get_or_init(MovableMutex::new);
+            upgradable.raw_lock();
+            let unlock = UpgradableUnlock(upgradable);
+            self.inner.read();
+            // The guard now owns the `upgradable` mutex.
+            mem::forget(unlock);
+            RwLockUpgradableReadGuard::new(self)
+        }
+    }
+
+    /// Attempts to acquire this rwlock with upgradable read access.
+    ///
+    /// If the access could not be granted at this time, then `Err` is returned.
+    /// Otherwise, an RAII guard is returned which will release the access when
+    /// it is dropped. See [`upgradable_read`] for what upgradable read access
+    /// allows.
+    ///
+    /// This function does not block.
+    ///
+    /// [`upgradable_read`]: RwLock::upgradable_read
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock. An
+    /// error will only be returned if the lock would have otherwise been
+    /// acquired.
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn try_upgradable_read(&self) -> TryLockResult<RwLockUpgradableReadGuard<'_, T>> {
+        unsafe {
+            let upgradable = self.upgradable.get_or_init(MovableMutex::new);
+            if !upgradable.try_lock() {
+                return Err(TryLockError::WouldBlock);
+            }
+            let unlock = UpgradableUnlock(upgradable);
+            if self.inner.try_read() {
+                mem::forget(unlock);
+                Ok(RwLockUpgradableReadGuard::new(self)?)
+            } else {
+                Err(TryLockError::WouldBlock)
+            }
+        }
+    }
+
     /// Determines whether the lock is poisoned.
     ///
     /// If another thread is active, the lock can still become poisoned at any
@@ -368,14 +645,15 @@ pub fn into_inner(self) -> LockResult<T>
         // but because `RwLock` impl-s `Drop`, we can't move out of it, so
         // we'll have to destructure it manually instead.
         unsafe {
-            // Like `let RwLock { inner, poison, data } = self`.
-            let (inner, poison, data) = {
-                let RwLock { ref inner, ref poison, ref data } = self;
-                (ptr::read(inner), ptr::read(poison), ptr::read(data))
+            // Like `let RwLock { inner, upgradable, poison, data } = self`.
+            let (inner, upgradable, poison, data) = {
+                let RwLock { ref inner, ref upgradable, ref poison, ref data } = self;
+                (ptr::read(inner), ptr::read(upgradable), ptr::read(poison), ptr::read(data))
             };
             mem::forget(self);
             inner.destroy(); // Keep in sync with the `Drop` impl.
             drop(inner);
+            drop(upgradable);
 
             poison::map_result(poison.borrow(), |_| data.into_inner())
         }
@@ -407,6 +685,47 @@ pub fn get_mut(&mut self) -> LockResult<&mut T> {
         let data = self.data.get_mut();
         poison::map_result(self.poison.borrow(), |_| data)
     }
+
+    /// Returns the `upgradable` mutex, which exists once there has been an
+    /// upgradable reader.
+    fn upgradable(&self) -> &MovableMutex {
+        self.upgradable.get().unwrap()
+    }
+
+    /// Acquires exclusive access to `inner` with `acquire`, which is one of the
+    /// ways of locking it for writing, holding the `upgradable` mutex while
+    /// doing so if it exists. `lock_upgradable` locks that mutex in the same
+    /// way. Returns whether the lock was acquired.
+    ///
+    /// Writers only pay for the `upgradable` mutex once there has been an
+    /// upgradable reader. A writer that found no mutex checks again once it has
+    /// exclusive access: an upgradable reader created the mutex before taking
+    /// shared access, so a writer that doesn't see it then can't have gotten in
+    /// between an upgradable reader's shared and exclusive access.
+    unsafe fn acquire_write(
+        &self,
+        mut lock_upgradable: impl FnMut(&MovableMutex) -> bool,
+        mut acquire: impl FnMut() -> bool,
+    ) -> bool {
+        loop {
+            if let Some(upgradable) = self.upgradable.get() {
+                if !lock_upgradable(upgradable) {
+                    return false;
+                }
+                let _unlock = UpgradableUnlock(upgradable);
+                return acquire();
+            }
+            if !acquire() {
+                return false;
+            }
+            if self.upgradable.get().is_none() {
+                return true;
+            }
+            // An upgradable reader may be waiting to upgrade, so go through the
+            // `upgradable` mutex instead.
+            self.inner.write_unlock();
+        }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -468,6 +787,97 @@ unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockWriteGuard<'rwlock,
     }
 }
 
+impl<'rwlock, T: ?Sized> RwLockUpgradableReadGuard<'rwlock, T> {
+    unsafe fn new(lock: &'rwlock RwLock<T>) -> LockResult<RwLockUpgradableReadGuard<'rwlock, T>> {
+        poison::map_result(lock.poison.borrow(), |_| RwLockUpgradableReadGuard { lock })
+    }
+
+    /// Atomically upgrades an upgradable read guard to exclusive write access,
+    /// blocking the current thread until all other readers have released the
+    /// lock.
+    ///
+    /// No other writer can acquire the lock between the upgradable read access
+    /// and the returned write access, so anything observed through the
+    /// upgradable guard is still valid once this returns.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockUpgradableReadGuard::upgrade(guard)`, so that it doesn't
+    /// conflict with a method on the protected data.
+    ///
+    /// No writer can run while the upgradable guard is held, so the lock cannot
+    /// become poisoned in the meantime. This is why no [`LockResult`] is
+    /// returned; a lock that was already poisoned when the upgradable guard
+    /// was acquired stays poisoned.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic or deadlock if the current thread also holds
+    /// a read guard on the same lock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(0);
+    ///
+    /// let r = lock.upgradable_read().unwrap();
+    /// let mut w = RwLockUpgradableReadGuard::upgrade(r);
+    /// *w += 1;
+    /// drop(w);
+    ///
+    /// assert_eq!(*lock.read().unwrap(), 1);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn upgrade(s: Self) -> RwLockWriteGuard<'rwlock, T> {
+        let lock = s.lock;
+        mem::forget(s);
+        unsafe {
+            let _unlock = UpgradableUnlock(lock.upgradable());
+            lock.inner.read_unlock();
+            lock.inner.write();
+        }
+        let poison = lock.poison.borrow().unwrap_or_else(poison::PoisonError::into_inner);
+        RwLockWriteGuard { lock, poison }
+    }
+
+    /// Downgrades an upgradable read guard to a plain read guard, allowing
+    /// another thread to acquire upgradable read access.
+    ///
+    /// The shared access is never released in between, so no writer can
+    /// acquire the lock during the downgrade.
+    ///
+    /// This is an associated function that needs to be used as
+    /// `RwLockUpgradableReadGuard::downgrade(guard)`, so that it doesn't
+    /// conflict with a method on the protected data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let lock = RwLock::new(0);
+    ///
+    /// let r = lock.upgradable_read().unwrap();
+    /// let r = RwLockUpgradableReadGuard::downgrade(r);
+    ///
+    /// // Another upgradable reader can now get in.
+    /// assert!(lock.try_upgradable_read().is_ok());
+    /// assert_eq!(*r, 0);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+    pub fn downgrade(s: Self) -> RwLockReadGuard<'rwlock, T> {
+        let lock = s.lock;
+        mem::forget(s);
+        unsafe { lock.upgradable().raw_unlock() };
+        RwLockReadGuard { lock }
+    }
+}
+
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl<T: fmt::Debug> fmt::Debug for RwLockReadGuard<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -482,6 +892,20 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockUpgradableReadGuard").field("lock", &self.lock).finish()
+    }
+}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized + fmt::Display> fmt::Display for RwLockUpgradableReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl<T: fmt::Debug> fmt::Debug for RwLockWriteGuard<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -505,6 +929,15 @@ fn deref(&self) -> &T {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> Deref for RwLockUpgradableReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
     type Target = T;
@@ -539,3 +972,13 @@ fn drop(&mut self) {
         }
     }
 }
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "none")]
+impl<T: ?Sized> Drop for RwLockUpgradableReadGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            self.lock.inner.read_unlock();
+            self.lock.upgradable().raw_unlock();
+        }
+    }
+}
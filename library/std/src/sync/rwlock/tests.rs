@@ -1,7 +1,8 @@
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sync::mpsc::channel;
-use crate::sync::{Arc, RwLock, TryLockError};
+use crate::sync::{Arc, RwLock, RwLockUpgradableReadGuard, TryLockError};
 use crate::thread;
+use crate::time::Duration;
 use rand::{self, Rng};
 
 #[derive(Eq, PartialEq, Debug)]
@@ -181,6 +182,126 @@ fn test_rwlock_try_write() {
     drop(read_guard);
 }
 
+#[test]
+fn test_rwlock_timed() {
+    let lock = RwLock::new(0);
+
+    let read_guard = lock.read().unwrap();
+    assert!(lock.try_read_for(Duration::from_millis(10)).is_ok());
+    match lock.try_write_for(Duration::from_millis(10)) {
+        Err(TryLockError::WouldBlock) => (),
+        _ => panic!("try_write_for should time out while a reader holds the lock"),
+    }
+    drop(read_guard);
+
+    let write_guard = lock.write().unwrap();
+    assert!(lock.try_read_for(Duration::from_millis(10)).is_err());
+    assert!(lock.try_write_for(Duration::from_millis(10)).is_err());
+    drop(write_guard);
+
+    *lock.try_write_for(Duration::from_millis(10)).unwrap() = 1;
+    assert_eq!(*lock.try_read_for(Duration::from_millis(10)).unwrap(), 1);
+}
+
+#[test]
+fn test_rwlock_upgradable_read() {
+    let lock = RwLock::new(0);
+
+    let upgradable = lock.upgradable_read().unwrap();
+    // Plain readers can coexist with an upgradable reader...
+    drop(lock.try_read().unwrap());
+    // ...but other upgradable readers and writers can't.
+    assert!(lock.try_upgradable_read().is_err());
+    assert!(lock.try_write().is_err());
+
+    let mut w = RwLockUpgradableReadGuard::upgrade(upgradable);
+    *w += 1;
+    assert!(lock.try_read().is_err());
+    drop(w);
+
+    let upgradable = lock.upgradable_read().unwrap();
+    let r = RwLockUpgradableReadGuard::downgrade(upgradable);
+    assert_eq!(*r, 1);
+    drop(lock.try_upgradable_read().unwrap());
+    assert!(lock.try_write().is_err());
+    drop(r);
+
+    assert_eq!(*lock.try_write().unwrap(), 1);
+}
+
+#[test]
+fn test_rwlock_upgrade_excludes_writers() {
+    let lock = Arc::new(RwLock::new(0));
+    let upgradable = lock.upgradable_read().unwrap();
+
+    let lock2 = lock.clone();
+    let writer = thread::spawn(move || {
+        let mut w = lock2.write().unwrap();
+        assert_eq!(*w, 1);
+        *w = 2;
+    });
+
+    // Give the writer a chance to run; it must not get in before the upgrade.
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(*upgradable, 0);
+    let mut w = RwLockUpgradableReadGuard::upgrade(upgradable);
+    *w = 1;
+    drop(w);
+
+    writer.join().unwrap();
+    assert_eq!(*lock.read().unwrap(), 2);
+}
+
+#[test]
+fn test_rwlock_upgrade_with_concurrent_writers() {
+    const N: usize = 4;
+    const M: usize = 100;
+
+    let lock = Arc::new(RwLock::new(0));
+    // Writers are already running when the first upgradable reader shows up.
+    let writers: Vec<_> = (0..N)
+        .map(|_| {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                for _ in 0..M {
+                    *lock.write().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+
+    for _ in 0..M {
+        let upgradable = lock.upgradable_read().unwrap();
+        let seen = *upgradable;
+        let mut w = RwLockUpgradableReadGuard::upgrade(upgradable);
+        assert_eq!(*w, seen);
+        *w += 1;
+    }
+
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    assert_eq!(*lock.read().unwrap(), (N + 1) * M);
+}
+
+#[test]
+fn test_rwlock_upgradable_read_poison() {
+    let lock = Arc::new(RwLock::new(1));
+    let lock2 = lock.clone();
+    let _ = thread::spawn(move || {
+        let _lock = lock2.write().unwrap();
+        panic!("test panic in inner thread to poison RwLock");
+    })
+    .join();
+
+    assert!(lock.upgradable_read().is_err());
+    let upgradable = lock.upgradable_read().unwrap_err().into_inner();
+    let w = RwLockUpgradableReadGuard::upgrade(upgradable);
+    assert_eq!(*w, 1);
+    drop(w);
+    assert!(lock.is_poisoned());
+}
+
 #[test]
 fn test_into_inner() {
     let m = RwLock::new(NonCopy(10));
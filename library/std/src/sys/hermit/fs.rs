@@ -281,6 +281,10 @@ pub fn file_attr(&self) -> io::Result<FileAttr> {
         Err(Error::from_raw_os_error(22))
     }
 
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
+
     pub fn fsync(&self) -> io::Result<()> {
         Err(Error::from_raw_os_error(22))
     }
@@ -31,6 +31,7 @@
 pub mod mutex;
 pub mod net;
 pub mod os;
+#[path = "../unix/path.rs"]
 pub mod path;
 pub mod pipe;
 #[path = "../unsupported/process.rs"]
@@ -6,6 +6,7 @@
 use crate::ptr;
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sys::hermit::abi;
+use crate::time::Instant;
 
 /// This type provides a lock based on busy waiting to realize mutual exclusion
 ///
@@ -208,6 +209,11 @@ pub unsafe fn try_lock(&self) -> bool {
         guard.locked
     }
 
+    #[inline]
+    pub unsafe fn try_lock_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_lock())
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
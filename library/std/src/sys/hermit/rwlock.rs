@@ -1,6 +1,7 @@
 use crate::cell::UnsafeCell;
 use crate::sys::condvar::Condvar;
 use crate::sys::mutex::Mutex;
+use crate::time::Instant;
 
 pub struct RWLock {
     lock: Mutex,
@@ -47,6 +48,11 @@ pub unsafe fn try_read(&self) -> bool {
         return ok;
     }
 
+    #[inline]
+    pub unsafe fn try_read_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_read())
+    }
+
     #[inline]
     pub unsafe fn write(&self) {
         self.lock.lock();
@@ -64,6 +70,11 @@ pub unsafe fn try_write(&self) -> bool {
         return ok;
     }
 
+    #[inline]
+    pub unsafe fn try_write_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_write())
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         self.lock.lock();
@@ -10,6 +10,10 @@ impl Stdin {
     pub const fn new() -> Stdin {
         Stdin
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Read for Stdin {
@@ -31,6 +35,10 @@ impl Stdout {
     pub const fn new() -> Stdout {
         Stdout
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stdout {
@@ -72,6 +80,10 @@ impl Stderr {
     pub const fn new() -> Stderr {
         Stderr
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stderr {
@@ -18,6 +18,10 @@ unsafe impl Sync for Thread {}
 
 pub const DEFAULT_MIN_STACK_SIZE: usize = 1 << 20;
 
+/// Platform-specific settings for spawning a thread. There are none on this platform.
+#[derive(Debug, Default)]
+pub struct Options;
+
 impl Thread {
     pub unsafe fn new_with_coreid(
         stack: usize,
@@ -53,7 +57,11 @@ extern "C" fn thread_start(main: usize) {
         }
     }
 
-    pub unsafe fn new(stack: usize, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+    pub unsafe fn new(
+        stack: usize,
+        _options: &Options,
+        p: Box<dyn FnOnce()>,
+    ) -> io::Result<Thread> {
         Thread::new_with_coreid(stack, p, -1 /* = no specific core */)
     }
 
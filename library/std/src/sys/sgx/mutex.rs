@@ -3,6 +3,7 @@
 use super::abi::thread;
 
 use super::waitqueue::{try_lock_or_false, NotifiedTcs, SpinMutex, WaitQueue, WaitVariable};
+use crate::time::Instant;
 
 pub struct Mutex {
     inner: SpinMutex<WaitVariable<bool>>,
@@ -56,6 +57,11 @@ pub unsafe fn try_lock(&self) -> bool {
         }
     }
 
+    #[inline]
+    pub unsafe fn try_lock_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_lock())
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {}
 }
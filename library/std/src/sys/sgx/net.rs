@@ -310,6 +310,96 @@ fn from_inner(inner: Socket) -> TcpListener {
     }
 }
 
+pub struct TcpSocket(Void);
+
+impl TcpSocket {
+    pub fn new(_: &SocketAddr) -> io::Result<TcpSocket> {
+        unsupported()
+    }
+
+    pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+        match self.0 {}
+    }
+
+    pub fn set_reuseaddr(&self, _: bool) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn set_reuseport(&self, _: bool) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn set_linger(&self, _: Option<Duration>) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        match self.0 {}
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        match self.0 {}
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        match self.0 {}
+    }
+
+    pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn nodelay(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn bind(&self, _: &SocketAddr) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn listen(self, _: u32) -> io::Result<TcpListener> {
+        match self.0 {}
+    }
+
+    pub fn connect(self, _: &SocketAddr) -> io::Result<TcpStream> {
+        match self.0 {}
+    }
+
+    pub fn connect_timeout(self, _: &SocketAddr, _: Duration) -> io::Result<TcpStream> {
+        match self.0 {}
+    }
+}
+
+impl fmt::Debug for TcpSocket {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
 pub struct UdpSocket(Void);
 
 impl UdpSocket {
@@ -444,6 +534,56 @@ fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+pub struct UdpSocketBuilder(Void);
+
+impl UdpSocketBuilder {
+    pub fn new(_: &SocketAddr) -> io::Result<UdpSocketBuilder> {
+        unsupported()
+    }
+
+    pub fn set_reuseaddr(&self, _: bool) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn set_reuseport(&self, _: bool) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        match self.0 {}
+    }
+
+    pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        match self.0 {}
+    }
+
+    pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+        match self.0 {}
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        match self.0 {}
+    }
+
+    pub fn bind(self, _: &SocketAddr) -> io::Result<UdpSocket> {
+        match self.0 {}
+    }
+}
+
+impl fmt::Debug for UdpSocketBuilder {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
 #[derive(Debug)]
 pub struct NonIpSockAddr {
     host: String,
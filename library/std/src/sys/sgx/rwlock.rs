@@ -7,6 +7,7 @@
     try_lock_or_false, NotifiedTcs, SpinMutex, SpinMutexGuard, WaitQueue, WaitVariable,
 };
 use crate::mem;
+use crate::time::Instant;
 
 pub struct RWLock {
     readers: SpinMutex<WaitVariable<Option<NonZeroUsize>>>,
@@ -61,6 +62,11 @@ pub unsafe fn try_read(&self) -> bool {
         }
     }
 
+    #[inline]
+    pub unsafe fn try_read_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_read())
+    }
+
     #[inline]
     pub unsafe fn write(&self) {
         let rguard = self.readers.lock();
@@ -90,6 +96,11 @@ pub unsafe fn try_write(&self) -> bool {
         }
     }
 
+    #[inline]
+    pub unsafe fn try_write_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_write())
+    }
+
     #[inline]
     unsafe fn __read_unlock(
         &self,
@@ -22,6 +22,10 @@ impl Stdin {
     pub const fn new() -> Stdin {
         Stdin(())
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Read for Stdin {
@@ -34,6 +38,10 @@ impl Stdout {
     pub const fn new() -> Stdout {
         Stdout(())
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stdout {
@@ -50,6 +58,10 @@ impl Stderr {
     pub const fn new() -> Stderr {
         Stderr(())
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stderr {
@@ -47,9 +47,17 @@ pub(super) fn lock() -> MutexGuard<'static, Vec<Task>> {
     }
 }
 
+/// Platform-specific settings for spawning a thread. There are none on this platform.
+#[derive(Debug, Default)]
+pub struct Options;
+
 impl Thread {
     // unsafe: see thread::Builder::spawn_unchecked for safety requirements
-    pub unsafe fn new(_stack: usize, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+    pub unsafe fn new(
+        _stack: usize,
+        _options: &Options,
+        p: Box<dyn FnOnce()>,
+    ) -> io::Result<Thread> {
         let mut queue_lock = task_queue::lock();
         unsafe { usercalls::launch_thread()? };
         let (task, handle) = task_queue::Task::new(p);
@@ -92,8 +92,8 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
 #[derive(Clone)]
 #[stable(feature = "unix_socket", since = "1.10.0")]
 pub struct SocketAddr {
-    addr: libc::sockaddr_un,
-    len: libc::socklen_t,
+    pub(super) addr: libc::sockaddr_un,
+    pub(super) len: libc::socklen_t,
 }
 
 impl SocketAddr {
@@ -196,6 +196,73 @@ pub fn as_pathname(&self) -> Option<&Path> {
         if let AddressKind::Pathname(path) = self.address() { Some(path) } else { None }
     }
 
+    /// Creates an address in the Linux abstract namespace.
+    ///
+    /// Abstract addresses are not bound to a file on the filesystem. The name
+    /// may contain arbitrary bytes, including null bytes, and is removed
+    /// automatically once all sockets bound to it have been closed.
+    ///
+    /// Returns an error if `name` is longer than `SUN_LEN - 1` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_abstract)]
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::{SocketAddr, UnixSeqpacketListener};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let addr = SocketAddr::from_abstract_namespace(b"my-service")?;
+    ///     let listener = UnixSeqpacketListener::bind_addr(&addr)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_abstract", issue = "none")]
+    pub fn from_abstract_namespace(name: &[u8]) -> io::Result<SocketAddr> {
+        unsafe {
+            let mut addr: libc::sockaddr_un = mem::zeroed();
+            addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+
+            if name.len() + 1 > addr.sun_path.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "namespace must be shorter than SUN_LEN",
+                ));
+            }
+            // The leading null byte that marks the address as abstract is
+            // already there because we zeroed the struct.
+            for (dst, src) in addr.sun_path[1..].iter_mut().zip(name.iter()) {
+                *dst = *src as libc::c_char;
+            }
+
+            let len = sun_path_offset(&addr) + 1 + name.len();
+            SocketAddr::from_parts(addr, len as libc::socklen_t)
+        }
+    }
+
+    /// Returns the name of this address if it is in the Linux abstract
+    /// namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_abstract)]
+    /// use std::os::unix::net::SocketAddr;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let addr = SocketAddr::from_abstract_namespace(b"my-service")?;
+    ///     assert_eq!(addr.as_abstract_namespace(), Some(&b"my-service"[..]));
+    ///     assert_eq!(addr.as_pathname(), None);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_abstract", issue = "none")]
+    pub fn as_abstract_namespace(&self) -> Option<&[u8]> {
+        if let AddressKind::Abstract(name) = self.address() { Some(name) } else { None }
+    }
+
     fn address(&self) -> AddressKind<'_> {
         let len = self.len as usize - sun_path_offset(&self.addr);
         let path = unsafe { mem::transmute::<&[libc::c_char], &[u8]>(&self.addr.sun_path) };
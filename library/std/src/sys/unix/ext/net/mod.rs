@@ -26,6 +26,16 @@
 mod datagram;
 mod listener;
 mod raw_fd;
+#[cfg(any(
+    doc,
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod seqpacket;
 mod stream;
 #[cfg(all(test, not(target_os = "emscripten")))]
 mod tests;
@@ -50,5 +60,16 @@
 pub use self::listener::*;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::raw_fd::*;
+#[cfg(any(
+    doc,
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+pub use self::seqpacket::*;
 #[stable(feature = "unix_socket", since = "1.10.0")]
 pub use self::stream::*;
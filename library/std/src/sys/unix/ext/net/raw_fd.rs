@@ -38,3 +38,30 @@ fn into_raw_fd(self) -> RawFd {
     )*};
 }
 impl_into_raw_fd! { TcpStream TcpListener UdpSocket }
+
+macro_rules! impl_raw_fd_unstable {
+    ($($t:ident)*) => {$(
+        #[unstable(feature = "socket_builder", issue = "none")]
+        impl AsRawFd for net::$t {
+            fn as_raw_fd(&self) -> RawFd {
+                *self.as_inner().socket().as_inner()
+            }
+        }
+
+        #[unstable(feature = "socket_builder", issue = "none")]
+        impl FromRawFd for net::$t {
+            unsafe fn from_raw_fd(fd: RawFd) -> net::$t {
+                let socket = sys::net::Socket::from_inner(fd);
+                net::$t::from_inner(sys_common::net::$t::from_inner(socket))
+            }
+        }
+
+        #[unstable(feature = "socket_builder", issue = "none")]
+        impl IntoRawFd for net::$t {
+            fn into_raw_fd(self) -> RawFd {
+                self.into_inner().into_socket().into_inner()
+            }
+        }
+    )*};
+}
+impl_raw_fd_unstable! { TcpSocket UdpSocketBuilder }
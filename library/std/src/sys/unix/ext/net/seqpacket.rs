@@ -0,0 +1,634 @@
+use super::{recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to, SocketAncillary};
+use super::{sockaddr_un, SocketAddr};
+use crate::io::{IoSlice, IoSliceMut};
+use crate::net::Shutdown;
+use crate::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
+#[cfg(any(
+    target_os = "android",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "openbsd"
+))]
+use crate::os::unix::ucred::{self, UCred};
+use crate::path::Path;
+use crate::sys::cvt;
+use crate::sys::net::Socket;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+use crate::{fmt, io, mem};
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd"
+))]
+use libc::MSG_NOSIGNAL;
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd"
+)))]
+const MSG_NOSIGNAL: libc::c_int = 0x0;
+
+/// A Unix domain socket server for `SOCK_SEQPACKET` connections.
+///
+/// Sequenced-packet sockets combine properties of [`UnixStream`] and
+/// [`UnixDatagram`]: like a stream they are connection-oriented and reliable,
+/// delivering messages in order, and like datagrams they preserve message
+/// boundaries, so every [`recv`] returns exactly one message sent by the
+/// peer. Connections are represented by [`UnixSeqpacketConn`].
+///
+/// [`UnixStream`]: super::UnixStream
+/// [`UnixDatagram`]: super::UnixDatagram
+/// [`recv`]: UnixSeqpacketConn::recv
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacketListener;
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+///
+///     loop {
+///         let (conn, _addr) = listener.accept()?;
+///         let mut buf = [0; 4096];
+///         let len = conn.recv(&mut buf)?;
+///         conn.send(&buf[..len])?;
+///     }
+/// }
+/// ```
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+pub struct UnixSeqpacketListener(Socket);
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketListener");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketListener {
+    /// Creates a new `UnixSeqpacketListener` bound to the specified path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// let listener = match UnixSeqpacketListener::bind("/path/to/the/socket") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't bind: {:?}", e);
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+            UnixSeqpacketListener::bind_raw(&addr, len)
+        }
+    }
+
+    /// Creates a new `UnixSeqpacketListener` bound to the specified socket
+    /// address.
+    ///
+    /// Unlike [`bind`], this accepts addresses in the Linux abstract
+    /// namespace, created with [`SocketAddr::from_abstract_namespace`].
+    ///
+    /// [`bind`]: UnixSeqpacketListener::bind
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener1 = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     let addr = listener1.local_addr()?;
+    ///     drop(listener1);
+    ///     std::fs::remove_file("/path/to/the/socket")?;
+    ///
+    ///     let listener2 = UnixSeqpacketListener::bind_addr(&addr)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn bind_addr(addr: &SocketAddr) -> io::Result<UnixSeqpacketListener> {
+        unsafe { UnixSeqpacketListener::bind_raw(&addr.addr, addr.len) }
+    }
+
+    unsafe fn bind_raw(
+        addr: &libc::sockaddr_un,
+        len: libc::socklen_t,
+    ) -> io::Result<UnixSeqpacketListener> {
+        let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+
+        cvt(libc::bind(*inner.as_inner(), addr as *const _ as *const _, len as _))?;
+        cvt(libc::listen(*inner.as_inner(), 128))?;
+
+        Ok(UnixSeqpacketListener(inner))
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new connection is
+    /// established. When established, the corresponding [`UnixSeqpacketConn`]
+    /// and the remote peer's address will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///
+    ///     match listener.accept() {
+    ///         Ok((conn, addr)) => println!("Got a client: {:?}", addr),
+    ///         Err(e) => println!("accept function failed: {:?}", e),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn accept(&self) -> io::Result<(UnixSeqpacketConn, SocketAddr)> {
+        let mut storage: libc::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&storage) as libc::socklen_t;
+        let sock = self.0.accept(&mut storage as *mut _ as *mut _, &mut len)?;
+        let addr = SocketAddr::from_parts(storage, len)?;
+        Ok((UnixSeqpacketConn(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacketListener` is a reference to the same socket
+    /// that this object references. Both handles can be used to accept
+    /// incoming connections and options set on one listener will affect the
+    /// other.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketListener> {
+        self.0.duplicate().map(UnixSeqpacketListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// This will result in the `accept` operation becoming nonblocking,
+    /// i.e., immediately returning from their calls. If the IO operation is
+    /// successful, `Ok` is returned and no further action is required. If the
+    /// IO operation could not be completed and needs to be retried, an error
+    /// with kind [`io::ErrorKind::WouldBlock`] is returned.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacketListener {
+    fn as_raw_fd(&self) -> RawFd {
+        *self.0.as_inner()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacketListener {
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(fd))
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacketListener {
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_inner()
+    }
+}
+
+/// A connected Unix `SOCK_SEQPACKET` socket.
+///
+/// Each call to [`send`] transmits one message, and each call to [`recv`]
+/// receives exactly one. A message that does not fit into the receive buffer
+/// is truncated and the rest of it is discarded; use
+/// [`recv_vectored_with_ancillary`] to find out whether that happened.
+///
+/// Because message boundaries are significant, this type does not implement
+/// [`io::Read`] or [`io::Write`].
+///
+/// [`send`]: UnixSeqpacketConn::send
+/// [`recv`]: UnixSeqpacketConn::recv
+/// [`recv_vectored_with_ancillary`]: UnixSeqpacketConn::recv_vectored_with_ancillary
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacketConn;
+///
+/// fn main() -> std::io::Result<()> {
+///     let conn = UnixSeqpacketConn::connect("/path/to/the/socket")?;
+///     conn.send(b"hello")?;
+///     conn.send(b"world")?;
+///
+///     let mut buf = [0; 4096];
+///     let len = conn.recv(&mut buf)?;
+///     println!("{:?}", &buf[..len]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+pub struct UnixSeqpacketConn(Socket);
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketConn {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketConn");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketConn {
+    /// Connects to the socket named by `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketConn;
+    ///
+    /// let conn = match UnixSeqpacketConn::connect("/tmp/sock") {
+    ///     Ok(conn) => conn,
+    ///     Err(e) => {
+    ///         println!("Couldn't connect: {:?}", e);
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketConn> {
+        unsafe {
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+            UnixSeqpacketConn::connect_raw(&addr, len)
+        }
+    }
+
+    /// Connects to the socket at the specified address.
+    ///
+    /// Unlike [`connect`], this accepts addresses in the Linux abstract
+    /// namespace, created with [`SocketAddr::from_abstract_namespace`].
+    ///
+    /// [`connect`]: UnixSeqpacketConn::connect
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn connect_addr(addr: &SocketAddr) -> io::Result<UnixSeqpacketConn> {
+        unsafe { UnixSeqpacketConn::connect_raw(&addr.addr, addr.len) }
+    }
+
+    unsafe fn connect_raw(
+        addr: &libc::sockaddr_un,
+        len: libc::socklen_t,
+    ) -> io::Result<UnixSeqpacketConn> {
+        let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+        cvt(libc::connect(*inner.as_inner(), addr as *const _ as *const _, len))?;
+        Ok(UnixSeqpacketConn(inner))
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketConn;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let (conn1, conn2) = UnixSeqpacketConn::pair()?;
+    ///     conn1.send(b"ping")?;
+    ///     let mut buf = [0; 16];
+    ///     let len = conn2.recv(&mut buf)?;
+    ///     assert_eq!(&buf[..len], b"ping");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn pair() -> io::Result<(UnixSeqpacketConn, UnixSeqpacketConn)> {
+        let (i1, i2) = Socket::new_pair(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+        Ok((UnixSeqpacketConn(i1), UnixSeqpacketConn(i2)))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacketConn` is a reference to the same connection
+    /// that this object references. A message is received by only one of the
+    /// handles, and options set on one handle will be propagated to the other.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketConn> {
+        self.0.duplicate().map(UnixSeqpacketConn)
+    }
+
+    /// Returns the socket address of the local half of this connection.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Returns the socket address of the remote half of this connection.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Gets the peer credentials for this connection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// #![feature(peer_credentials_unix_socket)]
+    /// use std::os::unix::net::UnixSeqpacketConn;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let conn = UnixSeqpacketConn::connect("/tmp/sock")?;
+    ///     let peer_cred = conn.peer_cred().expect("Couldn't get peer credentials");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "42839", reason = "unstable")]
+    #[cfg(any(
+        target_os = "android",
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "openbsd"
+    ))]
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        ucred::peer_cred(self)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`recv`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`recv`]: UnixSeqpacketConn::recv
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`send`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`send`]: UnixSeqpacketConn::send
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Moves the socket to pass unix credentials as control message in [`SocketAncillary`].
+    ///
+    /// Set the socket option `SO_PASSCRED`.
+    #[cfg(any(doc, target_os = "android", target_os = "linux",))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
+        self.0.set_passcred(passcred)
+    }
+
+    /// Get the current value of the socket for passing unix credentials in [`SocketAncillary`].
+    /// This value can be change by [`set_passcred`].
+    ///
+    /// Get the socket option `SO_PASSCRED`.
+    ///
+    /// [`set_passcred`]: UnixSeqpacketConn::set_passcred
+    #[cfg(any(doc, target_os = "android", target_os = "linux",))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passcred(&self) -> io::Result<bool> {
+        self.0.passcred()
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of [`Shutdown`]).
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.0.shutdown(how)
+    }
+
+    /// Sends `buf` as a single message to the peer.
+    ///
+    /// On success, returns the number of bytes written, which is always the
+    /// full length of `buf`. Messages larger than the socket's send buffer
+    /// are rejected with an error.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        let count = cvt(unsafe {
+            libc::send(*self.0.as_inner(), buf.as_ptr() as *const _, buf.len(), MSG_NOSIGNAL)
+        })?;
+        Ok(count as usize)
+    }
+
+    /// Like [`send`], except that the message is gathered from a slice of
+    /// buffers.
+    ///
+    /// [`send`]: UnixSeqpacketConn::send
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn send_vectored(&self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        // `writev` would raise `SIGPIPE` if the peer is gone, like `write`.
+        let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+        msg.msg_iov = bufs.as_ptr() as *mut libc::iovec;
+        msg.msg_iovlen = bufs.len() as _;
+        let count = cvt(unsafe { libc::sendmsg(*self.0.as_inner(), &msg, MSG_NOSIGNAL) })?;
+        Ok(count as usize)
+    }
+
+    /// Receives a single message from the peer.
+    ///
+    /// On success, returns the number of bytes read. If the message is longer
+    /// than `buf`, the excess bytes are discarded. `Ok(0)` is returned once
+    /// the peer has shut down its writing half, but an empty message sent by
+    /// the peer is also received as `Ok(0)`.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Like [`recv`], except that the message is scattered into a slice of
+    /// buffers.
+    ///
+    /// [`recv`]: UnixSeqpacketConn::recv
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn recv_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    /// Receives the next message without removing it from the queue.
+    ///
+    /// On success, returns the number of bytes peeked. Successive calls return
+    /// the same message.
+    #[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
+    /// Receives a single message and its ancillary data from the socket.
+    ///
+    /// On success, returns the number of bytes read and whether the message
+    /// was truncated because it did not fit into `bufs`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{AncillaryData, SocketAncillary, UnixSeqpacketConn};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let conn = UnixSeqpacketConn::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 4096];
+    ///     let mut bufs = &mut [IoSliceMut::new(&mut buf)][..];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     let (size, truncated) = conn.recv_vectored_with_ancillary(bufs, &mut ancillary)?;
+    ///     println!("received {}, truncated: {}", size, truncated);
+    ///     for ancillary_result in ancillary.messages() {
+    ///         if let AncillaryData::ScmRights(scm_rights) = ancillary_result.unwrap() {
+    ///             for fd in scm_rights {
+    ///                 println!("receive file descriptor: {}", fd);
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn recv_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<(usize, bool)> {
+        let (count, truncated, _) = recv_vectored_with_ancillary_from(&self.0, bufs, ancillary)?;
+
+        Ok((count, truncated))
+    }
+
+    /// Sends a single message and ancillary data on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_seqpacket)]
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{SocketAncillary, UnixSeqpacketConn};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let conn = UnixSeqpacketConn::connect("/tmp/sock")?;
+    ///     let mut buf = *b"here are some fds";
+    ///     let mut bufs = &mut [IoSliceMut::new(&mut buf)][..];
+    ///     let fds = [0, 1, 2];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_fds(&fds[..]);
+    ///     conn.send_vectored_with_ancillary(bufs, &mut ancillary)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn send_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacketConn {
+    fn as_raw_fd(&self) -> RawFd {
+        *self.0.as_inner()
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacketConn {
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacketConn {
+        UnixSeqpacketConn(Socket::from_inner(fd))
+    }
+}
+
+#[unstable(feature = "unix_socket_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacketConn {
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_inner()
+    }
+}
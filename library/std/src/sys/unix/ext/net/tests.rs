@@ -638,3 +638,174 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
         unreachable!("must be ScmRights");
     }
 }
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn seqpacket_pair_preserves_boundaries() {
+    let (s1, s2) = or_panic!(UnixSeqpacketConn::pair());
+
+    assert_eq!(or_panic!(s1.send(b"hello")), 5);
+    assert_eq!(or_panic!(s1.send(b"world!")), 6);
+
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(s2.peek(&mut buf)), 5);
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 5);
+    assert_eq!(&buf[..5], b"hello");
+    // A short buffer truncates the message rather than leaving the rest queued.
+    assert_eq!(or_panic!(s2.recv(&mut buf[..3])), 3);
+    assert_eq!(&buf[..3], b"wor");
+
+    or_panic!(s1.shutdown(crate::net::Shutdown::Write));
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 0);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn seqpacket_send_vectored() {
+    let (s1, s2) = or_panic!(UnixSeqpacketConn::pair());
+
+    let bufs = [IoSlice::new(b"hello "), IoSlice::new(b"world")];
+    assert_eq!(or_panic!(s1.send_vectored(&bufs)), 11);
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 11);
+    assert_eq!(&buf[..11], b"hello world");
+
+    // Sending to a closed peer fails instead of raising `SIGPIPE`.
+    drop(s2);
+    let err = s1.send_vectored(&bufs).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn seqpacket_listener() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let (conn, _addr) = or_panic!(listener.accept());
+        let mut buf = [0; 16];
+        let len = or_panic!(conn.recv(&mut buf));
+        or_panic!(conn.send(&buf[..len]));
+    });
+
+    let conn = or_panic!(UnixSeqpacketConn::connect(&socket_path));
+    assert_eq!(Some(&*socket_path), or_panic!(conn.peer_addr()).as_pathname());
+    or_panic!(conn.send(b"ping"));
+    let mut buf = [0; 16];
+    let len = or_panic!(conn.recv(&mut buf));
+    assert_eq!(&buf[..len], b"ping");
+
+    thread.join().unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn seqpacket_abstract_namespace() {
+    let name = format!("rust-seqpacket-test-{}", crate::process::id());
+    let addr = or_panic!(SocketAddr::from_abstract_namespace(name.as_bytes()));
+    assert_eq!(addr.as_abstract_namespace(), Some(name.as_bytes()));
+    assert_eq!(addr.as_pathname(), None);
+    assert!(!addr.is_unnamed());
+
+    let listener = or_panic!(UnixSeqpacketListener::bind_addr(&addr));
+    assert_eq!(or_panic!(listener.local_addr()).as_abstract_namespace(), Some(name.as_bytes()));
+
+    let conn = or_panic!(UnixSeqpacketConn::connect_addr(&addr));
+    let (accepted, _addr) = or_panic!(listener.accept());
+    or_panic!(conn.send(b"abstract"));
+    let mut buf = [0; 16];
+    let len = or_panic!(accepted.recv(&mut buf));
+    assert_eq!(&buf[..len], b"abstract");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn abstract_namespace_too_long() {
+    let name = [b'x'; 200];
+    assert!(SocketAddr::from_abstract_namespace(&name).is_err());
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_send_vectored_fds_unix_seqpacket() {
+    let (s1, s2) = or_panic!(UnixSeqpacketConn::pair());
+
+    let mut buf1 = [1; 8];
+    let mut bufs_send = &mut [IoSliceMut::new(&mut buf1[..])][..];
+
+    let mut ancillary1_buffer = [0; 128];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_fds(&[s1.as_raw_fd()][..]));
+
+    let usize = or_panic!(s1.send_vectored_with_ancillary(&mut bufs_send, &mut ancillary1));
+    assert_eq!(usize, 8);
+
+    let mut buf2 = [0; 8];
+    let mut bufs_recv = &mut [IoSliceMut::new(&mut buf2[..])][..];
+
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+
+    let (usize, truncated) =
+        or_panic!(s2.recv_vectored_with_ancillary(&mut bufs_recv, &mut ancillary2));
+    assert_eq!(usize, 8);
+    assert!(!truncated);
+    assert_eq!(buf1, buf2);
+
+    let mut ancillary_data_vec = Vec::from_iter(ancillary2.messages());
+    assert_eq!(ancillary_data_vec.len(), 1);
+    if let AncillaryData::ScmRights(scm_rights) = ancillary_data_vec.pop().unwrap().unwrap() {
+        let fd_vec = Vec::from_iter(scm_rights);
+        assert_eq!(fd_vec.len(), 1);
+        unsafe {
+            libc::close(fd_vec[0]);
+        }
+    } else {
+        unreachable!("must be ScmRights");
+    }
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "openbsd",
+))]
+#[test]
+fn seqpacket_peer_cred() {
+    let (s1, _s2) = or_panic!(UnixSeqpacketConn::pair());
+    let cred = or_panic!(s1.peer_cred());
+    assert_eq!(cred.uid, unsafe { libc::getuid() });
+    assert_eq!(cred.gid, unsafe { libc::getgid() });
+}
@@ -2,10 +2,18 @@
 
 #![stable(feature = "thread_extensions", since = "1.9.0")]
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::fmt;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::io;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::mem;
 #[allow(deprecated)]
 use crate::os::unix::raw::pthread_t;
-use crate::sys_common::{AsInner, IntoInner};
-use crate::thread::JoinHandle;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::sys;
+use crate::sys_common::{AsInner, AsInnerMut, IntoInner};
+use crate::thread::{Builder, JoinHandle};
 
 #[stable(feature = "thread_extensions", since = "1.9.0")]
 #[allow(deprecated)]
@@ -37,3 +45,273 @@ fn into_pthread_t(self) -> RawPthread {
         self.into_inner().into_id() as RawPthread
     }
 }
+
+mod private {
+    /// This trait being unreachable from outside the crate
+    /// prevents other implementations of the `BuilderExt` trait,
+    /// which allows potentially adding more trait methods in the future.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub trait Sealed {}
+}
+
+/// Unix-specific extensions to [`thread::Builder`].
+///
+/// The settings are applied before the spawned thread runs any of its code,
+/// so there is no window in which it runs with the default settings. If a
+/// setting cannot be applied, [`Builder::spawn`] returns an error and the
+/// closure is never called.
+///
+/// [`thread::Builder`]: crate::thread::Builder
+///
+/// # Examples
+///
+/// ```
+/// #![feature(thread_builder_ext)]
+/// use std::os::unix::thread::BuilderExt;
+/// use std::thread;
+///
+/// let handle = thread::Builder::new()
+///     .stack_size(64 * 1024)
+///     .guard_size(16 * 1024)
+///     .spawn(|| {
+///         // thread code
+///     })
+///     .unwrap();
+/// handle.join().unwrap();
+/// ```
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+pub trait BuilderExt: private::Sealed {
+    /// Restricts the thread to run only on the given set of CPUs.
+    ///
+    /// This corresponds to `sched_setaffinity(2)`. Spawning fails if none of
+    /// the CPUs in the set is available to the process.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_builder_ext)]
+    /// use std::os::unix::thread::{BuilderExt, CpuSet};
+    /// use std::thread;
+    ///
+    /// let mut cpus = CpuSet::new();
+    /// cpus.insert(0);
+    /// let handle = thread::Builder::new()
+    ///     .name("pinned".into())
+    ///     .affinity(cpus)
+    ///     .spawn(|| {
+    ///         // latency-critical work here
+    ///     })
+    ///     .unwrap();
+    /// handle.join().unwrap();
+    /// ```
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    fn affinity(self, cpus: CpuSet) -> Self;
+
+    /// Sets the scheduling policy of the thread.
+    ///
+    /// Unless a [`priority`](BuilderExt::priority) is set as well, the thread
+    /// gets the lowest priority allowed for the policy. Real-time policies
+    /// usually require privileges, without which spawning fails.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    fn sched_policy(self, policy: SchedPolicy) -> Self;
+
+    /// Sets the static scheduling priority of the thread.
+    ///
+    /// This is the `sched_priority` passed to `pthread_setschedparam(3)`. Its
+    /// valid range depends on the scheduling policy: it must be `0` for the
+    /// non-real-time policies, and within `1..=99` for [`SchedPolicy::Fifo`]
+    /// and [`SchedPolicy::RoundRobin`] on Linux.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    fn priority(self, priority: i32) -> Self;
+
+    /// Sets the size in bytes of the guard area below the thread's stack.
+    ///
+    /// A guard size of zero disables the guard area, which means that a stack
+    /// overflow is no longer detected. Has no effect on platforms where the
+    /// standard library does not set up stack guards.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    fn guard_size(self, size: usize) -> Self;
+}
+
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+impl private::Sealed for Builder {}
+
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+impl BuilderExt for Builder {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn affinity(mut self, cpus: CpuSet) -> Self {
+        self.as_inner_mut().affinity(cpus.set);
+        self
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn sched_policy(mut self, policy: SchedPolicy) -> Self {
+        self.as_inner_mut().sched_policy(policy.as_raw());
+        self
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn priority(mut self, priority: i32) -> Self {
+        self.as_inner_mut().priority(priority as libc::c_int);
+        self
+    }
+
+    fn guard_size(mut self, size: usize) -> Self {
+        self.as_inner_mut().guard_size(size);
+        self
+    }
+}
+
+/// A thread scheduling policy, see `sched(7)`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SchedPolicy {
+    /// The default time-sharing policy, `SCHED_OTHER`.
+    Other,
+    /// First-in, first-out real-time scheduling, `SCHED_FIFO`.
+    Fifo,
+    /// Round-robin real-time scheduling, `SCHED_RR`.
+    RoundRobin,
+    /// Like [`Other`](SchedPolicy::Other), but for CPU-intensive batch work,
+    /// `SCHED_BATCH`.
+    Batch,
+    /// For very low priority background work, `SCHED_IDLE`.
+    Idle,
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl SchedPolicy {
+    fn as_raw(self) -> libc::c_int {
+        match self {
+            SchedPolicy::Other => libc::SCHED_OTHER,
+            SchedPolicy::Fifo => libc::SCHED_FIFO,
+            SchedPolicy::RoundRobin => libc::SCHED_RR,
+            SchedPolicy::Batch => libc::SCHED_BATCH,
+            SchedPolicy::Idle => libc::SCHED_IDLE,
+        }
+    }
+}
+
+/// A set of CPUs, used for the affinity of a thread.
+///
+/// CPUs are identified by their index, starting at `0`. The set can hold
+/// indices below [`CpuSet::capacity`].
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+#[derive(Copy, Clone)]
+pub struct CpuSet {
+    set: libc::cpu_set_t,
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl CpuSet {
+    /// Creates an empty set.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn new() -> CpuSet {
+        CpuSet { set: unsafe { mem::zeroed() } }
+    }
+
+    /// Returns the number of CPUs the set can hold.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn capacity() -> usize {
+        mem::size_of::<libc::cpu_set_t>() * 8
+    }
+
+    /// Adds a CPU to the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cpu` is not below [`CpuSet::capacity`].
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn insert(&mut self, cpu: usize) {
+        assert!(cpu < CpuSet::capacity(), "CPU index out of range");
+        unsafe { libc::CPU_SET(cpu, &mut self.set) }
+    }
+
+    /// Removes a CPU from the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cpu` is not below [`CpuSet::capacity`].
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn remove(&mut self, cpu: usize) {
+        assert!(cpu < CpuSet::capacity(), "CPU index out of range");
+        unsafe { libc::CPU_CLR(cpu, &mut self.set) }
+    }
+
+    /// Returns whether the set contains a CPU.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn contains(&self, cpu: usize) -> bool {
+        cpu < CpuSet::capacity() && unsafe { libc::CPU_ISSET(cpu, &self.set) }
+    }
+
+    /// Returns an iterator over the CPUs in the set, in ascending order.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..CpuSet::capacity()).filter(move |&cpu| self.contains(cpu))
+    }
+
+    /// Returns the number of CPUs in the set.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns whether the set is empty.
+    #[unstable(feature = "thread_builder_ext", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+impl Default for CpuSet {
+    fn default() -> CpuSet {
+        CpuSet::new()
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+impl PartialEq for CpuSet {
+    fn eq(&self, other: &CpuSet) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+impl Eq for CpuSet {}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+impl fmt::Debug for CpuSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Returns the set of CPUs the current thread is allowed to run on.
+///
+/// This corresponds to `sched_getaffinity(2)`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(thread_builder_ext)]
+/// use std::os::unix::thread;
+///
+/// let cpus = thread::current_affinity().unwrap();
+/// assert!(!cpus.is_empty());
+/// ```
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "thread_builder_ext", issue = "none")]
+pub fn current_affinity() -> io::Result<CpuSet> {
+    sys::thread::current_affinity().map(|set| CpuSet { set })
+}
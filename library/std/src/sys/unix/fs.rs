@@ -882,6 +882,10 @@ pub fn fd(&self) -> &FileDesc {
         &self.0
     }
 
+    pub fn is_terminal(&self) -> bool {
+        self.0.is_terminal()
+    }
+
     pub fn into_fd(self) -> FileDesc {
         self.0
     }
@@ -75,3 +75,15 @@ pub fn futex_wake(futex: &AtomicI32) {
         emscripten_futex_wake(futex as *const AtomicI32, 1);
     }
 }
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn futex_wake_all(futex: &AtomicI32) {
+    unsafe {
+        libc::syscall(
+            libc::SYS_futex,
+            futex as *const AtomicI32,
+            libc::FUTEX_WAKE | libc::FUTEX_PRIVATE_FLAG,
+            i32::MAX,
+        );
+    }
+}
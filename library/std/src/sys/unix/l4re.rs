@@ -314,6 +314,112 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 
+    pub struct TcpSocket {
+        inner: Socket,
+    }
+
+    impl TcpSocket {
+        pub fn new(_: &SocketAddr) -> io::Result<TcpSocket> {
+            unimpl!();
+        }
+
+        pub fn socket(&self) -> &Socket {
+            &self.inner
+        }
+
+        pub fn into_socket(self) -> Socket {
+            self.inner
+        }
+
+        pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+            unimpl!();
+        }
+
+        pub fn set_reuseaddr(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseaddr(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_reuseport(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseport(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive(&self, _: Option<Duration>) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_linger(&self, _: Option<Duration>) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn linger(&self) -> io::Result<Option<Duration>> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn nodelay(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn bind(&self, _: &SocketAddr) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn listen(self, _: u32) -> io::Result<TcpListener> {
+            unimpl!();
+        }
+
+        pub fn connect(self, _: &SocketAddr) -> io::Result<TcpStream> {
+            unimpl!();
+        }
+
+        pub fn connect_timeout(self, _: &SocketAddr, _: Duration) -> io::Result<TcpStream> {
+            unimpl!();
+        }
+    }
+
+    impl FromInner<Socket> for TcpSocket {
+        fn from_inner(socket: Socket) -> TcpSocket {
+            TcpSocket { inner: socket }
+        }
+    }
+
+    impl fmt::Debug for TcpSocket {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "No networking support available on L4Re.")
+        }
+    }
+
     pub struct UdpSocket {
         inner: Socket,
     }
@@ -464,6 +570,72 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 
+    pub struct UdpSocketBuilder {
+        inner: Socket,
+    }
+
+    impl UdpSocketBuilder {
+        pub fn new(_: &SocketAddr) -> io::Result<UdpSocketBuilder> {
+            unimpl!();
+        }
+
+        pub fn socket(&self) -> &Socket {
+            &self.inner
+        }
+
+        pub fn into_socket(self) -> Socket {
+            self.inner
+        }
+
+        pub fn set_reuseaddr(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseaddr(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_reuseport(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuseport(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn bind(self, _: &SocketAddr) -> io::Result<UdpSocket> {
+            unimpl!();
+        }
+    }
+
+    impl FromInner<Socket> for UdpSocketBuilder {
+        fn from_inner(socket: Socket) -> UdpSocketBuilder {
+            UdpSocketBuilder { inner: socket }
+        }
+    }
+
+    impl fmt::Debug for UdpSocketBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "No networking support available on L4Re.")
+        }
+    }
+
     pub struct LookupHost {
         original: *mut libc::addrinfo,
         cur: *mut libc::addrinfo,
@@ -1,6 +1,23 @@
 use crate::cell::UnsafeCell;
 use crate::mem::MaybeUninit;
 use crate::sys::cvt_nz;
+use crate::time::Instant;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+    target_os = "illumos"
+))]
+extern "C" {
+    fn pthread_mutex_timedlock(
+        mutex: *mut libc::pthread_mutex_t,
+        abstime: *const libc::timespec,
+    ) -> libc::c_int;
+}
 
 pub struct Mutex {
     inner: UnsafeCell<libc::pthread_mutex_t>,
@@ -72,6 +89,32 @@ pub unsafe fn unlock(&self) {
     pub unsafe fn try_lock(&self) -> bool {
         libc::pthread_mutex_trylock(self.inner.get()) == 0
     }
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    ))]
+    pub unsafe fn try_lock_until(&self, deadline: Instant) -> bool {
+        let r = lock_until(deadline, |abstime| pthread_mutex_timedlock(self.inner.get(), abstime));
+        debug_assert!(r == 0 || r == libc::ETIMEDOUT || r == libc::EDEADLK);
+        r == 0
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    )))]
+    pub unsafe fn try_lock_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_lock())
+    }
     #[inline]
     #[cfg(not(target_os = "dragonfly"))]
     pub unsafe fn destroy(&self) {
@@ -90,6 +133,50 @@ pub unsafe fn destroy(&self) {
     }
 }
 
+/// Calls `timed_lock`, one of the timed pthread lock functions, with `deadline`
+/// as an absolute time until it stops timing out or `deadline` has passed, and
+/// returns its result.
+///
+/// These functions measure the timeout with the realtime clock, which can be
+/// changed while waiting, so a timeout only counts once `Instant` agrees.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+    target_os = "illumos"
+))]
+pub unsafe fn lock_until(
+    deadline: Instant,
+    mut timed_lock: impl FnMut(*const libc::timespec) -> libc::c_int,
+) -> libc::c_int {
+    use crate::convert::TryFrom;
+
+    loop {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let mut now = MaybeUninit::<libc::timespec>::uninit();
+        let r = libc::clock_gettime(libc::CLOCK_REALTIME, now.as_mut_ptr());
+        debug_assert_eq!(r, 0);
+        let now = now.assume_init();
+
+        // Nanosecond calculations can't overflow because both values are below 1e9.
+        let nsec = timeout.subsec_nanos() + now.tv_nsec as u32;
+        let sec = <libc::time_t>::try_from(timeout.as_secs())
+            .ok()
+            .and_then(|s| s.checked_add((nsec / 1_000_000_000) as libc::time_t))
+            .and_then(|s| s.checked_add(now.tv_sec))
+            .unwrap_or(<libc::time_t>::MAX);
+        let abstime = libc::timespec { tv_sec: sec, tv_nsec: (nsec % 1_000_000_000) as _ };
+
+        let r = timed_lock(&abstime);
+        if r != libc::ETIMEDOUT || Instant::now() >= deadline {
+            return r;
+        }
+    }
+}
+
 pub struct ReentrantMutex {
     inner: UnsafeCell<libc::pthread_mutex_t>,
 }
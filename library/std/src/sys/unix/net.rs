@@ -13,6 +13,22 @@
 
 pub use crate::sys::{cvt, cvt_r};
 
+// Apple's `SO_LINGER` counts in clock ticks rather than seconds.
+#[cfg(not(target_vendor = "apple"))]
+use libc::SO_LINGER;
+#[cfg(target_vendor = "apple")]
+use libc::SO_LINGER_SEC as SO_LINGER;
+
+cfg_if::cfg_if! {
+    if #[cfg(target_vendor = "apple")] {
+        use libc::TCP_KEEPALIVE as KEEPALIVE_TIME;
+    } else if #[cfg(any(
+        target_os = "android", target_os = "dragonfly", target_os = "freebsd",
+        target_os = "fuchsia", target_os = "linux", target_os = "netbsd"))] {
+        use libc::TCP_KEEPIDLE as KEEPALIVE_TIME;
+    }
+}
+
 #[allow(unused_extern_crates)]
 pub extern crate libc as netc;
 
@@ -379,6 +395,107 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT, reuseport as c_int)
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(self, libc::SOL_SOCKET, libc::SO_REUSEPORT)?;
+        Ok(raw != 0)
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )))]
+    pub fn set_reuseport(&self, _reuseport: bool) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "SO_REUSEPORT is not supported on this platform"))
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )))]
+    pub fn reuseport(&self) -> io::Result<bool> {
+        Err(io::Error::new(io::ErrorKind::Other, "SO_REUSEPORT is not supported on this platform"))
+    }
+
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        // Not every platform allows changing the idle time of a connection
+        // before the first probe is sent; there keepalive is just turned on,
+        // using the system default. The idle time is set first, so that the
+        // socket is left unchanged if it is rejected.
+        #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "fuchsia",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+        ))]
+        if let Some(idle) = keepalive {
+            // Linux rejects idle times above `MAX_TCP_KEEPIDLE`.
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            const MAX_SECS: u64 = 32767;
+            #[cfg(not(any(target_os = "android", target_os = "linux")))]
+            const MAX_SECS: u64 = c_int::MAX as u64;
+            let secs = cmp::max(1, cmp::min(idle.as_secs(), MAX_SECS)) as c_int;
+            setsockopt(self, libc::IPPROTO_TCP, KEEPALIVE_TIME, secs)?;
+        }
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_KEEPALIVE, keepalive.is_some() as c_int)
+    }
+
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        let linger = libc::linger {
+            l_onoff: linger.is_some() as c_int,
+            l_linger: linger.map_or(0, |dur| cmp::min(dur.as_secs(), c_int::MAX as u64) as c_int),
+        };
+        setsockopt(self, libc::SOL_SOCKET, SO_LINGER, linger)
+    }
+
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        let val: libc::linger = getsockopt(self, libc::SOL_SOCKET, SO_LINGER)?;
+        Ok(if val.l_onoff != 0 { Some(Duration::from_secs(val.l_linger as u64)) } else { None })
+    }
+
     #[cfg(any(target_os = "android", target_os = "linux",))]
     pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
         setsockopt(self, libc::SOL_SOCKET, libc::SO_PASSCRED, passcred as libc::c_int)
@@ -38,7 +38,19 @@ pub fn anon_pipe() -> io::Result<(AnonPipe, AnonPipe)> {
     }
 }
 
+/// Creates the pipe backing `io::pipe`, returning its read and write ends.
+///
+/// Both ends have `CLOEXEC` set, just like the pipes `Command` creates for
+/// stdio; they are only passed on to a child when converted into `Stdio`.
+pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    anon_pipe()
+}
+
 impl AnonPipe {
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        self.0.duplicate().map(AnonPipe)
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -1,5 +1,26 @@
 use crate::cell::UnsafeCell;
 use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::time::Instant;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+    target_os = "illumos"
+))]
+extern "C" {
+    fn pthread_rwlock_timedrdlock(
+        rwlock: *mut libc::pthread_rwlock_t,
+        abstime: *const libc::timespec,
+    ) -> libc::c_int;
+    fn pthread_rwlock_timedwrlock(
+        rwlock: *mut libc::pthread_rwlock_t,
+        abstime: *const libc::timespec,
+    ) -> libc::c_int;
+}
 
 pub struct RWLock {
     inner: UnsafeCell<libc::pthread_rwlock_t>,
@@ -68,6 +89,44 @@ pub unsafe fn try_read(&self) -> bool {
             false
         }
     }
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    ))]
+    pub unsafe fn try_read_until(&self, deadline: Instant) -> bool {
+        let r = crate::sys::mutex::lock_until(deadline, |abstime| {
+            pthread_rwlock_timedrdlock(self.inner.get(), abstime)
+        });
+        // See `try_read` for why `write_locked` is checked.
+        if r == 0 {
+            if *self.write_locked.get() {
+                self.raw_unlock();
+                false
+            } else {
+                self.num_readers.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+        } else {
+            false
+        }
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    )))]
+    pub unsafe fn try_read_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_read())
+    }
     #[inline]
     pub unsafe fn write(&self) {
         let r = libc::pthread_rwlock_wrlock(self.inner.get());
@@ -107,6 +166,44 @@ pub unsafe fn try_write(&self) -> bool {
             false
         }
     }
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    ))]
+    pub unsafe fn try_write_until(&self, deadline: Instant) -> bool {
+        let r = crate::sys::mutex::lock_until(deadline, |abstime| {
+            pthread_rwlock_timedwrlock(self.inner.get(), abstime)
+        });
+        // See `write` for why `write_locked` and `num_readers` are checked.
+        if r == 0 {
+            if *self.write_locked.get() || self.num_readers.load(Ordering::Relaxed) != 0 {
+                self.raw_unlock();
+                false
+            } else {
+                *self.write_locked.get() = true;
+                true
+            }
+        } else {
+            false
+        }
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+        target_os = "illumos"
+    )))]
+    pub unsafe fn try_write_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_write())
+    }
     #[inline]
     unsafe fn raw_unlock(&self) {
         let r = libc::pthread_rwlock_unlock(self.inner.get());
@@ -10,6 +10,10 @@ impl Stdin {
     pub const fn new() -> Stdin {
         Stdin(())
     }
+
+    pub fn is_terminal(&self) -> bool {
+        unsafe { libc::isatty(libc::STDIN_FILENO) != 0 }
+    }
 }
 
 impl io::Read for Stdin {
@@ -31,6 +35,10 @@ impl Stdout {
     pub const fn new() -> Stdout {
         Stdout(())
     }
+
+    pub fn is_terminal(&self) -> bool {
+        unsafe { libc::isatty(libc::STDOUT_FILENO) != 0 }
+    }
 }
 
 impl io::Write for Stdout {
@@ -56,6 +64,10 @@ impl Stderr {
     pub const fn new() -> Stderr {
         Stderr(())
     }
+
+    pub fn is_terminal(&self) -> bool {
+        unsafe { libc::isatty(libc::STDERR_FILENO) != 0 }
+    }
 }
 
 impl io::Write for Stderr {
@@ -1,5 +1,6 @@
 use crate::cmp;
 use crate::ffi::CStr;
+use crate::fmt;
 use crate::io;
 use crate::mem;
 use crate::ptr;
@@ -22,9 +23,54 @@ pub struct Thread {
 unsafe impl Send for Thread {}
 unsafe impl Sync for Thread {}
 
+/// Unix-specific settings for spawning a thread, see `os::unix::thread::BuilderExt`.
+#[derive(Default)]
+pub struct Options {
+    guard_size: Option<usize>,
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    setup: setup::Setup,
+}
+
+impl Options {
+    pub fn guard_size(&mut self, size: usize) {
+        self.guard_size = Some(size);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn affinity(&mut self, cpus: libc::cpu_set_t) {
+        self.setup.affinity = Some(cpus);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn sched_policy(&mut self, policy: libc::c_int) {
+        self.setup.sched_policy = Some(policy);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn priority(&mut self, priority: libc::c_int) {
+        self.setup.priority = Some(priority);
+    }
+}
+
+impl fmt::Debug for Options {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Options");
+        debug.field("guard_size", &self.guard_size);
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        debug
+            .field("affinity", &self.setup.affinity.is_some())
+            .field("sched_policy", &self.setup.sched_policy)
+            .field("priority", &self.setup.priority);
+        debug.finish()
+    }
+}
+
 impl Thread {
     // unsafe: see thread::Builder::spawn_unchecked for safety requirements
-    pub unsafe fn new(stack: usize, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+    pub unsafe fn new(stack: usize, options: &Options, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let (p, setup) = options.setup.wrap(p);
+
         let p = Box::into_raw(box p);
         let mut native: libc::pthread_t = mem::zeroed();
         let mut attr: libc::pthread_attr_t = mem::zeroed();
@@ -47,20 +93,34 @@ pub unsafe fn new(stack: usize, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
             }
         };
 
+        if let Some(guard_size) = options.guard_size {
+            set_guard_size(&mut attr, guard_size);
+        }
+
         let ret = libc::pthread_create(&mut native, &attr, thread_start, p as *mut _);
         // Note: if the thread creation fails and this assert fails, then p will
         // be leaked. However, an alternative design could cause double-free
         // which is clearly worse.
         assert_eq!(libc::pthread_attr_destroy(&mut attr), 0);
 
-        return if ret != 0 {
+        if ret != 0 {
             // The thread failed to start and as a result p was not consumed. Therefore, it is
             // safe to reconstruct the box so that it gets deallocated.
             drop(Box::from_raw(p));
-            Err(io::Error::from_raw_os_error(ret))
-        } else {
-            Ok(Thread { id: native })
-        };
+            return Err(io::Error::from_raw_os_error(ret));
+        }
+
+        // If the thread could not apply its settings, it exits without running
+        // `p`, so it can be joined right away.
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Some(setup) = setup {
+            if let Err(err) = setup.wait() {
+                assert_eq!(libc::pthread_join(native, ptr::null_mut()), 0);
+                return Err(err);
+            }
+        }
+
+        return Ok(Thread { id: native });
 
         extern "C" fn thread_start(main: *mut libc::c_void) -> *mut libc::c_void {
             unsafe {
@@ -198,6 +258,124 @@ fn drop(&mut self) {
     }
 }
 
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "macos",
+    all(target_os = "netbsd", not(target_vendor = "rumprun")),
+    target_os = "openbsd",
+    target_os = "solaris"
+))]
+unsafe fn set_guard_size(attr: &mut libc::pthread_attr_t, size: usize) {
+    assert_eq!(libc::pthread_attr_setguardsize(attr, size), 0);
+}
+
+// Platforms without stack guard support keep their default guard.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "macos",
+    all(target_os = "netbsd", not(target_vendor = "rumprun")),
+    target_os = "openbsd",
+    target_os = "solaris"
+)))]
+unsafe fn set_guard_size(_attr: &mut libc::pthread_attr_t, _size: usize) {}
+
+/// Thread settings which can only be applied by the thread itself.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod setup {
+    use crate::io;
+    use crate::mem;
+    use crate::sync::{Arc, Condvar, Mutex};
+    use crate::sys::{cvt, cvt_nz};
+
+    #[derive(Clone, Copy, Default)]
+    pub struct Setup {
+        pub affinity: Option<libc::cpu_set_t>,
+        pub sched_policy: Option<libc::c_int>,
+        pub priority: Option<libc::c_int>,
+    }
+
+    /// The outcome of applying the settings, reported back to the spawning thread.
+    pub struct Report {
+        result: Mutex<Option<io::Result<()>>>,
+        done: Condvar,
+    }
+
+    impl Setup {
+        /// Wraps the thread's main function so that the settings are applied
+        /// before it runs. If there is nothing to apply, `p` is returned as is.
+        pub fn wrap(&self, p: Box<dyn FnOnce()>) -> (Box<dyn FnOnce()>, Option<Arc<Report>>) {
+            if self.affinity.is_none() && self.sched_policy.is_none() && self.priority.is_none() {
+                return (p, None);
+            }
+            let report = Arc::new(Report { result: Mutex::new(None), done: Condvar::new() });
+            let their_report = report.clone();
+            let setup = *self;
+            let main = move || {
+                let result = unsafe { setup.apply() };
+                let ok = result.is_ok();
+                *their_report.result.lock().unwrap() = Some(result);
+                their_report.done.notify_one();
+                drop(their_report);
+                if ok {
+                    p();
+                }
+            };
+            (Box::new(main), Some(report))
+        }
+
+        /// Applies the settings to the current thread.
+        unsafe fn apply(&self) -> io::Result<()> {
+            if let Some(cpus) = self.affinity {
+                cvt(libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &cpus))?;
+            }
+            if self.sched_policy.is_some() || self.priority.is_some() {
+                let thread = libc::pthread_self();
+                let mut policy = 0;
+                let mut param: libc::sched_param = mem::zeroed();
+                cvt_nz(libc::pthread_getschedparam(thread, &mut policy, &mut param))?;
+                if let Some(new_policy) = self.sched_policy {
+                    if new_policy != policy {
+                        // The old priority may not be valid for the new policy.
+                        param.sched_priority = libc::sched_get_priority_min(new_policy);
+                    }
+                    policy = new_policy;
+                }
+                if let Some(priority) = self.priority {
+                    param.sched_priority = priority;
+                }
+                cvt_nz(libc::pthread_setschedparam(thread, policy, &param))?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Report {
+        /// Blocks until the new thread has applied its settings.
+        pub fn wait(&self) -> io::Result<()> {
+            let mut result = self.result.lock().unwrap();
+            loop {
+                match result.take() {
+                    Some(result) => return result,
+                    None => result = self.done.wait(result).unwrap(),
+                }
+            }
+        }
+    }
+
+    pub fn current_affinity() -> io::Result<libc::cpu_set_t> {
+        unsafe {
+            let mut cpus: libc::cpu_set_t = mem::zeroed();
+            cvt(libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut cpus))?;
+            Ok(cpus)
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use self::setup::current_affinity;
+
 #[cfg(all(
     not(target_os = "linux"),
     not(target_os = "freebsd"),
@@ -401,40 +579,39 @@ pub unsafe fn current() -> Option<Guard> {
         if e == 0 {
             let mut guardsize = 0;
             assert_eq!(libc::pthread_attr_getguardsize(&attr, &mut guardsize), 0);
-            if guardsize == 0 {
-                if cfg!(all(target_os = "linux", target_env = "musl")) {
-                    // musl versions before 1.1.19 always reported guard
-                    // size obtained from pthread_attr_get_np as zero.
-                    // Use page size as a fallback.
-                    guardsize = PAGE_SIZE.load(Ordering::Relaxed);
+            if guardsize == 0 && cfg!(all(target_os = "linux", target_env = "musl")) {
+                // musl versions before 1.1.19 always reported guard
+                // size obtained from pthread_attr_get_np as zero.
+                // Use page size as a fallback.
+                guardsize = PAGE_SIZE.load(Ordering::Relaxed);
+            }
+            // Threads spawned with a guard size of zero have no guard page.
+            if guardsize != 0 {
+                let mut stackaddr = crate::ptr::null_mut();
+                let mut size = 0;
+                assert_eq!(libc::pthread_attr_getstack(&attr, &mut stackaddr, &mut size), 0);
+
+                let stackaddr = stackaddr as usize;
+                ret = if cfg!(target_os = "freebsd") {
+                    // FIXME does freebsd really fault *below* the guard addr?
+                    let guardaddr = stackaddr - guardsize;
+                    Some(guardaddr - PAGE_SIZE.load(Ordering::Relaxed)..guardaddr)
+                } else if cfg!(target_os = "netbsd") {
+                    Some(stackaddr - guardsize..stackaddr)
+                } else if cfg!(all(target_os = "linux", target_env = "musl")) {
+                    Some(stackaddr - guardsize..stackaddr)
+                } else if cfg!(all(target_os = "linux", target_env = "gnu")) {
+                    // glibc used to include the guard area within the stack, as noted in the
+                    // BUGS section of `man pthread_attr_getguardsize`.  This has been corrected
+                    // starting with glibc 2.27, and in some distro backports, so the guard is now
+                    // placed at the end (below) the stack.  There's no easy way for us to know
+                    // which we have at runtime, so we'll just match any fault in the range right
+                    // above or below the stack base to call that fault a stack overflow.
+                    Some(stackaddr - guardsize..stackaddr + guardsize)
                 } else {
-                    panic!("there is no guard page");
-                }
+                    Some(stackaddr..stackaddr + guardsize)
+                };
             }
-            let mut stackaddr = crate::ptr::null_mut();
-            let mut size = 0;
-            assert_eq!(libc::pthread_attr_getstack(&attr, &mut stackaddr, &mut size), 0);
-
-            let stackaddr = stackaddr as usize;
-            ret = if cfg!(target_os = "freebsd") {
-                // FIXME does freebsd really fault *below* the guard addr?
-                let guardaddr = stackaddr - guardsize;
-                Some(guardaddr - PAGE_SIZE.load(Ordering::Relaxed)..guardaddr)
-            } else if cfg!(target_os = "netbsd") {
-                Some(stackaddr - guardsize..stackaddr)
-            } else if cfg!(all(target_os = "linux", target_env = "musl")) {
-                Some(stackaddr - guardsize..stackaddr)
-            } else if cfg!(all(target_os = "linux", target_env = "gnu")) {
-                // glibc used to include the guard area within the stack, as noted in the BUGS
-                // section of `man pthread_attr_getguardsize`.  This has been corrected starting
-                // with glibc 2.27, and in some distro backports, so the guard is now placed at the
-                // end (below) the stack.  There's no easy way for us to know which we have at
-                // runtime, so we'll just match any fault in the range right above or below the
-                // stack base to call that fault a stack overflow.
-                Some(stackaddr - guardsize..stackaddr + guardsize)
-            } else {
-                Some(stackaddr..stackaddr + guardsize)
-            };
         }
         if e == 0 || cfg!(target_os = "freebsd") {
             assert_eq!(libc::pthread_attr_destroy(&mut attr), 0);
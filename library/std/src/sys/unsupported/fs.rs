@@ -182,6 +182,10 @@ pub fn file_attr(&self) -> io::Result<FileAttr> {
         match self.0 {}
     }
 
+    pub fn is_terminal(&self) -> bool {
+        match self.0 {}
+    }
+
     pub fn fsync(&self) -> io::Result<()> {
         match self.0 {}
     }
@@ -1,4 +1,5 @@
 use crate::cell::Cell;
+use crate::time::Instant;
 
 pub struct RWLock {
     // This platform has no threads, so we can use a Cell here.
@@ -34,6 +35,12 @@ pub unsafe fn try_read(&self) -> bool {
         }
     }
 
+    // The lock can't be released by another thread while waiting.
+    #[inline]
+    pub unsafe fn try_read_until(&self, _deadline: Instant) -> bool {
+        self.try_read()
+    }
+
     #[inline]
     pub unsafe fn write(&self) {
         if self.mode.replace(-1) != 0 {
@@ -51,6 +58,11 @@ pub unsafe fn try_write(&self) -> bool {
         }
     }
 
+    #[inline]
+    pub unsafe fn try_write_until(&self, _deadline: Instant) -> bool {
+        self.try_write()
+    }
+
     #[inline]
     pub unsafe fn read_unlock(&self) {
         self.mode.set(self.mode.get() - 1);
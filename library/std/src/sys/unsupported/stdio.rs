@@ -8,6 +8,10 @@ impl Stdin {
     pub const fn new() -> Stdin {
         Stdin
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Read for Stdin {
@@ -20,6 +24,10 @@ impl Stdout {
     pub const fn new() -> Stdout {
         Stdout
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stdout {
@@ -36,6 +44,10 @@ impl Stderr {
     pub const fn new() -> Stderr {
         Stderr
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stderr {
@@ -151,6 +151,11 @@ pub fn filestat_get(&self) -> io::Result<wasi::Filestat> {
         unsafe { wasi::fd_filestat_get(self.fd).map_err(err2io) }
     }
 
+    pub fn is_terminal(&self) -> bool {
+        // WASI has no `isatty`; a character device is the closest approximation.
+        self.filestat_get().map_or(false, |stat| stat.filetype == wasi::FILETYPE_CHARACTER_DEVICE)
+    }
+
     pub fn filestat_set_times(
         &self,
         atim: wasi::Timestamp,
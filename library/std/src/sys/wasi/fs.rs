@@ -445,6 +445,10 @@ pub fn fd(&self) -> &WasiFd {
         &self.fd
     }
 
+    pub fn is_terminal(&self) -> bool {
+        self.fd.is_terminal()
+    }
+
     pub fn into_fd(self) -> WasiFd {
         self.fd
     }
@@ -13,6 +13,10 @@ pub const fn new() -> Stdin {
         Stdin
     }
 
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(unsafe { WasiFd::from_raw(self.as_raw_fd()) }).is_terminal()
+    }
+
     #[inline]
     pub fn as_raw_fd(&self) -> u32 {
         0
@@ -39,6 +43,10 @@ pub const fn new() -> Stdout {
         Stdout
     }
 
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(unsafe { WasiFd::from_raw(self.as_raw_fd()) }).is_terminal()
+    }
+
     #[inline]
     pub fn as_raw_fd(&self) -> u32 {
         1
@@ -68,6 +76,10 @@ pub const fn new() -> Stderr {
         Stderr
     }
 
+    pub fn is_terminal(&self) -> bool {
+        ManuallyDrop::new(unsafe { WasiFd::from_raw(self.as_raw_fd()) }).is_terminal()
+    }
+
     #[inline]
     pub fn as_raw_fd(&self) -> u32 {
         2
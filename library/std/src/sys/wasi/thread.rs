@@ -10,9 +10,17 @@
 
 pub const DEFAULT_MIN_STACK_SIZE: usize = 4096;
 
+/// Platform-specific settings for spawning a thread. There are none on this platform.
+#[derive(Debug, Default)]
+pub struct Options;
+
 impl Thread {
     // unsafe: see thread::Builder::spawn_unchecked for safety requirements
-    pub unsafe fn new(_stack: usize, _p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+    pub unsafe fn new(
+        _stack: usize,
+        _options: &Options,
+        _p: Box<dyn FnOnce()>,
+    ) -> io::Result<Thread> {
         unsupported()
     }
 
@@ -15,3 +15,9 @@ pub fn futex_wake(futex: &AtomicI32) {
         wasm32::memory_atomic_notify(futex as *const AtomicI32 as *mut i32, 1);
     }
 }
+
+pub fn futex_wake_all(futex: &AtomicI32) {
+    unsafe {
+        wasm32::memory_atomic_notify(futex as *const AtomicI32 as *mut i32, u32::MAX);
+    }
+}
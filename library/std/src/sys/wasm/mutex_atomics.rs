@@ -3,6 +3,7 @@
 use crate::mem;
 use crate::sync::atomic::{AtomicU32, AtomicUsize, Ordering::SeqCst};
 use crate::sys::thread;
+use crate::time::Instant;
 
 pub struct Mutex {
     locked: AtomicUsize,
@@ -53,6 +54,11 @@ pub unsafe fn try_lock(&self) -> bool {
         self.locked.compare_exchange(0, 1, SeqCst, SeqCst).is_ok()
     }
 
+    #[inline]
+    pub unsafe fn try_lock_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_lock())
+    }
+
     #[inline]
     pub unsafe fn destroy(&self) {
         // nothing to do
@@ -240,6 +240,12 @@ fn clone(&self) -> Self {
 pub const IPV6_V6ONLY: c_int = 27;
 pub const SO_ERROR: c_int = 0x1007;
 pub const SO_BROADCAST: c_int = 0x0020;
+pub const SO_REUSEADDR: c_int = 0x0004;
+pub const SO_KEEPALIVE: c_int = 0x0008;
+pub const SO_LINGER: c_int = 0x0080;
+pub const SO_SNDBUF: c_int = 0x1001;
+pub const SO_RCVBUF: c_int = 0x1002;
+pub const SIO_KEEPALIVE_VALS: DWORD = 0x98000004;
 pub const IP_MULTICAST_LOOP: c_int = 11;
 pub const IPV6_MULTICAST_LOOP: c_int = 11;
 pub const IP_MULTICAST_TTL: c_int = 10;
@@ -261,6 +267,20 @@ pub struct ipv6_mreq {
     pub ipv6mr_interface: c_uint,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct linger {
+    pub l_onoff: c_ushort,
+    pub l_linger: c_ushort,
+}
+
+#[repr(C)]
+pub struct tcp_keepalive {
+    pub onoff: c_ulong,
+    pub keepalivetime: c_ulong,
+    pub keepaliveinterval: c_ulong,
+}
+
 pub const VOLUME_NAME_DOS: DWORD = 0x0;
 pub const MOVEFILE_REPLACE_EXISTING: DWORD = 1;
 
@@ -793,6 +813,17 @@ pub fn WSASocketW(
         dwFlags: DWORD,
     ) -> SOCKET;
     pub fn ioctlsocket(s: SOCKET, cmd: c_long, argp: *mut c_ulong) -> c_int;
+    pub fn WSAIoctl(
+        s: SOCKET,
+        dwIoControlCode: DWORD,
+        lpvInBuffer: LPVOID,
+        cbInBuffer: DWORD,
+        lpvOutBuffer: LPVOID,
+        cbOutBuffer: DWORD,
+        lpcbBytesReturned: LPDWORD,
+        lpOverlapped: LPWSAOVERLAPPED,
+        lpCompletionRoutine: LPWSAOVERLAPPED_COMPLETION_ROUTINE,
+    ) -> c_int;
     pub fn InitializeCriticalSection(CriticalSection: *mut CRITICAL_SECTION);
     pub fn EnterCriticalSection(CriticalSection: *mut CRITICAL_SECTION);
     pub fn TryEnterCriticalSection(CriticalSection: *mut CRITICAL_SECTION) -> BOOL;
@@ -878,6 +909,12 @@ pub fn CreateDirectoryW(
     ) -> BOOL;
     pub fn DeleteFileW(lpPathName: LPCWSTR) -> BOOL;
     pub fn GetCurrentDirectoryW(nBufferLength: DWORD, lpBuffer: LPWSTR) -> DWORD;
+    pub fn GetFullPathNameW(
+        lpFileName: LPCWSTR,
+        nBufferLength: DWORD,
+        lpBuffer: LPWSTR,
+        lpFilePart: *mut LPWSTR,
+    ) -> DWORD;
     pub fn SetCurrentDirectoryW(lpPathName: LPCWSTR) -> BOOL;
 
     pub fn closesocket(socket: SOCKET) -> c_int;
@@ -991,6 +1028,12 @@ pub fn WaitForMultipleObjects(
         bWaitAll: BOOL,
         dwMilliseconds: DWORD,
     ) -> DWORD;
+    pub fn CreatePipe(
+        hReadPipe: LPHANDLE,
+        hWritePipe: LPHANDLE,
+        lpPipeAttributes: LPSECURITY_ATTRIBUTES,
+        nSize: DWORD,
+    ) -> BOOL;
     pub fn CreateNamedPipeW(
         lpName: LPCWSTR,
         dwOpenMode: DWORD,
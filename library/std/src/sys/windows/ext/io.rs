@@ -121,6 +121,34 @@ fn into_raw_handle(self) -> RawHandle {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawHandle for io::PipeReader {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.as_inner().handle().raw() as *mut _
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawHandle for io::PipeReader {
+    fn into_raw_handle(self) -> RawHandle {
+        self.into_inner().into_handle().into_raw() as *mut _
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawHandle for io::PipeWriter {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.as_inner().handle().raw() as *mut _
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawHandle for io::PipeWriter {
+    fn into_raw_handle(self) -> RawHandle {
+        self.into_inner().into_handle().into_raw() as *mut _
+    }
+}
+
 /// Extracts raw sockets.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub trait AsRawSocket {
@@ -220,3 +248,47 @@ fn into_raw_socket(self) -> RawSocket {
         self.into_inner().into_socket().into_inner()
     }
 }
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl AsRawSocket for net::TcpSocket {
+    fn as_raw_socket(&self) -> RawSocket {
+        *self.as_inner().socket().as_inner()
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl FromRawSocket for net::TcpSocket {
+    unsafe fn from_raw_socket(sock: RawSocket) -> net::TcpSocket {
+        let sock = sys::net::Socket::from_inner(sock);
+        net::TcpSocket::from_inner(sys_common::net::TcpSocket::from_inner(sock))
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl IntoRawSocket for net::TcpSocket {
+    fn into_raw_socket(self) -> RawSocket {
+        self.into_inner().into_socket().into_inner()
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl AsRawSocket for net::UdpSocketBuilder {
+    fn as_raw_socket(&self) -> RawSocket {
+        *self.as_inner().socket().as_inner()
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl FromRawSocket for net::UdpSocketBuilder {
+    unsafe fn from_raw_socket(sock: RawSocket) -> net::UdpSocketBuilder {
+        let sock = sys::net::Socket::from_inner(sock);
+        net::UdpSocketBuilder::from_inner(sys_common::net::UdpSocketBuilder::from_inner(sock))
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl IntoRawSocket for net::UdpSocketBuilder {
+    fn into_raw_socket(self) -> RawSocket {
+        self.into_inner().into_socket().into_inner()
+    }
+}
@@ -461,6 +461,10 @@ pub fn handle(&self) -> &Handle {
         &self.handle
     }
 
+    pub fn is_terminal(&self) -> bool {
+        super::stdio::is_console(self.handle.raw())
+    }
+
     pub fn into_handle(self) -> Handle {
         self.handle
     }
@@ -23,6 +23,7 @@
 use crate::mem::{self, MaybeUninit};
 use crate::sync::atomic::{AtomicUsize, Ordering};
 use crate::sys::c;
+use crate::time::Instant;
 
 pub struct Mutex {
     // This is either directly an SRWLOCK (if supported), or a Box<Inner> otherwise.
@@ -95,6 +96,10 @@ pub unsafe fn try_lock(&self) -> bool {
             }
         }
     }
+    #[inline]
+    pub unsafe fn try_lock_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_lock())
+    }
     pub unsafe fn unlock(&self) {
         match kind() {
             Kind::SRWLock => c::ReleaseSRWLockExclusive(raw(self)),
@@ -398,6 +398,53 @@ pub fn nodelay(&self) -> io::Result<bool> {
         Ok(raw != 0)
     }
 
+    pub fn set_reuseport(&self, _reuseport: bool) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "SO_REUSEPORT is not supported on Windows"))
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        Err(io::Error::new(io::ErrorKind::Other, "SO_REUSEPORT is not supported on Windows"))
+    }
+
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        let idle = match keepalive {
+            Some(idle) => idle,
+            None => return net::setsockopt(self, c::SOL_SOCKET, c::SO_KEEPALIVE, 0 as c_int),
+        };
+        // Only `SIO_KEEPALIVE_VALS` can change the idle time on all supported
+        // versions of Windows. The interval between unanswered probes is left
+        // at the system default of one second.
+        let ms = cmp::max(1, cmp::min(idle.as_millis(), c_ulong::MAX as u128)) as c_ulong;
+        let mut vals = c::tcp_keepalive { onoff: 1, keepalivetime: ms, keepaliveinterval: 1000 };
+        let mut bytes = 0;
+        cvt(unsafe {
+            c::WSAIoctl(
+                self.0,
+                c::SIO_KEEPALIVE_VALS,
+                &mut vals as *mut _ as c::LPVOID,
+                mem::size_of_val(&vals) as c::DWORD,
+                ptr::null_mut(),
+                0,
+                &mut bytes,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        })?;
+        Ok(())
+    }
+
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        let secs = linger.map_or(0, |dur| cmp::min(dur.as_secs(), c::USHORT::MAX as u64));
+        let linger =
+            c::linger { l_onoff: linger.is_some() as c::USHORT, l_linger: secs as c::USHORT };
+        net::setsockopt(self, c::SOL_SOCKET, c::SO_LINGER, linger)
+    }
+
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        let val: c::linger = net::getsockopt(self, c::SOL_SOCKET, c::SO_LINGER)?;
+        Ok(if val.l_onoff != 0 { Some(Duration::from_secs(val.l_linger as u64)) } else { None })
+    }
+
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         let raw: c_int = net::getsockopt(self, c::SOL_SOCKET, c::SO_ERROR)?;
         if raw == 0 { Ok(None) } else { Ok(Some(io::Error::from_raw_os_error(raw as i32))) }
@@ -1,6 +1,9 @@
+use super::{c, fill_utf16_buf, os2path, to_u16s};
 use crate::ffi::OsStr;
+use crate::io;
 use crate::mem;
-use crate::path::Prefix;
+use crate::path::{Path, PathBuf, Prefix};
+use crate::ptr;
 
 #[cfg(test)]
 mod tests;
@@ -139,3 +142,21 @@ fn parse_next_component(path: &OsStr, verbatim: bool) -> (&OsStr, &OsStr) {
         None => (path, OsStr::new("")),
     }
 }
+
+/// Make a Windows path absolute.
+pub(crate) fn absolute(path: &Path) -> io::Result<PathBuf> {
+    // Verbatim paths are passed to the filesystem as-is, so `GetFullPathNameW`
+    // must not be allowed to "fix" them.
+    if path.as_os_str().bytes().starts_with(br"\\?\") {
+        return Ok(path.into());
+    }
+    let path = to_u16s(path)?;
+    let lpfilename = path.as_ptr();
+    fill_utf16_buf(
+        // SAFETY: `fill_utf16_buf` ensures the `buffer` and `size` are valid.
+        // `lpfilename` is a pointer to a null terminated string that is not
+        // invalidated until after `GetFullPathNameW` returns successfully.
+        |buffer, size| unsafe { c::GetFullPathNameW(lpfilename, size, buffer, ptr::null_mut()) },
+        os2path,
+    )
+}
@@ -42,3 +42,12 @@ fn test_parse_next_component() {
         (OsStr::new(r"server"), OsStr::new(r"\\\\\\\\\\\\\share"))
     );
 }
+
+#[test]
+fn verbatim_absolute() {
+    // `GetFullPathNameW` would normalize away the trailing dots and the `..`,
+    // but verbatim paths are passed through untouched.
+    for path in &[r"\\?\C:\a\..\b.", r"\\?\UNC\server\share\.\c", r"\\?\PIPE\name"] {
+        assert_eq!(absolute(Path::new(path)).unwrap().as_os_str(), OsStr::new(path));
+    }
+}
@@ -9,6 +9,7 @@
 use crate::sync::atomic::AtomicUsize;
 use crate::sync::atomic::Ordering::SeqCst;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys::fs::{File, OpenOptions};
 use crate::sys::handle::Handle;
 use crate::sys::hashmap_random_keys;
@@ -166,7 +167,27 @@ fn random_number() -> usize {
     }
 }
 
+/// Creates the pipe backing `io::pipe`, returning its read and write ends.
+///
+/// Unlike `anon_pipe` this uses a plain anonymous pipe, so neither end is in
+/// overlapped mode and both can be used from several threads at once. Neither
+/// handle is inheritable; they are duplicated into an inheritable handle only
+/// when converted into `Stdio` and passed to a child.
+pub fn pipe() -> io::Result<(AnonPipe, AnonPipe)> {
+    let mut read = ptr::null_mut();
+    let mut write = ptr::null_mut();
+    unsafe {
+        cvt(c::CreatePipe(&mut read, &mut write, ptr::null_mut(), 0))?;
+    }
+    Ok((AnonPipe { inner: Handle::new(read) }, AnonPipe { inner: Handle::new(write) }))
+}
+
 impl AnonPipe {
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        let inner = self.inner.duplicate(0, false, c::DUPLICATE_SAME_ACCESS)?;
+        Ok(AnonPipe { inner })
+    }
+
     pub fn handle(&self) -> &Handle {
         &self.inner
     }
@@ -1,5 +1,6 @@
 use crate::cell::UnsafeCell;
 use crate::sys::c;
+use crate::time::Instant;
 
 pub struct RWLock {
     inner: UnsafeCell<c::SRWLOCK>,
@@ -21,6 +22,10 @@ pub unsafe fn try_read(&self) -> bool {
         c::TryAcquireSRWLockShared(self.inner.get()) != 0
     }
     #[inline]
+    pub unsafe fn try_read_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_read())
+    }
+    #[inline]
     pub unsafe fn write(&self) {
         c::AcquireSRWLockExclusive(self.inner.get())
     }
@@ -29,6 +34,10 @@ pub unsafe fn try_write(&self) -> bool {
         c::TryAcquireSRWLockExclusive(self.inner.get()) != 0
     }
     #[inline]
+    pub unsafe fn try_write_until(&self, deadline: Instant) -> bool {
+        crate::sys_common::mutex::try_until(deadline, || self.try_write())
+    }
+    #[inline]
     pub unsafe fn read_unlock(&self) {
         c::ReleaseSRWLockShared(self.inner.get())
     }
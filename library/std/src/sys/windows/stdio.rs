@@ -42,7 +42,7 @@ pub fn get_handle(handle_id: c::DWORD) -> io::Result<c::HANDLE> {
     }
 }
 
-fn is_console(handle: c::HANDLE) -> bool {
+pub fn is_console(handle: c::HANDLE) -> bool {
     // `GetConsoleMode` will return false (0) if this is a pipe (we don't care about the reported
     // mode). This will only detect Windows Console, not other terminals connected to a pipe like
     // MSYS. Which is exactly what we need, as only Windows Console needs a conversion to UTF-16.
@@ -134,6 +134,10 @@ impl Stdin {
     pub const fn new() -> Stdin {
         Stdin { surrogate: 0 }
     }
+
+    pub fn is_terminal(&self) -> bool {
+        get_handle(c::STD_INPUT_HANDLE).map_or(false, is_console)
+    }
 }
 
 impl io::Read for Stdin {
@@ -258,6 +262,10 @@ impl Stdout {
     pub const fn new() -> Stdout {
         Stdout
     }
+
+    pub fn is_terminal(&self) -> bool {
+        get_handle(c::STD_OUTPUT_HANDLE).map_or(false, is_console)
+    }
 }
 
 impl io::Write for Stdout {
@@ -274,6 +282,10 @@ impl Stderr {
     pub const fn new() -> Stderr {
         Stderr
     }
+
+    pub fn is_terminal(&self) -> bool {
+        get_handle(c::STD_ERROR_HANDLE).map_or(false, is_console)
+    }
 }
 
 impl io::Write for Stderr {
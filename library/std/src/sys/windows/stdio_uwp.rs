@@ -23,6 +23,11 @@ pub fn get_handle(handle_id: c::DWORD) -> io::Result<c::HANDLE> {
     }
 }
 
+pub fn is_console(_handle: c::HANDLE) -> bool {
+    // UWP apps have no console.
+    false
+}
+
 fn write(handle_id: c::DWORD, data: &[u8]) -> io::Result<usize> {
     let handle = get_handle(handle_id)?;
     let handle = Handle::new(handle);
@@ -33,6 +38,10 @@ impl Stdin {
     pub const fn new() -> Stdin {
         Stdin {}
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Read for Stdin {
@@ -47,6 +56,10 @@ impl Stdout {
     pub const fn new() -> Stdout {
         Stdout
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stdout {
@@ -63,6 +76,10 @@ impl Stderr {
     pub const fn new() -> Stderr {
         Stderr
     }
+
+    pub fn is_terminal(&self) -> bool {
+        false
+    }
 }
 
 impl io::Write for Stderr {
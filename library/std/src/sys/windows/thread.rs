@@ -16,9 +16,17 @@ pub struct Thread {
     handle: Handle,
 }
 
+/// Platform-specific settings for spawning a thread. There are none on this platform.
+#[derive(Debug, Default)]
+pub struct Options;
+
 impl Thread {
     // unsafe: see thread::Builder::spawn_unchecked for safety requirements
-    pub unsafe fn new(stack: usize, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+    pub unsafe fn new(
+        stack: usize,
+        _options: &Options,
+        p: Box<dyn FnOnce()>,
+    ) -> io::Result<Thread> {
         let p = Box::into_raw(box p);
 
         // FIXME On UNIX, we guard against stack sizes that are too small but
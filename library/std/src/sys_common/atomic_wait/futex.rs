@@ -0,0 +1,22 @@
+use crate::sync::atomic::{AtomicI32, AtomicU32};
+use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
+use crate::time::Duration;
+
+// The futex primitives operate on `AtomicI32`, but the kernel only ever
+// compares the raw bits of the word.
+fn as_futex(atomic: &AtomicU32) -> &AtomicI32 {
+    // SAFETY: `AtomicU32` and `AtomicI32` have the same size and alignment.
+    unsafe { &*(atomic as *const AtomicU32 as *const AtomicI32) }
+}
+
+pub fn wait(atomic: &AtomicU32, expected: u32, timeout: Option<Duration>) {
+    futex_wait(as_futex(atomic), expected as i32, timeout);
+}
+
+pub fn wake_one(atomic: &AtomicU32) {
+    futex_wake(as_futex(atomic));
+}
+
+pub fn wake_all(atomic: &AtomicU32) {
+    futex_wake_all(as_futex(atomic));
+}
@@ -0,0 +1,52 @@
+//! Address-based waiting implemented with a fixed table of Mutex/Condvar
+//! pairs, keyed by the address of the atomic.
+
+use crate::lazy::SyncLazy;
+use crate::sync::atomic::AtomicU32;
+use crate::sync::atomic::Ordering::SeqCst;
+use crate::sync::{Condvar, Mutex, PoisonError};
+use crate::time::Duration;
+
+const BUCKETS: usize = 64;
+
+struct Bucket {
+    lock: Mutex<()>,
+    cvar: Condvar,
+}
+
+static TABLE: SyncLazy<Box<[Bucket]>> = SyncLazy::new(|| {
+    (0..BUCKETS).map(|_| Bucket { lock: Mutex::new(()), cvar: Condvar::new() }).collect()
+});
+
+fn bucket(atomic: &AtomicU32) -> &'static Bucket {
+    // The two lowest bits are always zero due to alignment, so drop them
+    // before picking a bucket.
+    let addr = atomic as *const AtomicU32 as usize;
+    &TABLE[(addr >> 2) % BUCKETS]
+}
+
+pub fn wait(atomic: &AtomicU32, expected: u32, timeout: Option<Duration>) {
+    let bucket = bucket(atomic);
+    let guard = bucket.lock.lock().unwrap_or_else(PoisonError::into_inner);
+    // Wakers take the bucket lock after changing the value, so checking it
+    // while holding the lock ensures a wakeup can't be missed.
+    if atomic.load(SeqCst) != expected {
+        return;
+    }
+    match timeout {
+        Some(dur) => drop(bucket.cvar.wait_timeout(guard, dur)),
+        None => drop(bucket.cvar.wait(guard)),
+    }
+}
+
+pub fn wake_one(atomic: &AtomicU32) {
+    // A bucket is shared by every atomic that hashes to it, so waking a
+    // single thread might pick one that waits on a different address.
+    wake_all(atomic);
+}
+
+pub fn wake_all(atomic: &AtomicU32) {
+    let bucket = bucket(atomic);
+    let _guard = bucket.lock.lock().unwrap_or_else(PoisonError::into_inner);
+    bucket.cvar.notify_all();
+}
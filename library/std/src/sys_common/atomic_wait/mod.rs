@@ -0,0 +1,16 @@
+//! Blocking on the value of an atomic integer, used by
+//! `std::sync::atomic::AtomicWait`.
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        all(target_arch = "wasm32", target_feature = "atomics"),
+    ))] {
+        mod futex;
+        pub use futex::{wait, wake_all, wake_one};
+    } else {
+        mod generic;
+        pub use generic::{wait, wake_all, wake_one};
+    }
+}
@@ -48,6 +48,7 @@ macro_rules! rtunwrap {
 
 pub mod alloc;
 pub mod at_exit_imp;
+pub mod atomic_wait;
 pub mod backtrace;
 pub mod bytestring;
 pub mod condvar;
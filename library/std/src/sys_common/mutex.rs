@@ -1,4 +1,8 @@
+use crate::cmp;
+use crate::hint;
 use crate::sys::mutex as imp;
+use crate::thread;
+use crate::time::{Duration, Instant};
 
 /// An OS-based mutual exclusion lock, meant for use in static variables.
 ///
@@ -83,6 +87,13 @@ pub fn try_lock(&self) -> bool {
         unsafe { self.0.try_lock() }
     }
 
+    /// Attempts to lock the mutex, giving up once `deadline` has passed,
+    /// returning whether it was successfully acquired or not.
+    #[inline]
+    pub fn try_lock_until(&self, deadline: Instant) -> bool {
+        unsafe { self.0.try_lock_until(deadline) }
+    }
+
     /// Unlocks the mutex.
     ///
     /// Behavior is undefined if the current thread does not actually hold the
@@ -98,3 +109,35 @@ fn drop(&mut self) {
         unsafe { self.0.destroy() };
     }
 }
+
+/// Repeatedly calls `try_acquire` until it succeeds or `deadline` has passed.
+///
+/// This is how platforms without a timed lock operation implement timed locking
+/// on top of the non-blocking one. The caller first spins briefly, then yields
+/// and finally sleeps in short intervals, so that a lock that is released soon
+/// is picked up quickly without burning CPU for long waits. Unlike a blocking
+/// waiter, the caller doesn't queue for the lock, so it may not get it at all
+/// while the lock is contended.
+#[allow(dead_code)] // not every platform needs it
+pub fn try_until(deadline: Instant, mut try_acquire: impl FnMut() -> bool) -> bool {
+    let mut attempt = 0u32;
+    loop {
+        if try_acquire() {
+            return true;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        if attempt < 6 {
+            for _ in 0..1 << attempt {
+                hint::spin_loop();
+            }
+        } else if attempt < 10 {
+            thread::yield_now();
+        } else {
+            thread::sleep(cmp::min(deadline - now, Duration::from_millis(1)));
+        }
+        attempt = attempt.saturating_add(1);
+    }
+}
@@ -82,6 +82,16 @@ pub fn getsockopt<T: Copy>(sock: &Socket, opt: c_int, val: c_int) -> io::Result<
     }
 }
 
+fn set_buffer_size(sock: &Socket, opt: c_int, size: usize) -> io::Result<()> {
+    let size = cmp::min(size, c_int::MAX as usize) as c_int;
+    setsockopt(sock, c::SOL_SOCKET, opt, size)
+}
+
+fn buffer_size(sock: &Socket, opt: c_int) -> io::Result<usize> {
+    let raw: c_int = getsockopt(sock, c::SOL_SOCKET, opt)?;
+    Ok(raw as usize)
+}
+
 fn sockname<F>(f: F) -> io::Result<SocketAddr>
 where
     F: FnOnce(*mut c::sockaddr, *mut c::socklen_t) -> c_int,
@@ -457,6 +467,134 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Unconnected TCP sockets
+////////////////////////////////////////////////////////////////////////////////
+
+pub struct TcpSocket {
+    inner: Socket,
+}
+
+impl TcpSocket {
+    pub fn new(addr: &SocketAddr) -> io::Result<TcpSocket> {
+        init();
+
+        let sock = Socket::new(addr, c::SOCK_STREAM)?;
+        Ok(TcpSocket { inner: sock })
+    }
+
+    pub fn socket(&self) -> &Socket {
+        &self.inner
+    }
+
+    pub fn into_socket(self) -> Socket {
+        self.inner
+    }
+
+    pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+        sockname(|buf, len| unsafe { c::getsockname(*self.inner.as_inner(), buf, len) })
+    }
+
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR, reuseaddr as c_int)
+    }
+
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.inner.set_reuseport(reuseport)
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.inner.reuseport()
+    }
+
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.inner.set_keepalive(keepalive)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        self.inner.set_linger(linger)
+    }
+
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.inner.linger()
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_RCVBUF)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_SNDBUF)
+    }
+
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.inner.set_nodelay(nodelay)
+    }
+
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.inner.nodelay()
+    }
+
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        let (addrp, len) = addr.into_inner();
+        cvt(unsafe { c::bind(*self.inner.as_inner(), addrp, len as _) }).map(drop)
+    }
+
+    pub fn listen(self, backlog: u32) -> io::Result<TcpListener> {
+        let backlog = cmp::min(backlog, c_int::MAX as u32) as c_int;
+        cvt(unsafe { c::listen(*self.inner.as_inner(), backlog) })?;
+        Ok(TcpListener { inner: self.inner })
+    }
+
+    pub fn connect(self, addr: &SocketAddr) -> io::Result<TcpStream> {
+        let (addrp, len) = addr.into_inner();
+        cvt_r(|| unsafe { c::connect(*self.inner.as_inner(), addrp, len) })?;
+        Ok(TcpStream { inner: self.inner })
+    }
+
+    pub fn connect_timeout(self, addr: &SocketAddr, timeout: Duration) -> io::Result<TcpStream> {
+        self.inner.connect_timeout(addr, timeout)?;
+        Ok(TcpStream { inner: self.inner })
+    }
+}
+
+impl FromInner<Socket> for TcpSocket {
+    fn from_inner(socket: Socket) -> TcpSocket {
+        TcpSocket { inner: socket }
+    }
+}
+
+impl fmt::Debug for TcpSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = f.debug_struct("TcpSocket");
+
+        if let Ok(addr) = self.socket_addr() {
+            res.field("addr", &addr);
+        }
+
+        let name = if cfg!(windows) { "socket" } else { "fd" };
+        res.field(name, &self.inner.as_inner()).finish()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // UDP
 ////////////////////////////////////////////////////////////////////////////////
@@ -672,3 +810,80 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         res.field(name, &self.inner.as_inner()).finish()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Unbound UDP sockets
+////////////////////////////////////////////////////////////////////////////////
+
+pub struct UdpSocketBuilder {
+    inner: Socket,
+}
+
+impl UdpSocketBuilder {
+    pub fn new(addr: &SocketAddr) -> io::Result<UdpSocketBuilder> {
+        init();
+
+        let sock = Socket::new(addr, c::SOCK_DGRAM)?;
+        Ok(UdpSocketBuilder { inner: sock })
+    }
+
+    pub fn socket(&self) -> &Socket {
+        &self.inner
+    }
+
+    pub fn into_socket(self) -> Socket {
+        self.inner
+    }
+
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR, reuseaddr as c_int)
+    }
+
+    pub fn reuseaddr(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        self.inner.set_reuseport(reuseport)
+    }
+
+    pub fn reuseport(&self) -> io::Result<bool> {
+        self.inner.reuseport()
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_RCVBUF)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        set_buffer_size(&self.inner, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        buffer_size(&self.inner, c::SO_SNDBUF)
+    }
+
+    pub fn bind(self, addr: &SocketAddr) -> io::Result<UdpSocket> {
+        let (addrp, len) = addr.into_inner();
+        cvt(unsafe { c::bind(*self.inner.as_inner(), addrp, len as _) })?;
+        Ok(UdpSocket { inner: self.inner })
+    }
+}
+
+impl FromInner<Socket> for UdpSocketBuilder {
+    fn from_inner(socket: Socket) -> UdpSocketBuilder {
+        UdpSocketBuilder { inner: socket }
+    }
+}
+
+impl fmt::Debug for UdpSocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = if cfg!(windows) { "socket" } else { "fd" };
+        f.debug_struct("UdpSocketBuilder").field(name, &self.inner.as_inner()).finish()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::sys::rwlock as imp;
+use crate::time::Instant;
 
 /// An OS-based reader-writer lock.
 ///
@@ -38,6 +39,16 @@ pub unsafe fn try_read(&self) -> bool {
         self.0.try_read()
     }
 
+    /// Attempts to acquire shared access to this lock, giving up once
+    /// `deadline` has passed.
+    ///
+    /// Behavior is undefined if the rwlock has been moved between this and any
+    /// previous method call.
+    #[inline]
+    pub unsafe fn try_read_until(&self, deadline: Instant) -> bool {
+        self.0.try_read_until(deadline)
+    }
+
     /// Acquires write access to the underlying lock, blocking the current thread
     /// to do so.
     ///
@@ -60,6 +71,16 @@ pub unsafe fn try_write(&self) -> bool {
         self.0.try_write()
     }
 
+    /// Attempts to acquire exclusive access to this lock, giving up once
+    /// `deadline` has passed.
+    ///
+    /// Behavior is undefined if the rwlock has been moved between this and any
+    /// previous method call.
+    #[inline]
+    pub unsafe fn try_write_until(&self, deadline: Instant) -> bool {
+        self.0.try_write_until(deadline)
+    }
+
     /// Unlocks previously acquired shared access to this lock.
     ///
     /// Behavior is undefined if the current thread does not have shared access.
@@ -164,7 +164,7 @@
 use crate::sys_common::thread;
 use crate::sys_common::thread_info;
 use crate::sys_common::thread_parker::Parker;
-use crate::sys_common::{AsInner, IntoInner};
+use crate::sys_common::{AsInner, AsInnerMut, IntoInner};
 use crate::time::Duration;
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -257,6 +257,8 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread in bytes
     stack_size: Option<usize>,
+    // Platform-specific settings, see the `BuilderExt` traits in `std::os`
+    native: imp::Options,
 }
 
 impl Builder {
@@ -280,7 +282,7 @@ impl Builder {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Builder {
-        Builder { name: None, stack_size: None }
+        Builder { name: None, stack_size: None, native: imp::Options::default() }
     }
 
     /// Names the thread-to-be. Currently the name is used for identification
@@ -446,7 +448,7 @@ pub unsafe fn spawn_unchecked<'a, F, T>(self, f: F) -> io::Result<JoinHandle<T>>
         F: Send + 'a,
         T: Send + 'a,
     {
-        let Builder { name, stack_size } = self;
+        let Builder { name, stack_size, native: options } = self;
 
         let stack_size = stack_size.unwrap_or_else(thread::min_stack);
 
@@ -497,6 +499,7 @@ pub unsafe fn spawn_unchecked<'a, F, T>(self, f: F) -> io::Result<JoinHandle<T>>
             native: unsafe {
                 Some(imp::Thread::new(
                     stack_size,
+                    &options,
                     mem::transmute::<Box<dyn FnOnce() + 'a>, Box<dyn FnOnce() + 'static>>(
                         Box::new(main),
                     ),
@@ -1379,6 +1382,12 @@ pub fn join(mut self) -> Result<T> {
     }
 }
 
+impl AsInnerMut<imp::Options> for Builder {
+    fn as_inner_mut(&mut self) -> &mut imp::Options {
+        &mut self.native
+    }
+}
+
 impl<T> AsInner<imp::Thread> for JoinHandle<T> {
     fn as_inner(&self) -> &imp::Thread {
         self.0.native.as_ref().unwrap()
@@ -260,3 +260,53 @@ fn test_thread_id_not_equal() {
 
 // NOTE: the corresponding test for stderr is in ui/thread-stderr, due
 // to the test harness apparently interfering with stderr configuration.
+
+#[test]
+#[cfg(unix)]
+fn test_guard_size() {
+    use crate::os::unix::thread::BuilderExt;
+
+    let (tx, rx) = channel();
+    Builder::new()
+        .guard_size(64 * 1024)
+        .spawn(move || tx.send(()).unwrap())
+        .unwrap()
+        .join()
+        .unwrap();
+    rx.recv().unwrap();
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_affinity() {
+    use crate::os::unix::thread::{current_affinity, BuilderExt, CpuSet};
+
+    let cpu = current_affinity().unwrap().iter().next().unwrap();
+    let mut cpus = CpuSet::new();
+    cpus.insert(cpu);
+
+    let (tx, rx) = channel();
+    Builder::new()
+        .affinity(cpus)
+        .spawn(move || tx.send(current_affinity().unwrap()).unwrap())
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(rx.recv().unwrap(), cpus);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn test_sched_policy() {
+    use crate::os::unix::thread::{BuilderExt, SchedPolicy};
+
+    // Lowering the priority never requires privileges.
+    Builder::new().sched_policy(SchedPolicy::Idle).spawn(|| {}).unwrap().join().unwrap();
+
+    // An invalid priority makes spawning fail without running the closure.
+    let (tx, rx) = channel();
+    let result =
+        Builder::new().sched_policy(SchedPolicy::Fifo).priority(1000).spawn(move || tx.send(()));
+    assert!(result.is_err());
+    assert!(rx.recv().is_err());
+}
@@ -14,6 +14,7 @@
 
 #[cfg(test)]
 mod tests;
+mod utc;
 
 use crate::cmp;
 use crate::error::Error;
@@ -26,6 +27,9 @@
 #[stable(feature = "time", since = "1.3.0")]
 pub use core::time::Duration;
 
+#[unstable(feature = "utc_datetime", issue = "none")]
+pub use self::utc::{ParseUtcDateTimeError, UtcDateTime};
+
 /// A measurement of a monotonically nondecreasing clock.
 /// Opaque and useful only with `Duration`.
 ///
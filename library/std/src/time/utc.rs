@@ -0,0 +1,451 @@
+//! Conversions between `SystemTime` and civil UTC dates and times.
+//!
+//! The calendar arithmetic follows Howard Hinnant's `days_from_civil` and
+//! `civil_from_days` algorithms for the proleptic Gregorian calendar, see
+//! <http://howardhinnant.github.io/date_algorithms.html>.
+
+#[cfg(test)]
+mod tests;
+
+use super::{Duration, SystemTime, UNIX_EPOCH};
+use crate::error::Error;
+use crate::fmt;
+use crate::str::FromStr;
+
+const SECS_PER_DAY: i64 = 86_400;
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Days between 0000-03-01 and 1970-01-01.
+const DAYS_TO_UNIX_EPOCH: i64 = 719_468;
+const DAYS_PER_ERA: i64 = 146_097;
+
+/// Years further than this from year 0 are rejected before any arithmetic is
+/// done on them. The bound lies well beyond what a `SystemTime` can represent
+/// on any platform, yet is small enough for the day count not to overflow.
+const MAX_ABS_YEAR: u64 = 1 << 40;
+
+/// A date and time in the proleptic Gregorian calendar, in UTC.
+///
+/// `UtcDateTime` is a plain breakdown of a point in time into its calendar
+/// fields. It carries no time zone information and does not account for leap
+/// seconds: like [`SystemTime`] itself, every day is exactly 86 400 seconds
+/// long.
+///
+/// A `UtcDateTime` is obtained from [`SystemTime::to_utc_datetime`], built with
+/// [`UtcDateTime::new`], or parsed from an [RFC 3339] timestamp with the `Z`
+/// offset. Its [`Display`] implementation produces such a timestamp.
+///
+/// Ordering compares the fields from the year downwards, which is the same as
+/// comparing the points in time they describe.
+///
+/// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+/// [`Display`]: fmt::Display
+///
+/// # Examples
+///
+/// ```
+/// #![feature(utc_datetime)]
+/// use std::time::{SystemTime, UtcDateTime};
+///
+/// let dt: UtcDateTime = "2021-03-25T14:05:09.25Z".parse().unwrap();
+/// assert_eq!((dt.year(), dt.month(), dt.day()), (2021, 3, 25));
+/// assert_eq!((dt.hour(), dt.minute(), dt.second()), (14, 5, 9));
+/// assert_eq!(dt.nanosecond(), 250_000_000);
+///
+/// let time = SystemTime::from_utc_datetime(&dt).unwrap();
+/// assert_eq!(time.to_utc_datetime(), dt);
+/// assert_eq!(dt.to_string(), "2021-03-25T14:05:09.25Z");
+/// ```
+#[unstable(feature = "utc_datetime", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcDateTime {
+    year: i64,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+/// An error which can be returned when parsing a [`UtcDateTime`].
+///
+/// Only [RFC 3339] timestamps of the form `YYYY-MM-DDTHH:MM:SS[.fraction]Z`
+/// are accepted, with at most nine fractional digits. Years outside of 0 to
+/// 9999 take a sign and at least four digits instead, as written by the
+/// [`Display`] implementation of [`UtcDateTime`]. Leap seconds are rejected as
+/// out of range, since a `UtcDateTime` cannot represent them.
+///
+/// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+/// [`Display`]: fmt::Display
+#[unstable(feature = "utc_datetime", issue = "none")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUtcDateTimeError {
+    kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParseErrorKind {
+    Syntax,
+    OutOfRange,
+}
+
+impl UtcDateTime {
+    /// Creates a `UtcDateTime` from its calendar fields.
+    ///
+    /// `month` and `day` start at 1. Returns [`None`] if any field is out of
+    /// range, including days that do not exist in the given month, such as
+    /// February 29th of a common year. Leap seconds (a `second` of 60) are
+    /// not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_datetime)]
+    /// use std::time::UtcDateTime;
+    ///
+    /// assert!(UtcDateTime::new(2020, 2, 29, 12, 0, 0, 0).is_some());
+    /// assert!(UtcDateTime::new(2021, 2, 29, 12, 0, 0, 0).is_none());
+    /// assert!(UtcDateTime::new(2021, 1, 1, 24, 0, 0, 0).is_none());
+    /// ```
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn new(
+        year: i64,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<UtcDateTime> {
+        if year.unsigned_abs() > MAX_ABS_YEAR
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 59
+            || nanosecond >= NANOS_PER_SEC
+        {
+            return None;
+        }
+        Some(UtcDateTime { year, month, day, hour, minute, second, nanosecond })
+    }
+
+    /// Returns the year. Years before 1 AD are numbered astronomically, so
+    /// the year 0 is 1 BC.
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1 to 31.
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour, from 0 to 23.
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 59.
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fraction of the second in nanoseconds, from 0 to
+    /// 999 999 999.
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+}
+
+impl SystemTime {
+    /// Breaks this system time down into a date and time in UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_datetime)]
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(951_782_400);
+    /// let dt = time.to_utc_datetime();
+    /// assert_eq!((dt.year(), dt.month(), dt.day()), (2000, 2, 29));
+    /// assert_eq!(dt.to_string(), "2000-02-29T00:00:00Z");
+    /// ```
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn to_utc_datetime(&self) -> UtcDateTime {
+        let (secs, nanosecond) = match self.duration_since(UNIX_EPOCH) {
+            Ok(dur) => (dur.as_secs() as i64, dur.subsec_nanos()),
+            Err(err) => {
+                // `wrapping_neg` maps a distance of exactly 2^63 seconds to
+                // `i64::MIN`, which is the correct result.
+                let dur = err.duration();
+                let secs = (dur.as_secs() as i64).wrapping_neg();
+                match dur.subsec_nanos() {
+                    0 => (secs, 0),
+                    nanos => (secs - 1, NANOS_PER_SEC - nanos),
+                }
+            }
+        };
+
+        let days = secs.div_euclid(SECS_PER_DAY);
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        UtcDateTime {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u8,
+            minute: (secs_of_day / 60 % 60) as u8,
+            second: (secs_of_day % 60) as u8,
+            nanosecond,
+        }
+    }
+
+    /// Creates the system time corresponding to a date and time in UTC.
+    ///
+    /// This is the inverse of [`SystemTime::to_utc_datetime`]. Returns [`None`]
+    /// if the point in time cannot be represented by a `SystemTime` on this
+    /// platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(utc_datetime)]
+    /// use std::time::{Duration, SystemTime, UtcDateTime};
+    ///
+    /// let dt = UtcDateTime::new(1970, 1, 2, 0, 0, 1, 0).unwrap();
+    /// let time = SystemTime::from_utc_datetime(&dt).unwrap();
+    /// assert_eq!(time, SystemTime::UNIX_EPOCH + Duration::from_secs(86_401));
+    /// ```
+    #[unstable(feature = "utc_datetime", issue = "none")]
+    pub fn from_utc_datetime(dt: &UtcDateTime) -> Option<SystemTime> {
+        let days = days_from_civil(dt.year, dt.month, dt.day);
+        let secs_of_day = dt.hour as i64 * 3600 + dt.minute as i64 * 60 + dt.second as i64;
+        let secs = days.checked_mul(SECS_PER_DAY)?.checked_add(secs_of_day)?;
+        let nanos = Duration::from_nanos(dt.nanosecond as u64);
+
+        if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(secs as u64))?.checked_add(nanos)
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs()))?.checked_add(nanos)
+        }
+    }
+}
+
+#[unstable(feature = "utc_datetime", issue = "none")]
+impl fmt::Display for UtcDateTime {
+    /// Formats the date and time as an RFC 3339 timestamp, such as
+    /// `2021-03-25T14:05:09.25Z`.
+    ///
+    /// Trailing zeros of the fractional second are omitted, as is the whole
+    /// fraction when it is zero. A precision, as in `{:.3}`, prints exactly
+    /// that many fractional digits (at most nine), truncating the rest.
+    ///
+    /// Years outside of 0 to 9999 cannot be expressed in RFC 3339. They are
+    /// written with an explicit sign, as in ISO 8601's expanded
+    /// representation, which [`FromStr`] accepts back.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            write!(f, "{:+05}", self.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.month, self.day, self.hour, self.minute, self.second
+        )?;
+
+        let digits = match f.precision() {
+            Some(precision) => precision.min(9),
+            None => {
+                let mut digits = 9;
+                let mut nanos = self.nanosecond;
+                while digits > 0 && nanos % 10 == 0 {
+                    nanos /= 10;
+                    digits -= 1;
+                }
+                digits
+            }
+        };
+        if digits > 0 {
+            let fraction = self.nanosecond / 10u32.pow(9 - digits as u32);
+            write!(f, ".{:01$}", fraction, digits)?;
+        }
+        f.write_str("Z")
+    }
+}
+
+#[unstable(feature = "utc_datetime", issue = "none")]
+impl FromStr for UtcDateTime {
+    type Err = ParseUtcDateTimeError;
+
+    /// Parses an RFC 3339 timestamp in UTC, such as `2021-03-25T14:05:09.25Z`.
+    ///
+    /// The date and time may be separated by `T` or `t`, and the offset must
+    /// be `Z` or `z`; numeric offsets are rejected, even `+00:00`. Years
+    /// outside of 0 to 9999 are accepted in the signed form written by
+    /// [`Display`], such as `-0001` or `+10000`.
+    ///
+    /// A leap second (a second of 60) is rejected as out of range.
+    ///
+    /// [`Display`]: fmt::Display
+    fn from_str(s: &str) -> Result<UtcDateTime, ParseUtcDateTimeError> {
+        let syntax = ParseUtcDateTimeError { kind: ParseErrorKind::Syntax };
+        let (year, b) = split_year(s.as_bytes()).ok_or_else(|| syntax.clone())?;
+        if b.len() < 16
+            || b[0] != b'-'
+            || b[3] != b'-'
+            || !matches!(b[6], b'T' | b't')
+            || b[9] != b':'
+            || b[12] != b':'
+            || !matches!(b[b.len() - 1], b'Z' | b'z')
+        {
+            return Err(syntax);
+        }
+
+        let month = parse_digits(&b[1..3]).ok_or_else(|| syntax.clone())?;
+        let day = parse_digits(&b[4..6]).ok_or_else(|| syntax.clone())?;
+        let hour = parse_digits(&b[7..9]).ok_or_else(|| syntax.clone())?;
+        let minute = parse_digits(&b[10..12]).ok_or_else(|| syntax.clone())?;
+        let second = parse_digits(&b[13..15]).ok_or_else(|| syntax.clone())?;
+
+        let nanosecond = match &b[15..b.len() - 1] {
+            [] => 0,
+            [b'.', fraction @ ..] if (1..=9).contains(&fraction.len()) => {
+                let value = parse_digits(fraction).ok_or(syntax)?;
+                value * 10u32.pow(9 - fraction.len() as u32)
+            }
+            _ => return Err(syntax),
+        };
+
+        UtcDateTime::new(
+            year,
+            month as u8,
+            day as u8,
+            hour as u8,
+            minute as u8,
+            second as u8,
+            nanosecond,
+        )
+        .ok_or(ParseUtcDateTimeError { kind: ParseErrorKind::OutOfRange })
+    }
+}
+
+#[unstable(feature = "utc_datetime", issue = "none")]
+impl fmt::Display for ParseUtcDateTimeError {
+    #[allow(deprecated, deprecated_in_future)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.description())
+    }
+}
+
+#[unstable(feature = "utc_datetime", issue = "none")]
+impl Error for ParseUtcDateTimeError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        match self.kind {
+            ParseErrorKind::Syntax => "invalid RFC 3339 timestamp syntax",
+            ParseErrorKind::OutOfRange => "RFC 3339 timestamp field out of range",
+        }
+    }
+}
+
+/// Splits the year off the start of a timestamp, returning it and the rest.
+///
+/// The years 0 to 9999 are four digits and other years a sign followed by at
+/// least four digits, as `Display` writes them. Years too far out for a
+/// `UtcDateTime` come back just past `MAX_ABS_YEAR`, so that they are reported
+/// as out of range rather than as a syntax error.
+fn split_year(s: &[u8]) -> Option<(i64, &[u8])> {
+    let (sign, s) = match s.first() {
+        Some(b'+') => (Some(1), &s[1..]),
+        Some(b'-') => (Some(-1), &s[1..]),
+        _ => (None, s),
+    };
+    let len = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    let abs = s[..len]
+        .iter()
+        .fold(0u64, |acc, &b| acc.saturating_mul(10).saturating_add((b - b'0') as u64))
+        .min(MAX_ABS_YEAR + 1) as i64;
+    let year = match sign {
+        None if len == 4 => abs,
+        Some(sign) if len >= 4 && !(0..=9999).contains(&(sign * abs)) => sign * abs,
+        _ => return None,
+    };
+    Some((year, &s[len..]))
+}
+
+/// Parses a run of ASCII digits, which must not be empty and is at most nine
+/// digits long so that the result fits in a `u32`.
+fn parse_digits(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0, |acc, &b| match b {
+        b'0'..=b'9' => Some(acc * 10 + (b - b'0') as u32),
+        _ => None,
+    })
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days between 1970-01-01 and the given date, which
+/// must be valid and within `MAX_ABS_YEAR`.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    // Years are counted from March so that the leap day comes last.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * DAYS_PER_ERA + day_of_era - DAYS_TO_UNIX_EPOCH
+}
+
+/// Returns the year, month and day of the date that lies `days` days after
+/// 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + DAYS_TO_UNIX_EPOCH;
+    let era = days.div_euclid(DAYS_PER_ERA);
+    let day_of_era = days.rem_euclid(DAYS_PER_ERA);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u8;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+    let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+    (year, month as u8, day)
+}
@@ -0,0 +1,260 @@
+use super::{civil_from_days, days_from_civil, days_in_month, is_leap_year};
+use super::{ParseErrorKind, ParseUtcDateTimeError, UtcDateTime};
+use crate::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn dt(year: i64, month: u8, day: u8, hour: u8, minute: u8, second: u8, nanos: u32) -> UtcDateTime {
+    UtcDateTime::new(year, month, day, hour, minute, second, nanos).unwrap()
+}
+
+fn at(secs: u64, nanos: u32) -> SystemTime {
+    UNIX_EPOCH + Duration::new(secs, nanos)
+}
+
+#[cfg(any(unix, windows))]
+fn before(secs: u64, nanos: u32) -> SystemTime {
+    UNIX_EPOCH - Duration::new(secs, nanos)
+}
+
+#[test]
+fn leap_years() {
+    for &year in &[-400, -4, 0, 4, 1600, 1972, 1996, 2000, 2004, 2020, 2400] {
+        assert!(is_leap_year(year), "{}", year);
+        assert_eq!(days_in_month(year, 2), 29);
+        assert!(UtcDateTime::new(year, 2, 29, 0, 0, 0, 0).is_some(), "{}", year);
+    }
+    for &year in &[-100, -1, 1, 1700, 1800, 1900, 1970, 1999, 2001, 2100, 2200, 2300] {
+        assert!(!is_leap_year(year), "{}", year);
+        assert_eq!(days_in_month(year, 2), 28);
+        assert!(UtcDateTime::new(year, 2, 29, 0, 0, 0, 0).is_none(), "{}", year);
+    }
+}
+
+#[test]
+fn invalid_fields() {
+    assert!(UtcDateTime::new(2021, 0, 1, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2021, 13, 1, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2021, 1, 0, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2021, 1, 32, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2021, 4, 31, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2021, 1, 1, 24, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(2021, 1, 1, 0, 60, 0, 0).is_none());
+    assert!(UtcDateTime::new(2021, 1, 1, 0, 0, 60, 0).is_none());
+    assert!(UtcDateTime::new(2021, 1, 1, 0, 0, 0, 1_000_000_000).is_none());
+    assert!(UtcDateTime::new(i64::MAX, 1, 1, 0, 0, 0, 0).is_none());
+    assert!(UtcDateTime::new(i64::MIN, 1, 1, 0, 0, 0, 0).is_none());
+
+    assert!(UtcDateTime::new(2021, 12, 31, 23, 59, 59, 999_999_999).is_some());
+}
+
+#[test]
+fn every_day_matches_calendar() {
+    // Walk day by day from 1200 to 2800 and check both conversions against a
+    // naive calendar, covering several 400-year cycles on either side of the
+    // epoch.
+    let (mut year, mut month, mut day) = (1200, 1, 1);
+    let mut days = days_from_civil(year, month, day);
+    assert_eq!(days, -281_237);
+    while year < 2800 {
+        assert_eq!(days_from_civil(year, month, day), days);
+        assert_eq!(civil_from_days(days), (year, month, day));
+
+        days += 1;
+        day += 1;
+        if day > days_in_month(year, month) {
+            day = 1;
+            month += 1;
+            if month > 12 {
+                month = 1;
+                year += 1;
+            }
+        }
+    }
+}
+
+#[test]
+fn days_around_epoch() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(days_from_civil(1969, 12, 31), -1);
+    assert_eq!(days_from_civil(1968, 2, 29), -672);
+    assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+    assert_eq!(days_from_civil(0, 3, 1), -719_468);
+    assert_eq!(days_from_civil(0, 1, 1), -719_528);
+    assert_eq!(days_from_civil(-1, 12, 31), -719_529);
+    assert_eq!(civil_from_days(-719_529), (-1, 12, 31));
+    assert_eq!(civil_from_days(-719_468), (0, 3, 1));
+    assert_eq!(civil_from_days(-719_469), (0, 2, 29));
+}
+
+#[test]
+fn to_utc_datetime() {
+    assert_eq!(UNIX_EPOCH.to_utc_datetime(), dt(1970, 1, 1, 0, 0, 0, 0));
+    assert_eq!(at(1, 1).to_utc_datetime(), dt(1970, 1, 1, 0, 0, 1, 1));
+    assert_eq!(at(68_169_600, 0).to_utc_datetime(), dt(1972, 2, 29, 0, 0, 0, 0));
+    assert_eq!(at(951_868_799, 0).to_utc_datetime(), dt(2000, 2, 29, 23, 59, 59, 0));
+    assert_eq!(at(951_868_800, 0).to_utc_datetime(), dt(2000, 3, 1, 0, 0, 0, 0));
+    assert_eq!(at(1_234_567_890, 5).to_utc_datetime(), dt(2009, 2, 13, 23, 31, 30, 5));
+    assert_eq!(at(2_147_483_648, 0).to_utc_datetime(), dt(2038, 1, 19, 3, 14, 8, 0));
+    assert_eq!(at(4_107_542_400, 0).to_utc_datetime(), dt(2100, 3, 1, 0, 0, 0, 0));
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn to_utc_datetime_before_epoch() {
+    assert_eq!(before(0, 1).to_utc_datetime(), dt(1969, 12, 31, 23, 59, 59, 999_999_999));
+    assert_eq!(before(1, 0).to_utc_datetime(), dt(1969, 12, 31, 23, 59, 59, 0));
+    assert_eq!(before(1, 1).to_utc_datetime(), dt(1969, 12, 31, 23, 59, 58, 999_999_999));
+    assert_eq!(before(86_400, 0).to_utc_datetime(), dt(1969, 12, 31, 0, 0, 0, 0));
+    assert_eq!(before(86_401, 0).to_utc_datetime(), dt(1969, 12, 30, 23, 59, 59, 0));
+    assert_eq!(before(58_060_800, 0).to_utc_datetime(), dt(1968, 2, 29, 0, 0, 0, 0));
+    assert_eq!(before(2_203_891_200, 0).to_utc_datetime(), dt(1900, 3, 1, 0, 0, 0, 0));
+    assert_eq!(before(2_203_891_201, 0).to_utc_datetime(), dt(1900, 2, 28, 23, 59, 59, 0));
+    assert_eq!(before(11_644_473_600, 0).to_utc_datetime(), dt(1601, 1, 1, 0, 0, 0, 0));
+}
+
+#[test]
+fn from_utc_datetime() {
+    let cases = [
+        (dt(1970, 1, 1, 0, 0, 0, 0), at(0, 0)),
+        (dt(1970, 1, 1, 0, 0, 0, 1), at(0, 1)),
+        (dt(1972, 2, 29, 0, 0, 0, 0), at(68_169_600, 0)),
+        (dt(2000, 2, 29, 23, 59, 59, 0), at(951_868_799, 0)),
+        (dt(2009, 2, 13, 23, 31, 30, 5), at(1_234_567_890, 5)),
+        (dt(2038, 1, 19, 3, 14, 8, 0), at(2_147_483_648, 0)),
+    ];
+    for &(dt, time) in &cases {
+        assert_eq!(SystemTime::from_utc_datetime(&dt), Some(time), "{}", dt);
+        assert_eq!(time.to_utc_datetime(), dt);
+    }
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn from_utc_datetime_before_epoch() {
+    let cases = [
+        (dt(1969, 12, 31, 23, 59, 59, 999_999_999), before(0, 1)),
+        (dt(1969, 12, 31, 23, 59, 58, 999_999_999), before(1, 1)),
+        (dt(1968, 2, 29, 0, 0, 0, 0), before(58_060_800, 0)),
+        (dt(1900, 2, 28, 23, 59, 59, 0), before(2_203_891_201, 0)),
+        (dt(1601, 1, 1, 0, 0, 0, 0), before(11_644_473_600, 0)),
+    ];
+    for &(dt, time) in &cases {
+        assert_eq!(SystemTime::from_utc_datetime(&dt), Some(time), "{}", dt);
+        assert_eq!(time.to_utc_datetime(), dt);
+    }
+}
+
+#[test]
+fn from_utc_datetime_out_of_range() {
+    let far_future = dt(1 << 40, 1, 1, 0, 0, 0, 0);
+    assert_eq!(SystemTime::from_utc_datetime(&far_future), None);
+    let far_past = dt(-(1 << 40), 1, 1, 0, 0, 0, 0);
+    assert_eq!(SystemTime::from_utc_datetime(&far_past), None);
+}
+
+#[test]
+fn display() {
+    assert_eq!(dt(1970, 1, 1, 0, 0, 0, 0).to_string(), "1970-01-01T00:00:00Z");
+    assert_eq!(dt(2021, 3, 25, 14, 5, 9, 250_000_000).to_string(), "2021-03-25T14:05:09.25Z");
+    assert_eq!(dt(2021, 3, 25, 14, 5, 9, 1).to_string(), "2021-03-25T14:05:09.000000001Z");
+    assert_eq!(dt(0, 1, 1, 0, 0, 0, 0).to_string(), "0000-01-01T00:00:00Z");
+    assert_eq!(dt(9999, 12, 31, 23, 59, 59, 0).to_string(), "9999-12-31T23:59:59Z");
+    assert_eq!(dt(-1, 12, 31, 0, 0, 0, 0).to_string(), "-0001-12-31T00:00:00Z");
+    assert_eq!(dt(10000, 1, 1, 0, 0, 0, 0).to_string(), "+10000-01-01T00:00:00Z");
+
+    let time = dt(2021, 3, 25, 14, 5, 9, 123_456_789);
+    assert_eq!(format!("{:.0}", time), "2021-03-25T14:05:09Z");
+    assert_eq!(format!("{:.3}", time), "2021-03-25T14:05:09.123Z");
+    assert_eq!(format!("{:.9}", time), "2021-03-25T14:05:09.123456789Z");
+    assert_eq!(format!("{:.12}", time), "2021-03-25T14:05:09.123456789Z");
+    assert_eq!(format!("{:.3}", dt(2021, 1, 1, 0, 0, 0, 0)), "2021-01-01T00:00:00.000Z");
+}
+
+#[test]
+fn parse() {
+    let parse = |s: &str| s.parse::<UtcDateTime>();
+
+    assert_eq!(parse("1970-01-01T00:00:00Z"), Ok(dt(1970, 1, 1, 0, 0, 0, 0)));
+    assert_eq!(parse("2000-02-29t12:34:56z"), Ok(dt(2000, 2, 29, 12, 34, 56, 0)));
+    assert_eq!(parse("1969-12-31T23:59:59.5Z"), Ok(dt(1969, 12, 31, 23, 59, 59, 500_000_000)));
+    assert_eq!(parse("2021-03-25T14:05:09.000000001Z"), Ok(dt(2021, 3, 25, 14, 5, 9, 1)));
+    assert_eq!(parse("0000-01-01T00:00:00Z"), Ok(dt(0, 1, 1, 0, 0, 0, 0)));
+
+    for &time in &[
+        dt(1904, 2, 29, 6, 7, 8, 0),
+        dt(1970, 1, 1, 0, 0, 0, 100),
+        dt(2021, 12, 31, 23, 59, 59, 999_999_999),
+    ] {
+        assert_eq!(parse(&time.to_string()), Ok(time));
+    }
+}
+
+#[test]
+fn parse_expanded_years() {
+    let parse = |s: &str| s.parse::<UtcDateTime>();
+
+    assert_eq!(parse("-0001-12-31T00:00:00Z"), Ok(dt(-1, 12, 31, 0, 0, 0, 0)));
+    assert_eq!(parse("+10000-01-01T00:00:00Z"), Ok(dt(10000, 1, 1, 0, 0, 0, 0)));
+    assert_eq!(parse("-10000-02-29T00:00:00.5Z"), Ok(dt(-10000, 2, 29, 0, 0, 0, 500_000_000)));
+    assert_eq!(parse("+01099511627776-01-01T00:00:00Z"), Ok(dt(1 << 40, 1, 1, 0, 0, 0, 0)));
+}
+
+#[test]
+fn display_parse_round_trip() {
+    // Everything `Display` writes must be accepted back by `FromStr`, across
+    // the whole range of years.
+    let max = 1 << 40;
+    let years = [-max, -max + 1, -123_456_789, -10_000, -9999, -1, 0, 1, 1970, 9999, 10_000, max];
+    for &year in &years {
+        for &(month, day) in &[(1, 1), (2, 28), (12, 31)] {
+            for &(hour, minute, second) in &[(0, 0, 0), (12, 34, 56), (23, 59, 59)] {
+                for &nanos in &[0, 1, 500_000_000, 999_999_999] {
+                    let time = dt(year, month, day, hour, minute, second, nanos);
+                    assert_eq!(time.to_string().parse(), Ok(time), "{}", time);
+                    for precision in 0..=9 {
+                        let s = format!("{:.*}", precision, time);
+                        let parsed: UtcDateTime = s.parse().unwrap();
+                        assert_eq!((parsed.year(), parsed.second()), (year, second), "{}", s);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_errors() {
+    let syntax = Err(ParseUtcDateTimeError { kind: ParseErrorKind::Syntax });
+    let out_of_range = Err(ParseUtcDateTimeError { kind: ParseErrorKind::OutOfRange });
+    let parse = |s: &str| s.parse::<UtcDateTime>();
+
+    assert_eq!(parse(""), syntax);
+    assert_eq!(parse("2021-03-25"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:09"), syntax);
+    assert_eq!(parse("2021-03-25 14:05:09Z"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:09+00:00"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:09.Z"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:09.1234567890Z"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:09,5Z"), syntax);
+    assert_eq!(parse("2021-3-25T14:05:09Z"), syntax);
+    assert_eq!(parse("+2021-03-25T14:05:09Z"), syntax);
+    assert_eq!(parse("20a1-03-25T14:05:09Z"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:+9Z"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:09.+5Z"), syntax);
+    assert_eq!(parse("2021-03-25T14:05:09Z "), syntax);
+    assert_eq!(parse("02021-03-25T14:05:09Z"), syntax);
+    assert_eq!(parse("-001-03-25T14:05:09Z"), syntax);
+    assert_eq!(parse("-0000-03-25T14:05:09Z"), syntax);
+    assert_eq!(parse("+9999-03-25T14:05:09Z"), syntax);
+    assert_eq!(parse("--0001-03-25T14:05:09Z"), syntax);
+
+    assert_eq!(parse("2021-02-29T00:00:00Z"), out_of_range);
+    assert_eq!(parse("1900-02-29T00:00:00Z"), out_of_range);
+    assert_eq!(parse("2021-13-01T00:00:00Z"), out_of_range);
+    assert_eq!(parse("2021-00-01T00:00:00Z"), out_of_range);
+    assert_eq!(parse("2021-01-00T00:00:00Z"), out_of_range);
+    assert_eq!(parse("2021-01-01T24:00:00Z"), out_of_range);
+    assert_eq!(parse("2021-01-01T00:60:00Z"), out_of_range);
+    assert_eq!(parse("2016-12-31T23:59:60Z"), out_of_range);
+    assert_eq!(parse("+01099511627777-01-01T00:00:00Z"), out_of_range);
+    assert_eq!(parse("-99999999999999999999999-01-01T00:00:00Z"), out_of_range);
+}
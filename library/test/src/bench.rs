@@ -12,6 +12,9 @@
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+/// Number of resamples used to bootstrap the confidence interval of a benchmark's median.
+const BOOTSTRAP_RESAMPLES: usize = 1000;
+
 /// Manager of the benchmarking runs.
 ///
 /// This is fed into functions marked with `#[bench]` to allow for
@@ -21,6 +24,7 @@
 pub struct Bencher {
     mode: BenchMode,
     summary: Option<stats::Summary>,
+    median_ci: Option<stats::ConfidenceInterval>,
     pub bytes: u64,
 }
 
@@ -35,7 +39,9 @@ pub fn iter<T, F>(&mut self, mut inner: F)
             return;
         }
 
-        self.summary = Some(iter(&mut inner));
+        let samples = ns_iter_samples(&mut inner);
+        self.summary = Some(stats::Summary::new(&samples));
+        self.median_ci = Some(stats::bootstrap_median(&samples, BOOTSTRAP_RESAMPLES, 95.0));
     }
 
     pub fn bench<F>(&mut self, mut f: F) -> Option<stats::Summary>
@@ -50,7 +56,11 @@ pub fn bench<F>(&mut self, mut f: F) -> Option<stats::Summary>
 #[derive(Debug, Clone, PartialEq)]
 pub struct BenchSamples {
     pub ns_iter_summ: stats::Summary,
+    /// 95% confidence interval of `ns_iter_summ.median`
+    pub ns_iter_ci: stats::ConfidenceInterval,
     pub mb_s: usize,
+    /// Bytes processed per iteration, as set through `Bencher::bytes`
+    pub bytes: u64,
 }
 
 pub fn fmt_bench_samples(bs: &BenchSamples) -> String {
@@ -109,6 +119,14 @@ fn ns_iter_inner<T, F>(inner: &mut F, k: u64) -> u64
 }
 
 pub fn iter<T, F>(inner: &mut F) -> stats::Summary
+where
+    F: FnMut() -> T,
+{
+    stats::Summary::new(&ns_iter_samples(inner))
+}
+
+// Returns the time per iteration, in ns, of each sample of the final round of measurements.
+fn ns_iter_samples<T, F>(inner: &mut F) -> Vec<f64>
 where
     F: FnMut() -> T,
 {
@@ -155,13 +173,13 @@ pub fn iter<T, F>(inner: &mut F) -> stats::Summary
             && summ.median_abs_dev_pct < 1.0
             && summ.median - summ5.median < summ5.median_abs_dev
         {
-            return summ5;
+            return samples.to_vec();
         }
 
         total_run += loop_run;
         // Longest we ever run for is 3s.
         if total_run > Duration::from_secs(3) {
-            return summ5;
+            return samples.to_vec();
         }
 
         // If we overflow here just return the results so far. We check a
@@ -171,7 +189,7 @@ pub fn iter<T, F>(inner: &mut F) -> stats::Summary
         n = match n.checked_mul(10) {
             Some(_) => n * 2,
             None => {
-                return summ5;
+                return samples.to_vec();
             }
         };
     }
@@ -181,7 +199,7 @@ pub fn benchmark<F>(desc: TestDesc, monitor_ch: Sender<CompletedTest>, nocapture
 where
     F: FnMut(&mut Bencher),
 {
-    let mut bs = Bencher { mode: BenchMode::Auto, summary: None, bytes: 0 };
+    let mut bs = Bencher { mode: BenchMode::Auto, summary: None, median_ci: None, bytes: 0 };
 
     let data = Arc::new(Mutex::new(Vec::new()));
 
@@ -199,14 +217,21 @@ pub fn benchmark<F>(desc: TestDesc, monitor_ch: Sender<CompletedTest>, nocapture
             let ns_iter = cmp::max(ns_iter_summ.median as u64, 1);
             let mb_s = bs.bytes * 1000 / ns_iter;
 
-            let bs = BenchSamples { ns_iter_summ, mb_s: mb_s as usize };
+            let ns_iter_ci = bs.median_ci.unwrap();
+            let bs =
+                BenchSamples { ns_iter_summ, ns_iter_ci, mb_s: mb_s as usize, bytes: bs.bytes };
             TestResult::TrBench(bs)
         }
         Ok(None) => {
             // iter not called, so no data.
             // FIXME: error in this case?
             let samples: &mut [f64] = &mut [0.0_f64; 1];
-            let bs = BenchSamples { ns_iter_summ: stats::Summary::new(samples), mb_s: 0 };
+            let bs = BenchSamples {
+                ns_iter_summ: stats::Summary::new(samples),
+                ns_iter_ci: stats::ConfidenceInterval { lower: 0.0, upper: 0.0 },
+                mb_s: 0,
+                bytes: 0,
+            };
             TestResult::TrBench(bs)
         }
         Err(_) => TestResult::TrFailed,
@@ -221,6 +246,6 @@ pub fn run_once<F>(f: F)
 where
     F: FnMut(&mut Bencher),
 {
-    let mut bs = Bencher { mode: BenchMode::Single, summary: None, bytes: 0 };
+    let mut bs = Bencher { mode: BenchMode::Single, summary: None, median_ci: None, bytes: 0 };
     bs.bench(f);
 }
@@ -1,11 +1,14 @@
 //! Module converting command-line arguments into test configuration.
 
 use std::env;
+use std::io::{self, IsTerminal};
 use std::path::PathBuf;
+use std::time::Duration;
 
-use super::helpers::isatty;
-use super::options::{ColorConfig, Options, OutputFormat, RunIgnored};
-use super::time::TestTimeOptions;
+use super::options::{
+    CaptureMode, ColorConfig, Options, OutputFormat, Partition, PartitionKind, RunIgnored,
+};
+use super::time::{self, TestTimeOptions};
 
 #[derive(Debug)]
 pub struct TestOpts {
@@ -19,18 +22,27 @@ pub struct TestOpts {
     pub bench_benchmarks: bool,
     pub logfile: Option<PathBuf>,
     pub nocapture: bool,
+    pub capture: CaptureMode,
     pub color: ColorConfig,
     pub format: OutputFormat,
     pub test_threads: Option<usize>,
     pub skip: Vec<String>,
+    pub shuffle: bool,
+    pub shuffle_seed: Option<u64>,
+    pub partition: Option<Partition>,
+    pub save_baseline: Option<String>,
+    pub baseline: Option<String>,
+    pub ratchet_metrics: Option<PathBuf>,
     pub time_options: Option<TestTimeOptions>,
+    pub test_timeout: Option<Duration>,
+    pub retries: usize,
     pub options: Options,
 }
 
 impl TestOpts {
     pub fn use_color(&self) -> bool {
         match self.color {
-            ColorConfig::AutoColor => !self.nocapture && isatty::stdout_isatty(),
+            ColorConfig::AutoColor => !self.nocapture && io::stdout().is_terminal(),
             ColorConfig::AlwaysColor => true,
             ColorConfig::NeverColor => false,
         }
@@ -65,6 +77,14 @@ fn optgroups() -> getopts::Options {
             "don't capture stdout/stderr of each \
              task, allow printing directly",
         )
+        .optopt(
+            "",
+            "capture",
+            "Configure how the output of tests is captured:
+            print = capture the print macros and panic messages (default);
+            fd    = redirect the standard output and error file descriptors",
+            "print|fd",
+        )
         .optopt(
             "",
             "test-threads",
@@ -86,6 +106,39 @@ fn optgroups() -> getopts::Options {
              Alias to --format=terse",
         )
         .optflag("", "exact", "Exactly match filters rather than by substring")
+        .optflag("", "shuffle", "Run tests in random order")
+        .optopt(
+            "",
+            "shuffle-seed",
+            "Run tests in random order; seed the random number generator with SEED",
+            "SEED",
+        )
+        .optopt(
+            "",
+            "partition",
+            "Only run the INDEX-th of COUNT disjoint subsets of the tests, \
+             assigned either in turn (count, the default) or by name hash (hash)",
+            "[count:|hash:]INDEX/COUNT",
+        )
+        .optopt(
+            "",
+            "save-baseline",
+            "Save the results of the benchmarks as the baseline NAME",
+            "NAME",
+        )
+        .optopt(
+            "",
+            "baseline",
+            "Compare the results of the benchmarks to the baseline NAME",
+            "NAME",
+        )
+        .optopt(
+            "",
+            "ratchet-metrics",
+            "Fail tests whose metrics got worse than the ones saved in FILE,
+            and save new and improved metrics to FILE",
+            "FILE",
+        )
         .optopt(
             "",
             "color",
@@ -101,8 +154,9 @@ fn optgroups() -> getopts::Options {
             "Configure formatting of output:
             pretty = Print verbose output;
             terse  = Display one character per test;
-            json   = Output a json document",
-            "pretty|terse|json",
+            json   = Output a json document;
+            junit  = Output a JUnit document",
+            "pretty|terse|json|junit",
         )
         .optflag("", "show-output", "Show captured stdout of successful tests")
         .optopt(
@@ -143,6 +197,27 @@ fn optgroups() -> getopts::Options {
 
             `CRITICAL_TIME` here means the limit that should not be exceeded by test.
             ",
+        )
+        .optopt(
+            "",
+            "test-timeout",
+            "Stop tests that run for longer than DURATION and report them as timed out.
+            DURATION is a number followed by `ms`, `s` or `m`, e.g. `30s`.
+
+            Tests marked with `#[timeout]` use their own limit instead.
+            Tests run in a subprocess (with panic=abort) are killed, while a test run
+            in-process can't be stopped, so the whole test run is aborted instead.",
+            "DURATION",
+        )
+        .optopt(
+            "",
+            "retries",
+            "Rerun a failing test up to N times, and report it as flaky if it passes.
+
+            Tests marked with `#[flaky(retries = N)]` use their own count instead.
+            Only tests defined with `#[test]` are rerun, not dynamic tests such as
+            doctests.",
+            "N",
         );
     opts
 }
@@ -162,6 +237,39 @@ fn usage(binary: &str, options: &getopts::Options) {
 All tests have their standard output and standard error captured by default.
 This can be overridden with the --nocapture flag or setting RUST_TEST_NOCAPTURE
 environment variable to a value other than "0". Logging is not captured by default.
+Only what is printed with the print macros is captured, unless --capture=fd is
+given (requires -Z unstable-options): then the standard output and error file
+descriptors are redirected, which also captures the output of C code and child
+processes. As they are shared by the whole process, tests that run in-process
+are run one at a time with --capture=fd.
+
+Tests are run in alphabetical order by default. The --shuffle flag (or setting
+the RUST_TEST_SHUFFLE environment variable to a value other than "0") runs them
+in a random order instead; the seed used is printed at the start of the run and
+can be passed back with --shuffle-seed (or RUST_TEST_SHUFFLE_SEED) to reproduce
+the same order. Both require -Z unstable-options.
+
+The --partition flag splits the tests, after filtering, into COUNT disjoint
+subsets and only runs the INDEX-th one (counting from 1), so that a test suite
+can be spread across several machines. With `count:` the tests are dealt out in
+turn in alphabetical order, which keeps the subsets the same size; with `hash:`
+each test is assigned by a hash of its name, so adding or removing tests
+doesn't move other tests to a different subset. Requires -Z unstable-options.
+
+Benchmark results can be saved with --save-baseline NAME and compared to in a
+later run with --baseline NAME. Baselines are stored per test binary in the
+bench-baselines directory of cargo's target directory. Only a run with --bench
+saves a baseline, and benchmarks that didn't run keep their saved results. A
+benchmark is reported as regressed or improved when the 95% confidence intervals
+of the old and new medians don't overlap and the median changed by more than
+2%. Both require -Z unstable-options.
+
+Tests can record metrics, such as the number of bytes they allocated, with
+`test::record_metric(name, value, noise)`. They are reported by the JSON output
+format, and --ratchet-metrics FILE (requires -Z unstable-options) compares them
+to the best values saved in FILE: a passing test fails if one of its metrics
+got worse by more than its noise, and otherwise its new and improved metrics
+are saved to FILE, so that they can only get better over time.
 
 Test Attributes:
 
@@ -222,6 +330,21 @@ macro_rules! unstable_optflag {
     }};
 }
 
+// Gets the option value and checks if unstable features are enabled.
+macro_rules! unstable_optopt {
+    ($matches:ident, $allow_unstable:ident, $option_name:literal) => {{
+        let opt = $matches.opt_str($option_name);
+        if !$allow_unstable && opt.is_some() {
+            return Err(format!(
+                "The \"{}\" flag is only accepted on the nightly compiler with -Z unstable-options",
+                $option_name
+            ));
+        }
+
+        opt
+    }};
+}
+
 // Implementation of `parse_opts` that doesn't care about help message
 // and returns a `Result`.
 fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
@@ -232,6 +355,18 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
     let exclude_should_panic = unstable_optflag!(matches, allow_unstable, "exclude-should-panic");
     let include_ignored = unstable_optflag!(matches, allow_unstable, "include-ignored");
     let time_options = get_time_options(&matches, allow_unstable)?;
+    let shuffle = get_shuffle(&matches, allow_unstable)?;
+    let shuffle_seed = get_shuffle_seed(&matches, allow_unstable)?;
+    let partition = get_partition(&matches, allow_unstable)?;
+    let save_baseline = unstable_optopt!(matches, allow_unstable, "save-baseline");
+    let save_baseline = get_baseline_name("save-baseline", save_baseline)?;
+    let baseline = unstable_optopt!(matches, allow_unstable, "baseline");
+    let baseline = get_baseline_name("baseline", baseline)?;
+    let ratchet_metrics =
+        unstable_optopt!(matches, allow_unstable, "ratchet-metrics").map(PathBuf::from);
+    let test_timeout = get_test_timeout(&matches, allow_unstable)?;
+    let capture = get_capture_mode(&matches, allow_unstable)?;
+    let retries = get_retries(&matches, allow_unstable)?;
 
     let quiet = matches.opt_present("quiet");
     let exact = matches.opt_present("exact");
@@ -262,11 +397,20 @@ fn parse_opts_impl(matches: getopts::Matches) -> OptRes {
         bench_benchmarks,
         logfile,
         nocapture,
+        capture,
         color,
         format,
         test_threads,
         skip,
+        shuffle,
+        shuffle_seed,
+        partition,
+        save_baseline,
+        baseline,
+        ratchet_metrics,
         time_options,
+        test_timeout,
+        retries,
         options,
     };
 
@@ -307,6 +451,32 @@ fn get_time_options(
     Ok(options)
 }
 
+fn get_test_timeout(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<Duration>> {
+    let test_timeout = match unstable_optopt!(matches, allow_unstable, "test-timeout") {
+        Some(timeout) => Some(
+            time::parse_timeout(&timeout)
+                .map_err(|e| format!("argument for --test-timeout is invalid: {}", e))?,
+        ),
+        None => None,
+    };
+
+    Ok(test_timeout)
+}
+
+fn get_retries(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<usize> {
+    let retries = match unstable_optopt!(matches, allow_unstable, "retries") {
+        Some(n_str) => n_str
+            .parse::<usize>()
+            .map_err(|e| format!("argument for --retries must be a number (error: {})", e))?,
+        None => 0,
+    };
+
+    Ok(retries)
+}
+
 fn get_test_threads(matches: &getopts::Matches) -> OptPartRes<Option<usize>> {
     let test_threads = match matches.opt_str("test-threads") {
         Some(n_str) => match n_str.parse::<usize>() {
@@ -326,6 +496,95 @@ fn get_test_threads(matches: &getopts::Matches) -> OptPartRes<Option<usize>> {
     Ok(test_threads)
 }
 
+fn get_shuffle(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<bool> {
+    let mut shuffle = unstable_optflag!(matches, allow_unstable, "shuffle");
+    if !shuffle && allow_unstable {
+        shuffle = match env::var("RUST_TEST_SHUFFLE") {
+            Ok(val) => &val != "0",
+            Err(_) => false,
+        };
+    }
+
+    Ok(shuffle)
+}
+
+fn get_shuffle_seed(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<Option<u64>> {
+    let mut shuffle_seed = match unstable_optopt!(matches, allow_unstable, "shuffle-seed") {
+        Some(n_str) => match n_str.parse::<u64>() {
+            Ok(n) => Some(n),
+            Err(e) => {
+                return Err(format!(
+                    "argument for --shuffle-seed must be a number \
+                     (error: {})",
+                    e
+                ));
+            }
+        },
+        None => None,
+    };
+
+    if shuffle_seed.is_none() && allow_unstable {
+        shuffle_seed = match env::var("RUST_TEST_SHUFFLE_SEED") {
+            Ok(val) => match val.parse::<u64>() {
+                Ok(n) => Some(n),
+                Err(_) => {
+                    return Err(format!("RUST_TEST_SHUFFLE_SEED must be a number (was {})", val));
+                }
+            },
+            Err(_) => None,
+        };
+    }
+
+    Ok(shuffle_seed)
+}
+
+fn get_partition(
+    matches: &getopts::Matches,
+    allow_unstable: bool,
+) -> OptPartRes<Option<Partition>> {
+    let partition = match unstable_optopt!(matches, allow_unstable, "partition") {
+        Some(partition) => partition,
+        None => return Ok(None),
+    };
+
+    let invalid = || {
+        format!(
+            "argument for --partition must be of the form \
+             [count:|hash:]INDEX/COUNT with 1 <= INDEX <= COUNT (was {})",
+            partition
+        )
+    };
+
+    let (kind, range) = match partition.split_once(':') {
+        Some(("count", range)) => (PartitionKind::Count, range),
+        Some(("hash", range)) => (PartitionKind::Hash, range),
+        Some(_) => return Err(invalid()),
+        None => (PartitionKind::Count, &*partition),
+    };
+    let (index, count) = range.split_once('/').ok_or_else(invalid)?;
+    let index = index.parse::<usize>().map_err(|_| invalid())?;
+    let count = count.parse::<usize>().map_err(|_| invalid())?;
+    if index == 0 || index > count {
+        return Err(invalid());
+    }
+
+    Ok(Some(Partition { kind, index, count }))
+}
+
+fn get_baseline_name(option_name: &str, name: Option<String>) -> OptPartRes<Option<String>> {
+    if let Some(ref name) = name {
+        // The name is used as the name of the directory the baseline is stored in.
+        if name.is_empty() || name.starts_with('.') || name.contains(&['/', '\\'][..]) {
+            return Err(format!(
+                "argument for --{} must be a valid directory name (was {})",
+                option_name, name
+            ));
+        }
+    }
+
+    Ok(name)
+}
+
 fn get_format(
     matches: &getopts::Matches,
     quiet: bool,
@@ -341,10 +600,16 @@ fn get_format(
             }
             OutputFormat::Json
         }
+        Some("junit") => {
+            if !allow_unstable {
+                return Err("The \"junit\" format is only accepted on the nightly compiler".into());
+            }
+            OutputFormat::Junit
+        }
 
         Some(v) => {
             return Err(format!(
-                "argument for --format must be pretty, terse, or json (was \
+                "argument for --format must be pretty, terse, json or junit (was \
                  {})",
                 v
             ));
@@ -384,6 +649,19 @@ fn get_nocapture(matches: &getopts::Matches) -> OptPartRes<bool> {
     Ok(nocapture)
 }
 
+fn get_capture_mode(matches: &getopts::Matches, allow_unstable: bool) -> OptPartRes<CaptureMode> {
+    let capture = match unstable_optopt!(matches, allow_unstable, "capture").as_deref() {
+        Some("print") | None => CaptureMode::Print,
+        Some("fd") => CaptureMode::Fd,
+
+        Some(v) => {
+            return Err(format!("argument for --capture must be print or fd (was {})", v));
+        }
+    };
+
+    Ok(capture)
+}
+
 fn get_run_ignored(matches: &getopts::Matches, include_ignored: bool) -> OptPartRes<RunIgnored> {
     let run_ignored = match (include_ignored, matches.opt_present("ignored")) {
         (true, true) => {
@@ -6,12 +6,17 @@
 use std::time::Instant;
 
 use super::{
-    bench::fmt_bench_samples,
+    bench::{fmt_bench_samples, BenchSamples},
     cli::TestOpts,
     event::{CompletedTest, TestEvent},
     filter_tests,
-    formatters::{JsonFormatter, OutputFormatter, PrettyFormatter, TerseFormatter},
-    helpers::{concurrency::get_concurrency, metrics::MetricMap},
+    formatters::{JsonFormatter, JunitFormatter, OutputFormatter, PrettyFormatter, TerseFormatter},
+    helpers::{
+        baseline::{self, Baseline},
+        concurrency::get_concurrency,
+        metrics::MetricMap,
+        ratchet::Ratchet,
+    },
     options::{Options, OutputFormat},
     run_tests,
     test_result::TestResult,
@@ -52,9 +57,14 @@ pub struct ConsoleTestState {
     pub measured: usize,
     pub exec_time: Option<TestSuiteExecTime>,
     pub metrics: MetricMap,
+    pub benchmarks: Vec<(TestDesc, BenchSamples)>,
+    pub baseline: Option<Baseline>,
+    pub ratchet: Option<Ratchet>,
     pub failures: Vec<(TestDesc, Vec<u8>)>,
     pub not_failures: Vec<(TestDesc, Vec<u8>)>,
+    pub flaky: Vec<(TestDesc, Vec<u8>)>,
     pub time_failures: Vec<(TestDesc, Vec<u8>)>,
+    pub timeouts: Vec<(TestDesc, Vec<u8>)>,
     pub options: Options,
 }
 
@@ -76,9 +86,14 @@ pub fn new(opts: &TestOpts) -> io::Result<ConsoleTestState> {
             measured: 0,
             exec_time: None,
             metrics: MetricMap::new(),
+            benchmarks: Vec::new(),
+            baseline: None,
+            ratchet: None,
             failures: Vec::new(),
             not_failures: Vec::new(),
+            flaky: Vec::new(),
             time_failures: Vec::new(),
+            timeouts: Vec::new(),
             options: opts.options,
         })
     }
@@ -115,6 +130,8 @@ pub fn write_log_result(
                     TestResult::TrAllowedFail => "failed (allowed)".to_owned(),
                     TestResult::TrBench(ref bs) => fmt_bench_samples(bs),
                     TestResult::TrTimedFail => "failed (time limit exceeded)".to_owned(),
+                    TestResult::TrTimedOut => "timed out".to_owned(),
+                    TestResult::TrFlaky(attempts) => format!("flaky ({} attempts)", attempts),
                 },
                 test.name,
             )
@@ -157,6 +174,8 @@ pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Res
                 nbench += 1;
                 "benchmark"
             }
+            // Fixtures only run around tests.
+            StaticFixtureFn(..) => continue,
         };
 
         writeln!(output, "{}: {}", name, fntype)?;
@@ -175,7 +194,11 @@ fn plural(count: u32, s: &str) -> String {
             writeln!(output)?;
         }
 
-        writeln!(output, "{}, {}", plural(ntest, "test"), plural(nbench, "benchmark"))?;
+        write!(output, "{}, {}", plural(ntest, "test"), plural(nbench, "benchmark"))?;
+        if let Some(partition) = opts.partition {
+            write!(output, " in partition {}", partition)?;
+        }
+        writeln!(output)?;
     }
 
     Ok(())
@@ -190,6 +213,10 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
             st.passed += 1;
             st.not_failures.push((test, stdout));
         }
+        TestResult::TrFlaky(_) => {
+            st.passed += 1;
+            st.flaky.push((test, stdout));
+        }
         TestResult::TrIgnored => st.ignored += 1,
         TestResult::TrAllowedFail => st.allowed_fail += 1,
         TestResult::TrBench(bs) => {
@@ -198,6 +225,7 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
                 bs.ns_iter_summ.median,
                 bs.ns_iter_summ.max - bs.ns_iter_summ.min,
             );
+            st.benchmarks.push((test, bs));
             st.measured += 1
         }
         TestResult::TrFailed => {
@@ -214,6 +242,10 @@ fn handle_test_result(st: &mut ConsoleTestState, completed_test: CompletedTest)
             st.failed += 1;
             st.time_failures.push((test, stdout));
         }
+        TestResult::TrTimedOut => {
+            st.failed += 1;
+            st.timeouts.push((test, stdout));
+        }
     }
 }
 
@@ -225,25 +257,36 @@ fn on_test_event(
     out: &mut dyn OutputFormatter,
 ) -> io::Result<()> {
     match (*event).clone() {
-        TestEvent::TeFiltered(ref filtered_tests) => {
+        TestEvent::TeFiltered(ref filtered_tests, shuffle_seed) => {
             st.total = filtered_tests.len();
-            out.write_run_start(filtered_tests.len())?;
+            out.write_run_start(filtered_tests.len(), shuffle_seed)?;
         }
         TestEvent::TeFilteredOut(filtered_out) => {
             st.filtered_out = filtered_out;
         }
         TestEvent::TeWait(ref test) => out.write_test_start(test)?,
         TestEvent::TeTimeout(ref test) => out.write_timeout(test)?,
-        TestEvent::TeResult(completed_test) => {
+        TestEvent::TeResult(mut completed_test) => {
+            if let Some(ratchet) = &mut st.ratchet {
+                ratchet.check(&mut completed_test);
+            }
+
             let test = &completed_test.desc;
             let result = &completed_test.result;
             let exec_time = &completed_test.exec_time;
             let stdout = &completed_test.stdout;
+            let metrics = &completed_test.metrics;
 
             st.write_log_result(test, result, exec_time.as_ref())?;
-            out.write_result(test, result, exec_time.as_ref(), &*stdout, st)?;
+            out.write_result(test, result, exec_time.as_ref(), &*stdout, metrics, st)?;
             handle_test_result(st, completed_test);
         }
+        TestEvent::TeFixtureFailed(completed_test) => {
+            // The failure is reported like the result of one more test.
+            st.total += 1;
+            out.write_test_start(&completed_test.desc)?;
+            on_test_event(&TestEvent::TeResult(completed_test), st, out)?;
+        }
     }
 
     Ok(())
@@ -276,9 +319,16 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Resu
         OutputFormat::Terse => {
             Box::new(TerseFormatter::new(output, opts.use_color(), max_name_len, is_multithreaded))
         }
-        OutputFormat::Json => Box::new(JsonFormatter::new(output)),
+        OutputFormat::Json => Box::new(JsonFormatter::new(output, opts.partition)),
+        OutputFormat::Junit => Box::new(JunitFormatter::new(output)),
     };
     let mut st = ConsoleTestState::new(opts)?;
+    if let Some(ref name) = opts.baseline {
+        st.baseline = Some(Baseline::load(name)?);
+    }
+    if let Some(ref path) = opts.ratchet_metrics {
+        st.ratchet = Some(Ratchet::load(path)?);
+    }
 
     // Prevent the usage of `Instant` in some cases:
     // - It's currently not supported for wasm targets.
@@ -289,9 +339,29 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Resu
     run_tests(opts, tests, |x| on_test_event(&x, &mut st, &mut *out))?;
     st.exec_time = start_time.map(|t| TestSuiteExecTime(t.elapsed()));
 
-    assert!(st.current_test_count() == st.total);
+    // A test that times out in-process can't be stopped, so `run_tests` gives up
+    // on the tests that haven't finished yet.
+    assert!(st.current_test_count() == st.total || !st.timeouts.is_empty());
+
+    let success = out.write_run_finish(&st)?;
+
+    save_baseline(opts, &st)?;
+    if let Some(ref ratchet) = st.ratchet {
+        ratchet.save()?;
+    }
+
+    Ok(success)
+}
 
-    out.write_run_finish(&st)
+/// Saves the results of the benchmarks as the baseline named by `--save-baseline`.
+///
+/// Benchmarks only have results when run with `--bench`. Otherwise they were run once as tests,
+/// and there is nothing to save.
+pub fn save_baseline(opts: &TestOpts, st: &ConsoleTestState) -> io::Result<()> {
+    match opts.save_baseline {
+        Some(ref name) if opts.bench_benchmarks => baseline::save(name, &st.benchmarks),
+        _ => Ok(()),
+    }
 }
 
 // Calculates padding for given test description.
@@ -1,6 +1,7 @@
 //! Module containing different events that can occur
 //! during tests execution process.
 
+use super::helpers::metrics::MetricMap;
 use super::test_result::TestResult;
 use super::time::TestExecTime;
 use super::types::TestDesc;
@@ -11,6 +12,11 @@ pub struct CompletedTest {
     pub result: TestResult,
     pub exec_time: Option<TestExecTime>,
     pub stdout: Vec<u8>,
+    /// The output of the `#[test_teardown(each)]` functions, if any of them
+    /// failed after the test.
+    pub teardown_failure: Option<Vec<u8>>,
+    /// The metrics the test recorded with `record_metric`.
+    pub metrics: MetricMap,
 }
 
 impl CompletedTest {
@@ -20,7 +26,7 @@ pub fn new(
         exec_time: Option<TestExecTime>,
         stdout: Vec<u8>,
     ) -> Self {
-        Self { desc, result, exec_time, stdout }
+        Self { desc, result, exec_time, stdout, teardown_failure: None, metrics: MetricMap::new() }
     }
 }
 
@@ -28,9 +34,12 @@ unsafe impl Send for CompletedTest {}
 
 #[derive(Debug, Clone)]
 pub enum TestEvent {
-    TeFiltered(Vec<TestDesc>),
+    TeFiltered(Vec<TestDesc>, Option<u64>),
     TeWait(TestDesc),
     TeResult(CompletedTest),
     TeTimeout(TestDesc),
     TeFilteredOut(usize),
+    /// A setup or teardown function failed. Its result isn't part of the
+    /// tests announced by `TeFiltered`.
+    TeFixtureFailed(CompletedTest),
 }
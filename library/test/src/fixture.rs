@@ -0,0 +1,107 @@
+//! Setup and teardown functions registered with `#[test_setup]` and `#[test_teardown]`.
+
+use std::panic::catch_unwind;
+
+use super::__rust_begin_short_backtrace;
+use super::event::CompletedTest;
+use super::helpers::capture::OutputCapture;
+use super::options::ShouldPanic;
+use super::test_result::TrFailed;
+use super::types::{FixtureKind, StaticFixtureFn, TestDesc, TestDescAndFn, TestName};
+
+/// The fixtures of a test binary, in the order they were registered.
+#[derive(Default)]
+pub struct Fixtures {
+    setup_once: Vec<(TestDesc, fn())>,
+    teardown_once: Vec<(TestDesc, fn())>,
+    pub each: AroundEach,
+}
+
+impl Fixtures {
+    /// Takes the fixtures out of `tests`. Ignored fixtures are dropped.
+    pub fn extract(tests: Vec<TestDescAndFn>) -> (Fixtures, Vec<TestDescAndFn>) {
+        let mut fixtures = Fixtures::default();
+        let mut remaining = Vec::with_capacity(tests.len());
+        for test in tests {
+            match test.testfn {
+                StaticFixtureFn(_, _) if test.desc.ignore => {}
+                StaticFixtureFn(FixtureKind::SetupOnce, f) => {
+                    fixtures.setup_once.push((test.desc, f))
+                }
+                StaticFixtureFn(FixtureKind::SetupEach, f) => fixtures.each.setup.push(f),
+                StaticFixtureFn(FixtureKind::TeardownEach, f) => fixtures.each.teardown.push(f),
+                StaticFixtureFn(FixtureKind::TeardownOnce, f) => {
+                    fixtures.teardown_once.push((test.desc, f))
+                }
+                _ => remaining.push(test),
+            }
+        }
+        (fixtures, remaining)
+    }
+
+    /// Runs the `#[test_setup]` functions, stopping at the first one that fails.
+    pub fn setup_once(&self, nocapture: bool, capture_fds: bool) -> Result<(), CompletedTest> {
+        let mut fixtures = self.setup_once.iter();
+        match fixtures.find_map(|(desc, f)| run_once(desc, *f, nocapture, capture_fds)) {
+            Some(failure) => Err(failure),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs all `#[test_teardown]` functions, even if some of them fail, and returns
+    /// the results of those that failed.
+    pub fn teardown_once(&self, nocapture: bool, capture_fds: bool) -> Vec<CompletedTest> {
+        self.teardown_once
+            .iter()
+            .filter_map(|(desc, f)| run_once(desc, *f, nocapture, capture_fds))
+            .collect()
+    }
+}
+
+/// The `#[test_setup(each)]` and `#[test_teardown(each)]` functions, which run in the
+/// thread or subprocess of every test.
+#[derive(Clone, Default)]
+pub struct AroundEach {
+    pub setup: Vec<fn()>,
+    pub teardown: Vec<fn()>,
+}
+
+impl AroundEach {
+    /// Runs the setup functions, stopping at the first one that panics.
+    /// Returns whether all of them succeeded.
+    pub fn setup(&self) -> bool {
+        self.setup.iter().all(|&f| catch_unwind(|| __rust_begin_short_backtrace(f)).is_ok())
+    }
+
+    /// Runs all teardown functions, even if some of them panic.
+    /// Returns whether all of them succeeded.
+    pub fn teardown(&self) -> bool {
+        self.teardown
+            .iter()
+            .fold(true, |ok, &f| catch_unwind(|| __rust_begin_short_backtrace(f)).is_ok() && ok)
+    }
+}
+
+/// The description of the result reported when the teardown functions
+/// fail after `test`.
+pub fn teardown_desc(test: &TestDesc) -> TestDesc {
+    let name = TestName::DynTestName(format!("{} (teardown)", test.name.as_slice()));
+    TestDesc {
+        name,
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        timeout: None,
+        retries: None,
+        test_type: test.test_type,
+    }
+}
+
+/// Runs a fixture on the current thread and returns its result if it failed.
+fn run_once(desc: &TestDesc, f: fn(), nocapture: bool, capture_fds: bool) -> Option<CompletedTest> {
+    let capture = OutputCapture::start(nocapture, capture_fds);
+    let result = catch_unwind(|| __rust_begin_short_backtrace(f));
+    let stdout = capture.finish();
+
+    result.err().map(|_| CompletedTest::new(desc.clone(), TrFailed, None, stdout))
+}
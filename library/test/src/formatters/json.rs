@@ -3,6 +3,8 @@
 use super::OutputFormatter;
 use crate::{
     console::{ConsoleTestState, OutputLocation},
+    helpers::metrics::MetricMap,
+    options::Partition,
     test_result::TestResult,
     time,
     types::TestDesc,
@@ -10,11 +12,17 @@
 
 pub(crate) struct JsonFormatter<T> {
     out: OutputLocation<T>,
+    partition: Option<Partition>,
 }
 
 impl<T: Write> JsonFormatter<T> {
-    pub fn new(out: OutputLocation<T>) -> Self {
-        Self { out }
+    pub fn new(out: OutputLocation<T>, partition: Option<Partition>) -> Self {
+        Self { out, partition }
+    }
+
+    #[cfg(test)]
+    pub fn output_location(&self) -> &OutputLocation<T> {
+        &self.out
     }
 
     fn writeln_message(&mut self, s: &str) -> io::Result<()> {
@@ -37,6 +45,7 @@ fn write_event(
         evt: &str,
         exec_time: Option<&time::TestExecTime>,
         stdout: Option<Cow<'_, str>>,
+        metrics: &MetricMap,
         extra: Option<&str>,
     ) -> io::Result<()> {
         // A doc test's name includes a filename which must be escaped for correct json.
@@ -52,6 +61,20 @@ fn write_event(
         if let Some(stdout) = stdout {
             self.write_message(&*format!(r#", "stdout": "{}""#, EscapedString(stdout)))?;
         }
+        if !metrics.is_empty() {
+            let metrics = metrics
+                .iter()
+                .map(|(name, metric)| {
+                    format!(
+                        r#""{}": {{ "value": {}, "noise": {} }}"#,
+                        EscapedString(name),
+                        metric.value(),
+                        metric.noise()
+                    )
+                })
+                .collect::<Vec<_>>();
+            self.write_message(&*format!(r#", "metrics": {{ {} }}"#, metrics.join(", ")))?;
+        }
         if let Some(extra) = extra {
             self.write_message(&*format!(r#", {}"#, extra))?;
         }
@@ -60,10 +83,20 @@ fn write_event(
 }
 
 impl<T: Write> OutputFormatter for JsonFormatter<T> {
-    fn write_run_start(&mut self, test_count: usize) -> io::Result<()> {
+    fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()> {
+        let shuffle_seed_json = if let Some(shuffle_seed) = shuffle_seed {
+            format!(r#", "shuffle_seed": {}"#, shuffle_seed)
+        } else {
+            String::new()
+        };
+        let partition_json = if let Some(partition) = self.partition {
+            format!(r#", "partition": "{}""#, partition)
+        } else {
+            String::new()
+        };
         self.writeln_message(&*format!(
-            r#"{{ "type": "suite", "event": "started", "test_count": {} }}"#,
-            test_count
+            r#"{{ "type": "suite", "event": "started", "test_count": {}{}{} }}"#,
+            test_count, shuffle_seed_json, partition_json
         ))
     }
 
@@ -80,6 +113,7 @@ fn write_result(
         result: &TestResult,
         exec_time: Option<&time::TestExecTime>,
         stdout: &[u8],
+        metrics: &MetricMap,
         state: &ConsoleTestState,
     ) -> io::Result<()> {
         let display_stdout = state.options.display_output || *result != TestResult::TrOk;
@@ -89,13 +123,25 @@ fn write_result(
             None
         };
         match *result {
-            TestResult::TrOk => {
-                self.write_event("test", desc.name.as_slice(), "ok", exec_time, stdout, None)
-            }
+            TestResult::TrOk => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "ok",
+                exec_time,
+                stdout,
+                metrics,
+                None,
+            ),
 
-            TestResult::TrFailed => {
-                self.write_event("test", desc.name.as_slice(), "failed", exec_time, stdout, None)
-            }
+            TestResult::TrFailed => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "failed",
+                exec_time,
+                stdout,
+                metrics,
+                None,
+            ),
 
             TestResult::TrTimedFail => self.write_event(
                 "test",
@@ -103,21 +149,49 @@ fn write_result(
                 "failed",
                 exec_time,
                 stdout,
+                metrics,
                 Some(r#""reason": "time limit exceeded""#),
             ),
 
+            TestResult::TrTimedOut => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "timed_out",
+                exec_time,
+                stdout,
+                metrics,
+                None,
+            ),
+
             TestResult::TrFailedMsg(ref m) => self.write_event(
                 "test",
                 desc.name.as_slice(),
                 "failed",
                 exec_time,
                 stdout,
+                metrics,
                 Some(&*format!(r#""message": "{}""#, EscapedString(m))),
             ),
 
-            TestResult::TrIgnored => {
-                self.write_event("test", desc.name.as_slice(), "ignored", exec_time, stdout, None)
-            }
+            TestResult::TrIgnored => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "ignored",
+                exec_time,
+                stdout,
+                metrics,
+                None,
+            ),
+
+            TestResult::TrFlaky(attempts) => self.write_event(
+                "test",
+                desc.name.as_slice(),
+                "flaky",
+                exec_time,
+                stdout,
+                metrics,
+                Some(&*format!(r#""attempts": {}"#, attempts)),
+            ),
 
             TestResult::TrAllowedFail => self.write_event(
                 "test",
@@ -125,6 +199,7 @@ fn write_result(
                 "allowed_failure",
                 exec_time,
                 stdout,
+                metrics,
                 None,
             ),
 
@@ -135,18 +210,38 @@ fn write_result(
                 let mbps = if bs.mb_s == 0 {
                     String::new()
                 } else {
-                    format!(r#", "mib_per_second": {}"#, bs.mb_s)
+                    format!(r#", "mib_per_second": {}, "bytes_per_iter": {}"#, bs.mb_s, bs.bytes)
+                };
+
+                let comparison = state
+                    .baseline
+                    .as_ref()
+                    .and_then(|baseline| baseline.compare(desc.name.as_slice(), bs));
+                let comparison = if let Some(comparison) = comparison {
+                    format!(
+                        r#", "baseline_median": {}, "change_pct": {:.2}, "change": "{}""#,
+                        comparison.baseline_median as usize,
+                        comparison.change_pct,
+                        comparison.change.as_str()
+                    )
+                } else {
+                    String::new()
                 };
 
                 let line = format!(
                     "{{ \"type\": \"bench\", \
                      \"name\": \"{}\", \
                      \"median\": {}, \
-                     \"deviation\": {}{} }}",
+                     \"median_lower\": {}, \
+                     \"median_upper\": {}, \
+                     \"deviation\": {}{}{} }}",
                     EscapedString(desc.name.as_slice()),
                     median,
+                    bs.ns_iter_ci.lower as usize,
+                    bs.ns_iter_ci.upper as usize,
                     deviation,
-                    mbps
+                    mbps,
+                    comparison
                 );
 
                 self.writeln_message(&*line)
@@ -180,6 +275,12 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             state.filtered_out,
         ))?;
 
+        // Only reported when tests were retried, so that the summary of other
+        // runs does not change.
+        if !state.flaky.is_empty() {
+            self.write_message(&*format!(", \"flaky\": {}", state.flaky.len()))?;
+        }
+
         if let Some(ref exec_time) = state.exec_time {
             let time_str = format!(", \"exec_time\": {}", exec_time.0.as_secs_f64());
             self.write_message(&time_str)?;
@@ -0,0 +1,233 @@
+use std::{io, io::prelude::Write};
+
+use super::OutputFormatter;
+use crate::{
+    console::{ConsoleTestState, OutputLocation},
+    helpers::metrics::MetricMap,
+    test_result::TestResult,
+    time,
+    types::{TestDesc, TestType},
+};
+
+pub(crate) struct JunitFormatter<T> {
+    out: OutputLocation<T>,
+    results: Vec<(TestDesc, TestResult, Option<time::TestExecTime>, Vec<u8>)>,
+}
+
+impl<T: Write> JunitFormatter<T> {
+    pub fn new(out: OutputLocation<T>) -> Self {
+        Self { out, results: Vec::new() }
+    }
+
+    #[cfg(test)]
+    pub fn output_location(&self) -> &OutputLocation<T> {
+        &self.out
+    }
+
+    fn writeln_message(&mut self, s: &str) -> io::Result<()> {
+        assert!(!s.contains('\n'));
+
+        self.out.write_all(s.as_ref())?;
+        self.out.write_all(b"\n")
+    }
+
+    fn write_message(&mut self, s: &str) -> io::Result<()> {
+        assert!(!s.contains('\n'));
+
+        self.out.write_all(s.as_ref())
+    }
+}
+
+impl<T: Write> OutputFormatter for JunitFormatter<T> {
+    fn write_run_start(
+        &mut self,
+        _test_count: usize,
+        _shuffle_seed: Option<u64>,
+    ) -> io::Result<()> {
+        // The whole document is written once the run has finished, so that
+        // output of tests run with `--nocapture` can't end up inside of it.
+        Ok(())
+    }
+
+    fn write_test_start(&mut self, _desc: &TestDesc) -> io::Result<()> {
+        // We do not output anything on test start.
+        Ok(())
+    }
+
+    fn write_timeout(&mut self, _desc: &TestDesc) -> io::Result<()> {
+        // We do not output anything on test timeout.
+        Ok(())
+    }
+
+    fn write_result(
+        &mut self,
+        desc: &TestDesc,
+        result: &TestResult,
+        exec_time: Option<&time::TestExecTime>,
+        stdout: &[u8],
+        _: &MetricMap,
+        state: &ConsoleTestState,
+    ) -> io::Result<()> {
+        // Captured output is only kept for failures and flaky tests, unless
+        // `--show-output` was given.
+        let keep_output = matches!(
+            result,
+            TestResult::TrFailed
+                | TestResult::TrFailedMsg(_)
+                | TestResult::TrTimedFail
+                | TestResult::TrTimedOut
+                | TestResult::TrFlaky(_)
+        );
+        let stdout =
+            if state.options.display_output || keep_output { stdout.to_vec() } else { Vec::new() };
+
+        // Because the testsuite node holds some of the information as attributes,
+        // we can't write it before all tests have finished running.
+        self.results.push((desc.clone(), result.clone(), exec_time.cloned(), stdout));
+        Ok(())
+    }
+
+    fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
+        self.writeln_message("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        self.writeln_message("<testsuites>")?;
+
+        self.write_message(&*format!(
+            "<testsuite name=\"test\" package=\"test\" id=\"0\" \
+             errors=\"{}\" \
+             failures=\"{}\" \
+             tests=\"{}\" \
+             skipped=\"{}\"",
+            state.timeouts.len(),
+            state.failed - state.timeouts.len(),
+            state.total,
+            state.ignored
+        ))?;
+        if let Some(ref exec_time) = state.exec_time {
+            self.write_message(&*format!(" time=\"{}\"", exec_time.0.as_secs_f64()))?;
+        }
+        self.writeln_message(">")?;
+
+        for (desc, result, exec_time, stdout) in std::mem::take(&mut self.results) {
+            let (class_name, test_name) = parse_class_name(&desc);
+            self.write_message(&*format!(
+                "<testcase classname=\"{}\" name=\"{}\"",
+                XmlEscaped(&*class_name),
+                XmlEscaped(&*test_name)
+            ))?;
+            if let Some(exec_time) = exec_time {
+                self.write_message(&*format!(" time=\"{}\"", exec_time.0.as_secs_f64()))?;
+            }
+            self.write_message(">")?;
+
+            match result {
+                TestResult::TrFailed => {
+                    self.write_message("<failure type=\"assert\"/>")?;
+                }
+
+                TestResult::TrFailedMsg(ref m) => {
+                    self.write_message(&*format!(
+                        "<failure type=\"assert\" message=\"{}\"/>",
+                        XmlEscaped(m)
+                    ))?;
+                }
+
+                TestResult::TrTimedFail => {
+                    self.write_message(
+                        "<failure type=\"timeout\" message=\"time limit exceeded\"/>",
+                    )?;
+                }
+
+                TestResult::TrTimedOut => {
+                    self.write_message("<error type=\"timeout\" message=\"timed out\"/>")?;
+                }
+
+                TestResult::TrIgnored => {
+                    self.write_message("<skipped/>")?;
+                }
+
+                TestResult::TrOk
+                | TestResult::TrAllowedFail
+                | TestResult::TrFlaky(_)
+                | TestResult::TrBench(_) => {}
+            }
+
+            if !stdout.is_empty() {
+                self.write_message(&*format!(
+                    "<system-out>{}</system-out>",
+                    XmlEscaped(&*String::from_utf8_lossy(&stdout))
+                ))?;
+            }
+
+            self.writeln_message("</testcase>")?;
+        }
+
+        self.writeln_message("</testsuite>")?;
+        self.writeln_message("</testsuites>")?;
+
+        Ok(state.failed == 0)
+    }
+}
+
+/// Splits the name of a test into a JUnit class name and test name.
+///
+/// For unit and integration tests the module path becomes the class name and the
+/// function name becomes the test name, e.g. `foo::bar::baz` is reported as test
+/// `baz` of class `foo::bar`. Tests at the crate root are reported in class `crate`.
+/// Doctests (`src/lib.rs - foo::bar (line 5)`) use the file path as the class name.
+fn parse_class_name(desc: &TestDesc) -> (String, String) {
+    let name = desc.name.as_slice();
+    if desc.test_type == TestType::DocTest {
+        if let Some((file, item)) = name.split_once(" - ") {
+            return (file.trim().to_owned(), item.trim().to_owned());
+        }
+    }
+
+    match name.rsplit_once("::") {
+        Some((path, test)) => (path.to_owned(), test.to_owned()),
+        None => ("crate".to_owned(), name.to_owned()),
+    }
+}
+
+/// A formatting utility used to print strings as XML attribute values or text.
+///
+/// Characters that are not allowed in XML 1.0 documents are replaced with
+/// U+FFFD, and newlines are escaped so they survive attribute normalization.
+struct XmlEscaped<S: AsRef<str>>(S);
+
+impl<S: AsRef<str>> std::fmt::Display for XmlEscaped<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let s = self.0.as_ref();
+        let mut start = 0;
+
+        for (i, c) in s.char_indices() {
+            let escaped = match c {
+                '<' => "&lt;",
+                '>' => "&gt;",
+                '&' => "&amp;",
+                '"' => "&quot;",
+                '\'' => "&apos;",
+                '\n' => "&#10;",
+                '\r' => "&#13;",
+                '\t' => "&#9;",
+                '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => "\u{fffd}",
+                _ => {
+                    continue;
+                }
+            };
+
+            if start < i {
+                f.write_str(&s[start..i])?;
+            }
+
+            f.write_str(escaped)?;
+
+            start = i + c.len_utf8();
+        }
+
+        if start != s.len() {
+            f.write_str(&s[start..])?;
+        }
+
+        Ok(())
+    }
+}
@@ -2,21 +2,24 @@
 
 use crate::{
     console::ConsoleTestState,
+    helpers::metrics::MetricMap,
     test_result::TestResult,
     time,
     types::{TestDesc, TestName},
 };
 
 mod json;
+mod junit;
 mod pretty;
 mod terse;
 
 pub(crate) use self::json::JsonFormatter;
+pub(crate) use self::junit::JunitFormatter;
 pub(crate) use self::pretty::PrettyFormatter;
 pub(crate) use self::terse::TerseFormatter;
 
 pub(crate) trait OutputFormatter {
-    fn write_run_start(&mut self, test_count: usize) -> io::Result<()>;
+    fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()>;
     fn write_test_start(&mut self, desc: &TestDesc) -> io::Result<()>;
     fn write_timeout(&mut self, desc: &TestDesc) -> io::Result<()>;
     fn write_result(
@@ -25,6 +28,7 @@ fn write_result(
         result: &TestResult,
         exec_time: Option<&time::TestExecTime>,
         stdout: &[u8],
+        metrics: &MetricMap,
         state: &ConsoleTestState,
     ) -> io::Result<()>;
     fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool>;
@@ -4,6 +4,10 @@
 use crate::{
     bench::fmt_bench_samples,
     console::{ConsoleTestState, OutputLocation},
+    helpers::{
+        baseline::{Change, Comparison},
+        metrics::MetricMap,
+    },
     test_result::TestResult,
     time,
     types::TestDesc,
@@ -52,10 +56,18 @@ pub fn write_allowed_fail(&mut self) -> io::Result<()> {
         self.write_short_result("FAILED (allowed)", term::color::YELLOW)
     }
 
+    pub fn write_flaky(&mut self, attempts: usize) -> io::Result<()> {
+        self.write_short_result(&format!("flaky ({} attempts)", attempts), term::color::YELLOW)
+    }
+
     pub fn write_time_failed(&mut self) -> io::Result<()> {
         self.write_short_result("FAILED (time limit exceeded)", term::color::RED)
     }
 
+    pub fn write_timed_out(&mut self) -> io::Result<()> {
+        self.write_short_result("TIMED OUT", term::color::RED)
+    }
+
     pub fn write_bench(&mut self) -> io::Result<()> {
         self.write_pretty("bench", term::color::CYAN)
     }
@@ -163,10 +175,28 @@ pub fn write_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
         self.write_results(&state.failures, "failures")
     }
 
+    pub fn write_flaky_tests(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.flaky, "flaky")
+    }
+
     pub fn write_time_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
         self.write_results(&state.time_failures, "failures (time limit exceeded)")
     }
 
+    pub fn write_timeouts(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.timeouts, "timeouts")
+    }
+
+    fn write_comparison(&mut self, comparison: &Comparison) -> io::Result<()> {
+        self.write_plain(&format!(" ({:+.2}% vs baseline, ", comparison.change_pct))?;
+        match comparison.change {
+            Change::Regressed => self.write_pretty("regressed", term::color::RED)?,
+            Change::Improved => self.write_pretty("improved", term::color::GREEN)?,
+            Change::Unchanged => self.write_plain("unchanged")?,
+        }
+        self.write_plain(")")
+    }
+
     fn write_test_name(&mut self, desc: &TestDesc) -> io::Result<()> {
         let name = desc.padded_name(self.max_name_len, desc.name.padding());
         self.write_plain(&format!("test {} ... ", name))?;
@@ -176,9 +206,14 @@ fn write_test_name(&mut self, desc: &TestDesc) -> io::Result<()> {
 }
 
 impl<T: Write> OutputFormatter for PrettyFormatter<T> {
-    fn write_run_start(&mut self, test_count: usize) -> io::Result<()> {
+    fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()> {
         let noun = if test_count != 1 { "tests" } else { "test" };
-        self.write_plain(&format!("\nrunning {} {}\n", test_count, noun))
+        let shuffle_seed_msg = if let Some(shuffle_seed) = shuffle_seed {
+            format!(" (shuffle seed: {})", shuffle_seed)
+        } else {
+            String::new()
+        };
+        self.write_plain(&format!("\nrunning {} {}{}\n", test_count, noun, shuffle_seed_msg))
     }
 
     fn write_test_start(&mut self, desc: &TestDesc) -> io::Result<()> {
@@ -199,7 +234,8 @@ fn write_result(
         result: &TestResult,
         exec_time: Option<&time::TestExecTime>,
         _: &[u8],
-        _: &ConsoleTestState,
+        _: &MetricMap,
+        state: &ConsoleTestState,
     ) -> io::Result<()> {
         if self.is_multithreaded {
             self.write_test_name(desc)?;
@@ -210,11 +246,18 @@ fn write_result(
             TestResult::TrFailed | TestResult::TrFailedMsg(_) => self.write_failed()?,
             TestResult::TrIgnored => self.write_ignored()?,
             TestResult::TrAllowedFail => self.write_allowed_fail()?,
+            TestResult::TrFlaky(attempts) => self.write_flaky(attempts)?,
             TestResult::TrBench(ref bs) => {
                 self.write_bench()?;
                 self.write_plain(&format!(": {}", fmt_bench_samples(bs)))?;
+                if let Some(baseline) = &state.baseline {
+                    if let Some(comparison) = baseline.compare(desc.name.as_slice(), bs) {
+                        self.write_comparison(&comparison)?;
+                    }
+                }
             }
             TestResult::TrTimedFail => self.write_time_failed()?,
+            TestResult::TrTimedOut => self.write_timed_out()?,
         }
 
         self.write_time(desc, exec_time)?;
@@ -237,6 +280,9 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         if state.options.display_output {
             self.write_successes(state)?;
         }
+        if !state.flaky.is_empty() {
+            self.write_flaky_tests(state)?;
+        }
         let success = state.failed == 0;
         if !success {
             if !state.failures.is_empty() {
@@ -246,6 +292,14 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             if !state.time_failures.is_empty() {
                 self.write_time_failures(state)?;
             }
+
+            if !state.timeouts.is_empty() {
+                self.write_timeouts(state)?;
+            }
+        }
+
+        if let Some(baseline) = &state.baseline {
+            self.write_plain(&baseline.report(&state.benchmarks))?;
         }
 
         self.write_plain("\ntest result: ")?;
@@ -257,10 +311,16 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             self.write_pretty("FAILED", term::color::RED)?;
         }
 
+        let flaky = if state.flaky.is_empty() {
+            String::new()
+        } else {
+            format!(" ({} flaky)", state.flaky.len())
+        };
         let s = if state.allowed_fail > 0 {
             format!(
-                ". {} passed; {} failed ({} allowed); {} ignored; {} measured; {} filtered out",
+                ". {} passed{}; {} failed ({} allowed); {} ignored; {} measured; {} filtered out",
                 state.passed,
+                flaky,
                 state.failed + state.allowed_fail,
                 state.allowed_fail,
                 state.ignored,
@@ -269,8 +329,13 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             )
         } else {
             format!(
-                ". {} passed; {} failed; {} ignored; {} measured; {} filtered out",
-                state.passed, state.failed, state.ignored, state.measured, state.filtered_out
+                ". {} passed{}; {} failed; {} ignored; {} measured; {} filtered out",
+                state.passed,
+                flaky,
+                state.failed,
+                state.ignored,
+                state.measured,
+                state.filtered_out
             )
         };
 
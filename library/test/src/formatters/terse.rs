@@ -4,6 +4,10 @@
 use crate::{
     bench::fmt_bench_samples,
     console::{ConsoleTestState, OutputLocation},
+    helpers::{
+        baseline::{Change, Comparison},
+        metrics::MetricMap,
+    },
     test_result::TestResult,
     time,
     types::NamePadding,
@@ -57,6 +61,14 @@ pub fn write_allowed_fail(&mut self) -> io::Result<()> {
         self.write_short_result("a", term::color::YELLOW)
     }
 
+    pub fn write_flaky(&mut self) -> io::Result<()> {
+        self.write_short_result("f", term::color::YELLOW)
+    }
+
+    pub fn write_timed_out(&mut self) -> io::Result<()> {
+        self.write_short_result("T", term::color::RED)
+    }
+
     pub fn write_bench(&mut self) -> io::Result<()> {
         self.write_pretty("bench", term::color::CYAN)
     }
@@ -131,10 +143,28 @@ pub fn write_outputs(&mut self, state: &ConsoleTestState) -> io::Result<()> {
     }
 
     pub fn write_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
-        self.write_plain("\nfailures:\n")?;
+        self.write_results(&state.failures, "failures")
+    }
+
+    pub fn write_timeouts(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.timeouts, "timeouts")
+    }
+
+    pub fn write_flaky_tests(&mut self, state: &ConsoleTestState) -> io::Result<()> {
+        self.write_results(&state.flaky, "flaky")
+    }
+
+    fn write_results(
+        &mut self,
+        inputs: &Vec<(TestDesc, Vec<u8>)>,
+        results_type: &str,
+    ) -> io::Result<()> {
+        let results_out_str = format!("\n{}:\n", results_type);
+
+        self.write_plain(&results_out_str)?;
         let mut failures = Vec::new();
         let mut fail_out = String::new();
-        for &(ref f, ref stdout) in &state.failures {
+        for &(ref f, ref stdout) in inputs {
             failures.push(f.name.to_string());
             if !stdout.is_empty() {
                 fail_out.push_str(&format!("---- {} stdout ----\n", f.name));
@@ -148,7 +178,7 @@ pub fn write_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
             self.write_plain(&fail_out)?;
         }
 
-        self.write_plain("\nfailures:\n")?;
+        self.write_plain(&results_out_str)?;
         failures.sort();
         for name in &failures {
             self.write_plain(&format!("    {}\n", name))?;
@@ -156,6 +186,16 @@ pub fn write_failures(&mut self, state: &ConsoleTestState) -> io::Result<()> {
         Ok(())
     }
 
+    fn write_comparison(&mut self, comparison: &Comparison) -> io::Result<()> {
+        self.write_plain(&format!(" ({:+.2}% vs baseline, ", comparison.change_pct))?;
+        match comparison.change {
+            Change::Regressed => self.write_pretty("regressed", term::color::RED)?,
+            Change::Improved => self.write_pretty("improved", term::color::GREEN)?,
+            Change::Unchanged => self.write_plain("unchanged")?,
+        }
+        self.write_plain(")")
+    }
+
     fn write_test_name(&mut self, desc: &TestDesc) -> io::Result<()> {
         let name = desc.padded_name(self.max_name_len, desc.name.padding());
         self.write_plain(&format!("test {} ... ", name))?;
@@ -165,10 +205,15 @@ fn write_test_name(&mut self, desc: &TestDesc) -> io::Result<()> {
 }
 
 impl<T: Write> OutputFormatter for TerseFormatter<T> {
-    fn write_run_start(&mut self, test_count: usize) -> io::Result<()> {
+    fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()> {
         self.total_test_count = test_count;
         let noun = if test_count != 1 { "tests" } else { "test" };
-        self.write_plain(&format!("\nrunning {} {}\n", test_count, noun))
+        let shuffle_seed_msg = if let Some(shuffle_seed) = shuffle_seed {
+            format!(" (shuffle seed: {})", shuffle_seed)
+        } else {
+            String::new()
+        };
+        self.write_plain(&format!("\nrunning {} {}{}\n", test_count, noun, shuffle_seed_msg))
     }
 
     fn write_test_start(&mut self, desc: &TestDesc) -> io::Result<()> {
@@ -189,21 +234,30 @@ fn write_result(
         result: &TestResult,
         _: Option<&time::TestExecTime>,
         _: &[u8],
-        _: &ConsoleTestState,
+        _: &MetricMap,
+        state: &ConsoleTestState,
     ) -> io::Result<()> {
         match *result {
             TestResult::TrOk => self.write_ok(),
             TestResult::TrFailed | TestResult::TrFailedMsg(_) | TestResult::TrTimedFail => {
                 self.write_failed()
             }
+            TestResult::TrTimedOut => self.write_timed_out(),
             TestResult::TrIgnored => self.write_ignored(),
             TestResult::TrAllowedFail => self.write_allowed_fail(),
+            TestResult::TrFlaky(_) => self.write_flaky(),
             TestResult::TrBench(ref bs) => {
                 if self.is_multithreaded {
                     self.write_test_name(desc)?;
                 }
                 self.write_bench()?;
-                self.write_plain(&format!(": {}\n", fmt_bench_samples(bs)))
+                self.write_plain(&format!(": {}", fmt_bench_samples(bs)))?;
+                if let Some(baseline) = &state.baseline {
+                    if let Some(comparison) = baseline.compare(desc.name.as_slice(), bs) {
+                        self.write_comparison(&comparison)?;
+                    }
+                }
+                self.write_plain("\n")
             }
         }
     }
@@ -220,9 +274,20 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
         if state.options.display_output {
             self.write_outputs(state)?;
         }
+        if !state.flaky.is_empty() {
+            self.write_flaky_tests(state)?;
+        }
         let success = state.failed == 0;
         if !success {
             self.write_failures(state)?;
+
+            if !state.timeouts.is_empty() {
+                self.write_timeouts(state)?;
+            }
+        }
+
+        if let Some(baseline) = &state.baseline {
+            self.write_plain(&baseline.report(&state.benchmarks))?;
         }
 
         self.write_plain("\ntest result: ")?;
@@ -234,10 +299,16 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             self.write_pretty("FAILED", term::color::RED)?;
         }
 
+        let flaky = if state.flaky.is_empty() {
+            String::new()
+        } else {
+            format!(" ({} flaky)", state.flaky.len())
+        };
         let s = if state.allowed_fail > 0 {
             format!(
-                ". {} passed; {} failed ({} allowed); {} ignored; {} measured; {} filtered out",
+                ". {} passed{}; {} failed ({} allowed); {} ignored; {} measured; {} filtered out",
                 state.passed,
+                flaky,
                 state.failed + state.allowed_fail,
                 state.allowed_fail,
                 state.ignored,
@@ -246,8 +317,13 @@ fn write_run_finish(&mut self, state: &ConsoleTestState) -> io::Result<bool> {
             )
         } else {
             format!(
-                ". {} passed; {} failed; {} ignored; {} measured; {} filtered out",
-                state.passed, state.failed, state.ignored, state.measured, state.filtered_out
+                ". {} passed{}; {} failed; {} ignored; {} measured; {} filtered out",
+                state.passed,
+                flaky,
+                state.failed,
+                state.ignored,
+                state.measured,
+                state.filtered_out
             )
         };
 
@@ -0,0 +1,192 @@
+//! Benchmark baselines: the results of a benchmark run saved under a name, so that later
+//! runs can be compared against them.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::bench::BenchSamples;
+use crate::stats::ConfidenceInterval;
+use crate::types::TestDesc;
+
+/// Changes of the median smaller than this percentage are never reported, even if they are
+/// statistically significant, as they are usually caused by the environment rather than the code.
+const NOISE_THRESHOLD_PCT: f64 = 2.0;
+
+const HEADER: &str = "# libtest benchmark baseline: median lower upper name";
+
+/// How a benchmark compares to its baseline.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    Regressed,
+    Improved,
+    Unchanged,
+}
+
+impl Change {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Change::Regressed => "regressed",
+            Change::Improved => "improved",
+            Change::Unchanged => "unchanged",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Comparison {
+    /// Median of the baseline, in ns/iter
+    pub baseline_median: f64,
+    /// Change of the median relative to the baseline
+    pub change_pct: f64,
+    pub change: Change,
+}
+
+/// The medians and their confidence intervals of a previous benchmark run.
+#[derive(Debug, PartialEq)]
+pub struct Baseline {
+    pub name: String,
+    medians: BTreeMap<String, (f64, ConfidenceInterval)>,
+}
+
+impl Baseline {
+    /// Reads the baseline called `name` of the running test binary.
+    pub fn load(name: &str) -> io::Result<Baseline> {
+        Baseline::load_from(&baseline_path(name)?, name)
+    }
+
+    fn load_from(path: &Path, name: &str) -> io::Result<Baseline> {
+        let contents = fs::read_to_string(path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("failed to read baseline `{}` from {}: {}", name, path.display(), e),
+            )
+        })?;
+        Baseline::parse(name, &contents).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("baseline `{}` at {} is malformed", name, path.display()),
+            )
+        })
+    }
+
+    pub fn parse(name: &str, contents: &str) -> Option<Baseline> {
+        let mut medians = BTreeMap::new();
+        for line in contents.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let mut fields = line.splitn(4, ' ');
+            let mut number = || fields.next()?.parse::<f64>().ok();
+            let median = number()?;
+            let ci = ConfidenceInterval { lower: number()?, upper: number()? };
+            medians.insert(fields.next()?.to_owned(), (median, ci));
+        }
+        Some(Baseline { name: name.to_owned(), medians })
+    }
+
+    /// Compares a benchmark against its result in the baseline, if it has one.
+    ///
+    /// A change is significant if the confidence intervals of the two medians don't overlap
+    /// and the median moved by more than `NOISE_THRESHOLD_PCT`.
+    pub fn compare(&self, name: &str, bs: &BenchSamples) -> Option<Comparison> {
+        let &(baseline_median, baseline_ci) = self.medians.get(name)?;
+        if baseline_median <= 0.0 {
+            return None;
+        }
+
+        let change_pct = (bs.ns_iter_summ.median - baseline_median) / baseline_median * 100.0;
+        let change = if change_pct.abs() <= NOISE_THRESHOLD_PCT {
+            Change::Unchanged
+        } else if bs.ns_iter_ci.lower > baseline_ci.upper {
+            Change::Regressed
+        } else if bs.ns_iter_ci.upper < baseline_ci.lower {
+            Change::Improved
+        } else {
+            Change::Unchanged
+        };
+        Some(Comparison { baseline_median, change_pct, change })
+    }
+
+    /// Records the results of the benchmarks, replacing their previous results.
+    pub fn record(&mut self, benchmarks: &[(TestDesc, BenchSamples)]) {
+        for (desc, bs) in benchmarks {
+            let name = desc.name.as_slice().to_owned();
+            self.medians.insert(name, (bs.ns_iter_summ.median, bs.ns_iter_ci));
+        }
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut output = format!("{}\n", HEADER);
+        for (name, (median, ci)) in &self.medians {
+            writeln!(output, "{} {} {} {}", median, ci.lower, ci.upper, name).unwrap();
+        }
+        output
+    }
+
+    /// Lists the benchmarks that regressed or improved compared to the baseline.
+    pub fn report(&self, benchmarks: &[(TestDesc, BenchSamples)]) -> String {
+        let mut output = String::new();
+        for &change in &[Change::Regressed, Change::Improved] {
+            let mut names = Vec::new();
+            for (desc, bs) in benchmarks {
+                match self.compare(desc.name.as_slice(), bs) {
+                    Some(c) if c.change == change => names.push(format!(
+                        "    {}: {:+.2}% ({:.0} ns/iter -> {:.0} ns/iter)\n",
+                        desc.name, c.change_pct, c.baseline_median, bs.ns_iter_summ.median
+                    )),
+                    _ => {}
+                }
+            }
+            if !names.is_empty() {
+                names.sort();
+                let title =
+                    if change == Change::Regressed { "regressions" } else { "improvements" };
+                writeln!(output, "\n{} compared to baseline `{}`:", title, self.name).unwrap();
+                output.extend(names);
+            }
+        }
+        output
+    }
+}
+
+/// Saves the results of the benchmarks in the baseline called `name` of the running test binary.
+pub fn save(name: &str, benchmarks: &[(TestDesc, BenchSamples)]) -> io::Result<()> {
+    save_to(&baseline_path(name)?, name, benchmarks)
+}
+
+/// Saves the results of the benchmarks in the baseline at `path`. Benchmarks that didn't run,
+/// for example because they were filtered out, keep the results the baseline already has.
+pub fn save_to(path: &Path, name: &str, benchmarks: &[(TestDesc, BenchSamples)]) -> io::Result<()> {
+    let mut baseline = if path.exists() {
+        Baseline::load_from(path, name)?
+    } else {
+        Baseline { name: name.to_owned(), medians: BTreeMap::new() }
+    };
+    baseline.record(benchmarks);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, baseline.serialize())
+}
+
+/// Returns where the baseline called `name` of the running test binary is stored.
+///
+/// Cargo puts test binaries into `<target>/<profile>/deps`, and baselines are kept in
+/// `<target>/bench-baselines/<name>/`. Test binaries built some other way keep them in a
+/// `bench-baselines` directory next to the binary.
+fn baseline_path(name: &str) -> io::Result<PathBuf> {
+    let exe = env::current_exe()?;
+    let mut dir = exe.parent().unwrap_or_else(|| Path::new("."));
+    if dir.file_name() == Some("deps".as_ref()) {
+        dir = dir.parent().and_then(Path::parent).unwrap_or(dir);
+    }
+
+    // Drop the hash cargo appends to the crate name, so that the baseline
+    // survives changes to the build configuration.
+    let stem = exe.file_stem().and_then(|stem| stem.to_str()).unwrap_or("test");
+    let stem = stem.rsplit_once('-').map_or(stem, |(crate_name, _)| crate_name);
+
+    Ok(dir.join("bench-baselines").join(name).join(format!("{}.txt", stem)))
+}
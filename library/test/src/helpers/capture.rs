@@ -0,0 +1,199 @@
+//! Capturing the output of tests by redirecting the standard output and error
+//! file descriptors, as done with `--capture=fd`.
+
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::process::{self, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Captures the output of a test that runs in-process.
+pub enum OutputCapture {
+    /// Nothing is captured, as requested with `--nocapture`.
+    None,
+    /// The output of the print macros, see `io::set_output_capture`.
+    Print(Arc<Mutex<Vec<u8>>>),
+    /// Everything written to the standard output and error of the process.
+    Fd(FdCapture),
+}
+
+impl OutputCapture {
+    /// Starts capturing the output. Redirecting the file descriptors isn't supported
+    /// everywhere, so this falls back to capturing the print macros if it fails.
+    pub fn start(nocapture: bool, capture_fds: bool) -> OutputCapture {
+        if nocapture {
+            return OutputCapture::None;
+        }
+        if capture_fds {
+            if let Ok(capture) = FdCapture::start() {
+                return OutputCapture::Fd(capture);
+            }
+        }
+
+        let data = Arc::new(Mutex::new(Vec::new()));
+        io::set_output_capture(Some(data.clone()));
+        OutputCapture::Print(data)
+    }
+
+    /// Takes the output captured so far, and keeps capturing.
+    pub fn take(&mut self) -> Vec<u8> {
+        match self {
+            OutputCapture::None => Vec::new(),
+            OutputCapture::Print(data) => {
+                std::mem::take(&mut *data.lock().unwrap_or_else(|e| e.into_inner()))
+            }
+            OutputCapture::Fd(capture) => capture.take().unwrap_or_else(|e| {
+                format!("note: failed to read the captured output: {}", e).into_bytes()
+            }),
+        }
+    }
+
+    /// Stops capturing and returns the output captured since the last `take`.
+    pub fn finish(mut self) -> Vec<u8> {
+        let output = self.take();
+        if let OutputCapture::Print(_) = self {
+            io::set_output_capture(None);
+        }
+        output
+    }
+}
+
+/// Redirects the standard output and error of the process into a temporary file
+/// while it exists. Only one can exist at a time.
+pub struct FdCapture {
+    file: File,
+    /// Duplicates of the original standard output and error, restored on drop
+    #[cfg(unix)]
+    saved_fds: [libc::c_int; 2],
+}
+
+#[cfg(unix)]
+const STD_FDS: [libc::c_int; 2] = [libc::STDOUT_FILENO, libc::STDERR_FILENO];
+
+impl FdCapture {
+    #[cfg(unix)]
+    pub fn start() -> io::Result<FdCapture> {
+        use std::os::unix::io::AsRawFd;
+
+        fn cvt(ret: libc::c_int) -> io::Result<libc::c_int> {
+            if ret == -1 { Err(io::Error::last_os_error()) } else { Ok(ret) }
+        }
+
+        // The file is never opened again, so it can be deleted right away.
+        let (file, path) = create_temp_file()?;
+        fs::remove_file(path)?;
+
+        flush_std_streams();
+        let mut capture = FdCapture { file, saved_fds: [-1; 2] };
+        for (saved_fd, &fd) in capture.saved_fds.iter_mut().zip(&STD_FDS) {
+            // SAFETY: these only operate on file descriptors owned by `capture`
+            // and on the standard ones, which `drop` restores on failure.
+            unsafe {
+                *saved_fd = cvt(libc::dup(fd))?;
+                cvt(libc::dup2(capture.file.as_raw_fd(), fd))?;
+            }
+        }
+        Ok(capture)
+    }
+
+    #[cfg(not(unix))]
+    pub fn start() -> io::Result<FdCapture> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "redirecting the standard output is not supported on this platform",
+        ))
+    }
+
+    /// Returns another handle to this capture, which can take the captured output
+    /// but leaves restoring the file descriptors to `self`.
+    pub fn share(&self) -> io::Result<FdCapture> {
+        Ok(FdCapture {
+            file: self.file.try_clone()?,
+            #[cfg(unix)]
+            saved_fds: [-1; 2],
+        })
+    }
+
+    /// Takes the output captured so far.
+    pub fn take(&mut self) -> io::Result<Vec<u8>> {
+        flush_std_streams();
+        // The redirected file descriptors share the file offset with `self.file`.
+        let mut output = Vec::new();
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.read_to_end(&mut output)?;
+        self.file.set_len(0)?;
+        self.file.seek(SeekFrom::Start(0))?;
+        Ok(output)
+    }
+}
+
+impl Drop for FdCapture {
+    fn drop(&mut self) {
+        flush_std_streams();
+        #[cfg(unix)]
+        for (&saved_fd, &fd) in self.saved_fds.iter().zip(&STD_FDS) {
+            if saved_fd != -1 {
+                // SAFETY: `saved_fd` is a duplicate owned by `self`.
+                unsafe {
+                    libc::dup2(saved_fd, fd);
+                    libc::close(saved_fd);
+                }
+            }
+        }
+    }
+}
+
+/// A file that a test subprocess writes both its standard output and error to,
+/// so that they are captured in the order they were written.
+pub struct SubprocessOutput {
+    file: Option<File>,
+    path: PathBuf,
+}
+
+impl SubprocessOutput {
+    pub fn new() -> io::Result<SubprocessOutput> {
+        let (file, path) = create_temp_file()?;
+        Ok(SubprocessOutput { file: Some(file), path })
+    }
+
+    /// Returns a handle to pass as the standard output or error of the subprocess.
+    pub fn stdio(&self) -> io::Result<Stdio> {
+        Ok(self.file().try_clone()?.into())
+    }
+
+    /// Reads what the subprocess wrote, once it has exited.
+    pub fn read(self) -> io::Result<Vec<u8>> {
+        let mut output = Vec::new();
+        self.file().seek(SeekFrom::Start(0))?;
+        self.file().read_to_end(&mut output)?;
+        Ok(output)
+    }
+
+    fn file(&self) -> &File {
+        self.file.as_ref().unwrap()
+    }
+}
+
+impl Drop for SubprocessOutput {
+    fn drop(&mut self) {
+        // On Windows, a file can't be deleted while it's open.
+        drop(self.file.take());
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+pub fn create_temp_file() -> io::Result<(File, PathBuf)> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let path = env::temp_dir().join(format!("rust-test-output-{}-{}", process::id(), id));
+    let file = OpenOptions::new().read(true).write(true).create_new(true).open(&path)?;
+    Ok((file, path))
+}
+
+fn flush_std_streams() {
+    let _ = io::stdout().flush();
+    let _ = io::stderr().flush();
+}
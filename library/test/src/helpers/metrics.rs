@@ -1,5 +1,12 @@
-//! Benchmark metrics.
+//! Benchmark metrics, and the metrics recorded by tests with `record_metric`.
+use std::cell::RefCell;
 use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::capture::create_temp_file;
 
 #[derive(Clone, PartialEq, Debug, Copy)]
 pub struct Metric {
@@ -11,9 +18,41 @@ impl Metric {
     pub fn new(value: f64, noise: f64) -> Metric {
         Metric { value, noise }
     }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn noise(&self) -> f64 {
+        self.noise
+    }
+
+    /// Compares the metric to its value in an earlier run, using the larger of
+    /// the two noises as the range of acceptable changes. The sign of the noise
+    /// of `old` tells which direction is an improvement, see
+    /// `MetricMap::insert_metric`.
+    pub fn compare_to_old(&self, old: &Metric) -> MetricChange {
+        let delta = self.value - old.value;
+        if delta.abs() <= self.noise.abs().max(old.noise.abs()) {
+            return MetricChange::LikelyNoise;
+        }
+
+        let pct = delta.abs() / old.value.abs().max(f64::EPSILON) * 100.0;
+        let improved = if old.noise < 0.0 { delta > 0.0 } else { delta < 0.0 };
+        if improved { MetricChange::Improvement(pct) } else { MetricChange::Regression(pct) }
+    }
+}
+
+/// How a metric changed compared to an earlier run. The percentages are
+/// relative to the old value.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MetricChange {
+    LikelyNoise,
+    Improvement(f64),
+    Regression(f64),
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct MetricMap(BTreeMap<String, Metric>);
 
 impl MetricMap {
@@ -39,6 +78,29 @@ pub fn insert_metric(&mut self, name: &str, value: f64, noise: f64) {
         self.0.insert(name.to_owned(), m);
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Metric> {
+        self.0.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Metric)> {
+        self.0.iter().map(|(name, metric)| (name.as_str(), metric))
+    }
+
+    /// Compares every metric that is also in `old`, see `Metric::compare_to_old`.
+    pub fn compare_to_old(&self, old: &MetricMap) -> BTreeMap<String, MetricChange> {
+        self.0
+            .iter()
+            .filter_map(|(name, metric)| {
+                let old = old.0.get(name)?;
+                Some((name.clone(), metric.compare_to_old(old)))
+            })
+            .collect()
+    }
+
     pub fn fmt_metrics(&self) -> String {
         let v = self
             .0
@@ -48,3 +110,116 @@ pub fn fmt_metrics(&self) -> String {
         v.join(", ")
     }
 }
+
+thread_local! {
+    /// The metrics recorded by the test running on this thread, if any.
+    static RECORDED_METRICS: RefCell<Option<MetricMap>> = RefCell::new(None);
+}
+
+/// Records a metric of the running test, such as the number of bytes it
+/// allocated. The metrics of a test are reported by the JSON output format, and
+/// can be compared to earlier runs with `--ratchet-metrics`.
+///
+/// The meaning of `noise` is the same as in `MetricMap::insert_metric`: its
+/// absolute value is the range of changes that aren't regressions, and its sign
+/// tells whether smaller (positive) or larger (negative) values are better.
+/// Recording a metric again replaces its value.
+///
+/// Metrics recorded outside of a test are ignored. This includes the threads
+/// spawned by a test: unlike captured output, the recorder is not inherited
+/// by them, so their measurements have to be passed back to the thread
+/// running the test, for example through the result of `JoinHandle::join`,
+/// and recorded there.
+///
+/// # Panics
+///
+/// Panics if `name` is empty or contains whitespace, or if `value` or `noise`
+/// isn't finite.
+pub fn record_metric(name: &str, value: f64, noise: f64) {
+    assert!(
+        !name.is_empty() && !name.contains(char::is_whitespace),
+        "metric names must be non-empty and must not contain whitespace: {:?}",
+        name
+    );
+    assert!(
+        value.is_finite() && noise.is_finite(),
+        "metric `{}` must be finite, got {} (+/- {})",
+        name,
+        value,
+        noise
+    );
+    RECORDED_METRICS.with(|metrics| {
+        if let Some(metrics) = &mut *metrics.borrow_mut() {
+            metrics.insert_metric(name, value, noise);
+        }
+    });
+}
+
+/// Starts recording the metrics of a test that runs on the current thread.
+pub fn start_recording() {
+    RECORDED_METRICS.with(|metrics| *metrics.borrow_mut() = Some(MetricMap::new()));
+}
+
+/// Stops recording and returns the metrics recorded on the current thread.
+pub fn finish_recording() -> MetricMap {
+    RECORDED_METRICS.with(|metrics| metrics.borrow_mut().take()).unwrap_or_else(MetricMap::new)
+}
+
+/// A file that a test subprocess writes its metrics to before exiting.
+pub struct SubprocessMetrics {
+    path: PathBuf,
+}
+
+impl SubprocessMetrics {
+    pub fn new() -> io::Result<SubprocessMetrics> {
+        // The subprocess opens the file again, which isn't possible on Windows
+        // while it's open here.
+        let (_, path) = create_temp_file()?;
+        Ok(SubprocessMetrics { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the metrics, once the subprocess has exited. A subprocess that
+    /// exited without writing any leaves the file empty.
+    pub fn read(&self) -> io::Result<MetricMap> {
+        let contents = fs::read_to_string(&self.path)?;
+        parse(&contents).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "the metrics of the test are malformed")
+        })
+    }
+
+    /// Writes the metrics recorded by the test, from the subprocess.
+    pub fn write(path: &Path, metrics: &MetricMap) -> io::Result<()> {
+        fs::write(path, serialize(metrics))
+    }
+}
+
+impl Drop for SubprocessMetrics {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Serializes metrics as lines of `value noise name`. `f64` values are
+/// formatted so that they parse back to the same value.
+fn serialize(metrics: &MetricMap) -> String {
+    let mut output = String::new();
+    for (name, metric) in metrics.iter() {
+        writeln!(output, "{} {} {}", metric.value, metric.noise, name).unwrap();
+    }
+    output
+}
+
+fn parse(contents: &str) -> Option<MetricMap> {
+    let mut metrics = MetricMap::new();
+    for line in contents.lines() {
+        let mut fields = line.splitn(3, ' ');
+        let mut number = || fields.next()?.parse::<f64>().ok();
+        let (value, noise) = (number()?, number()?);
+        metrics.insert_metric(fields.next()?, value, noise);
+    }
+    Some(metrics)
+}
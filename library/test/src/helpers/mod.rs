@@ -1,7 +1,11 @@
 //! Module with common helpers not directly related to tests
 //! but used in `libtest`.
 
+pub mod baseline;
+pub mod capture;
 pub mod concurrency;
 pub mod exit_code;
-pub mod isatty;
 pub mod metrics;
+pub mod partition;
+pub mod ratchet;
+pub mod shuffle;
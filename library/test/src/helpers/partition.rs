@@ -0,0 +1,32 @@
+//! Helpers for splitting the tests of a crate across several test runs.
+
+use crate::options::{Partition, PartitionKind};
+use crate::types::TestDescAndFn;
+
+/// Keeps only the tests that belong to `partition`.
+///
+/// `tests` must already be sorted by name, so that every run of the same test
+/// binary makes the same assignment and the partitions are disjoint.
+pub fn partition_tests(partition: Partition, tests: &mut Vec<TestDescAndFn>) {
+    let mut position: u64 = 0;
+    tests.retain(|test| {
+        let bucket = match partition.kind {
+            PartitionKind::Count => position,
+            PartitionKind::Hash => name_hash(test.desc.name.as_slice()),
+        };
+        position += 1;
+        bucket % partition.count as u64 == partition.index as u64 - 1
+    });
+}
+
+/// FNV-1a. Unlike `DefaultHasher`, its output is guaranteed not to change
+/// between releases, so binaries built by different toolchains still agree on
+/// which partition a test belongs to.
+pub fn name_hash(name: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in name.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
@@ -0,0 +1,125 @@
+//! Metrics ratchets: the best values of the metrics recorded by tests with `record_metric`,
+//! kept in a file across runs, so that a test fails when one of its metrics gets worse.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::metrics::{MetricChange, MetricMap};
+use crate::event::CompletedTest;
+use crate::test_result::TestResult;
+
+const HEADER: &str = "# libtest metrics ratchet: value noise metric test";
+
+/// The metrics of the tests, as saved in a ratchet file.
+#[derive(Debug, PartialEq)]
+pub struct Ratchet {
+    path: PathBuf,
+    /// The metrics of each test, by test name
+    tests: BTreeMap<String, MetricMap>,
+    changed: bool,
+}
+
+impl Ratchet {
+    /// Reads the ratchet file at `path`. A missing file is read as an empty
+    /// ratchet, which the metrics of this run are then saved to.
+    pub fn load(path: &Path) -> io::Result<Ratchet> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => {
+                return Err(io::Error::new(
+                    e.kind(),
+                    format!("failed to read metrics ratchet from {}: {}", path.display(), e),
+                ));
+            }
+        };
+        Ratchet::parse(path, &contents).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("metrics ratchet at {} is malformed", path.display()),
+            )
+        })
+    }
+
+    pub fn parse(path: &Path, contents: &str) -> Option<Ratchet> {
+        let mut tests = BTreeMap::new();
+        for line in contents.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let mut fields = line.splitn(4, ' ');
+            let mut number = || fields.next()?.parse::<f64>().ok();
+            let (value, noise) = (number()?, number()?);
+            let metric = fields.next()?;
+            let test = fields.next()?;
+            tests
+                .entry(test.to_owned())
+                .or_insert_with(MetricMap::new)
+                .insert_metric(metric, value, noise);
+        }
+        Some(Ratchet { path: path.to_owned(), tests, changed: false })
+    }
+
+    /// Compares the metrics of a test that passed to the ratchet, and fails the
+    /// test if any of them regressed. Otherwise, the new and improved metrics
+    /// replace the ones in the ratchet, so that they can only get better.
+    pub fn check(&mut self, completed_test: &mut CompletedTest) {
+        let passed = matches!(completed_test.result, TestResult::TrOk | TestResult::TrFlaky(_));
+        if !passed || completed_test.metrics.is_empty() {
+            return;
+        }
+
+        let test = completed_test.desc.name.as_slice().to_owned();
+        let old = self.tests.entry(test).or_insert_with(MetricMap::new);
+        let mut regressions = Vec::new();
+        let mut updates = Vec::new();
+        for (name, metric) in completed_test.metrics.iter() {
+            match old.get(name).map(|old| (old, metric.compare_to_old(old))) {
+                Some((old, MetricChange::Regression(pct))) => regressions.push(format!(
+                    "`{}` regressed by {:.2}% ({} -> {})",
+                    name,
+                    pct,
+                    old.value(),
+                    metric.value()
+                )),
+                Some((_, MetricChange::LikelyNoise)) => {}
+                Some((_, MetricChange::Improvement(_))) | None => updates.push((name, metric)),
+            }
+        }
+
+        if !regressions.is_empty() {
+            completed_test.result = TestResult::TrFailedMsg(format!(
+                "metrics regressed compared to {}: {}",
+                self.path.display(),
+                regressions.join(", ")
+            ));
+            return;
+        }
+        for (name, metric) in updates {
+            old.insert_metric(name, metric.value(), metric.noise());
+            self.changed = true;
+        }
+    }
+
+    /// Saves the ratchet if any metric was added or improved.
+    pub fn save(&self) -> io::Result<()> {
+        if !self.changed {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, self.serialize())
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut output = format!("{}\n", HEADER);
+        for (test, metrics) in &self.tests {
+            for (name, metric) in metrics.iter() {
+                writeln!(output, "{} {} {} {}", metric.value(), metric.noise(), name, test)
+                    .unwrap();
+            }
+        }
+        output
+    }
+}
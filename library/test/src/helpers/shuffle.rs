@@ -0,0 +1,76 @@
+//! Helpers for running tests in a random, but reproducible, order.
+
+use super::partition::name_hash;
+use crate::cli::TestOpts;
+use crate::types::TestDescAndFn;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Returns the seed the tests should be shuffled with, if shuffling is enabled.
+///
+/// An explicitly provided seed always wins; otherwise a fresh seed is
+/// derived from the current time.
+pub fn get_shuffle_seed(opts: &TestOpts) -> Option<u64> {
+    opts.shuffle_seed.or_else(|| {
+        if opts.shuffle {
+            Some(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Failed to get system time")
+                    .as_nanos() as u64,
+            )
+        } else {
+            None
+        }
+    })
+}
+
+/// Permutes `tests` in place.
+///
+/// The resulting order only depends on the seed and on the names of the
+/// tests, so rerunning the same set of tests with the same seed reproduces
+/// the same order regardless of how many threads are used.
+pub fn shuffle_tests(shuffle_seed: u64, tests: &mut [TestDescAndFn]) {
+    let test_names_hash =
+        tests.iter().fold(0, |hash, test| mix(hash ^ name_hash(test.desc.name.as_slice())));
+    let mut rng = Rng::new(shuffle_seed, test_names_hash);
+    shuffle(&mut rng, tests);
+}
+
+// Fisher-Yates shuffle.
+fn shuffle<T>(rng: &mut Rng, slice: &mut [T]) {
+    for i in 0..slice.len() {
+        let idx = i + rng.rand_range(0..(slice.len() - i) as u64) as usize;
+        slice.swap(i, idx);
+    }
+}
+
+/// A tiny pseudo-random number generator, seeded with the shuffle seed.
+///
+/// This is SplitMix64. Like the hash of the test names, it is spelled out here
+/// rather than built on `DefaultHasher`, whose algorithm may change between
+/// releases, so that a seed reproduces the same order with any toolchain.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64, extra: u64) -> Self {
+        Self { state: seed ^ extra }
+    }
+
+    fn rand_range(&mut self, range: std::ops::Range<u64>) -> u64 {
+        self.rand_u64() % (range.end - range.start) + range.start
+    }
+
+    fn rand_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        mix(self.state)
+    }
+}
+
+// The output function of SplitMix64.
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
@@ -25,6 +25,7 @@
 #![feature(nll)]
 #![feature(available_concurrency)]
 #![feature(internal_output_capture)]
+#![feature(is_terminal)]
 #![feature(panic_unwind)]
 #![feature(staged_api)]
 #![feature(termination_trait_lib)]
@@ -35,7 +36,11 @@
 // Public reexports
 pub use self::bench::{black_box, Bencher};
 pub use self::console::run_tests_console;
-pub use self::options::{ColorConfig, Options, OutputFormat, RunIgnored, ShouldPanic};
+pub use self::helpers::metrics::record_metric;
+pub use self::options::{
+    CaptureMode, ColorConfig, Options, OutputFormat, Partition, PartitionKind, RunIgnored,
+    ShouldPanic,
+};
 pub use self::types::TestName::*;
 pub use self::types::*;
 pub use self::ColorConfig::*;
@@ -48,22 +53,25 @@ pub mod test {
         bench::Bencher,
         cli::{parse_opts, TestOpts},
         filter_tests,
-        helpers::metrics::{Metric, MetricMap},
-        options::{Options, RunIgnored, RunStrategy, ShouldPanic},
+        helpers::metrics::{record_metric, Metric, MetricMap},
+        options::{
+            CaptureMode, Options, Partition, PartitionKind, RunIgnored, RunStrategy, ShouldPanic,
+        },
         run_test, test_main, test_main_static,
         test_result::{TestResult, TrFailed, TrFailedMsg, TrIgnored, TrOk},
         time::{TestExecTime, TestTimeOptions},
         types::{
-            DynTestFn, DynTestName, StaticBenchFn, StaticTestFn, StaticTestName, TestDesc,
-            TestDescAndFn, TestName, TestType,
+            DynTestFn, DynTestName, FixtureKind, StaticBenchFn, StaticFixtureFn, StaticTestFn,
+            StaticTestName, TestDesc, TestDescAndFn, TestName, TestType,
         },
     };
 }
 
 use std::{
-    env, io,
+    cmp, env, io,
     io::prelude::Write,
     panic::{self, catch_unwind, AssertUnwindSafe, PanicInfo},
+    path::{Path, PathBuf},
     process::{self, Command, Termination},
     sync::mpsc::{channel, Sender},
     sync::{Arc, Mutex},
@@ -75,9 +83,11 @@ pub mod test {
 mod cli;
 mod console;
 mod event;
+mod fixture;
 mod formatters;
 mod helpers;
 mod options;
+mod retry;
 pub mod stats;
 mod test_result;
 mod time;
@@ -87,9 +97,15 @@ pub mod test {
 mod tests;
 
 use event::{CompletedTest, TestEvent};
+use fixture::{AroundEach, Fixtures};
+use helpers::capture::{FdCapture, OutputCapture, SubprocessOutput};
 use helpers::concurrency::get_concurrency;
 use helpers::exit_code::get_exit_code;
+use helpers::metrics::{self, MetricMap, SubprocessMetrics};
+use helpers::partition::partition_tests;
+use helpers::shuffle::{get_shuffle_seed, shuffle_tests};
 use options::{Concurrent, RunStrategy};
+use retry::{Attempt, Retries};
 use test_result::*;
 use time::TestExecTime;
 
@@ -97,6 +113,8 @@ pub mod test {
 const ERROR_EXIT_CODE: i32 = 101;
 
 const SECONDARY_TEST_INVOKER_VAR: &str = "__RUST_TEST_INVOKE";
+// The file a test subprocess writes its metrics to.
+const SECONDARY_TEST_METRICS_VAR: &str = "__RUST_TEST_METRICS";
 
 // The default console test runner. It accepts the command line
 // arguments and a vector of test_descs.
@@ -153,18 +171,19 @@ pub fn test_main_static_abort(tests: &[&TestDescAndFn]) {
     // will then exit the process.
     if let Ok(name) = env::var(SECONDARY_TEST_INVOKER_VAR) {
         env::remove_var(SECONDARY_TEST_INVOKER_VAR);
+        let metrics_path = env::var_os(SECONDARY_TEST_METRICS_VAR).map(PathBuf::from);
+        env::remove_var(SECONDARY_TEST_METRICS_VAR);
+        let (fixtures, tests) = Fixtures::extract(tests.iter().map(make_owned_test).collect());
         let test = tests
-            .iter()
-            .filter(|test| test.desc.name.as_slice() == name)
-            .map(make_owned_test)
-            .next()
+            .into_iter()
+            .find(|test| test.desc.name.as_slice() == name)
             .unwrap_or_else(|| panic!("couldn't find a test with the provided name '{}'", name));
         let TestDescAndFn { desc, testfn } = test;
         let testfn = match testfn {
             StaticTestFn(f) => f,
             _ => panic!("only static tests are supported"),
         };
-        run_test_in_spawned_subprocess(desc, Box::new(testfn));
+        run_test_in_spawned_subprocess(desc, Box::new(testfn), fixtures.each, metrics_path);
     }
 
     let args = env::args().collect::<Vec<_>>();
@@ -180,6 +199,9 @@ fn make_owned_test(test: &&TestDescAndFn) -> TestDescAndFn {
     match test.testfn {
         StaticTestFn(f) => TestDescAndFn { testfn: StaticTestFn(f), desc: test.desc.clone() },
         StaticBenchFn(f) => TestDescAndFn { testfn: StaticBenchFn(f), desc: test.desc.clone() },
+        StaticFixtureFn(kind, f) => {
+            TestDescAndFn { testfn: StaticFixtureFn(kind, f), desc: test.desc.clone() }
+        }
         _ => panic!("non-static tests passed to test::test_main_static"),
     }
 }
@@ -212,6 +234,8 @@ pub fn run_tests<F>(
     type TestMap =
         HashMap<TestDesc, Instant, BuildHasherDefault<collections::hash_map::DefaultHasher>>;
 
+    // Fixtures aren't tests themselves, so they are taken out before filtering.
+    let (fixtures, tests) = Fixtures::extract(tests);
     let tests_len = tests.len();
 
     let mut filtered_tests = filter_tests(opts, tests);
@@ -234,7 +258,9 @@ pub fn run_tests<F>(
 
     let filtered_descs = filtered_tests.iter().map(|t| t.desc.clone()).collect();
 
-    let event = TestEvent::TeFiltered(filtered_descs);
+    let shuffle_seed = get_shuffle_seed(opts);
+
+    let event = TestEvent::TeFiltered(filtered_descs, shuffle_seed);
     notify_about_test_event(event)?;
 
     let (filtered_tests, filtered_benchs): (Vec<_>, _) = filtered_tests
@@ -244,17 +270,54 @@ pub fn run_tests<F>(
     let concurrency = opts.test_threads.unwrap_or_else(get_concurrency);
 
     let mut remaining = filtered_tests;
+    if let Some(shuffle_seed) = shuffle_seed {
+        shuffle_tests(shuffle_seed, &mut remaining);
+    }
     remaining.reverse();
     let mut pending = 0;
 
+    // The fixtures are only needed if there is anything to run.
+    if remaining.is_empty() && (filtered_benchs.is_empty() || !opts.bench_benchmarks) {
+        return Ok(());
+    }
+
+    let capture_fds = opts.capture == CaptureMode::Fd;
+    if let Err(failure) = fixtures.setup_once(opts.nocapture, capture_fds) {
+        let message = format!("not run because `{}` failed", failure.desc.name);
+        notify_about_test_event(TestEvent::TeFixtureFailed(failure))?;
+
+        let benchs = if opts.bench_benchmarks { filtered_benchs } else { Vec::new() };
+        for test in remaining.into_iter().rev().chain(benchs) {
+            notify_about_test_event(TestEvent::TeWait(test.desc.clone()))?;
+            let ignored = test.desc.ignore || !opts.run_tests;
+            let result = if ignored { TrIgnored } else { TrFailedMsg(message.clone()) };
+            let completed_test = CompletedTest::new(test.desc, result, None, Vec::new());
+            notify_about_test_event(TestEvent::TeResult(completed_test))?;
+        }
+
+        // Teardown functions may still have to clean up after the setup
+        // functions that succeeded.
+        return teardown_once(opts, &fixtures, notify_about_test_event);
+    }
+
     let (tx, rx) = channel::<CompletedTest>();
     let run_strategy = if opts.options.panic_abort && !opts.force_run_in_process {
         RunStrategy::SpawnPrimary
     } else {
         RunStrategy::InProcess
     };
+    // The file descriptors are shared by the whole process, so tests can't run
+    // in parallel while they are redirected.
+    let concurrency = match run_strategy {
+        RunStrategy::InProcess if capture_fds => 1,
+        _ => concurrency,
+    };
 
+    let mut retries = Retries::default();
     let mut running_tests: TestMap = HashMap::default();
+    // Deadlines of the tests that have a timeout but run in-process. Those can't
+    // be stopped, so the test run is abandoned once any of them expires.
+    let mut test_deadlines: TestMap = HashMap::default();
 
     fn get_timed_out_tests(running_tests: &mut TestMap) -> Vec<TestDesc> {
         let now = Instant::now();
@@ -268,8 +331,8 @@ fn get_timed_out_tests(running_tests: &mut TestMap) -> Vec<TestDesc> {
         timed_out
     }
 
-    fn calc_timeout(running_tests: &TestMap) -> Option<Duration> {
-        running_tests.values().min().map(|next_timeout| {
+    fn calc_timeout(running_tests: &TestMap, test_deadlines: &TestMap) -> Option<Duration> {
+        running_tests.values().chain(test_deadlines.values()).min().map(|next_timeout| {
             let now = Instant::now();
             if *next_timeout >= now { *next_timeout - now } else { Duration::new(0, 0) }
         })
@@ -277,14 +340,53 @@ fn calc_timeout(running_tests: &TestMap) -> Option<Duration> {
 
     if concurrency == 1 {
         while !remaining.is_empty() {
-            let test = remaining.pop().unwrap();
+            let mut test = remaining.pop().unwrap();
             let event = TestEvent::TeWait(test.desc.clone());
             notify_about_test_event(event)?;
-            run_test(opts, !opts.run_tests, test, run_strategy, tx.clone(), Concurrent::No);
-            let completed_test = rx.recv().unwrap();
+            retries.register(opts, &test);
+
+            let completed_test = loop {
+                // A test with a timeout runs on its own thread, so that it can be
+                // abandoned if it doesn't finish in time.
+                let desc = test.desc.clone();
+                let timeout = in_process_timeout(opts, run_strategy, &desc);
+                let concurrency = if timeout.is_some() { Concurrent::Yes } else { Concurrent::No };
+                // The file descriptors of a test that may be abandoned are redirected
+                // here rather than on its thread, so that they can be restored before
+                // the timeout is reported.
+                let fd_capture = match timeout {
+                    Some(_) if capture_fds && !opts.nocapture => FdCapture::start().ok(),
+                    _ => None,
+                };
+                run_test_with_fixtures(
+                    opts,
+                    !opts.run_tests,
+                    test,
+                    run_strategy,
+                    tx.clone(),
+                    concurrency,
+                    fd_capture.as_ref(),
+                    &fixtures.each,
+                );
+                let completed_test = match timeout {
+                    Some(timeout) => match rx.recv_timeout(timeout) {
+                        Ok(completed_test) => completed_test,
+                        Err(_) => {
+                            drop(fd_capture);
+                            let event = TestEvent::TeResult(timed_out_test(opts, desc, timeout));
+                            return notify_about_test_event(event);
+                        }
+                    },
+                    None => rx.recv().unwrap(),
+                };
 
-            let event = TestEvent::TeResult(completed_test);
-            notify_about_test_event(event)?;
+                match retries.finish_attempt(completed_test) {
+                    Attempt::Retry(retry) => test = retry,
+                    Attempt::Done(completed_test) => break completed_test,
+                }
+            };
+
+            notify_about_completed_test(completed_test, &mut notify_about_test_event)?;
         }
     } else {
         while pending > 0 || !remaining.is_empty() {
@@ -292,16 +394,29 @@ fn calc_timeout(running_tests: &TestMap) -> Option<Duration> {
                 let test = remaining.pop().unwrap();
                 let timeout = time::get_default_test_timeout();
                 running_tests.insert(test.desc.clone(), timeout);
+                if let Some(timeout) = in_process_timeout(opts, run_strategy, &test.desc) {
+                    test_deadlines.insert(test.desc.clone(), Instant::now() + timeout);
+                }
 
                 let event = TestEvent::TeWait(test.desc.clone());
                 notify_about_test_event(event)?; //here no pad
-                run_test(opts, !opts.run_tests, test, run_strategy, tx.clone(), Concurrent::Yes);
+                retries.register(opts, &test);
+                run_test_with_fixtures(
+                    opts,
+                    !opts.run_tests,
+                    test,
+                    run_strategy,
+                    tx.clone(),
+                    Concurrent::Yes,
+                    None,
+                    &fixtures.each,
+                );
                 pending += 1;
             }
 
             let mut res;
             loop {
-                if let Some(timeout) = calc_timeout(&running_tests) {
+                if let Some(timeout) = calc_timeout(&running_tests, &test_deadlines) {
                     res = rx.recv_timeout(timeout);
                     for test in get_timed_out_tests(&mut running_tests) {
                         let event = TestEvent::TeTimeout(test);
@@ -310,7 +425,17 @@ fn calc_timeout(running_tests: &TestMap) -> Option<Duration> {
 
                     match res {
                         Err(RecvTimeoutError::Timeout) => {
-                            // Result is not yet ready, continue waiting.
+                            // Result is not yet ready, so give up on the run if a test
+                            // has exceeded its timeout, and continue waiting otherwise.
+                            let timed_out = get_timed_out_tests(&mut test_deadlines);
+                            if !timed_out.is_empty() {
+                                for desc in timed_out {
+                                    let timeout = test_timeout(opts, &desc).unwrap();
+                                    let completed_test = timed_out_test(opts, desc, timeout);
+                                    notify_about_test_event(TestEvent::TeResult(completed_test))?;
+                                }
+                                return Ok(());
+                            }
                         }
                         _ => {
                             // We've got a result, stop the loop.
@@ -325,9 +450,31 @@ fn calc_timeout(running_tests: &TestMap) -> Option<Duration> {
 
             let completed_test = res.unwrap();
             running_tests.remove(&completed_test.desc);
+            test_deadlines.remove(&completed_test.desc);
+
+            let completed_test = match retries.finish_attempt(completed_test) {
+                Attempt::Retry(test) => {
+                    // The test is run again right away, without being announced again.
+                    running_tests.insert(test.desc.clone(), time::get_default_test_timeout());
+                    if let Some(timeout) = in_process_timeout(opts, run_strategy, &test.desc) {
+                        test_deadlines.insert(test.desc.clone(), Instant::now() + timeout);
+                    }
+                    run_test_with_fixtures(
+                        opts,
+                        !opts.run_tests,
+                        test,
+                        run_strategy,
+                        tx.clone(),
+                        Concurrent::Yes,
+                        None,
+                        &fixtures.each,
+                    );
+                    continue;
+                }
+                Attempt::Done(completed_test) => completed_test,
+            };
 
-            let event = TestEvent::TeResult(completed_test);
-            notify_about_test_event(event)?;
+            notify_about_completed_test(completed_test, &mut notify_about_test_event)?;
             pending -= 1;
         }
     }
@@ -344,9 +491,79 @@ fn calc_timeout(running_tests: &TestMap) -> Option<Duration> {
             notify_about_test_event(event)?;
         }
     }
+
+    // If the run was given up on because a test timed out, the teardown functions
+    // aren't run, as that test may still be using what they clean up.
+    teardown_once(opts, &fixtures, notify_about_test_event)
+}
+
+/// Reports the result of a test, followed by the failure of the
+/// `#[test_teardown(each)]` functions that ran after it, if any.
+fn notify_about_completed_test<F>(
+    mut completed_test: CompletedTest,
+    notify_about_test_event: &mut F,
+) -> io::Result<()>
+where
+    F: FnMut(TestEvent) -> io::Result<()>,
+{
+    let teardown_failure = completed_test.teardown_failure.take().map(|stdout| {
+        let desc = fixture::teardown_desc(&completed_test.desc);
+        CompletedTest::new(desc, TrFailed, None, stdout)
+    });
+    notify_about_test_event(TestEvent::TeResult(completed_test))?;
+
+    if let Some(failure) = teardown_failure {
+        notify_about_test_event(TestEvent::TeFixtureFailed(failure))?;
+    }
+    Ok(())
+}
+
+/// Runs the `#[test_teardown]` functions and reports those that failed.
+fn teardown_once<F>(
+    opts: &TestOpts,
+    fixtures: &Fixtures,
+    mut notify_about_test_event: F,
+) -> io::Result<()>
+where
+    F: FnMut(TestEvent) -> io::Result<()>,
+{
+    for failure in fixtures.teardown_once(opts.nocapture, opts.capture == CaptureMode::Fd) {
+        notify_about_test_event(TestEvent::TeFixtureFailed(failure))?;
+    }
     Ok(())
 }
 
+/// Returns the time after which the test should be stopped, if any.
+fn test_timeout(opts: &TestOpts, desc: &TestDesc) -> Option<Duration> {
+    desc.timeout.or(opts.test_timeout)
+}
+
+/// Returns the timeout `run_tests` has to enforce itself. Tests run in a
+/// subprocess are killed by `spawn_test_subprocess` instead.
+fn in_process_timeout(opts: &TestOpts, strategy: RunStrategy, desc: &TestDesc) -> Option<Duration> {
+    match strategy {
+        RunStrategy::InProcess if supports_threads() => test_timeout(opts, desc),
+        _ => None,
+    }
+}
+
+/// Creates the result of an in-process test that didn't finish in time.
+fn timed_out_test(opts: &TestOpts, desc: TestDesc, timeout: Duration) -> CompletedTest {
+    let exec_time = opts.time_options.map(|_| TestExecTime(timeout));
+    let message = format!(
+        "note: test did not finish within {:?}; tests running in-process can't be stopped, \
+         so the remaining tests were not run",
+        timeout
+    );
+    CompletedTest::new(desc, TrTimedOut, exec_time, message.into_bytes())
+}
+
+// If the platform is single-threaded we're just going to run the tests
+// synchronously, regardless of the concurrency level.
+fn supports_threads() -> bool {
+    !cfg!(target_os = "emscripten") && !cfg!(target_arch = "wasm32")
+}
+
 pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescAndFn> {
     let mut filtered = tests;
     let matches_filter = |test: &TestDescAndFn, filter: &str| {
@@ -386,6 +603,11 @@ pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescA
     // Sort the tests alphabetically
     filtered.sort_by(|t1, t2| t1.desc.name.as_slice().cmp(t2.desc.name.as_slice()));
 
+    // Only keep this run's share of the tests
+    if let Some(partition) = opts.partition {
+        partition_tests(partition, &mut filtered);
+    }
+
     filtered
 }
 
@@ -415,6 +637,32 @@ pub fn run_test(
     strategy: RunStrategy,
     monitor_ch: Sender<CompletedTest>,
     concurrency: Concurrent,
+) {
+    let fixtures = AroundEach::default();
+    run_test_with_fixtures(
+        opts,
+        force_ignore,
+        test,
+        strategy,
+        monitor_ch,
+        concurrency,
+        None,
+        &fixtures,
+    )
+}
+
+/// Runs a test like `run_test`, with the `#[test_setup(each)]` and
+/// `#[test_teardown(each)]` functions around it. With `fd_capture`, the caller
+/// has already redirected the file descriptors for an in-process test.
+fn run_test_with_fixtures(
+    opts: &TestOpts,
+    force_ignore: bool,
+    test: TestDescAndFn,
+    strategy: RunStrategy,
+    monitor_ch: Sender<CompletedTest>,
+    concurrency: Concurrent,
+    fd_capture: Option<&FdCapture>,
+    fixtures: &AroundEach,
 ) {
     let TestDescAndFn { desc, testfn } = test;
 
@@ -432,8 +680,12 @@ pub fn run_test(
     struct TestRunOpts {
         pub strategy: RunStrategy,
         pub nocapture: bool,
+        pub capture_fds: bool,
+        pub shared_fd_capture: Option<FdCapture>,
         pub concurrency: Concurrent,
         pub time: Option<time::TestTimeOptions>,
+        pub timeout: Option<Duration>,
+        pub fixtures: AroundEach,
     }
 
     fn run_test_inner(
@@ -449,25 +701,26 @@ fn run_test_inner(
             RunStrategy::InProcess => run_test_in_process(
                 desc,
                 opts.nocapture,
+                opts.capture_fds,
+                opts.shared_fd_capture,
                 opts.time.is_some(),
                 testfn,
                 monitor_ch,
                 opts.time,
+                &opts.fixtures,
             ),
             RunStrategy::SpawnPrimary => spawn_test_subprocess(
                 desc,
                 opts.nocapture,
+                opts.capture_fds,
                 opts.time.is_some(),
                 monitor_ch,
                 opts.time,
+                opts.timeout,
             ),
         };
 
-        // If the platform is single-threaded we're just going to run
-        // the test synchronously, regardless of the concurrency
-        // level.
-        let supports_threads = !cfg!(target_os = "emscripten") && !cfg!(target_arch = "wasm32");
-        if concurrency == Concurrent::Yes && supports_threads {
+        if concurrency == Concurrent::Yes && supports_threads() {
             let cfg = thread::Builder::new().name(name.as_slice().to_owned());
             cfg.spawn(runtest).unwrap();
         } else {
@@ -475,8 +728,18 @@ fn run_test_inner(
         }
     }
 
-    let test_run_opts =
-        TestRunOpts { strategy, nocapture: opts.nocapture, concurrency, time: opts.time_options };
+    let test_run_opts = TestRunOpts {
+        strategy,
+        nocapture: opts.nocapture,
+        // If the caller's capture can't be shared, the test falls back to capturing
+        // the print macros rather than redirecting the file descriptors again.
+        capture_fds: opts.capture == CaptureMode::Fd && fd_capture.is_none(),
+        shared_fd_capture: fd_capture.and_then(|capture| capture.share().ok()),
+        concurrency,
+        time: opts.time_options,
+        timeout: test_timeout(opts, &desc),
+        fixtures: fixtures.clone(),
+    };
 
     match testfn {
         DynBenchFn(bencher) => {
@@ -501,7 +764,7 @@ fn run_test_inner(
                 test_run_opts,
             );
         }
-        StaticTestFn(f) => run_test_inner(
+        StaticTestFn(f) | StaticFixtureFn(_, f) => run_test_inner(
             desc,
             monitor_ch,
             Box::new(move || __rust_begin_short_backtrace(f)),
@@ -522,60 +785,109 @@ fn __rust_begin_short_backtrace<F: FnOnce()>(f: F) {
 fn run_test_in_process(
     desc: TestDesc,
     nocapture: bool,
+    capture_fds: bool,
+    shared_fd_capture: Option<FdCapture>,
     report_time: bool,
     testfn: Box<dyn FnOnce() + Send>,
     monitor_ch: Sender<CompletedTest>,
     time_opts: Option<time::TestTimeOptions>,
+    fixtures: &AroundEach,
 ) {
-    // Buffer for capturing standard I/O
-    let data = Arc::new(Mutex::new(Vec::new()));
-
-    if !nocapture {
-        io::set_output_capture(Some(data.clone()));
-    }
+    let mut capture = match shared_fd_capture {
+        Some(capture) => OutputCapture::Fd(capture),
+        None => OutputCapture::start(nocapture, capture_fds),
+    };
+    metrics::start_recording();
 
     let start = report_time.then(Instant::now);
-    let result = catch_unwind(AssertUnwindSafe(testfn));
+    // A panicking setup function must not count as the panic of a `#[should_panic]` test.
+    let result = fixtures.setup().then(|| catch_unwind(AssertUnwindSafe(testfn)));
     let exec_time = start.map(|start| {
         let duration = start.elapsed();
         TestExecTime(duration)
     });
 
-    io::set_output_capture(None);
-
     let test_result = match result {
-        Ok(()) => calc_result(&desc, Ok(()), &time_opts, &exec_time),
-        Err(e) => calc_result(&desc, Err(e.as_ref()), &time_opts, &exec_time),
+        Some(Ok(())) => calc_result(&desc, Ok(()), &time_opts, &exec_time),
+        Some(Err(e)) => calc_result(&desc, Err(e.as_ref()), &time_opts, &exec_time),
+        None => TrFailedMsg("a `#[test_setup(each)]` function failed".to_string()),
     };
-    let stdout = data.lock().unwrap_or_else(|e| e.into_inner()).to_vec();
-    let message = CompletedTest::new(desc, test_result, exec_time, stdout);
-    monitor_ch.send(message).unwrap();
+    let stdout = capture.take();
+
+    // The teardown functions run even if the test failed, and their output is
+    // reported separately.
+    let teardown_succeeded = fixtures.teardown();
+    let teardown_output = capture.finish();
+
+    let mut message = CompletedTest::new(desc, test_result, exec_time, stdout);
+    if !teardown_succeeded {
+        message.teardown_failure = Some(teardown_output);
+    }
+    message.metrics = metrics::finish_recording();
+    // Nobody is listening anymore if the test run was abandoned because this
+    // test took longer than its timeout.
+    let _ = monitor_ch.send(message);
 }
 
 fn spawn_test_subprocess(
     desc: TestDesc,
     nocapture: bool,
+    capture_fds: bool,
     report_time: bool,
     monitor_ch: Sender<CompletedTest>,
     time_opts: Option<time::TestTimeOptions>,
+    timeout: Option<Duration>,
 ) {
-    let (result, test_output, exec_time) = (|| {
+    let mut metrics = MetricMap::new();
+    let (result, test_output, exec_time, teardown_failed) = (|| {
         let args = env::args().collect::<Vec<_>>();
         let current_exe = &args[0];
 
         let mut command = Command::new(current_exe);
         command.env(SECONDARY_TEST_INVOKER_VAR, desc.name.as_slice());
+        let metrics_file = match SubprocessMetrics::new() {
+            Ok(metrics_file) => metrics_file,
+            Err(e) => {
+                let err = format!("Failed to create the metrics file for test: {:?}", e);
+                return (TrFailed, err.into_bytes(), None, false);
+            }
+        };
+        command.env(SECONDARY_TEST_METRICS_VAR, metrics_file.path());
+        // With `--capture=fd`, the standard output and error of the child share a
+        // file, which keeps them in the order they were written.
+        let mut shared_output = None;
         if nocapture {
             command.stdout(process::Stdio::inherit());
             command.stderr(process::Stdio::inherit());
+        } else if capture_fds {
+            let output = SubprocessOutput::new()
+                .and_then(|output| Ok((output.stdio()?, output.stdio()?, output)));
+            match output {
+                Ok((stdout, stderr, output)) => {
+                    command.stdout(stdout);
+                    command.stderr(stderr);
+                    shared_output = Some(output);
+                }
+                Err(e) => {
+                    let err = format!("Failed to create the output file for test: {:?}", e);
+                    return (TrFailed, err.into_bytes(), None, false);
+                }
+            }
+        } else {
+            command.stdout(process::Stdio::piped());
+            command.stderr(process::Stdio::piped());
         }
 
         let start = report_time.then(Instant::now);
-        let output = match command.output() {
+        let output = match timeout {
+            Some(timeout) => output_with_timeout(command, timeout),
+            None => command.output().map(|out| (out, false)),
+        };
+        let (output, timed_out) = match output {
             Ok(out) => out,
             Err(e) => {
                 let err = format!("Failed to spawn {} as child for test: {:?}", args[0], e);
-                return (TrFailed, err.into_bytes(), None);
+                return (TrFailed, err.into_bytes(), None, false);
             }
         };
         let exec_time = start.map(|start| {
@@ -584,30 +896,128 @@ fn spawn_test_subprocess(
         });
 
         let std::process::Output { stdout, stderr, status } = output;
-        let mut test_output = stdout;
-        formatters::write_stderr_delimiter(&mut test_output, &desc.name);
-        test_output.extend_from_slice(&stderr);
+        let mut test_output = match shared_output {
+            Some(output) => output.read().unwrap_or_else(|e| {
+                format!("note: failed to read the output of the test: {}", e).into_bytes()
+            }),
+            None => {
+                let mut test_output = stdout;
+                formatters::write_stderr_delimiter(&mut test_output, &desc.name);
+                test_output.extend_from_slice(&stderr);
+                test_output
+            }
+        };
+
+        if let (true, Some(timeout)) = (timed_out, timeout) {
+            write!(
+                &mut test_output,
+                "note: test did not finish within {:?} and was killed",
+                timeout
+            )
+            .unwrap();
+            return (TrTimedOut, test_output, exec_time, false);
+        }
+
+        match metrics_file.read() {
+            Ok(recorded) => metrics = recorded,
+            Err(e) => {
+                writeln!(&mut test_output, "note: failed to read the metrics: {}", e).unwrap()
+            }
+        }
 
-        let result = match (|| -> Result<TestResult, String> {
-            let exit_code = get_exit_code(status)?;
-            Ok(get_result_from_exit_code(&desc, exit_code, &time_opts, &exec_time))
+        let (result, teardown_failed) = match (|| -> Result<(TestResult, bool), String> {
+            let mut exit_code = get_exit_code(status)?;
+            let teardown_failed = exit_code == TR_OK + TR_TEARDOWN_FAILED
+                || exit_code == TR_FAILED + TR_TEARDOWN_FAILED;
+            if teardown_failed {
+                exit_code -= TR_TEARDOWN_FAILED;
+            }
+            let result = get_result_from_exit_code(&desc, exit_code, &time_opts, &exec_time);
+            Ok((result, teardown_failed))
         })() {
             Ok(r) => r,
             Err(e) => {
                 write!(&mut test_output, "Unexpected error: {}", e).unwrap();
-                TrFailed
+                (TrFailed, false)
             }
         };
 
-        (result, test_output, exec_time)
+        (result, test_output, exec_time, teardown_failed)
     })();
 
-    let message = CompletedTest::new(desc, result, exec_time, test_output);
+    // The output of the teardown functions can't be told apart from the output
+    // of the test, so the teardown failure is reported with all of it.
+    let teardown_failure = teardown_failed.then(|| test_output.clone());
+    let mut message = CompletedTest::new(desc, result, exec_time, test_output);
+    message.teardown_failure = teardown_failure;
+    message.metrics = metrics;
     monitor_ch.send(message).unwrap();
 }
 
-fn run_test_in_spawned_subprocess(desc: TestDesc, testfn: Box<dyn FnOnce() + Send>) -> ! {
-    let builtin_panic_hook = panic::take_hook();
+/// Runs `command` like `Command::output` does, but kills it if it doesn't exit
+/// within `timeout`. The returned flag tells whether the command was killed.
+fn output_with_timeout(
+    mut command: Command,
+    timeout: Duration,
+) -> io::Result<(process::Output, bool)> {
+    use std::io::Read;
+
+    let deadline = Instant::now() + timeout;
+    let mut child = command.spawn()?;
+
+    // Drain both pipes while waiting, so that the child can't block on a full pipe.
+    fn read_to_end<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buf);
+            }
+            buf
+        })
+    }
+    let stdout = read_to_end(child.stdout.take());
+    let stderr = read_to_end(child.stderr.take());
+
+    let mut timed_out = false;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            // The child may have exited in the meantime, in which case killing it fails.
+            let _ = child.kill();
+            timed_out = true;
+            break child.wait()?;
+        }
+        thread::sleep(cmp::min(deadline - now, Duration::from_millis(10)));
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    Ok((process::Output { status, stdout, stderr }, timed_out))
+}
+
+/// What a test subprocess is running, which decides what a panic means.
+#[derive(Clone, Copy)]
+enum SubprocessStage {
+    Setup,
+    Test,
+    /// The teardown functions run after a test that exited with the given code.
+    Teardown(i32),
+}
+
+fn run_test_in_spawned_subprocess(
+    desc: TestDesc,
+    testfn: Box<dyn FnOnce() + Send>,
+    fixtures: AroundEach,
+    metrics_path: Option<PathBuf>,
+) -> ! {
+    metrics::start_recording();
+    let builtin_panic_hook: Arc<dyn Fn(&PanicInfo<'_>) + Sync + Send> = panic::take_hook().into();
+    let builtin_panic_hook2 = builtin_panic_hook.clone();
+    let stage = Arc::new(Mutex::new(SubprocessStage::Setup));
     let record_result = Arc::new(move |panic_info: Option<&'_ PanicInfo<'_>>| {
         let test_result = match panic_info {
             Some(info) => calc_result(&desc, Err(info.payload()), &None, &None),
@@ -624,15 +1034,47 @@ fn run_test_in_spawned_subprocess(desc: TestDesc, testfn: Box<dyn FnOnce() + Sen
             builtin_panic_hook(info);
         }
 
-        if let TrOk = test_result {
-            process::exit(test_result::TR_OK);
-        } else {
-            process::exit(test_result::TR_FAILED);
-        }
+        if let TrOk = test_result { test_result::TR_OK } else { test_result::TR_FAILED }
     });
+
     let record_result2 = record_result.clone();
-    panic::set_hook(Box::new(move |info| record_result2(Some(&info))));
+    let stage2 = stage.clone();
+    let metrics_path2 = metrics_path.clone();
+    panic::set_hook(Box::new(move |info| {
+        // With panic=abort the process can't go on after a panic, so the
+        // teardown functions don't run after a test that panicked.
+        let exit_code = match *stage2.lock().unwrap_or_else(|e| e.into_inner()) {
+            SubprocessStage::Setup => {
+                builtin_panic_hook2(info);
+                eprintln!("a `#[test_setup(each)]` function failed");
+                test_result::TR_FAILED
+            }
+            SubprocessStage::Test => record_result2(Some(&info)),
+            SubprocessStage::Teardown(exit_code) => {
+                builtin_panic_hook2(info);
+                exit_code + TR_TEARDOWN_FAILED
+            }
+        };
+        write_subprocess_metrics(metrics_path2.as_deref());
+        process::exit(exit_code);
+    }));
+
+    fixtures.setup.iter().for_each(|&f| __rust_begin_short_backtrace(f));
+    *stage.lock().unwrap() = SubprocessStage::Test;
     testfn();
-    record_result(None);
-    unreachable!("panic=abort callback should have exited the process")
+    let exit_code = record_result(None);
+    *stage.lock().unwrap() = SubprocessStage::Teardown(exit_code);
+    fixtures.teardown.iter().for_each(|&f| __rust_begin_short_backtrace(f));
+    write_subprocess_metrics(metrics_path.as_deref());
+    process::exit(exit_code)
+}
+
+/// Writes the metrics recorded on the current thread to the file the parent
+/// process reads them from.
+fn write_subprocess_metrics(path: Option<&Path>) {
+    if let Some(path) = path {
+        if let Err(e) = SubprocessMetrics::write(path, &metrics::finish_recording()) {
+            eprintln!("failed to write the metrics of the test: {}", e);
+        }
+    }
 }
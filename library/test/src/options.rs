@@ -1,5 +1,7 @@
 //! Enums denoting options for test execution.
 
+use std::fmt;
+
 /// Whether to execute tests concurrently or not
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Concurrent {
@@ -39,6 +41,19 @@ pub enum OutputFormat {
     Terse,
     /// JSON output
     Json,
+    /// JUnit output
+    Junit,
+}
+
+/// How the output of tests is captured, unless `--nocapture` is given
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CaptureMode {
+    /// Capture what the test prints with the print macros and its panic
+    /// messages, including those of the threads it spawns
+    Print,
+    /// Redirect the standard output and error file descriptors, which also
+    /// captures what C code and child processes write to them
+    Fd,
 }
 
 /// Whether ignored test should be run or not
@@ -50,6 +65,36 @@ pub enum RunIgnored {
     Only,
 }
 
+/// How tests are assigned to partitions
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PartitionKind {
+    /// Tests are dealt out in turn, in alphabetical order, so that all
+    /// partitions get the same number of tests
+    Count,
+    /// Tests are assigned by a hash of their name, so that adding or removing
+    /// a test doesn't move any other test to a different partition
+    Hash,
+}
+
+/// The subset of tests to run, as selected by `--partition`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Partition {
+    pub kind: PartitionKind,
+    /// The partition to run, from 1 to `count`
+    pub index: usize,
+    pub count: usize,
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            PartitionKind::Count => "count",
+            PartitionKind::Hash => "hash",
+        };
+        write!(f, "{}:{}/{}", kind, self.index, self.count)
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum RunStrategy {
     /// Runs the test in the current process, and sends the result back over the
@@ -0,0 +1,86 @@
+//! Rerunning failed tests, as requested with `--retries` or `#[flaky(retries = N)]`.
+
+use std::collections::HashMap;
+
+use super::cli::TestOpts;
+use super::event::CompletedTest;
+use super::test_result::{TestResult, TrFlaky};
+use super::types::{StaticTestFn, TestDesc, TestDescAndFn};
+
+/// The tests that are rerun if they fail.
+#[derive(Default)]
+pub struct Retries {
+    tests: HashMap<TestDesc, Retry>,
+}
+
+struct Retry {
+    testfn: fn(),
+    retries: usize,
+    attempts: usize,
+    /// The output of the attempts that failed
+    output: Vec<u8>,
+}
+
+/// What to do after an attempt at running a test.
+pub enum Attempt {
+    /// Run the test again.
+    Retry(TestDescAndFn),
+    /// Report this result.
+    Done(CompletedTest),
+}
+
+impl Retries {
+    /// Registers a test that is about to run for the first time. Only static
+    /// tests can be rerun, as the function of a dynamic test runs only once.
+    pub fn register(&mut self, opts: &TestOpts, test: &TestDescAndFn) {
+        let retries = test.desc.retries.unwrap_or(opts.retries);
+        if let (StaticTestFn(f), true) = (&test.testfn, retries > 0) {
+            let retry = Retry { testfn: *f, retries, attempts: 1, output: Vec::new() };
+            self.tests.insert(test.desc.clone(), retry);
+        }
+    }
+
+    /// Decides whether a test has to run again after it completed. A test that
+    /// passes after failing before is reported as `TrFlaky`, with the output of
+    /// all its attempts.
+    pub fn finish_attempt(&mut self, mut completed_test: CompletedTest) -> Attempt {
+        let retry = match self.tests.get_mut(&completed_test.desc) {
+            Some(retry) => retry,
+            None => return Attempt::Done(completed_test),
+        };
+
+        let failed = matches!(
+            completed_test.result,
+            TestResult::TrFailed
+                | TestResult::TrFailedMsg(_)
+                | TestResult::TrTimedFail
+                | TestResult::TrTimedOut
+        );
+        // A failed teardown may have left things in a state the test can't run in.
+        let can_retry =
+            retry.attempts <= retry.retries && completed_test.teardown_failure.is_none();
+        if failed && can_retry {
+            retry.output.append(&mut completed_test.stdout);
+            if let TestResult::TrFailedMsg(msg) = &completed_test.result {
+                retry.output.extend_from_slice(format!("note: {}\n", msg).as_bytes());
+            }
+            let note =
+                format!("note: attempt {} of {} failed\n", retry.attempts, retry.retries + 1);
+            retry.output.extend_from_slice(note.as_bytes());
+            retry.attempts += 1;
+
+            let testfn = StaticTestFn(retry.testfn);
+            return Attempt::Retry(TestDescAndFn { desc: completed_test.desc, testfn });
+        }
+
+        let mut retry = self.tests.remove(&completed_test.desc).unwrap();
+        if retry.attempts > 1 {
+            if completed_test.result == TestResult::TrOk {
+                completed_test.result = TrFlaky(retry.attempts);
+            }
+            retry.output.append(&mut completed_test.stdout);
+            completed_test.stdout = retry.output;
+        }
+        Attempt::Done(completed_test)
+    }
+}
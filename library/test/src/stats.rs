@@ -301,3 +301,55 @@ pub fn winsorize(samples: &mut [f64], pct: f64) {
         }
     }
 }
+
+/// A range that is expected to contain the true value of an estimated statistic.
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// Estimate a confidence interval for the median of a set of samples by bootstrapping. The
+/// samples are resampled with replacement `resamples` times, and the interval is formed by the
+/// percentiles that enclose the central `confidence` percent of the medians of those resamples.
+///
+/// Resamples are drawn from a generator with a fixed seed, so the same samples always give the
+/// same interval.
+///
+/// See: <https://en.wikipedia.org/wiki/Bootstrapping_(statistics)>
+pub fn bootstrap_median(samples: &[f64], resamples: usize, confidence: f64) -> ConfidenceInterval {
+    assert!(!samples.is_empty());
+    assert!(resamples > 0);
+    assert!(0.0 < confidence && confidence <= 100.0);
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    let mut resample = vec![0.0; samples.len()];
+    let mut medians = Vec::with_capacity(resamples);
+    for _ in 0..resamples {
+        for samp in &mut resample {
+            *samp = samples[(rng.next() % samples.len() as u64) as usize];
+        }
+        medians.push(resample.median());
+    }
+    local_sort(&mut medians);
+
+    let tail = (100.0 - confidence) / 2.0;
+    ConfidenceInterval {
+        lower: percentile_of_sorted(&medians, tail),
+        upper: percentile_of_sorted(&medians, 100.0 - tail),
+    }
+}
+
+// Helper struct: a xorshift64 generator, which is good enough to pick resamples.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+}
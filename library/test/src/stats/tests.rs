@@ -571,6 +571,18 @@ fn test_sum_f64_between_ints_that_sum_to_0() {
     assert_eq!([1e30f64, 1.2f64, -1e30f64].sum(), 1.2);
 }
 
+#[test]
+fn test_bootstrap_median() {
+    let samples: Vec<f64> = (1..=50).map(|x| x as f64).collect();
+    let ci = bootstrap_median(&samples, 1000, 95.0);
+    assert!(ci.lower < samples.median() && samples.median() < ci.upper);
+    assert!(ci.lower > 15.0 && ci.upper < 36.0);
+    assert_eq!(ci, bootstrap_median(&samples, 1000, 95.0));
+
+    let ci = bootstrap_median(&[7.0; 20], 100, 95.0);
+    assert_eq!(ci, ConfidenceInterval { lower: 7.0, upper: 7.0 });
+}
+
 #[bench]
 pub fn sum_three_items(b: &mut Bencher) {
     b.iter(|| {
@@ -12,6 +12,9 @@
 // it means.
 pub const TR_OK: i32 = 50;
 pub const TR_FAILED: i32 = 51;
+// Added to the return code when a `#[test_teardown(each)]` function failed
+// after the test.
+pub const TR_TEARDOWN_FAILED: i32 = 10;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TestResult {
@@ -22,6 +25,9 @@ pub enum TestResult {
     TrAllowedFail,
     TrBench(BenchSamples),
     TrTimedFail,
+    TrTimedOut,
+    /// Passed after failing and being rerun, in the given number of attempts.
+    TrFlaky(usize),
 }
 
 unsafe impl Send for TestResult {}
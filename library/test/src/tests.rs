@@ -1,9 +1,14 @@
 use super::*;
 
 use crate::{
-    bench::Bencher,
+    bench::{BenchSamples, Bencher},
     console::OutputLocation,
-    formatters::PrettyFormatter,
+    formatters::{JsonFormatter, JunitFormatter, OutputFormatter, PrettyFormatter},
+    helpers::{
+        baseline::{self, Baseline, Change},
+        metrics::MetricChange,
+        ratchet::Ratchet,
+    },
     options::OutputFormat,
     test::{
         filter_tests,
@@ -28,6 +33,8 @@
     time::{TestTimeOptions, TimeThreshold},
 };
 use std::any::TypeId;
+use std::cell::RefCell;
+use std::fs;
 use std::sync::mpsc::channel;
 use std::time::Duration;
 
@@ -44,11 +51,20 @@ fn new() -> TestOpts {
             bench_benchmarks: false,
             logfile: None,
             nocapture: false,
+            capture: CaptureMode::Print,
             color: AutoColor,
             format: OutputFormat::Pretty,
             test_threads: None,
             skip: vec![],
+            shuffle: false,
+            shuffle_seed: None,
+            partition: None,
+            save_baseline: None,
+            baseline: None,
+            ratchet_metrics: None,
             time_options: None,
+            test_timeout: None,
+            retries: 0,
             options: Options::new(),
         }
     }
@@ -62,6 +78,8 @@ fn one_ignored_one_unignored_test() -> Vec<TestDescAndFn> {
                 ignore: true,
                 should_panic: ShouldPanic::No,
                 allow_fail: false,
+                timeout: None,
+                retries: None,
                 test_type: TestType::Unknown,
             },
             testfn: DynTestFn(Box::new(move || {})),
@@ -72,6 +90,8 @@ fn one_ignored_one_unignored_test() -> Vec<TestDescAndFn> {
                 ignore: false,
                 should_panic: ShouldPanic::No,
                 allow_fail: false,
+                timeout: None,
+                retries: None,
                 test_type: TestType::Unknown,
             },
             testfn: DynTestFn(Box::new(move || {})),
@@ -90,6 +110,8 @@ fn f() {
             ignore: true,
             should_panic: ShouldPanic::No,
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -109,6 +131,8 @@ fn f() {}
             ignore: true,
             should_panic: ShouldPanic::No,
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -132,6 +156,8 @@ fn f() {
             ignore: false,
             should_panic: ShouldPanic::Yes,
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -155,6 +181,8 @@ fn f() {
             ignore: false,
             should_panic: ShouldPanic::YesWithMessage("error message"),
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -183,6 +211,8 @@ fn f() {
             ignore: false,
             should_panic: ShouldPanic::YesWithMessage(expected),
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -214,6 +244,8 @@ fn f() {
             ignore: false,
             should_panic: ShouldPanic::YesWithMessage(expected),
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -235,6 +267,8 @@ fn f() {}
             ignore: false,
             should_panic: ShouldPanic::Yes,
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -253,6 +287,8 @@ fn f() {}
             ignore: false,
             should_panic: ShouldPanic::No,
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -286,6 +322,8 @@ fn f() {}
             ignore: false,
             should_panic: ShouldPanic::No,
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type,
         },
         testfn: DynTestFn(Box::new(f)),
@@ -323,6 +361,8 @@ fn typed_test_desc(test_type: TestType) -> TestDesc {
         ignore: false,
         should_panic: ShouldPanic::No,
         allow_fail: false,
+        timeout: None,
+        retries: None,
         test_type,
     }
 }
@@ -439,6 +479,8 @@ pub fn exclude_should_panic_option() {
             ignore: false,
             should_panic: ShouldPanic::Yes,
             allow_fail: false,
+            timeout: None,
+            retries: None,
             test_type: TestType::Unknown,
         },
         testfn: DynTestFn(Box::new(move || {})),
@@ -461,6 +503,8 @@ fn tests() -> Vec<TestDescAndFn> {
                     ignore: false,
                     should_panic: ShouldPanic::No,
                     allow_fail: false,
+                    timeout: None,
+                    retries: None,
                     test_type: TestType::Unknown,
                 },
                 testfn: DynTestFn(Box::new(move || {})),
@@ -536,6 +580,8 @@ fn testfn() {}
                     ignore: false,
                     should_panic: ShouldPanic::No,
                     allow_fail: false,
+                    timeout: None,
+                    retries: None,
                     test_type: TestType::Unknown,
                 },
                 testfn: DynTestFn(Box::new(testfn)),
@@ -586,6 +632,15 @@ pub fn test_metricmap_compare() {
 
     m1.insert_metric("in-both-want-upwards-and-improved", 1000.0, -10.0);
     m2.insert_metric("in-both-want-upwards-and-improved", 2000.0, -10.0);
+
+    let diff = m2.compare_to_old(&m1);
+    assert_eq!(diff["in-both-noise"], MetricChange::LikelyNoise);
+    assert!(!diff.contains_key("in-first-noise"));
+    assert!(!diff.contains_key("in-second-noise"));
+    assert_eq!(diff["in-both-want-downwards-but-regressed"], MetricChange::Regression(100.0));
+    assert_eq!(diff["in-both-want-downwards-and-improved"], MetricChange::Improvement(50.0));
+    assert_eq!(diff["in-both-want-upwards-but-regressed"], MetricChange::Regression(50.0));
+    assert_eq!(diff["in-both-want-upwards-and-improved"], MetricChange::Improvement(100.0));
 }
 
 #[test]
@@ -613,6 +668,8 @@ fn f(_: &mut Bencher) {}
         ignore: false,
         should_panic: ShouldPanic::No,
         allow_fail: false,
+        timeout: None,
+        retries: None,
         test_type: TestType::Unknown,
     };
 
@@ -633,6 +690,8 @@ fn f(b: &mut Bencher) {
         ignore: false,
         should_panic: ShouldPanic::No,
         allow_fail: false,
+        timeout: None,
+        retries: None,
         test_type: TestType::Unknown,
     };
 
@@ -647,6 +706,8 @@ fn should_sort_failures_before_printing_them() {
         ignore: false,
         should_panic: ShouldPanic::No,
         allow_fail: false,
+        timeout: None,
+        retries: None,
         test_type: TestType::Unknown,
     };
 
@@ -655,6 +716,8 @@ fn should_sort_failures_before_printing_them() {
         ignore: false,
         should_panic: ShouldPanic::No,
         allow_fail: false,
+        timeout: None,
+        retries: None,
         test_type: TestType::Unknown,
     };
 
@@ -671,10 +734,15 @@ fn should_sort_failures_before_printing_them() {
         measured: 0,
         exec_time: None,
         metrics: MetricMap::new(),
+        benchmarks: Vec::new(),
+        baseline: None,
+        ratchet: None,
         failures: vec![(test_b, Vec::new()), (test_a, Vec::new())],
         options: Options::new(),
         not_failures: Vec::new(),
+        flaky: Vec::new(),
         time_failures: Vec::new(),
+        timeouts: Vec::new(),
     };
 
     out.write_failures(&st).unwrap();
@@ -687,3 +755,936 @@ fn should_sort_failures_before_printing_them() {
     let bpos = s.find("b").unwrap();
     assert!(apos < bpos);
 }
+
+#[test]
+fn parse_junit_format_flag() {
+    let args = vec!["progname".to_string(), "--format=junit".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--format=junit".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.format, OutputFormat::Junit);
+}
+
+#[test]
+fn junit_formatter_output() {
+    let desc = |name: &'static str, test_type| TestDesc {
+        name: StaticTestName(name),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        timeout: None,
+        retries: None,
+        test_type,
+    };
+    let passed = desc("tests::passes", TestType::UnitTest);
+    let failed = desc("fails", TestType::UnitTest);
+    let ignored = desc("a::b::ignored", TestType::IntegrationTest);
+    let doc = desc("src/lib.rs - foo::bar (line 5)", TestType::DocTest);
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    st.total = 4;
+    st.passed = 2;
+    st.failed = 1;
+    st.ignored = 1;
+
+    let mut out = JunitFormatter::new(OutputLocation::Raw(Vec::new()));
+    let time = test_exec_time(1500);
+    let metrics = MetricMap::new();
+    out.write_result(&passed, &TrOk, Some(&time), b"not shown", &metrics, &st).unwrap();
+    let msg = TrFailedMsg("<\"a\" & 'b'>\nc".to_string());
+    out.write_result(&failed, &msg, None, b"out\x1b", &metrics, &st).unwrap();
+    out.write_result(&ignored, &TrIgnored, None, b"", &metrics, &st).unwrap();
+    out.write_result(&doc, &TrOk, None, b"", &metrics, &st).unwrap();
+    assert!(!out.write_run_finish(&st).unwrap());
+
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    let expected = "\
+        <?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <testsuites>\n\
+        <testsuite name=\"test\" package=\"test\" id=\"0\" errors=\"0\" failures=\"1\" \
+         tests=\"4\" skipped=\"1\">\n\
+        <testcase classname=\"tests\" name=\"passes\" time=\"1.5\"></testcase>\n\
+        <testcase classname=\"crate\" name=\"fails\">\
+         <failure type=\"assert\" message=\"&lt;&quot;a&quot; &amp; &apos;b&apos;&gt;&#10;c\"/>\
+         <system-out>out\u{fffd}</system-out></testcase>\n\
+        <testcase classname=\"a::b\" name=\"ignored\"><skipped/></testcase>\n\
+        <testcase classname=\"src/lib.rs\" name=\"foo::bar (line 5)\"></testcase>\n\
+        </testsuite>\n\
+        </testsuites>\n";
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn parse_shuffle_flags() {
+    let args = vec!["progname".to_string(), "--shuffle".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec!["progname".to_string(), "--shuffle-seed=42".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--shuffle".to_string(),
+        "--shuffle-seed=42".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert!(opts.shuffle);
+    assert_eq!(opts.shuffle_seed, Some(42));
+
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--shuffle-seed=forty-two".to_string(),
+    ];
+    assert!(parse_opts(&args).unwrap().is_err());
+}
+
+fn shuffled_run_order(opts: &TestOpts, count: usize) -> (Vec<String>, Option<u64>) {
+    let tests = (0..count)
+        .map(|i| TestDescAndFn {
+            desc: TestDesc {
+                name: DynTestName(format!("test{:02}", i)),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                timeout: None,
+                retries: None,
+                test_type: TestType::Unknown,
+            },
+            testfn: DynTestFn(Box::new(move || {})),
+        })
+        .collect();
+
+    let mut order = Vec::new();
+    let mut seed = None;
+    run_tests(opts, tests, |event| {
+        match event {
+            TestEvent::TeFiltered(_, shuffle_seed) => seed = shuffle_seed,
+            TestEvent::TeResult(completed) => order.push(completed.desc.name.to_string()),
+            _ => {}
+        }
+        Ok(())
+    })
+    .unwrap();
+    (order, seed)
+}
+
+#[test]
+fn shuffle_is_reproducible() {
+    let mut opts = TestOpts::new();
+    opts.run_tests = true;
+    opts.test_threads = Some(1);
+
+    let (sorted, seed) = shuffled_run_order(&opts, 20);
+    assert_eq!(seed, None);
+    assert!(sorted.windows(2).all(|w| w[0] < w[1]));
+
+    opts.shuffle_seed = Some(7);
+    let (first, seed) = shuffled_run_order(&opts, 20);
+    let (second, _) = shuffled_run_order(&opts, 20);
+    assert_eq!(seed, Some(7));
+    assert_eq!(first, second);
+    assert_ne!(first, sorted);
+
+    let mut permutation = first.clone();
+    permutation.sort();
+    assert_eq!(permutation, sorted);
+
+    opts.shuffle_seed = Some(8);
+    assert_ne!(shuffled_run_order(&opts, 20).0, first);
+
+    // Skipped tests are removed before shuffling.
+    opts.skip = vec!["test0".to_string()];
+    let (skipped, _) = shuffled_run_order(&opts, 20);
+    assert_eq!(skipped.len(), 10);
+    assert!(skipped.iter().all(|name| !name.starts_with("test0")));
+}
+
+#[test]
+fn shuffle_order_is_stable() {
+    let mut opts = TestOpts::new();
+    opts.run_tests = true;
+    opts.test_threads = Some(1);
+    opts.shuffle_seed = Some(7);
+
+    // The order for a given seed must not depend on the toolchain, so it is
+    // pinned here.
+    let (order, _) = shuffled_run_order(&opts, 5);
+    assert_eq!(order, ["test01", "test03", "test00", "test02", "test04"]);
+}
+
+#[test]
+fn shuffle_without_seed_reports_seed() {
+    let mut opts = TestOpts::new();
+    opts.run_tests = true;
+    opts.shuffle = true;
+
+    let (order, seed) = shuffled_run_order(&opts, 5);
+    assert_eq!(order.len(), 5);
+    assert!(seed.is_some());
+}
+
+#[test]
+fn parse_test_timeout() {
+    assert_eq!(time::parse_timeout("250ms"), Ok(Duration::from_millis(250)));
+    assert_eq!(time::parse_timeout("30s"), Ok(Duration::from_secs(30)));
+    assert_eq!(time::parse_timeout("2m"), Ok(Duration::from_secs(120)));
+    assert!(time::parse_timeout("30").is_err());
+    assert!(time::parse_timeout("s").is_err());
+    assert!(time::parse_timeout("1.5s").is_err());
+    assert!(time::parse_timeout("-1s").is_err());
+
+    let args = vec!["progname".to_string(), "--test-timeout=30s".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--test-timeout=30s".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.test_timeout, Some(Duration::from_secs(30)));
+}
+
+fn run_timed_tests(opts: &TestOpts, timeout: Option<Duration>) -> Vec<(String, TestResult)> {
+    fn test(name: &'static str, timeout: Option<Duration>, f: fn()) -> TestDescAndFn {
+        TestDescAndFn {
+            desc: TestDesc {
+                name: StaticTestName(name),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+                allow_fail: false,
+                timeout,
+                retries: None,
+                test_type: TestType::Unknown,
+            },
+            testfn: DynTestFn(Box::new(f)),
+        }
+    }
+
+    let tests = vec![
+        test("a", None, || {}),
+        test("b", timeout, || thread::sleep(Duration::from_millis(200))),
+        test("c", None, || {}),
+    ];
+
+    let mut results = Vec::new();
+    run_tests(opts, tests, |event| {
+        if let TestEvent::TeResult(completed) = event {
+            results.push((completed.desc.name.to_string(), completed.result));
+        }
+        Ok(())
+    })
+    .unwrap();
+    results
+}
+
+#[test]
+fn in_process_timeout_aborts_run() {
+    let mut opts = TestOpts::new();
+    opts.run_tests = true;
+
+    for &threads in &[1, 4] {
+        opts.test_threads = Some(threads);
+        let results = run_timed_tests(&opts, Some(Duration::from_millis(20)));
+        assert_eq!(results.last(), Some(&("b".to_string(), TrTimedOut)));
+        assert!(!results.contains(&("b".to_string(), TrOk)));
+    }
+}
+
+#[test]
+fn test_timeout_option() {
+    let mut opts = TestOpts::new();
+    opts.run_tests = true;
+    opts.test_threads = Some(1);
+    opts.test_timeout = Some(Duration::from_millis(20));
+
+    let results = run_timed_tests(&opts, None);
+    assert_eq!(results, vec![("a".to_string(), TrOk), ("b".to_string(), TrTimedOut)]);
+
+    // The `#[timeout]` attribute takes precedence over the command line.
+    let results = run_timed_tests(&opts, Some(Duration::from_secs(60)));
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|(_, result)| *result == TrOk));
+}
+
+#[test]
+fn parse_partition_flag() {
+    let parse = |partition: &str| {
+        let args = vec![
+            "progname".to_string(),
+            "-Zunstable-options".to_string(),
+            format!("--partition={}", partition),
+        ];
+        parse_opts(&args).unwrap().map(|opts| opts.partition.unwrap())
+    };
+
+    let args = vec!["progname".to_string(), "--partition=1/2".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    assert_eq!(parse("1/2"), Ok(Partition { kind: PartitionKind::Count, index: 1, count: 2 }));
+    assert_eq!(
+        parse("count:2/2"),
+        Ok(Partition { kind: PartitionKind::Count, index: 2, count: 2 })
+    );
+    assert_eq!(parse("hash:3/4"), Ok(Partition { kind: PartitionKind::Hash, index: 3, count: 4 }));
+    assert_eq!(parse("hash:3/4").unwrap().to_string(), "hash:3/4");
+
+    for invalid in &["0/2", "3/2", "1/0", "1", "1/", "/2", "one/two", "random:1/2"] {
+        assert!(parse(invalid).is_err(), "{} should be rejected", invalid);
+    }
+}
+
+#[test]
+fn parse_capture_flag() {
+    let parse = |capture: &str| {
+        let args = vec![
+            "progname".to_string(),
+            "-Zunstable-options".to_string(),
+            format!("--capture={}", capture),
+        ];
+        parse_opts(&args).unwrap().map(|opts| opts.capture)
+    };
+
+    let args = vec!["progname".to_string()];
+    assert_eq!(parse_opts(&args).unwrap().unwrap().capture, CaptureMode::Print);
+    let args = vec!["progname".to_string(), "--capture=fd".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    assert_eq!(parse("print"), Ok(CaptureMode::Print));
+    assert_eq!(parse("fd"), Ok(CaptureMode::Fd));
+    assert!(parse("sys").is_err());
+}
+
+#[test]
+fn partitions_are_disjoint() {
+    fn tests() -> Vec<TestDescAndFn> {
+        (0..50)
+            .map(|i| TestDescAndFn {
+                desc: TestDesc {
+                    name: DynTestName(format!("test{:02}", i)),
+                    ignore: false,
+                    should_panic: ShouldPanic::No,
+                    allow_fail: false,
+                    timeout: None,
+                    retries: None,
+                    test_type: TestType::Unknown,
+                },
+                testfn: DynTestFn(Box::new(move || {})),
+            })
+            .collect()
+    }
+
+    for &kind in &[PartitionKind::Count, PartitionKind::Hash] {
+        let mut opts = TestOpts::new();
+        opts.skip = vec!["test4".to_string()];
+
+        let mut seen = Vec::new();
+        for index in 1..=3 {
+            opts.partition = Some(Partition { kind, index, count: 3 });
+            let names: Vec<String> =
+                filter_tests(&opts, tests()).iter().map(|t| t.desc.name.to_string()).collect();
+
+            // The same partition is selected on every run.
+            let again: Vec<String> =
+                filter_tests(&opts, tests()).iter().map(|t| t.desc.name.to_string()).collect();
+            assert_eq!(names, again);
+
+            if kind == PartitionKind::Count {
+                assert!(names.len() == 13 || names.len() == 14);
+            }
+            seen.extend(names);
+        }
+
+        // Every test that survives filtering is run by exactly one partition.
+        seen.sort();
+        opts.partition = None;
+        let all: Vec<String> =
+            filter_tests(&opts, tests()).iter().map(|t| t.desc.name.to_string()).collect();
+        assert_eq!(all.len(), 40);
+        assert_eq!(seen, all);
+    }
+}
+
+#[test]
+fn hash_partition_is_stable() {
+    let name = |name: &'static str| TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName(name),
+            ignore: false,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            timeout: None,
+            retries: None,
+            test_type: TestType::Unknown,
+        },
+        testfn: DynTestFn(Box::new(move || {})),
+    };
+    let partition_of = |test: &'static str, others: &[&'static str]| {
+        (1..=4)
+            .find(|&index| {
+                let mut opts = TestOpts::new();
+                opts.partition = Some(Partition { kind: PartitionKind::Hash, index, count: 4 });
+                let tests = others.iter().chain(Some(&test)).map(|&n| name(n)).collect();
+                filter_tests(&opts, tests).iter().any(|t| t.desc.name.as_slice() == test)
+            })
+            .unwrap()
+    };
+
+    // Adding tests doesn't move existing tests to another partition.
+    assert_eq!(partition_of("a::b", &[]), partition_of("a::b", &["a::a", "a::c", "z"]));
+}
+
+#[test]
+fn json_formatter_reports_partition() {
+    let partition = Partition { kind: PartitionKind::Hash, index: 2, count: 5 };
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()), Some(partition));
+    out.write_run_start(3, None).unwrap();
+
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    assert_eq!(
+        s,
+        "{ \"type\": \"suite\", \"event\": \"started\", \"test_count\": 3, \
+         \"partition\": \"hash:2/5\" }\n"
+    );
+}
+
+#[test]
+fn parse_baseline_flags() {
+    let args = vec!["progname".to_string(), "--save-baseline=main".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--save-baseline=new".to_string(),
+        "--baseline=main".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.save_baseline.as_deref(), Some("new"));
+    assert_eq!(opts.baseline.as_deref(), Some("main"));
+
+    for invalid in &["", "..", "a/b", "a\\b"] {
+        let args = vec![
+            "progname".to_string(),
+            "-Zunstable-options".to_string(),
+            format!("--baseline={}", invalid),
+        ];
+        assert!(parse_opts(&args).unwrap().is_err(), "{:?} should be rejected", invalid);
+    }
+}
+
+fn bench_samples(
+    name: &'static str,
+    median: f64,
+    lower: f64,
+    upper: f64,
+) -> (TestDesc, BenchSamples) {
+    let desc = TestDesc {
+        name: StaticTestName(name),
+        ignore: false,
+        should_panic: ShouldPanic::No,
+        allow_fail: false,
+        timeout: None,
+        retries: None,
+        test_type: TestType::Unknown,
+    };
+    let bs = BenchSamples {
+        ns_iter_summ: stats::Summary::new(&[median]),
+        ns_iter_ci: stats::ConfidenceInterval { lower, upper },
+        mb_s: 0,
+        bytes: 0,
+    };
+    (desc, bs)
+}
+
+#[test]
+fn baseline_comparison() {
+    let mut saved = Baseline::parse("main", "").unwrap();
+    saved.record(&[
+        bench_samples("a", 100.0, 98.0, 102.0),
+        bench_samples("b", 100.0, 98.0, 102.0),
+        bench_samples("c", 100.0, 98.0, 102.0),
+        bench_samples("with spaces - d", 100.5, 98.25, 102.0),
+    ]);
+    let baseline = Baseline::parse("main", &saved.serialize()).unwrap();
+    assert_eq!(baseline, saved);
+    assert!(Baseline::parse("main", "100 98 oops a").is_none());
+
+    let compare = |name, median, lower, upper| {
+        baseline.compare(name, &bench_samples("", median, lower, upper).1).map(|c| c.change)
+    };
+    assert_eq!(compare("a", 120.0, 110.0, 130.0), Some(Change::Regressed));
+    assert_eq!(compare("b", 80.0, 70.0, 90.0), Some(Change::Improved));
+    // The confidence intervals overlap.
+    assert_eq!(compare("c", 110.0, 101.0, 119.0), Some(Change::Unchanged));
+    // The change is too small to matter.
+    assert_eq!(compare("with spaces - d", 101.0, 100.0, 102.0), Some(Change::Unchanged));
+    assert_eq!(compare("with spaces - d", 80.0, 79.0, 81.0), Some(Change::Improved));
+    assert_eq!(compare("new", 100.0, 98.0, 102.0), None);
+
+    let report = baseline.report(&[
+        bench_samples("a", 120.0, 110.0, 130.0),
+        bench_samples("b", 80.0, 70.0, 90.0),
+        bench_samples("c", 100.0, 98.0, 102.0),
+    ]);
+    assert_eq!(
+        report,
+        "\nregressions compared to baseline `main`:\n    a: +20.00% (100 ns/iter -> 120 ns/iter)\n\
+         \nimprovements compared to baseline `main`:\n    b: -20.00% (100 ns/iter -> 80 ns/iter)\n"
+    );
+}
+
+#[test]
+fn baseline_save_keeps_other_benchmarks() {
+    let dir = env::temp_dir().join(format!("libtest-baseline-{}", process::id()));
+    let path = dir.join("main").join("test.txt");
+    let save = |benchmarks: &[_]| baseline::save_to(&path, "main", benchmarks).unwrap();
+
+    save(&[bench_samples("a", 100.0, 98.0, 102.0), bench_samples("b", 100.0, 98.0, 102.0)]);
+    // A later run only ran some of the benchmarks, say because of a filter.
+    save(&[bench_samples("b", 80.0, 79.5, 81.0), bench_samples("c", 50.0, 49.0, 51.0)]);
+
+    let saved = fs::read_to_string(&path).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(
+        saved,
+        "# libtest benchmark baseline: median lower upper name\n\
+         100 98 102 a\n\
+         80 79.5 81 b\n\
+         50 49 51 c\n"
+    );
+}
+
+#[test]
+fn save_baseline_only_when_benchmarking() {
+    // Without `--bench`, benchmarks are run once as tests and have no results to save.
+    let name = format!("libtest-unbenched-{}", process::id());
+    let mut opts = TestOpts::new();
+    opts.save_baseline = Some(name.clone());
+    let st = console::ConsoleTestState::new(&opts).unwrap();
+
+    console::save_baseline(&opts, &st).unwrap();
+    assert_eq!(Baseline::load(&name).unwrap_err().kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn json_formatter_bench_output() {
+    let (desc, mut bs) = bench_samples("a", 120.0, 110.0, 130.0);
+    bs.mb_s = 8;
+    bs.bytes = 1000;
+
+    let mut st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    st.baseline = Baseline::parse("main", "100 98 102 a\n");
+
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()), None);
+    out.write_result(&desc, &TestResult::TrBench(bs), None, b"", &MetricMap::new(), &st).unwrap();
+
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    assert_eq!(
+        s,
+        "{ \"type\": \"bench\", \"name\": \"a\", \"median\": 120, \"median_lower\": 110, \
+         \"median_upper\": 130, \"deviation\": 0, \"mib_per_second\": 8, \"bytes_per_iter\": 1000, \
+         \"baseline_median\": 100, \"change_pct\": 20.00, \"change\": \"regressed\" }\n"
+    );
+}
+
+fn fixture_test(name: &'static str, ignore: bool, testfn: TestFn) -> TestDescAndFn {
+    TestDescAndFn {
+        desc: TestDesc {
+            name: StaticTestName(name),
+            ignore,
+            should_panic: ShouldPanic::No,
+            allow_fail: false,
+            timeout: None,
+            retries: None,
+            test_type: TestType::Unknown,
+        },
+        testfn,
+    }
+}
+
+thread_local!(static FIXTURE_LOG: RefCell<Vec<&'static str>> = RefCell::new(Vec::new()));
+
+fn log_fixture_event(event: &'static str) {
+    FIXTURE_LOG.with(|log| log.borrow_mut().push(event));
+}
+
+fn fixture_log_count(event: &'static str) -> usize {
+    FIXTURE_LOG.with(|log| log.borrow().iter().filter(|&&e| e == event).count())
+}
+
+// Runs the tests on the current thread, so that they all see the same `FIXTURE_LOG`.
+fn run_logged_tests(mut opts: TestOpts, tests: Vec<TestDescAndFn>) -> Vec<CompletedTest> {
+    opts.run_tests = true;
+    opts.test_threads = Some(1);
+
+    let mut results = Vec::new();
+    run_tests(&opts, tests, |event| {
+        match event {
+            TestEvent::TeResult(completed) | TestEvent::TeFixtureFailed(completed) => {
+                results.push(completed)
+            }
+            _ => {}
+        }
+        Ok(())
+    })
+    .unwrap();
+    results
+}
+
+fn run_fixture_tests(tests: Vec<TestDescAndFn>) -> (Vec<(String, TestResult)>, Vec<&'static str>) {
+    let results = run_logged_tests(TestOpts::new(), tests)
+        .into_iter()
+        .map(|completed| (completed.desc.name.to_string(), completed.result))
+        .collect();
+    (results, FIXTURE_LOG.with(|log| log.borrow_mut().split_off(0)))
+}
+
+#[test]
+fn fixtures_run_around_tests() {
+    let tests = vec![
+        fixture_test("b", false, StaticTestFn(|| log_fixture_event("b"))),
+        fixture_test("a", false, StaticTestFn(|| log_fixture_event("a"))),
+        fixture_test(
+            "teardown",
+            false,
+            StaticFixtureFn(FixtureKind::TeardownOnce, || log_fixture_event("teardown")),
+        ),
+        fixture_test(
+            "setup",
+            false,
+            StaticFixtureFn(FixtureKind::SetupOnce, || log_fixture_event("setup")),
+        ),
+        fixture_test(
+            "setup_each",
+            false,
+            StaticFixtureFn(FixtureKind::SetupEach, || log_fixture_event("setup each")),
+        ),
+        fixture_test(
+            "teardown_each",
+            false,
+            StaticFixtureFn(FixtureKind::TeardownEach, || log_fixture_event("teardown each")),
+        ),
+        fixture_test(
+            "ignored",
+            true,
+            StaticFixtureFn(FixtureKind::SetupEach, || log_fixture_event("ignored")),
+        ),
+    ];
+
+    let (results, log) = run_fixture_tests(tests);
+    assert_eq!(results, [("a".to_string(), TrOk), ("b".to_string(), TrOk)]);
+    assert_eq!(
+        log,
+        [
+            "setup",
+            "setup each",
+            "a",
+            "teardown each",
+            "setup each",
+            "b",
+            "teardown each",
+            "teardown"
+        ]
+    );
+}
+
+#[test]
+fn fixture_failures_are_reported() {
+    let tests = vec![
+        fixture_test("a", false, StaticTestFn(|| log_fixture_event("a"))),
+        fixture_test("b", false, StaticTestFn(|| panic!())),
+        fixture_test("teardown", false, StaticFixtureFn(FixtureKind::TeardownOnce, || panic!())),
+        fixture_test(
+            "teardown_each",
+            false,
+            StaticFixtureFn(FixtureKind::TeardownEach, || {
+                log_fixture_event("teardown each");
+                panic!()
+            }),
+        ),
+        fixture_test(
+            "teardown_each_2",
+            false,
+            StaticFixtureFn(FixtureKind::TeardownEach, || log_fixture_event("teardown each 2")),
+        ),
+    ];
+
+    let (results, log) = run_fixture_tests(tests);
+    assert_eq!(
+        results,
+        [
+            ("a".to_string(), TrOk),
+            ("a (teardown)".to_string(), TrFailed),
+            ("b".to_string(), TrFailed),
+            ("b (teardown)".to_string(), TrFailed),
+            ("teardown".to_string(), TrFailed),
+        ]
+    );
+    // A failing teardown function doesn't keep the others from running.
+    assert_eq!(log, ["a", "teardown each", "teardown each 2", "teardown each", "teardown each 2"]);
+}
+
+#[test]
+fn failed_setup_skips_tests() {
+    let mut should_panic = fixture_test("should_panic", false, StaticTestFn(|| {}));
+    should_panic.desc.should_panic = ShouldPanic::Yes;
+    let tests = vec![
+        should_panic,
+        fixture_test("setup_each", false, StaticFixtureFn(FixtureKind::SetupEach, || panic!())),
+        fixture_test(
+            "teardown_each",
+            false,
+            StaticFixtureFn(FixtureKind::TeardownEach, || log_fixture_event("teardown each")),
+        ),
+    ];
+
+    // A panicking setup function doesn't count as the panic the test expects.
+    let (results, log) = run_fixture_tests(tests);
+    let message = "a `#[test_setup(each)]` function failed".to_string();
+    assert_eq!(results, [("should_panic".to_string(), TrFailedMsg(message))]);
+    assert_eq!(log, ["teardown each"]);
+
+    let tests = vec![
+        fixture_test("a", false, StaticTestFn(|| log_fixture_event("a"))),
+        fixture_test("ignored", true, StaticTestFn(|| log_fixture_event("ignored"))),
+        fixture_test("setup", false, StaticFixtureFn(FixtureKind::SetupOnce, || panic!())),
+        fixture_test(
+            "teardown",
+            false,
+            StaticFixtureFn(FixtureKind::TeardownOnce, || log_fixture_event("teardown")),
+        ),
+    ];
+
+    let (results, log) = run_fixture_tests(tests);
+    let message = "not run because `setup` failed".to_string();
+    assert_eq!(
+        results,
+        [
+            ("setup".to_string(), TrFailed),
+            ("a".to_string(), TrFailedMsg(message)),
+            ("ignored".to_string(), TrIgnored),
+        ]
+    );
+    assert_eq!(log, ["teardown"]);
+}
+
+#[test]
+fn failing_tests_are_retried() {
+    let tests = vec![
+        fixture_test("passes", false, StaticTestFn(|| log_fixture_event("passes"))),
+        fixture_test(
+            "flaky",
+            false,
+            StaticTestFn(|| {
+                log_fixture_event("flaky");
+                println!("attempt {}", fixture_log_count("flaky"));
+                assert!(fixture_log_count("flaky") == 3);
+            }),
+        ),
+        fixture_test(
+            "fails",
+            false,
+            StaticTestFn(|| {
+                log_fixture_event("fails");
+                panic!()
+            }),
+        ),
+    ];
+
+    let mut opts = TestOpts::new();
+    opts.retries = 2;
+    let results = run_logged_tests(opts, tests);
+    let names_and_results: Vec<_> = results
+        .iter()
+        .map(|completed| (completed.desc.name.as_slice(), &completed.result))
+        .collect();
+    assert_eq!(
+        names_and_results,
+        [("fails", &TrFailed), ("flaky", &TrFlaky(3)), ("passes", &TrOk)]
+    );
+
+    // The output of every attempt is kept.
+    let stdout = String::from_utf8_lossy(&results[1].stdout);
+    assert!(stdout.starts_with("attempt 1\n"));
+    assert!(stdout.contains("note: attempt 2 of 3 failed\n"));
+    assert!(stdout.ends_with("attempt 3\n"));
+
+    let log = FIXTURE_LOG.with(|log| log.borrow_mut().split_off(0));
+    assert_eq!(log, ["fails", "fails", "fails", "flaky", "flaky", "flaky", "passes"]);
+}
+
+#[test]
+fn flaky_attribute_overrides_retries() {
+    let mut flaky = fixture_test(
+        "flaky",
+        false,
+        StaticTestFn(|| {
+            log_fixture_event("flaky");
+            assert!(fixture_log_count("flaky") == 2);
+        }),
+    );
+    flaky.desc.retries = Some(1);
+    let mut not_retried = fixture_test(
+        "not_retried",
+        false,
+        StaticTestFn(|| {
+            log_fixture_event("not retried");
+            panic!()
+        }),
+    );
+    not_retried.desc.retries = Some(0);
+
+    let mut opts = TestOpts::new();
+    opts.retries = 3;
+    let results = run_logged_tests(opts, vec![flaky, not_retried]);
+    let results: Vec<_> = results.into_iter().map(|completed| completed.result).collect();
+    assert_eq!(results, [TrFlaky(2), TrFailed]);
+
+    let log = FIXTURE_LOG.with(|log| log.borrow_mut().split_off(0));
+    assert_eq!(log, ["flaky", "flaky", "not retried"]);
+}
+
+#[test]
+fn recorded_metrics_are_collected() {
+    let tests = vec![
+        fixture_test(
+            "a",
+            false,
+            StaticTestFn(|| {
+                record_metric("bytes_allocated", 1000.0, 10.0);
+                record_metric("bytes_allocated", 2000.0, 10.0);
+                record_metric("throughput", 5.5, -0.5);
+            }),
+        ),
+        fixture_test("b", false, StaticTestFn(|| {})),
+    ];
+
+    let results = run_logged_tests(TestOpts::new(), tests);
+    let mut expected = MetricMap::new();
+    expected.insert_metric("bytes_allocated", 2000.0, 10.0);
+    expected.insert_metric("throughput", 5.5, -0.5);
+    assert_eq!(results[0].metrics, expected);
+    assert!(results[1].metrics.is_empty());
+}
+
+#[test]
+fn metrics_recorded_on_spawned_threads_are_ignored() {
+    let tests = vec![fixture_test(
+        "a",
+        false,
+        StaticTestFn(|| {
+            thread::spawn(|| record_metric("from_thread", 1.0, 0.0)).join().unwrap();
+            // Measurements made on other threads have to be recorded by the
+            // thread running the test.
+            let value = thread::spawn(|| 2.0).join().unwrap();
+            record_metric("joined", value, 0.0);
+        }),
+    )];
+
+    let results = run_logged_tests(TestOpts::new(), tests);
+    let mut expected = MetricMap::new();
+    expected.insert_metric("joined", 2.0, 0.0);
+    assert_eq!(results[0].metrics, expected);
+}
+
+#[test]
+fn json_formatter_test_metrics() {
+    let desc = fixture_test("a", false, StaticTestFn(|| {})).desc;
+    let mut metrics = MetricMap::new();
+    metrics.insert_metric("bytes_allocated", 1024.0, 0.0);
+    metrics.insert_metric("throughput", 1.5, -0.25);
+
+    let st = console::ConsoleTestState::new(&TestOpts::new()).unwrap();
+    let mut out = JsonFormatter::new(OutputLocation::Raw(Vec::new()), None);
+    out.write_result(&desc, &TrOk, None, b"", &metrics, &st).unwrap();
+
+    let s = match out.output_location() {
+        &OutputLocation::Raw(ref m) => String::from_utf8_lossy(&m[..]).into_owned(),
+        &OutputLocation::Pretty(_) => unreachable!(),
+    };
+    assert_eq!(
+        s,
+        "{ \"type\": \"test\", \"name\": \"a\", \"event\": \"ok\", \"metrics\": { \
+         \"bytes_allocated\": { \"value\": 1024, \"noise\": 0 }, \
+         \"throughput\": { \"value\": 1.5, \"noise\": -0.25 } } }\n"
+    );
+}
+
+#[test]
+fn metrics_ratchet() {
+    let path = Path::new("metrics.txt");
+    let mut ratchet = Ratchet::parse(
+        path,
+        "# libtest metrics ratchet: value noise metric test\n\
+         1000 10 bytes a b\n\
+         10 -1 throughput a b\n\
+         5 0 bytes c\n",
+    )
+    .unwrap();
+    let mut check = |name: &'static str, result: TestResult, metrics: &[(&str, f64, f64)]| {
+        let desc = fixture_test(name, false, StaticTestFn(|| {})).desc;
+        let mut completed = CompletedTest::new(desc, result, None, Vec::new());
+        for &(metric, value, noise) in metrics {
+            completed.metrics.insert_metric(metric, value, noise);
+        }
+        ratchet.check(&mut completed);
+        completed.result
+    };
+
+    // Changes within the noise, or in the good direction, pass.
+    assert_eq!(check("a b", TrOk, &[("bytes", 1005.0, 10.0), ("throughput", 12.0, -1.0)]), TrOk);
+    // The metrics of failed tests are not compared.
+    assert_eq!(check("c", TrFailed, &[("bytes", 100.0, 0.0)]), TrFailed);
+    let msg = "metrics regressed compared to metrics.txt: `bytes` regressed by 20.00% (5 -> 6)";
+    assert_eq!(
+        check("c", TrFlaky(2), &[("bytes", 6.0, 0.0), ("new", 1.0, 0.0)]),
+        TrFailedMsg(msg.to_string())
+    );
+    assert_eq!(check("d", TrOk, &[("new", 1.0, 0.0)]), TrOk);
+
+    // Only the improved and new metrics of passing tests are saved.
+    assert_eq!(
+        ratchet.serialize(),
+        "# libtest metrics ratchet: value noise metric test\n\
+         1000 10 bytes a b\n\
+         12 -1 throughput a b\n\
+         5 0 bytes c\n\
+         1 0 new d\n"
+    );
+}
+
+#[test]
+fn parse_retries_flag() {
+    let args = vec!["progname".to_string(), "--retries=2".to_string()];
+    assert!(parse_opts(&args).unwrap().is_err());
+
+    let parse = |retries: &str| {
+        let args = vec![
+            "progname".to_string(),
+            "-Zunstable-options".to_string(),
+            format!("--retries={}", retries),
+        ];
+        parse_opts(&args).unwrap().map(|opts| opts.retries)
+    };
+    assert_eq!(parse("2"), Ok(2));
+    assert_eq!(parse("0"), Ok(0));
+    assert!(parse("-1").is_err());
+    assert!(parse("many").is_err());
+}
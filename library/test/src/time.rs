@@ -61,6 +61,27 @@ pub fn get_default_test_timeout() -> Instant {
     Instant::now() + Duration::from_secs(TEST_WARN_TIMEOUT_S)
 }
 
+/// Parses a test timeout given as a number followed by a unit, e.g. `500ms`,
+/// `30s` or `2m`.
+pub fn parse_timeout(timeout: &str) -> Result<Duration, String> {
+    let unit_start = timeout.find(|c: char| !c.is_ascii_digit()).unwrap_or(timeout.len());
+    let (value, unit) = timeout.split_at(unit_start);
+
+    let duration = u64::from_str(value).ok().and_then(|value| match unit {
+        "ms" => Some(Duration::from_millis(value)),
+        "s" => Some(Duration::from_secs(value)),
+        "m" => value.checked_mul(60).map(Duration::from_secs),
+        _ => None,
+    });
+
+    duration.ok_or_else(|| {
+        format!(
+            "timeout must be a number followed by `ms`, `s` or `m` (e.g. `30s`), but got `{}`",
+            timeout
+        )
+    })
+}
+
 /// The measured execution time of a unit test.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TestExecTime(pub Duration);
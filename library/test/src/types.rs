@@ -2,6 +2,7 @@
 
 use std::borrow::Cow;
 use std::fmt;
+use std::time::Duration;
 
 use super::bench::Bencher;
 use super::options;
@@ -79,6 +80,19 @@ pub trait TDynBenchFn: Send {
     fn run(&self, harness: &mut Bencher);
 }
 
+/// When a function registered with `#[test_setup]` or `#[test_teardown]` runs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FixtureKind {
+    /// `#[test_setup]`: once, before the first test.
+    SetupOnce,
+    /// `#[test_setup(each)]`: before each test, in the thread or subprocess running it.
+    SetupEach,
+    /// `#[test_teardown(each)]`: after each test, in the thread or subprocess running it.
+    TeardownEach,
+    /// `#[test_teardown]`: once, after the last test.
+    TeardownOnce,
+}
+
 // A function that runs a test. If the function returns successfully,
 // the test succeeds; if the function panics then the test fails. We
 // may need to come up with a more clever definition of test in order
@@ -88,6 +102,9 @@ pub enum TestFn {
     StaticBenchFn(fn(&mut Bencher)),
     DynTestFn(Box<dyn FnOnce() + Send>),
     DynBenchFn(Box<dyn TDynBenchFn + 'static>),
+    /// A setup or teardown function, as registered with `#[test_setup]`
+    /// or `#[test_teardown]`. It isn't run as a test itself.
+    StaticFixtureFn(FixtureKind, fn()),
 }
 
 impl TestFn {
@@ -97,6 +114,7 @@ pub fn padding(&self) -> NamePadding {
             StaticBenchFn(..) => PadOnRight,
             DynTestFn(..) => PadNone,
             DynBenchFn(..) => PadOnRight,
+            StaticFixtureFn(..) => PadNone,
         }
     }
 }
@@ -108,6 +126,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             StaticBenchFn(..) => "StaticBenchFn(..)",
             DynTestFn(..) => "DynTestFn(..)",
             DynBenchFn(..) => "DynBenchFn(..)",
+            StaticFixtureFn(..) => "StaticFixtureFn(..)",
         })
     }
 }
@@ -120,6 +139,12 @@ pub struct TestDesc {
     pub ignore: bool,
     pub should_panic: options::ShouldPanic,
     pub allow_fail: bool,
+    /// The time after which the test is stopped and reported as timed out,
+    /// as set by the `#[timeout]` attribute. Overrides `--test-timeout`.
+    pub timeout: Option<Duration>,
+    /// How many times the test is rerun if it fails, as set by the
+    /// `#[flaky(retries = N)]` attribute. Overrides `--retries`.
+    pub retries: Option<usize>,
     pub test_type: TestType,
 }
 
@@ -1,141 +1,131 @@
 #!/usr/bin/env python3
 
 """
-Generate powers of ten using William Clinger's ``AlgorithmM`` for use in
-decimal to floating point conversions.
+Generate powers of five using arbitrary-precision integers for use in the
+Eisel-Lemire decimal to floating point conversion algorithm.
 
-Specifically, computes and outputs (as Rust code) a table of 10^e for some
-range of exponents e. The output is one array of 64 bit significands and
-another array of corresponding base two exponents. The approximations are
-normalized and rounded perfectly, i.e., within 0.5 ULP of the true value.
+Specifically, computes and outputs (as Rust code) a table of 5^e for every
+exponent e in [SMALLEST_POWER_OF_FIVE, LARGEST_POWER_OF_FIVE], each stored as
+a normalized 128-bit significand split into its high and low 64-bit halves.
+Negative powers are rounded up, positive powers are truncated, as required
+by the algorithm.
 
-The representation ([u64], [i16]) instead of the more natural [(u64, i16)]
-is used because (u64, i16) has a ton of padding which would make the table
-even larger, and it's already uncomfortably large (6 KiB).
-"""
-from __future__ import print_function
-from math import ceil, log
-from fractions import Fraction
-from collections import namedtuple
-
-
-N = 64  # Size of the significand field in bits
-MIN_SIG = 2 ** (N - 1)
-MAX_SIG = (2 ** N) - 1
-
-# Hand-rolled fp representation without arithmetic or any other operations.
-# The significand is normalized and always N bit, but the exponent is
-# unrestricted in range.
-Fp = namedtuple('Fp', 'sig exp')
-
-
-def algorithm_m(f, e):
-    assert f > 0
-    if e < 0:
-        u = f
-        v = 10 ** abs(e)
-    else:
-        u = f * 10 ** e
-        v = 1
-    k = 0
-    x = u // v
-    while True:
-        if x < MIN_SIG:
-            u <<= 1
-            k -= 1
-        elif x >= MAX_SIG:
-            v <<= 1
-            k += 1
-        else:
-            break
-        x = u // v
-    return ratio_to_float(u, v, k)
-
-
-def ratio_to_float(u, v, k):
-    q, r = divmod(u, v)
-    v_r = v - r
-    z = Fp(q, k)
-    if r < v_r:
-        return z
-    elif r > v_r:
-        return next_float(z)
-    elif q % 2 == 0:
-        return z
-    else:
-        return next_float(z)
+The algorithm is described in "Number Parsing at a Gigabyte per Second" by
+Daniel Lemire, available online: <https://arxiv.org/abs/2101.11408>.
 
+Also outputs the tables used by the decimal left shift of the slow path.
 
-def next_float(z):
-    if z.sig == MAX_SIG:
-        return Fp(MIN_SIG, z.exp + 1)
-    else:
-        return Fp(z.sig + 1, z.exp)
-
-
-def error(f, e, z):
-    decimal = f * Fraction(10) ** e
-    binary = z.sig * Fraction(2) ** z.exp
-    abs_err = abs(decimal - binary)
-    # The unit in the last place has value z.exp
-    ulp_err = abs_err / Fraction(2) ** z.exp
-    return float(ulp_err)
-
+Run with the output redirected to library/core/src/num/dec2flt/table.rs.
+"""
 
 HEADER = """
-//! Tables of approximations of powers of ten.
+//! Pre-computed tables powers-of-5 for extended-precision representations.
+//!
+//! These tables enable fast scaling of the significant digits
+//! of a float to the decimal exponent, with minimal rounding
+//! errors, in a 128-bit representation.
+//!
 //! DO NOT MODIFY: Generated by `src/etc/dec2flt_table.py`
 """
 
+STATIC_WARNING = """
+// Use static to avoid long compile times: Rust compiler errors
+// can have the entire table compiled multiple times, and then
+// emit code multiple times, even if it's stripped out in
+// the final binary.
+"""
+
+SMALLEST_POWER_OF_FIVE = -342
+LARGEST_POWER_OF_FIVE = 308
+N_POWERS_OF_FIVE = LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1
+
+# The largest shift performed at once by the slow path, see `slow.rs`.
+MAX_SHIFT = 60
+
 
 def main():
     print(HEADER.strip())
     print()
-    print_proper_powers()
+    print_powers_of_five()
     print()
-    print_short_powers(32, 24)
-    print()
-    print_short_powers(64, 53)
-
-
-def print_proper_powers():
-    MIN_E = -305
-    MAX_E = 305
-    e_range = range(MIN_E, MAX_E+1)
-    powers = []
-    for e in e_range:
-        z = algorithm_m(1, e)
-        err = error(1, e, z)
-        assert err < 0.5
-        powers.append(z)
-    print("pub const MIN_E: i16 = {};".format(MIN_E))
-    print("pub const MAX_E: i16 = {};".format(MAX_E))
+    print_left_shift_tables()
+
+
+def powers_of_five():
+    """Yields the normalized, 128-bit significands of 5^q for all q in range."""
+    for q in range(SMALLEST_POWER_OF_FIVE, LARGEST_POWER_OF_FIVE + 1):
+        if q < 0:
+            power5 = 5 ** -q
+            z = 0
+            while (1 << z) < power5:
+                z += 1
+            if q >= -27:
+                b = z + 127
+                c = 2 ** b // power5 + 1
+            else:
+                b = 2 * z + 2 * 64
+                c = 2 ** b // power5 + 1
+                # Truncate.
+                while c >= (1 << 128):
+                    c //= 2
+        else:
+            c = 5 ** q
+            # Move the most significant bit into position.
+            while c < (1 << 127):
+                c *= 2
+            # Truncate.
+            while c >= (1 << 128):
+                c //= 2
+        yield q, c
+
+
+def print_powers_of_five():
+    mask = (1 << 64) - 1
+    print('pub const SMALLEST_POWER_OF_FIVE: i32 = {};'.format(SMALLEST_POWER_OF_FIVE))
+    print('pub const LARGEST_POWER_OF_FIVE: i32 = {};'.format(LARGEST_POWER_OF_FIVE))
+    print('pub const N_POWERS_OF_FIVE: usize = ', end='')
+    print('(LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1) as usize;')
+    print(STATIC_WARNING.rstrip())
+    print('pub static POWER_OF_FIVE_128: [(u64, u64); N_POWERS_OF_FIVE] = [')
+    for q, c in powers_of_five():
+        hi = c >> 64
+        lo = c & mask
+        print('    ({}, {}), // 5^{}'.format(format_hex(hi), format_hex(lo), q))
+    print('];')
+
+
+def print_left_shift_tables():
+    # For a left shift by `s`, the number of new decimal digits is the number
+    # of digits in 2^s, minus one if the digits are less than those of 5^s.
+    # The entry past the last shift only marks the end of the digits of 5^MAX_SHIFT.
+    table = [0]
+    pow5 = []
+    for s in range(1, MAX_SHIFT + 2):
+        table.append(len(str(2 ** s)) << 11 | len(pow5))
+        if s <= MAX_SHIFT:
+            pow5.extend(int(c) for c in str(5 ** s))
+    assert len(pow5) < 1 << 11
+
+    print('/// The number of new digits and the offset of the digits of `5^shift` in')
+    print('/// `LEFT_SHIFT_POW5`, for every left shift of a decimal in `0..={}`.'.format(MAX_SHIFT))
+    print('#[rustfmt::skip]')
+    print('pub const LEFT_SHIFT_TABLE: [u16; {}] = ['.format(len(table)))
+    for i in range(0, len(table), 8):
+        row = ', '.join('0x{:04X}'.format(x) for x in table[i:i + 8])
+        print('    {},'.format(row))
+    print('];')
     print()
-    print("#[rustfmt::skip]")
-    typ = "([u64; {0}], [i16; {0}])".format(len(powers))
-    print("pub const POWERS: ", typ, " = (", sep='')
-    print("    [")
-    for z in powers:
-        print("        0x{:x},".format(z.sig))
-    print("    ],")
-    print("    [")
-    for z in powers:
-        print("        {},".format(z.exp))
-    print("    ],")
-    print(");")
-
-
-def print_short_powers(num_bits, significand_size):
-    max_sig = 2**significand_size - 1
-    # The fast path bails out for exponents >= ceil(log5(max_sig))
-    max_e = int(ceil(log(max_sig, 5)))
-    e_range = range(max_e)
-    typ = "[f{}; {}]".format(num_bits, len(e_range))
-    print("#[rustfmt::skip]")
-    print("pub const F", num_bits, "_SHORT_POWERS: ", typ, " = [", sep='')
-    for e in e_range:
-        print("    1e{},".format(e))
-    print("];")
+    print('/// The decimal digits of `5^1`, `5^2`, ..., concatenated.')
+    print('#[rustfmt::skip]')
+    print('pub const LEFT_SHIFT_POW5: [u8; {}] = ['.format(len(pow5)))
+    for i in range(0, len(pow5), 24):
+        print('    {},'.format(', '.join(str(d) for d in pow5[i:i + 24])))
+    print('];')
+
+
+def format_hex(num):
+    digits = '{:016X}'.format(num)
+    return '0x' + '_'.join(digits[i:i + 4] for i in range(0, 16, 4))
 
 
 if __name__ == '__main__':
@@ -826,6 +826,8 @@ fn add_test(&mut self, test: String, config: LangString, line: usize) {
                 // compiler failures are test failures
                 should_panic: testing::ShouldPanic::No,
                 allow_fail: config.allow_fail,
+                timeout: None,
+                retries: None,
                 test_type: testing::TestType::DocTest,
             },
             testfn: testing::DynTestFn(box move || {
@@ -0,0 +1,7 @@
+#[test_setup] //~ ERROR use of unstable library feature 'test_fixtures'
+fn setup() {}
+
+#[test_teardown(each)] //~ ERROR use of unstable library feature 'test_fixtures'
+fn teardown() {}
+
+fn main() {}
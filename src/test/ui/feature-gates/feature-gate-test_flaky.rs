@@ -0,0 +1,6 @@
+// check that #[flaky] is only recognized with the feature
+// compile-flags: --test
+
+#[test]
+#[flaky(retries = 3)] //~ ERROR cannot find attribute `flaky` in this scope
+fn flaky_test() {}
@@ -0,0 +1,6 @@
+// check that #[test_params] is feature-gated
+// compile-flags: --test
+
+#[test]
+#[test_params(1)] //~ ERROR the `#[test_params]` attribute is an experimental feature
+fn test_with_params(_: i32) {}
@@ -0,0 +1,6 @@
+// check that #[timeout] is only recognized with the feature
+// compile-flags: --test
+
+#[test]
+#[timeout = "30s"] //~ ERROR cannot find attribute `timeout` in this scope
+fn test_with_timeout() {}
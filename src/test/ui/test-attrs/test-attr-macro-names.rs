@@ -0,0 +1,23 @@
+// build-pass
+// aux-build:test_attr_macros.rs
+// compile-flags: --test
+
+// Tests that attribute macros can share their names with the attributes of
+// unstable test features, as long as those features are not enabled.
+
+#![allow(dead_code)]
+
+extern crate test_attr_macros;
+use test_attr_macros::{flaky, timeout};
+
+#[test]
+#[timeout(100)]
+fn test_with_timeout_macro() {}
+
+#[test]
+#[flaky]
+fn test_with_flaky_macro() {}
+
+#[timeout(100)]
+#[flaky]
+fn not_a_test() {}
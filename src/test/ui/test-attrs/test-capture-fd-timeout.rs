@@ -0,0 +1,30 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1 -Zunstable-options --capture=fd
+// run-fail
+// check-run-results
+// exec-env:RUST_BACKTRACE=0
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+
+// ignore-windows redirecting the file descriptors is only supported on unix
+// ignore-wasm no subprocess support
+// ignore-emscripten no subprocess support
+// ignore-sgx no subprocess support
+
+// Tests that with `--capture=fd`, the standard output is restored before a test
+// that timed out is reported, even though the test is still running.
+
+#![feature(test_timeout)]
+#![cfg(test)]
+
+use std::thread;
+use std::time::Duration;
+
+#[test]
+#[timeout = "100ms"]
+fn hangs() {
+    println!("captured, and never shown as the test doesn't finish");
+    loop {
+        thread::sleep(Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,53 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1 -Zunstable-options --capture=fd
+// run-fail
+// check-run-results
+// exec-env:RUST_BACKTRACE=0
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+
+// ignore-windows redirecting the file descriptors is only supported on unix
+// ignore-wasm no subprocess support
+// ignore-emscripten no subprocess support
+// ignore-sgx no subprocess support
+
+// Tests that `--capture=fd` captures everything written to the standard output
+// and error, not just the output of the print macros.
+
+#![cfg(test)]
+
+use std::fs::File;
+use std::io::Write;
+use std::mem::ManuallyDrop;
+use std::os::unix::io::FromRawFd;
+use std::process::Command;
+use std::thread;
+
+fn write_to_fd(fd: i32, message: &str) {
+    let mut file = ManuallyDrop::new(unsafe { File::from_raw_fd(fd) });
+    file.write_all(message.as_bytes()).unwrap();
+}
+
+#[test]
+fn from_child_process() {
+    Command::new("echo").arg("written by a child process").status().unwrap();
+    panic!("failed");
+}
+
+#[test]
+fn from_fds() {
+    write_to_fd(1, "written to stdout\n");
+    write_to_fd(2, "written to stderr\n");
+    panic!("failed");
+}
+
+#[test]
+fn from_thread() {
+    thread::spawn(|| println!("printed by a thread")).join().unwrap();
+    panic!("failed");
+}
+
+#[test]
+fn passes() {
+    write_to_fd(1, "not shown, as the test passes\n");
+}
@@ -0,0 +1,11 @@
+// compile-flags: --test
+#![feature(test_fixtures)]
+
+#[test_setup(always)] //~ ERROR malformed `test_setup` attribute input
+fn unknown_scope() {}
+
+#[test_teardown(each, each)] //~ ERROR malformed `test_teardown` attribute input
+fn two_scopes() {}
+
+#[test_setup]
+fn with_arg(_: i32) {} //~ ERROR functions used as tests can not have any arguments
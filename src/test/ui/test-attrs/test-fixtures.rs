@@ -0,0 +1,53 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1 --nocapture
+// run-pass
+// check-run-results
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+// ignore-wasm32 no support for `Instant`
+
+// Tests that setup and teardown functions run once, or around each test.
+
+#![feature(test_fixtures)]
+#![cfg(test)]
+
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+static SETUPS: AtomicUsize = AtomicUsize::new(0);
+static SETUPS_EACH: AtomicUsize = AtomicUsize::new(0);
+static TEARDOWNS_EACH: AtomicUsize = AtomicUsize::new(0);
+
+#[test_setup]
+fn setup() {
+    SETUPS.fetch_add(1, SeqCst);
+}
+
+#[test_setup(each)]
+fn setup_each() {
+    assert_eq!(SETUPS.load(SeqCst), 1);
+    SETUPS_EACH.fetch_add(1, SeqCst);
+}
+
+#[test_teardown(each)]
+fn teardown_each() {
+    TEARDOWNS_EACH.fetch_add(1, SeqCst);
+}
+
+#[test_teardown]
+fn teardown() {
+    assert_eq!(SETUPS_EACH.load(SeqCst), 2);
+    assert_eq!(TEARDOWNS_EACH.load(SeqCst), 2);
+    println!("all tests were set up and torn down");
+}
+
+#[test]
+fn first() {
+    assert_eq!(SETUPS.load(SeqCst), 1);
+    assert_eq!(SETUPS_EACH.load(SeqCst), TEARDOWNS_EACH.load(SeqCst) + 1);
+}
+
+#[test]
+fn second() {
+    assert_eq!(SETUPS.load(SeqCst), 1);
+    assert_eq!(SETUPS_EACH.load(SeqCst), TEARDOWNS_EACH.load(SeqCst) + 1);
+}
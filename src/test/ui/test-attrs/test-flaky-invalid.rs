@@ -0,0 +1,22 @@
+// compile-flags: --test
+#![feature(test_fixtures)]
+#![feature(test_flaky)]
+
+#[test]
+#[flaky(retries = "3")] //~ ERROR malformed `flaky` attribute input
+fn string_retries() {}
+
+#[test]
+#[flaky(attempts = 3)] //~ ERROR malformed `flaky` attribute input
+fn unknown_argument() {}
+
+#[test_setup]
+#[flaky(retries = 3)] //~ ERROR `#[flaky]` can only be used on `#[test]` functions
+fn setup() {}
+
+#[test]
+#[flaky] //~ ERROR malformed `flaky` attribute input
+fn no_arguments() {}
+
+#[flaky(retries = 3)] //~ ERROR cannot find attribute `flaky` in this scope
+fn not_a_test() {}
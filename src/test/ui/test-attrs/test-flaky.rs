@@ -0,0 +1,28 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1
+// run-pass
+// check-run-results
+// exec-env:RUST_BACKTRACE=0
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+// ignore-wasm32 no support for `Instant`
+
+// Tests that a failing test is rerun, and reported as flaky once it passes.
+
+#![feature(test_flaky)]
+#![cfg(test)]
+
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+#[flaky(retries = 2)]
+fn passes_on_third_attempt() {
+    let attempt = ATTEMPTS.fetch_add(1, SeqCst) + 1;
+    assert!(attempt == 3, "attempt {}", attempt);
+}
+
+#[test]
+#[flaky(retries = 2)]
+fn passes() {}
@@ -0,0 +1,17 @@
+// compile-flags: --test
+#![feature(test_params)]
+
+#[test]
+#[test_params(1, 2)] //~ ERROR this test case has 2 arguments but `one_arg` takes 1 argument
+fn one_arg(_: i32) {}
+
+#[test]
+#[test_params(1; skip)] //~ ERROR expected `ignore`
+fn unknown_option(_: i32) {}
+
+#[test]
+#[test_params = "1"] //~ ERROR malformed `test_params` attribute input
+fn not_a_list(_: i32) {}
+
+#[test]
+fn no_params(_: i32) {} //~ ERROR functions used as tests can not have any arguments
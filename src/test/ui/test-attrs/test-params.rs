@@ -0,0 +1,49 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1
+// run-pass
+// check-run-results
+// normalize-stdout-test "finished in \d+\.\d+s" -> "finished in $$TIME"
+// ignore-wasm32 no support for `Instant`
+
+// Tests that every case of a parameterized test is run and reported on its own.
+
+#![feature(test_params)]
+#![cfg(test)]
+
+#[derive(Debug, PartialEq)]
+enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+#[test]
+#[test_params(-3, Sign::Negative)]
+#[test_params(0, Sign::Zero)]
+#[test_params(i32::MAX, Sign::Positive)]
+#[test_params(1, Sign::Negative; ignore)]
+fn sign(n: i32, expected: Sign) {
+    let sign = match n {
+        n if n < 0 => Sign::Negative,
+        0 => Sign::Zero,
+        _ => Sign::Positive,
+    };
+    assert_eq!(sign, expected);
+}
+
+#[test]
+#[test_params("hello", 5)]
+#[test_params("", 0)]
+fn len(s: &str, len: usize) -> Result<(), String> {
+    if s.len() == len { Ok(()) } else { Err(format!("{:?} isn't {} bytes long", s, len)) }
+}
+
+mod nested {
+    #[test]
+    #[test_params(vec![1, 2, 3])]
+    #[should_panic]
+    fn out_of_bounds(v: Vec<u8>) {
+        let _ = v[3];
+    }
+}
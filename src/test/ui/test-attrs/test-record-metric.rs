@@ -0,0 +1,28 @@
+// no-prefer-dynamic
+// compile-flags: --test
+// run-flags: --test-threads=1 -Zunstable-options --format=json
+// run-pass
+// check-run-results
+// normalize-stdout-test "(exec_time.): [0-9.]+" -> "${1}: $$TIME"
+
+// Tests that the metrics recorded with `test::record_metric` are reported by
+// the JSON output format.
+
+#![cfg(test)]
+#![feature(test)]
+
+extern crate test;
+
+use std::thread;
+
+#[test]
+fn no_metrics() {}
+
+#[test]
+fn records_metrics() {
+    test::record_metric("bytes_allocated", 512.0, 64.0);
+    test::record_metric("bytes_allocated", 1024.0, 64.0);
+    test::record_metric("items_per_call", 2.5, -0.5);
+    // Metrics recorded outside of the test's thread are ignored.
+    thread::spawn(|| test::record_metric("from_thread", 1.0, 0.0)).join().unwrap();
+}
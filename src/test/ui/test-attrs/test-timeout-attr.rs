@@ -0,0 +1,15 @@
+// run-pass
+// ignore-wasm32-bare compiled with panic=abort by default
+// compile-flags: --test
+#![feature(test_timeout)]
+
+#[test]
+#[timeout = "30s"]
+fn finishes_in_time() {}
+
+#[test]
+#[timeout = "500ms"]
+#[should_panic]
+fn panics_in_time() {
+    panic!();
+}
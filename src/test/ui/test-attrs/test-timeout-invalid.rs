@@ -0,0 +1,17 @@
+// compile-flags: --test
+#![feature(test_timeout)]
+
+#[test]
+#[timeout = "30"] //~ ERROR invalid test timeout `30`
+fn missing_unit() {}
+
+#[test]
+#[timeout = "1h"] //~ ERROR invalid test timeout `1h`
+fn unknown_unit() {}
+
+#[test]
+#[timeout(30)] //~ ERROR malformed `timeout` attribute input
+fn not_a_string() {}
+
+#[timeout = "30s"] //~ ERROR cannot find attribute `timeout` in this scope
+fn not_a_test() {}
@@ -481,6 +481,25 @@ pub fn test_opts(config: &Config) -> test::TestOpts {
         options: test::Options::new(),
         time_options: None,
         force_run_in_process: false,
+        // compiletest is built against the libtest of the bootstrap compiler.
+        #[cfg(not(bootstrap))]
+        shuffle: false,
+        #[cfg(not(bootstrap))]
+        shuffle_seed: None,
+        #[cfg(not(bootstrap))]
+        partition: None,
+        #[cfg(not(bootstrap))]
+        test_timeout: None,
+        #[cfg(not(bootstrap))]
+        retries: 0,
+        #[cfg(not(bootstrap))]
+        save_baseline: None,
+        #[cfg(not(bootstrap))]
+        baseline: None,
+        #[cfg(not(bootstrap))]
+        ratchet_metrics: None,
+        #[cfg(not(bootstrap))]
+        capture: test::CaptureMode::Print,
     }
 }
 
@@ -639,6 +658,11 @@ fn make_test(config: &Config, testpaths: &TestPaths, inputs: &Stamp) -> Vec<test
                     ignore,
                     should_panic,
                     allow_fail: false,
+                    // compiletest is built against the libtest of the bootstrap compiler.
+                    #[cfg(not(bootstrap))]
+                    timeout: None,
+                    #[cfg(not(bootstrap))]
+                    retries: None,
                     test_type: test::TestType::Unknown,
                 },
                 testfn: make_test_closure(config, testpaths, revision),